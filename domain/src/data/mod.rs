@@ -1,7 +1,7 @@
 use crate::data::{
     health_check::HealthCheck,
     repository::DatabaseRepository,
-    service::{CacheService, PublisherService, StorageService},
+    service::{CacheService, PublisherService, ScannerService, StorageService},
 };
 
 pub mod health_check;
@@ -17,4 +17,6 @@ pub trait CacheServiceWithHealthCheck: CacheService + HealthCheck + Send + Sync
 
 pub trait PublisherServiceWithHealthCheck: PublisherService + HealthCheck + Send + Sync {}
 
+pub trait ScannerServiceWithHealthCheck: ScannerService + HealthCheck + Send + Sync {}
+
 pub trait StorageServiceWithHealthCheck: StorageService + HealthCheck + Send + Sync {}
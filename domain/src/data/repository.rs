@@ -1,23 +1,162 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
-use crate::{entities::TermOfUse, errors::Result};
+use crate::{
+    entities::{
+        Bundle, Group, TermMetadata, TermOfUse, TermReservation, UploadPolicy, UserAgreement,
+    },
+    errors::Result,
+};
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait TermRepository: Send + Sync {
     async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>>;
 
+    /// Latest version of each of `groups` at once, groups without terms are left out.
+    async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>>;
+
     async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>>;
 
+    async fn get_term_by_version(&self, group: &str, version: u32) -> Result<Option<TermOfUse>>;
+
+    /// All versions of a group, newest first.
+    ///
+    /// Only versions whose metadata contains every top-level entry of `metadata` are returned,
+    /// an empty filter returns all of them. Versions may come without their `html` and `text`,
+    /// which only single terms are read with.
+    async fn get_terms_for_group(
+        &self,
+        group: &str,
+        metadata: &TermMetadata,
+    ) -> Result<Vec<TermOfUse>>;
+
+    /// Fails with `Validation` when the group already has a term of that version.
     async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse>;
+
+    /// Every term of every group, which may come without their `html` and `text`.
+    async fn get_all_terms(&self) -> Result<Vec<TermOfUse>>;
 }
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait UserAgreementRepository: Send + Sync {
-    async fn has_user_agreed_to_term(&self, user_id: i32, term_id: i32) -> Result<bool>;
+    /// When the user last agreed to the term, `None` without an agreement.
+    async fn get_agreed_at(&self, user_id: i32, term_id: i32) -> Result<Option<NaiveDateTime>>;
+
+    /// Terms among `term_ids` the user agreed to, read at once.
+    async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>>;
+
+    /// Newest version of `group` the user agreed to, only versions from `min_version` on count.
+    async fn get_newest_agreed_version(
+        &self,
+        user_id: i32,
+        group: &str,
+        min_version: u32,
+    ) -> Result<Option<u32>>;
+
+    /// Optional clauses the user accepted with their last agreement to the term, `None` without
+    /// an agreement.
+    async fn get_accepted_clauses(&self, user_id: i32, term_id: i32)
+    -> Result<Option<Vec<String>>>;
+
+    /// Agreement to the term in effect at `at`, the newest one given by then.
+    async fn get_agreement_at(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<Option<UserAgreement>>;
+
+    /// Records an agreement to the term. Agreeing again, e.g. once the consent expired, keeps
+    /// the earlier agreements.
+    async fn create_user_agreement(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        accepted_clauses: &[String],
+    ) -> Result<()>;
+
+    /// Records agreements to all terms at once, either every one of them is stored or none.
+    ///
+    /// Only the mandatory clauses of the terms are accepted.
+    async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait TermReservationRepository: Send + Sync {
+    /// Fails with `Validation` when the version of the group is already reserved.
+    async fn create_reservation(&self, reservation: TermReservation) -> Result<TermReservation>;
+
+    async fn get_reservation(&self, reservation_id: i32) -> Result<Option<TermReservation>>;
+
+    /// Reservation of a version of a group, expired or not.
+    async fn get_reservation_for_version(
+        &self,
+        group: &str,
+        version: u32,
+    ) -> Result<Option<TermReservation>>;
+
+    /// Claims a reservation until `until`, telling whether this call got the claim. Of
+    /// concurrent claims only one succeeds, until the claim is released or runs out.
+    async fn claim_reservation(&self, reservation_id: i32, until: NaiveDateTime) -> Result<bool>;
+
+    async fn release_reservation(&self, reservation_id: i32) -> Result<()>;
+
+    async fn delete_reservation(&self, reservation_id: i32) -> Result<()>;
+
+    /// All reservations, expired or not.
+    async fn get_all_reservations(&self) -> Result<Vec<TermReservation>>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait UploadPolicyRepository: Send + Sync {
+    async fn get_upload_policy(&self, group: &str) -> Result<Option<UploadPolicy>>;
+
+    /// Creates the policy of a group or replaces the existing one.
+    async fn save_upload_policy(&self, policy: UploadPolicy) -> Result<UploadPolicy>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait GroupRepository: Send + Sync {
+    async fn get_group(&self, name: &str) -> Result<Option<Group>>;
+
+    /// All registered groups, ordered by name.
+    async fn get_groups(&self) -> Result<Vec<Group>>;
 
-    async fn create_user_agreement(&self, user_id: i32, term_id: i32) -> Result<()>;
+    async fn create_group(&self, group: Group) -> Result<Group>;
+
+    /// Replaces the properties of an existing group.
+    async fn update_group(&self, group: Group) -> Result<Group>;
+
+    async fn delete_group(&self, name: &str) -> Result<()>;
+}
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait BundleRepository: Send + Sync {
+    async fn get_bundle(&self, name: &str) -> Result<Option<Bundle>>;
+
+    /// All bundles, ordered by name.
+    async fn get_bundles(&self) -> Result<Vec<Bundle>>;
+
+    /// Creates the bundle or replaces the existing one.
+    async fn save_bundle(&self, bundle: Bundle) -> Result<Bundle>;
+
+    async fn delete_bundle(&self, name: &str) -> Result<()>;
 }
 
-pub trait DatabaseRepository: TermRepository + UserAgreementRepository + Send + Sync {}
+pub trait DatabaseRepository:
+    TermRepository
+    + UserAgreementRepository
+    + TermReservationRepository
+    + UploadPolicyRepository
+    + GroupRepository
+    + BundleRepository
+    + Send
+    + Sync
+{
+}
@@ -1,17 +1,44 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 
-use crate::{entities::TermOfUse, errors::Result};
+use crate::{
+    entities::{ConsentStatus, TermOfUse},
+    errors::Result,
+};
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait CacheService: Send + Sync {
-    async fn find_user_agreement(&self, user_id: i32, group: &str) -> Result<Option<bool>>;
+    async fn find_user_agreement(&self, user_id: i32, group: &str)
+    -> Result<Option<ConsentStatus>>;
 
-    async fn store_user_agreement(&self, user_id: i32, group: &str, agreed: bool) -> Result<()>;
+    /// Stores the status of a user, the entry must not outlive `expires_at` when given.
+    async fn store_user_agreement(
+        &self,
+        user_id: i32,
+        group: &str,
+        status: ConsentStatus,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<()>;
+
+    /// Newest version of a group the user is known to have agreed to.
+    async fn find_agreed_version(&self, user_id: i32, group: &str) -> Result<Option<u32>>;
+
+    /// Stores the newest agreed version, the entry must not outlive `expires_at` when given.
+    async fn store_agreed_version(
+        &self,
+        user_id: i32,
+        group: &str,
+        version: u32,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<()>;
 
     async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>>;
 
     async fn store_latest_term_for_group(&self, term: &TermOfUse) -> Result<()>;
 
     async fn invalidate_cache_for_group(&self, group: &str) -> Result<()>;
+
+    /// Removes the cached consents of all users to a group, e.g. once their expiry changed.
+    async fn invalidate_consents_for_group(&self, group: &str) -> Result<()>;
 }
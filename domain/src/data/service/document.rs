@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::{dto::DiffHunk, entities::TermDocument, errors::Result};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait DocumentService: Send + Sync {
+    /// Reads the rendered HTML, plain text and PDF metadata of a local document.
+    ///
+    /// Fails with a validation error for documents clients could not open, such as
+    /// encrypted PDFs or HTML that is not UTF-8 encoded.
+    async fn read_document(&self, file: &Path, content_type: &str) -> Result<TermDocument>;
+
+    /// Hex encoded SHA-256 digest of a local document.
+    async fn sha256(&self, file: &Path) -> Result<String>;
+
+    /// Renders Markdown to sanitized HTML.
+    fn render_markdown(&self, markdown: &str) -> String;
+
+    /// Compares two texts line by line, keeping `context_lines` unchanged lines around
+    /// every change.
+    fn diff_text(&self, old: &str, new: &str, context_lines: usize) -> Vec<DiffHunk>;
+}
@@ -1,14 +1,26 @@
 mod cache;
+mod document;
 mod publisher;
+mod scanner;
+mod schema;
 mod storage;
 
 pub use cache::CacheService;
+pub use document::DocumentService;
 pub use publisher::PublisherService;
+pub use scanner::ScannerService;
+pub use schema::SchemaService;
 pub use storage::StorageService;
 
 #[cfg(test)]
 pub use cache::MockCacheService;
 #[cfg(test)]
+pub use document::MockDocumentService;
+#[cfg(test)]
 pub use publisher::MockPublisherService;
 #[cfg(test)]
+pub use scanner::MockScannerService;
+#[cfg(test)]
+pub use schema::MockSchemaService;
+#[cfg(test)]
 pub use storage::MockStorageService;
@@ -0,0 +1,12 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+
+use crate::{entities::ScanVerdict, errors::Result};
+
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub trait ScannerService: Send + Sync {
+    /// Scans a document before it is stored, failing only when the scan itself could not run.
+    async fn scan_file(&self, file: &Path) -> Result<ScanVerdict>;
+}
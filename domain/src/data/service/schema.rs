@@ -0,0 +1,13 @@
+use serde_json::Value;
+
+use crate::errors::Result;
+
+#[cfg_attr(test, mockall::automock)]
+pub trait SchemaService: Send + Sync {
+    /// Fails with a validation error when `schema` is not a valid JSON Schema.
+    fn check_schema(&self, schema: &Value) -> Result<()>;
+
+    /// Lists every way `instance` violates `schema`, each prefixed with the path of the
+    /// offending value when it is not the instance itself.
+    fn violations(&self, schema: &Value, instance: &Value) -> Result<Vec<String>>;
+}
@@ -1,15 +1,70 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use async_trait::async_trait;
 
-use crate::errors::Result;
+use crate::{
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
+    errors::Result,
+};
 
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub trait StorageService: Send + Sync {
-    async fn upload_file(&self, file: &Path, content_type: &str) -> Result<String>;
+    async fn upload_file(
+        &self,
+        file: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String>;
+
+    /// Stores a file under an exact key, failing if the key is already taken.
+    async fn put_file(
+        &self,
+        key: &str,
+        file: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()>;
+
+    /// Marks an uploaded file as published by the given term. Published files
+    /// are labelled with the term id, kept immutable for the configured
+    /// retention period and can no longer be removed with `delete_file`.
+    async fn publish_file(&self, path: &str, term_id: i32) -> Result<()>;
 
     async fn delete_file(&self, path: &str) -> Result<()>;
 
     async fn get_file_url(&self, path: &str) -> Result<String>;
+
+    /// Downloads a stored file to a local path, overwriting it if it exists.
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()>;
+
+    /// Presigns a request uploading a document straight to the backend, so large
+    /// files do not pass through the service. Backends that can enforce the size or
+    /// the SHA-256 digest of the upload make them part of the signature.
+    async fn create_upload_url(
+        &self,
+        group: &str,
+        version: u32,
+        content_type: &str,
+        size: u64,
+        sha256: &str,
+        expires_in: Duration,
+    ) -> Result<PresignedUpload>;
+
+    /// Reads the properties of a stored file, `None` when it does not exist.
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>>;
+
+    /// Lists every document stored by the service, including unreferenced ones. A
+    /// document is listed once per copy, see `copies`.
+    async fn list_files(&self) -> Result<Vec<StoredFile>>;
+
+    /// Number of copies the service keeps of every document, e.g. one per mirrored
+    /// backend.
+    fn copies(&self) -> usize;
+
+    /// Whether `list_files` lists the whole bucket, including objects of others
+    /// sharing it, because the key template does not start with a prefix.
+    fn lists_whole_bucket(&self) -> bool;
 }
@@ -1,7 +1,39 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDateTime;
+
+use crate::entities::{Clause, PresignedUpload, TermMetadata, TermOfUse, UserAgreement};
+
 #[derive(Debug)]
 pub struct CreateTermOfUseDTO {
     pub group: String,
     pub info: Option<String>,
+    /// Markdown summaries of what changed, keyed by locale.
+    pub change_summaries: BTreeMap<String, String>,
+    pub metadata: TermMetadata,
+    pub clauses: Vec<Clause>,
+}
+
+#[derive(Debug)]
+pub struct ReserveTermOfUseDTO {
+    pub group: String,
+    pub info: Option<String>,
+    pub content_type: String,
+    pub size: u64,
+    /// Hex SHA-256 digest of the document that will be uploaded.
+    pub sha256: String,
+    /// Markdown summaries of what changed, keyed by locale.
+    pub change_summaries: BTreeMap<String, String>,
+    pub metadata: TermMetadata,
+    pub clauses: Vec<Clause>,
+}
+
+#[derive(Debug)]
+pub struct TermReservationDTO {
+    pub reservation_id: i32,
+    pub version: u32,
+    pub upload: PresignedUpload,
+    pub expires_at: NaiveDateTime,
 }
 
 #[derive(Debug)]
@@ -10,4 +42,88 @@ pub struct AcceptedTermOfUseDTO {
     pub term_id: i32,
     pub user_id: i32,
     pub group: String,
+    /// Optional clauses of the term the user accepted.
+    pub accepted_clauses: Vec<String>,
+}
+
+/// Where a user stood with a group at a point in time.
+#[derive(Debug)]
+pub struct ConsentAtDTO {
+    /// Version of the group in effect at the time.
+    pub term: TermOfUse,
+    /// Agreement to that version given by then, `None` when the user had not agreed yet.
+    pub agreement: Option<UserAgreement>,
+    /// Whether the agreement was in force at the time, it may have expired already.
+    pub has_consented: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct ReconciliationReportDTO {
+    /// Stored files not referenced by any term and older than the grace period.
+    pub orphaned_files: Vec<String>,
+    /// Orphaned files removed during this run.
+    pub deleted_files: Vec<String>,
+    /// Terms whose document is not in storage anymore.
+    pub missing_documents: Vec<TermOfUse>,
+}
+
+#[derive(Debug, Default)]
+pub struct PublicationReportDTO {
+    pub published_terms: Vec<TermOfUse>,
+    /// Terms whose document could not be published, e.g. because it is missing.
+    pub failed_terms: Vec<TermOfUse>,
+}
+
+#[derive(Debug, Default)]
+pub struct StorageCopyReportDTO {
+    /// Documents copied during this run, or that would be copied in a dry run.
+    pub copied_documents: Vec<CopiedDocumentDTO>,
+    /// Terms whose document already is intact in the destination, e.g. from an
+    /// interrupted run.
+    pub skipped_terms: Vec<TermOfUse>,
+    /// Terms whose document could not be copied or verified.
+    pub failed_terms: Vec<TermOfUse>,
+}
+
+#[derive(Debug)]
+pub struct CopiedDocumentDTO {
+    pub term_id: i32,
+    /// Key of the document, the same in both backends.
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DiffOperation {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffLine {
+    pub operation: DiffOperation,
+    /// 1-based line in the older version, `None` for inserted lines.
+    pub old_line: Option<usize>,
+    /// 1-based line in the newer version, `None` for deleted lines.
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+/// Changed lines with their surrounding context, like a hunk of a unified diff.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DiffHunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+#[derive(Debug)]
+pub struct TermDiffDTO {
+    pub group: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Empty when the texts of both versions are identical.
+    pub hunks: Vec<DiffHunk>,
 }
@@ -1,4 +1,19 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDateTime, TimeDelta};
+
+/// Content types accepted for term documents.
+pub const DOCUMENT_CONTENT_TYPES: [&str; 3] = ["application/pdf", "text/markdown", "text/html"];
+
+/// Term fields an upload policy can require every version to set.
+pub const REQUIRABLE_TERM_FIELDS: [&str; 3] = ["info", "changeSummaries", "metadata"];
+
+/// Largest document an upload policy may accept, in bytes.
+pub const MAX_DOCUMENT_SIZE: u64 = 100 * 1024 * 1024;
+
+/// Size limit of groups without an upload policy, in bytes.
+pub const DEFAULT_MAX_DOCUMENT_SIZE: u64 = 20 * 1024 * 1024;
+
+/// Structured metadata of a term, a JSON object.
+pub type TermMetadata = serde_json::Map<String, serde_json::Value>;
 
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -9,4 +24,209 @@ pub struct TermOfUse {
     pub version: u32,
     pub info: Option<String>,
     pub created_at: NaiveDateTime,
+    /// Sanitized HTML body of Markdown and HTML documents, rendered at upload time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub html: Option<String>,
+    /// Plain text extracted from the document at upload time, used to compare versions.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub text: Option<String>,
+    /// What changed compared to the previous version, one entry per locale.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub change_summaries: Vec<ChangeSummary>,
+    /// Read from PDF documents at upload time.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub pdf_metadata: Option<PdfMetadata>,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub metadata: TermMetadata,
+    /// Named parts of the term users accept, some of them optional.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub clauses: Vec<Clause>,
+}
+
+/// Named part of a term, such as a processing purpose of a privacy policy.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Clause {
+    /// Identifies the clause across versions, e.g. `marketing-emails`.
+    pub key: String,
+    pub title: String,
+    /// Mandatory clauses are accepted with the term, optional ones only when chosen.
+    pub mandatory: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PdfMetadata {
+    pub page_count: u32,
+    pub title: Option<String>,
+    pub producer: Option<String>,
+}
+
+/// Content read from a term document before the term is stored.
+///
+/// Multipart and direct uploads both build their terms from it, so a term carries
+/// the same fields whichever way its document was uploaded.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TermDocument {
+    /// Sanitized HTML of Markdown and HTML documents.
+    pub html: Option<String>,
+    /// Plain text, `None` when it could not be extracted.
+    pub text: Option<String>,
+    pub pdf_metadata: Option<PdfMetadata>,
+}
+
+/// Release notes of a term version in one locale.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChangeSummary {
+    /// BCP 47 language tag, e.g. `en` or `de-CH`.
+    pub locale: String,
+    pub markdown: String,
+    /// Sanitized HTML rendered from `markdown`.
+    pub html: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredFile {
+    pub key: String,
+    pub last_modified: NaiveDateTime,
+}
+
+/// Term waiting for its document to be uploaded straight to storage.
+#[derive(Debug, Clone)]
+pub struct TermReservation {
+    pub id: i32,
+    pub group: String,
+    pub version: u32,
+    pub info: Option<String>,
+    pub key: String,
+    pub content_type: String,
+    pub size: u64,
+    /// Hex SHA-256 digest announced by the client.
+    pub sha256: String,
+    pub expires_at: NaiveDateTime,
+    /// Validated when reserving, so finalizing cannot fail on them.
+    pub change_summaries: Vec<ChangeSummary>,
+    pub metadata: TermMetadata,
+    pub clauses: Vec<Clause>,
+}
+
+/// Presigned request uploading a document straight to the storage backend.
+#[derive(Debug, Clone)]
+pub struct PresignedUpload {
+    pub key: String,
+    pub method: String,
+    pub url: String,
+    /// Headers the client must send along, as they are part of the signature.
+    pub headers: Vec<(String, String)>,
+}
+
+/// Properties of a stored document, used to verify direct uploads.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredFileInfo {
+    pub size: u64,
+    pub content_type: Option<String>,
+    /// Hex SHA-256 digest, when the backend verified one during the upload.
+    pub sha256: Option<String>,
+}
+
+/// Outcome of scanning a document for malware.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScanVerdict {
+    Clean,
+    /// Name of the signature that matched, as reported by the scanner.
+    Infected(String),
+}
+
+/// Where a user stands with the latest term of a group.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "status", rename_all = "snake_case"))]
+pub enum ConsentStatus {
+    ConsentedToLatest,
+    /// Agreed to the latest version longer ago than the group allows.
+    ConsentExpired,
+    /// Agreed to older versions only, `version` being the newest of them.
+    ConsentedToOlder {
+        version: u32,
+    },
+    NeverConsented,
+    /// The group has no terms to agree to.
+    NoTermsPublished,
+}
+
+impl ConsentStatus {
+    /// Whether the user is up to date with the group.
+    pub fn has_consented(&self) -> bool {
+        matches!(self, ConsentStatus::ConsentedToLatest)
+    }
+}
+
+/// A user's agreement to a term.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UserAgreement {
+    pub user_id: i32,
+    pub term_id: i32,
+    pub agreed_at: NaiveDateTime,
+    /// Optional clauses of the term the user accepted.
+    pub accepted_clauses: Vec<String>,
+}
+
+/// A registered group of terms, e.g. `privacy-policy`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Group {
+    pub name: String,
+    pub description: Option<String>,
+    /// Team owning the terms of the group.
+    pub owner: Option<String>,
+    /// Whether users must consent to the terms of the group to use the product.
+    pub mandatory: bool,
+    /// BCP 47 language tag the documents of the group are written in.
+    pub default_locale: String,
+    /// Days after which users must agree again, agreements never expire without it.
+    pub max_consent_age_days: Option<u32>,
+}
+
+impl Group {
+    /// When an agreement given at `agreed_at` expires, `None` when agreements never do.
+    pub fn consent_expiry(&self, agreed_at: NaiveDateTime) -> Option<NaiveDateTime> {
+        self.max_consent_age_days
+            .map(|days| agreed_at + TimeDelta::days(days.into()))
+    }
+}
+
+/// Named set of groups users consent to together, e.g. at sign-up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bundle {
+    pub name: String,
+    pub description: Option<String>,
+    /// Registered groups of the bundle, in the order they are presented.
+    pub groups: Vec<String>,
+}
+
+/// Rules the documents of a group must follow.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadPolicy {
+    pub group: String,
+    /// Accepted content types, a subset of `DOCUMENT_CONTENT_TYPES`.
+    pub content_types: Vec<String>,
+    /// Largest accepted document, in bytes.
+    pub max_size: u64,
+    /// Fields every version must set, a subset of `REQUIRABLE_TERM_FIELDS`.
+    pub required_fields: Vec<String>,
+    /// JSON Schema the metadata of every version must satisfy.
+    pub metadata_schema: Option<serde_json::Value>,
+}
+
+impl UploadPolicy {
+    /// Policy of groups that have not configured one.
+    pub fn default_for(group: &str) -> Self {
+        Self {
+            group: group.to_string(),
+            content_types: DOCUMENT_CONTENT_TYPES.map(String::from).to_vec(),
+            max_size: DEFAULT_MAX_DOCUMENT_SIZE,
+            required_fields: vec![],
+            metadata_schema: None,
+        }
+    }
 }
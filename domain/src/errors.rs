@@ -1,6 +1,8 @@
 #[derive(Debug)]
 pub enum TermsOfUseError {
     NotFound,
+    /// The request was understood but rejected, the message is safe to show to clients.
+    Validation(String),
     InternalServerError,
 }
 
@@ -0,0 +1,188 @@
+use chrono::Utc;
+
+use crate::{
+    data::{
+        repository::{BundleRepository, DatabaseRepository},
+        service::{CacheService, PublisherService},
+    },
+    dto::AcceptedTermOfUseDTO,
+    entities::{Bundle, ConsentStatus},
+    errors::{Result, TermsOfUseError},
+    use_cases::{
+        check_group_use_case, get_consent_status_use_case,
+        group::{MAX_GROUP_NAME_LENGTH, is_group_name},
+        has_agreed_to_terms::consent_status_for_term,
+    },
+};
+
+/// Most groups a bundle may contain, keeping its agreements within a single transaction.
+const MAX_BUNDLE_GROUPS: usize = 25;
+
+fn validate_bundle(bundle: &Bundle) -> Result<()> {
+    if !is_group_name(&bundle.name) {
+        return Err(TermsOfUseError::Validation(format!(
+            "The bundle name must be 1 to {MAX_GROUP_NAME_LENGTH} letters, digits, '-', '_' or '.'"
+        )));
+    }
+
+    if bundle.groups.is_empty() || bundle.groups.len() > MAX_BUNDLE_GROUPS {
+        return Err(TermsOfUseError::Validation(format!(
+            "A bundle must contain 1 to {MAX_BUNDLE_GROUPS} groups"
+        )));
+    }
+
+    if let Some(group) = bundle
+        .groups
+        .iter()
+        .enumerate()
+        .find_map(|(index, group)| bundle.groups[..index].contains(group).then_some(group))
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "Group '{group}' is listed more than once"
+        )));
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn list_bundles_use_case(repository: &dyn BundleRepository) -> Result<Vec<Bundle>> {
+    repository.get_bundles().await
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn get_bundle_use_case(repository: &dyn BundleRepository, name: &str) -> Result<Bundle> {
+    repository
+        .get_bundle(name)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)
+}
+
+/// Creates or replaces a bundle of registered groups.
+#[tracing::instrument(skip(repository))]
+pub async fn save_bundle_use_case(
+    repository: &dyn DatabaseRepository,
+    bundle: Bundle,
+) -> Result<Bundle> {
+    validate_bundle(&bundle)?;
+
+    for group in &bundle.groups {
+        check_group_use_case(repository, group).await?;
+    }
+
+    repository.save_bundle(bundle).await
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn delete_bundle_use_case(repository: &dyn BundleRepository, name: &str) -> Result<()> {
+    if repository.get_bundle(name).await?.is_none() {
+        return Err(TermsOfUseError::NotFound);
+    }
+
+    repository.delete_bundle(name).await
+}
+
+/// Records the agreement of a user to the latest term of every group in the bundle atomically.
+///
+/// Terms the user already agreed to are left alone, keeping the optional clauses accepted
+/// with them, unless their consent expired.
+#[tracing::instrument(skip(repository, cache, publisher, user_id))]
+pub async fn create_bundle_agreement_use_case(
+    repository: &dyn DatabaseRepository,
+    cache: &dyn CacheService,
+    publisher: &dyn PublisherService,
+    user_id: i32,
+    name: &str,
+) -> Result<()> {
+    let bundle = get_bundle_use_case(repository, name).await?;
+
+    let mut terms = Vec::with_capacity(bundle.groups.len());
+    for group in &bundle.groups {
+        let term = repository
+            .get_latest_term_for_group(group)
+            .await?
+            .ok_or_else(|| {
+                TermsOfUseError::Validation(format!("Group '{group}' has no terms to agree to"))
+            })?;
+
+        terms.push(term);
+    }
+
+    let term_ids: Vec<i32> = terms.iter().map(|term| term.id).collect();
+    let agreed_term_ids = repository.get_agreed_term_ids(user_id, &term_ids).await?;
+
+    let mut pending_terms = Vec::with_capacity(terms.len());
+    for term in terms {
+        if agreed_term_ids.contains(&term.id) {
+            let (status, _) = consent_status_for_term(repository, user_id, &term).await?;
+
+            if status != ConsentStatus::ConsentExpired {
+                continue;
+            }
+        }
+
+        pending_terms.push(term);
+    }
+    let terms = pending_terms;
+
+    if terms.is_empty() {
+        return Ok(());
+    }
+
+    // Taken before storing, so cached consents never outlive the stored ones
+    let agreed_at = Utc::now().naive_utc();
+
+    let term_ids: Vec<i32> = terms.iter().map(|term| term.id).collect();
+    repository
+        .create_user_agreements(user_id, &term_ids)
+        .await?;
+
+    for term in terms {
+        if let Ok(group) = repository.get_group(&term.group).await {
+            let expires_at = group.and_then(|group| group.consent_expiry(agreed_at));
+
+            let _ = cache
+                .store_user_agreement(
+                    user_id,
+                    &term.group,
+                    ConsentStatus::ConsentedToLatest,
+                    expires_at,
+                )
+                .await;
+        }
+
+        let _ = publisher
+            .publish_agreement(AcceptedTermOfUseDTO {
+                term_id: term.id,
+                user_id,
+                group: term.group,
+                accepted_clauses: vec![],
+            })
+            .await;
+    }
+
+    Ok(())
+}
+
+/// Groups of the bundle whose latest term the user has not agreed to yet. Groups without
+/// terms have nothing to agree to and are not pending.
+#[tracing::instrument(skip(repository, cache, user_id))]
+pub async fn get_pending_bundle_groups_use_case(
+    repository: &dyn DatabaseRepository,
+    cache: &dyn CacheService,
+    user_id: i32,
+    name: &str,
+) -> Result<Vec<String>> {
+    let bundle = get_bundle_use_case(repository, name).await?;
+
+    let mut pending = Vec::new();
+    for group in bundle.groups {
+        match get_consent_status_use_case(repository, cache, user_id, &group).await? {
+            ConsentStatus::NoTermsPublished => {}
+            status if !status.has_consented() => pending.push(group),
+            _ => {}
+        }
+    }
+
+    Ok(pending)
+}
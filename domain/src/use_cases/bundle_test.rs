@@ -0,0 +1,695 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{
+                MockBundleRepository, MockGroupRepository, MockTermRepository,
+                MockUserAgreementRepository,
+            },
+            service::{MockCacheService, MockPublisherService},
+        },
+        entities::{Bundle, ConsentStatus, Group, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::{
+            create_bundle_agreement_use_case, get_pending_bundle_groups_use_case,
+            save_bundle_use_case,
+        },
+    };
+
+    // Combined mock for bundles
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        agreement_repo: MockUserAgreementRepository,
+        group_repo: MockGroupRepository,
+        bundle_repo: MockBundleRepository,
+    }
+
+    impl MockCombinedRepository {
+        fn new() -> Self {
+            MockCombinedRepository {
+                term_repo: MockTermRepository::new(),
+                agreement_repo: MockUserAgreementRepository::new(),
+                group_repo: MockGroupRepository::new(),
+                bundle_repo: MockBundleRepository::new(),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            self.agreement_repo
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
+                .await
+        }
+
+        async fn create_user_agreement(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            accepted_clauses: &[String],
+        ) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreement(user_id, term_id, accepted_clauses)
+                .await
+        }
+
+        async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreements(user_id, term_ids)
+                .await
+        }
+    }
+
+    // Reservations are not involved in bundles
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in bundles
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            self.group_repo.get_group(name).await
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            self.group_repo.get_groups().await
+        }
+
+        async fn create_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.create_group(group).await
+        }
+
+        async fn update_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.update_group(group).await
+        }
+
+        async fn delete_group(&self, name: &str) -> Result<()> {
+            self.group_repo.delete_group(name).await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, name: &str) -> Result<Option<Bundle>> {
+            self.bundle_repo.get_bundle(name).await
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            self.bundle_repo.get_bundles().await
+        }
+
+        async fn save_bundle(&self, bundle: Bundle) -> Result<Bundle> {
+            self.bundle_repo.save_bundle(bundle).await
+        }
+
+        async fn delete_bundle(&self, name: &str) -> Result<()> {
+            self.bundle_repo.delete_bundle(name).await
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn sign_up() -> Bundle {
+        Bundle {
+            name: "sign-up".to_string(),
+            description: Some("Accepted when creating an account".to_string()),
+            groups: vec![
+                "terms-of-service".to_string(),
+                "privacy-policy".to_string(),
+                "cookie-policy".to_string(),
+            ],
+        }
+    }
+
+    fn latest_term(group: &str) -> TermOfUse {
+        let id = match group {
+            "terms-of-service" => 1,
+            "privacy-policy" => 2,
+            _ => 3,
+        };
+
+        TermOfUse {
+            id,
+            group: group.to_string(),
+            url: format!("{group}/v1.pdf"),
+            version: 1,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn repository_with_sign_up() -> MockCombinedRepository {
+        let mut repository = MockCombinedRepository::new();
+        repository
+            .bundle_repo
+            .expect_get_bundle()
+            .with(eq("sign-up"))
+            .returning(|_| Ok(Some(sign_up())));
+        repository
+    }
+
+    #[tokio::test]
+    async fn save_bundle_stores_bundles_of_registered_groups() {
+        let mut repository = MockCombinedRepository::new();
+        repository.group_repo.expect_get_group().returning(|name| {
+            Ok(Some(Group {
+                name: name.to_string(),
+                description: None,
+                owner: None,
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: None,
+            }))
+        });
+        repository
+            .bundle_repo
+            .expect_save_bundle()
+            .with(eq(sign_up()))
+            .times(1)
+            .returning(Ok);
+
+        let bundle = save_bundle_use_case(&repository, sign_up()).await.unwrap();
+
+        assert_eq!(bundle, sign_up());
+    }
+
+    #[tokio::test]
+    async fn save_bundle_rejects_unregistered_groups() {
+        let mut repository = MockCombinedRepository::new();
+        repository
+            .group_repo
+            .expect_get_group()
+            .returning(|_| Ok(None));
+        repository.bundle_repo.expect_save_bundle().times(0);
+
+        let result = save_bundle_use_case(&repository, sign_up()).await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "Group 'terms-of-service' is not registered"
+        ));
+    }
+
+    #[tokio::test]
+    async fn save_bundle_rejects_empty_and_repeated_groups() {
+        let mut repository = MockCombinedRepository::new();
+        repository.bundle_repo.expect_save_bundle().times(0);
+
+        for groups in [
+            vec![],
+            vec!["privacy-policy".to_string(), "privacy-policy".to_string()],
+        ] {
+            let result = save_bundle_use_case(
+                &repository,
+                Bundle {
+                    groups,
+                    ..sign_up()
+                },
+            )
+            .await;
+
+            assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_records_every_group_at_once() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![]));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 2, 3])
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repository
+            .agreement_repo
+            .expect_create_user_agreement()
+            .times(0);
+
+        repository.group_repo.expect_get_group().returning(|name| {
+            Ok(Some(Group {
+                name: name.to_string(),
+                description: None,
+                owner: None,
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: (name == "privacy-policy").then_some(365),
+            }))
+        });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .withf(|user_id, group, status, expires_at| {
+                *user_id == 42
+                    && *status == ConsentStatus::ConsentedToLatest
+                    && expires_at.is_some() == (group == "privacy-policy")
+            })
+            .times(3)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher
+            .expect_publish_agreement()
+            .times(3)
+            .returning(|_| Ok(()));
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_skips_terms_already_agreed_to() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_term_ids()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 2, 3])
+            .returning(|_, _| Ok(vec![2]));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(42), eq(2))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 3])
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repository
+            .group_repo
+            .expect_get_group()
+            .returning(|_| Ok(None));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .withf(|_, group, _, _| group != "privacy-policy")
+            .times(2)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher
+            .expect_publish_agreement()
+            .withf(|agreement| agreement.term_id != 2)
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_renews_expired_consents() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, term_ids| Ok(term_ids.to_vec()));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [2])
+            .times(1)
+            .returning(|_, _| Ok(()));
+        repository.group_repo.expect_get_group().returning(|name| {
+            Ok(Some(Group {
+                name: name.to_string(),
+                description: None,
+                owner: None,
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: (name == "privacy-policy").then_some(365),
+            }))
+        });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .withf(|_, group, status, expires_at| {
+                group == "privacy-policy"
+                    && *status == ConsentStatus::ConsentedToLatest
+                    && expires_at.is_some_and(|expires_at| expires_at > Utc::now().naive_utc())
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher
+            .expect_publish_agreement()
+            .withf(|agreement| agreement.term_id == 2)
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_stores_nothing_when_every_term_is_agreed_to() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, term_ids| Ok(term_ids.to_vec()));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .times(0);
+        repository
+            .group_repo
+            .expect_get_group()
+            .returning(|_| Ok(None));
+
+        let mut cache = MockCacheService::new();
+        cache.expect_store_user_agreement().times(0);
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_publish_agreement().times(0);
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_records_nothing_when_a_group_has_no_terms() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok((group != "cookie-policy").then(|| latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .times(0);
+
+        let mut cache = MockCacheService::new();
+        cache.expect_store_user_agreement().times(0);
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_publish_agreement().times(0);
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "Group 'cookie-policy' has no terms to agree to"
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_publishes_nothing_when_storing_fails() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![]));
+        repository
+            .agreement_repo
+            .expect_create_user_agreements()
+            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+
+        let mut cache = MockCacheService::new();
+        cache.expect_store_user_agreement().times(0);
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_publish_agreement().times(0);
+
+        let result =
+            create_bundle_agreement_use_case(&repository, &cache, &publisher, 42, "sign-up").await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn create_bundle_agreement_returns_not_found_for_unknown_bundles() {
+        let mut repository = MockCombinedRepository::new();
+        repository
+            .bundle_repo
+            .expect_get_bundle()
+            .returning(|_| Ok(None));
+
+        let result = create_bundle_agreement_use_case(
+            &repository,
+            &MockCacheService::new(),
+            &MockPublisherService::new(),
+            42,
+            "sign-up",
+        )
+        .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn get_pending_bundle_groups_lists_groups_without_consent() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, term_id| Ok((term_id != 2).then(|| Utc::now().naive_utc())));
+        repository
+            .term_repo
+            .expect_get_terms_for_group()
+            .returning(|group, _| Ok(vec![latest_term(group)]));
+        repository
+            .group_repo
+            .expect_get_group()
+            .returning(|_| Ok(None));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .returning(|_, _, _, _| Ok(()));
+
+        let pending = get_pending_bundle_groups_use_case(&repository, &cache, 42, "sign-up")
+            .await
+            .unwrap();
+
+        assert_eq!(pending, ["privacy-policy"]);
+    }
+
+    #[tokio::test]
+    async fn get_pending_bundle_groups_skips_groups_without_terms() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok((group != "privacy-policy").then(|| latest_term(group))));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(None));
+        repository
+            .term_repo
+            .expect_get_terms_for_group()
+            .returning(|group, _| Ok(vec![latest_term(group)]));
+        repository
+            .group_repo
+            .expect_get_group()
+            .returning(|_| Ok(None));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .returning(|_, _, _, _| Ok(()));
+
+        let pending = get_pending_bundle_groups_use_case(&repository, &cache, 42, "sign-up")
+            .await
+            .unwrap();
+
+        assert_eq!(pending, ["terms-of-service", "cookie-policy"]);
+    }
+}
@@ -0,0 +1,59 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    data::service::DocumentService,
+    entities::ChangeSummary,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Longest accepted summary, in bytes of Markdown.
+const MAX_SUMMARY_LENGTH: usize = 10_000;
+
+/// Validates the Markdown change summaries of a new version and renders them to HTML.
+pub(crate) fn build_change_summaries(
+    documents: &dyn DocumentService,
+    summaries: BTreeMap<String, String>,
+) -> Result<Vec<ChangeSummary>> {
+    summaries
+        .into_iter()
+        .map(|(locale, markdown)| {
+            if !is_language_tag(&locale) {
+                return Err(TermsOfUseError::Validation(format!(
+                    "'{locale}' is not a valid locale"
+                )));
+            }
+
+            let markdown = markdown.trim().to_string();
+            if markdown.is_empty() {
+                return Err(TermsOfUseError::Validation(format!(
+                    "The change summary for '{locale}' must not be empty"
+                )));
+            }
+            if markdown.len() > MAX_SUMMARY_LENGTH {
+                return Err(TermsOfUseError::Validation(format!(
+                    "The change summary for '{locale}' must not exceed {MAX_SUMMARY_LENGTH} bytes"
+                )));
+            }
+
+            Ok(ChangeSummary {
+                html: documents.render_markdown(&markdown),
+                locale,
+                markdown,
+            })
+        })
+        .collect()
+}
+
+/// Loose check of a BCP 47 tag: a 2-3 letter language followed by alphanumeric subtags.
+pub(crate) fn is_language_tag(locale: &str) -> bool {
+    let mut subtags = locale.split('-');
+
+    let language_valid = subtags.next().is_some_and(|language| {
+        (2..=3).contains(&language.len()) && language.chars().all(|c| c.is_ascii_alphabetic())
+    });
+
+    language_valid
+        && subtags.all(|subtag| {
+            (1..=8).contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+        })
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{
+        data::service::MockDocumentService, errors::TermsOfUseError,
+        use_cases::change_summaries::build_change_summaries,
+    };
+
+    fn summaries(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(locale, markdown)| (locale.to_string(), markdown.to_string()))
+            .collect()
+    }
+
+    fn documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_render_markdown()
+            .returning(|markdown| format!("<p>{markdown}</p>"));
+        documents
+    }
+
+    #[test]
+    fn test_build_change_summaries_renders_markdown() {
+        let result = build_change_summaries(
+            &documents(),
+            summaries(&[
+                ("en", "We now **delete** inactive accounts.\n"),
+                ("de-CH", "Inaktive Konten werden *gelöscht*."),
+            ]),
+        )
+        .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].locale, "de-CH");
+        assert_eq!(result[0].html, "<p>Inaktive Konten werden *gelöscht*.</p>");
+        assert_eq!(result[1].locale, "en");
+        assert_eq!(result[1].markdown, "We now **delete** inactive accounts.");
+        assert_eq!(
+            result[1].html,
+            "<p>We now **delete** inactive accounts.</p>"
+        );
+    }
+
+    #[test]
+    fn test_build_change_summaries_rejects_invalid_locale() {
+        for locale in ["", "english", "en_US", "en-", "e1"] {
+            let result = build_change_summaries(&documents(), summaries(&[(locale, "Changed")]));
+
+            assert!(
+                matches!(result, Err(TermsOfUseError::Validation(_))),
+                "{locale} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_build_change_summaries_rejects_empty_summary() {
+        let result = build_change_summaries(&documents(), summaries(&[("en", "  \n")]));
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[test]
+    fn test_build_change_summaries_rejects_long_summary() {
+        let result =
+            build_change_summaries(&documents(), summaries(&[("en", &"a".repeat(10_001))]));
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::Utc;
+
+use crate::{
+    data::repository::DatabaseRepository,
+    entities::Clause,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Longest accepted clause key, in bytes.
+pub(crate) const MAX_CLAUSE_KEY_LENGTH: usize = 64;
+
+fn is_clause_key(key: &str) -> bool {
+    !key.is_empty()
+        && key.len() <= MAX_CLAUSE_KEY_LENGTH
+        && key
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '-' | '_'))
+}
+
+/// Checks the clauses declared by a new term: keys must be well formed and unique.
+pub(crate) fn validate_clauses(clauses: &[Clause]) -> Result<()> {
+    for (index, clause) in clauses.iter().enumerate() {
+        if !is_clause_key(&clause.key) {
+            return Err(TermsOfUseError::Validation(format!(
+                "The clause key '{}' must be 1 to {MAX_CLAUSE_KEY_LENGTH} lowercase letters, digits, '-' or '_'",
+                clause.key
+            )));
+        }
+
+        if clause.title.trim().is_empty() {
+            return Err(TermsOfUseError::Validation(format!(
+                "The clause '{}' must have a title",
+                clause.key
+            )));
+        }
+
+        if clauses[..index].iter().any(|c| c.key == clause.key) {
+            return Err(TermsOfUseError::Validation(format!(
+                "The clause '{}' is declared more than once",
+                clause.key
+            )));
+        }
+    }
+
+    Ok(())
+}
+
+/// Keeps the optional clauses among those a user accepted. Mandatory clauses are implied by
+/// the agreement itself, so only the optional ones are recorded.
+pub(crate) fn select_optional_clauses(
+    clauses: &[Clause],
+    accepted_clauses: &[String],
+) -> Result<Vec<String>> {
+    let mut optional_clauses: Vec<String> = Vec::new();
+
+    for key in accepted_clauses {
+        let clause = clauses.iter().find(|c| &c.key == key).ok_or_else(|| {
+            TermsOfUseError::Validation(format!("The term has no clause '{key}'"))
+        })?;
+
+        if !clause.mandatory && !optional_clauses.contains(key) {
+            optional_clauses.push(key.clone());
+        }
+    }
+
+    Ok(optional_clauses)
+}
+
+/// Tells whether a user accepted a clause of the latest term of a group. Mandatory clauses
+/// are accepted with the term, optional ones only when the user chose them. An expired
+/// consent accepts no clause until the user agrees again.
+///
+/// Answers are not cached, unlike `has_user_agreed_to_term_use_case`.
+#[tracing::instrument(skip(repository, user_id, group, clause))]
+pub async fn has_user_accepted_clause_use_case(
+    repository: &dyn DatabaseRepository,
+    user_id: i32,
+    group: &str,
+    clause: &str,
+) -> Result<bool> {
+    let latest_term = repository
+        .get_latest_term_for_group(group)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)?;
+
+    let declared = latest_term
+        .clauses
+        .iter()
+        .find(|c| c.key == clause)
+        .ok_or_else(|| {
+            TermsOfUseError::Validation(format!(
+                "Version {} of '{group}' has no clause '{clause}'",
+                latest_term.version
+            ))
+        })?;
+
+    let Some(accepted_clauses) = repository
+        .get_accepted_clauses(user_id, latest_term.id)
+        .await?
+    else {
+        return Ok(false);
+    };
+
+    if let Some(registered_group) = repository.get_group(group).await?
+        && registered_group.max_consent_age_days.is_some()
+    {
+        let expires_at = repository
+            .get_agreed_at(user_id, latest_term.id)
+            .await?
+            .and_then(|agreed_at| registered_group.consent_expiry(agreed_at));
+
+        if expires_at.is_none_or(|expires_at| expires_at <= Utc::now().naive_utc()) {
+            return Ok(false);
+        }
+    }
+
+    Ok(declared.mandatory || accepted_clauses.iter().any(|key| key == clause))
+}
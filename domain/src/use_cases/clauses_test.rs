@@ -0,0 +1,415 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::repository::{MockTermRepository, MockUserAgreementRepository},
+        entities::{Bundle, Clause, Group, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::{clauses::validate_clauses, has_user_accepted_clause_use_case},
+    };
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        agreement_repo: MockUserAgreementRepository,
+        group: Option<Group>,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            self.agreement_repo
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
+                .await
+        }
+
+        async fn create_user_agreement(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            accepted_clauses: &[String],
+        ) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreement(user_id, term_id, accepted_clauses)
+                .await
+        }
+
+        async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreements(user_id, term_ids)
+                .await
+        }
+    }
+
+    // Reservations are not involved in clauses
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in clauses
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are not involved in checking clauses
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>> {
+            Ok(self.group.clone())
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in checking clauses
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn clause(key: &str, mandatory: bool) -> Clause {
+        Clause {
+            key: key.to_string(),
+            title: "Clause title".to_string(),
+            mandatory,
+        }
+    }
+
+    fn repository(accepted_clauses: Option<Vec<String>>) -> MockCombinedRepository {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .returning(|_| {
+                Ok(Some(TermOfUse {
+                    id: 7,
+                    group: "privacy-policy".to_string(),
+                    version: 3,
+                    url: "uploads/privacy-v3.pdf".to_string(),
+                    created_at: Utc::now().naive_utc(),
+                    info: None,
+                    html: None,
+                    text: None,
+                    change_summaries: vec![],
+                    pdf_metadata: None,
+                    metadata: Default::default(),
+                    clauses: vec![
+                        clause("data-processing", true),
+                        clause("marketing-emails", false),
+                        clause("profiling", false),
+                    ],
+                }))
+            });
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_accepted_clauses()
+            .with(eq(42), eq(7))
+            .returning(move |_, _| Ok(accepted_clauses.clone()));
+
+        MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        }
+    }
+
+    fn group(max_consent_age_days: Option<u32>) -> Group {
+        Group {
+            name: "privacy-policy".to_string(),
+            description: None,
+            owner: None,
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_optional_clause_is_accepted_when_chosen() {
+        let mut repository = repository(Some(vec!["marketing-emails".to_string()]));
+        repository.group = Some(group(None));
+
+        let marketing = has_user_accepted_clause_use_case(
+            &repository,
+            42,
+            "privacy-policy",
+            "marketing-emails",
+        )
+        .await;
+        let profiling =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "profiling").await;
+
+        assert!(marketing.unwrap());
+        assert!(!profiling.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_mandatory_clause_is_accepted_with_the_term() {
+        let mut repository = repository(Some(vec![]));
+        repository.group = Some(group(None));
+
+        let result =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "data-processing")
+                .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_no_clause_is_accepted_with_an_expired_agreement() {
+        let mut repository = repository(Some(vec!["marketing-emails".to_string()]));
+        repository.group = Some(group(Some(365)));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(42), eq(7))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+
+        let mandatory =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "data-processing")
+                .await;
+        let optional = has_user_accepted_clause_use_case(
+            &repository,
+            42,
+            "privacy-policy",
+            "marketing-emails",
+        )
+        .await;
+
+        assert!(!mandatory.unwrap());
+        assert!(!optional.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_clause_is_accepted_with_an_agreement_not_expired_yet() {
+        let mut repository = repository(Some(vec!["marketing-emails".to_string()]));
+        repository.group = Some(group(Some(365)));
+        repository
+            .agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(30))));
+
+        let result = has_user_accepted_clause_use_case(
+            &repository,
+            42,
+            "privacy-policy",
+            "marketing-emails",
+        )
+        .await;
+
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_no_clause_is_accepted_without_an_agreement() {
+        let repository = repository(None);
+
+        let result =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "data-processing")
+                .await;
+
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_unknown_clause_is_rejected() {
+        let repository = repository(Some(vec![]));
+
+        let result =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "newsletter")
+                .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_missing_term_is_not_found() {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo: MockUserAgreementRepository::new(),
+            group: None,
+        };
+
+        let result =
+            has_user_accepted_clause_use_case(&repository, 42, "privacy-policy", "profiling").await;
+
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[test]
+    fn test_validate_clauses() {
+        assert!(
+            validate_clauses(&[clause("data-processing", true), clause("ads_2", false)]).is_ok()
+        );
+        assert!(validate_clauses(&[clause("Marketing", false)]).is_err());
+        assert!(validate_clauses(&[clause("", false)]).is_err());
+        assert!(validate_clauses(&[clause(&"a".repeat(65), false)]).is_err());
+        assert!(validate_clauses(&[clause("ads", false), clause("ads", true)]).is_err());
+
+        let mut untitled = clause("ads", false);
+        untitled.title = " ".to_string();
+        assert!(validate_clauses(&[untitled]).is_err());
+    }
+}
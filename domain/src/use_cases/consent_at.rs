@@ -0,0 +1,67 @@
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::{
+    data::{repository::DatabaseRepository, service::StorageService},
+    dto::ConsentAtDTO,
+    entities::TermMetadata,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Reads a point in time given in RFC 3339, e.g. `2025-03-01T00:00:00Z`, as naive UTC.
+pub fn parse_point_in_time(value: &str) -> Result<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.naive_utc())
+        .map_err(|_| {
+            TermsOfUseError::Validation(format!(
+                "'{value}' is not an RFC 3339 timestamp such as 2025-03-01T00:00:00Z"
+            ))
+        })
+}
+
+/// Where a user stood with a group at `at`, e.g. to answer legal inquiries.
+///
+/// The version in effect is the newest one published by then. Its newest agreement given by
+/// then is returned, and counts as long as it had not expired at `at`. Agreements are never
+/// withdrawn and renewals keep the earlier ones, expiry follows the current maximum consent
+/// age of the group.
+#[tracing::instrument(skip(repository, storage, user_id, group))]
+pub async fn get_consent_at_use_case(
+    repository: &dyn DatabaseRepository,
+    storage: &dyn StorageService,
+    user_id: i32,
+    group: &str,
+    at: NaiveDateTime,
+) -> Result<ConsentAtDTO> {
+    // Versions published later could still change the answer
+    if at > Utc::now().naive_utc() {
+        return Err(TermsOfUseError::Validation(
+            "The point in time must not lie in the future".to_string(),
+        ));
+    }
+
+    let mut term = repository
+        .get_terms_for_group(group, &TermMetadata::new())
+        .await?
+        .into_iter()
+        .find(|term| term.created_at <= at)
+        .ok_or(TermsOfUseError::NotFound)?;
+
+    let agreement = repository.get_agreement_at(user_id, term.id, at).await?;
+
+    let has_consented = match &agreement {
+        Some(agreement) => repository
+            .get_group(group)
+            .await?
+            .and_then(|group| group.consent_expiry(agreement.agreed_at))
+            .is_none_or(|expires_at| expires_at > at),
+        None => false,
+    };
+
+    term.url = storage.get_file_url(&term.url).await?;
+
+    Ok(ConsentAtDTO {
+        term,
+        agreement,
+        has_consented,
+    })
+}
@@ -0,0 +1,530 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{NaiveDateTime, TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{MockTermRepository, MockUserAgreementRepository},
+            service::MockStorageService,
+        },
+        entities::{Bundle, Group, TermOfUse, TermReservation, UploadPolicy, UserAgreement},
+        errors::TermsOfUseError,
+        use_cases::{get_consent_at_use_case, parse_point_in_time},
+    };
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        agreement_repo: MockUserAgreementRepository,
+        group: Option<Group>,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(
+            &self,
+            group: &str,
+        ) -> Result<Option<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(
+            &self,
+            groups: &[String],
+        ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse, TermsOfUseError> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>, TermsOfUseError> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(
+            &self,
+            user_id: i32,
+            term_ids: &[i32],
+        ) -> Result<Vec<i32>, TermsOfUseError> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>, TermsOfUseError> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>, TermsOfUseError> {
+            self.agreement_repo
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>, TermsOfUseError> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
+                .await
+        }
+
+        async fn create_user_agreement(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            accepted_clauses: &[String],
+        ) -> Result<(), TermsOfUseError> {
+            self.agreement_repo
+                .create_user_agreement(user_id, term_id, accepted_clauses)
+                .await
+        }
+
+        async fn create_user_agreements(
+            &self,
+            user_id: i32,
+            term_ids: &[i32],
+        ) -> Result<(), TermsOfUseError> {
+            self.agreement_repo
+                .create_user_agreements(user_id, term_ids)
+                .await
+        }
+    }
+
+    // Reservations are not involved in consent queries
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(
+            &self,
+            _reservation_id: i32,
+        ) -> Result<Option<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: NaiveDateTime,
+        ) -> Result<bool, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in consent queries
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(
+            &self,
+            _group: &str,
+        ) -> Result<Option<UploadPolicy>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(
+            &self,
+            _policy: UploadPolicy,
+        ) -> Result<UploadPolicy, TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are only read for their consent expiry
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>, TermsOfUseError> {
+            Ok(self.group.clone())
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in consent queries
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn at(value: &str) -> NaiveDateTime {
+        parse_point_in_time(value).unwrap()
+    }
+
+    fn term(id: i32, version: u32, created_at: &str) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version,
+            url: format!("privacy-policy/v{version}.pdf"),
+            created_at: at(created_at),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn history() -> MockTermRepository {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_terms_for_group()
+            .with(eq("privacy-policy"), always())
+            .returning(|_, _| {
+                Ok(vec![
+                    term(3, 3, "2025-06-01T00:00:00Z"),
+                    term(2, 2, "2025-01-15T00:00:00Z"),
+                    term(1, 1, "2024-01-01T00:00:00Z"),
+                ])
+            });
+        term_repo
+    }
+
+    fn storage() -> MockStorageService {
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+        storage
+    }
+
+    fn yearly_consent_group() -> Group {
+        Group {
+            name: "privacy-policy".to_string(),
+            description: None,
+            owner: None,
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: Some(365),
+        }
+    }
+
+    #[test]
+    fn test_parse_point_in_time_reads_rfc_3339() {
+        assert_eq!(
+            parse_point_in_time("2025-03-01T01:00:00+01:00").unwrap(),
+            at("2025-03-01T00:00:00Z")
+        );
+        assert!(matches!(
+            parse_point_in_time("2025-03-01"),
+            Err(TermsOfUseError::Validation(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_returns_the_version_in_effect_and_its_agreement() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreement_at()
+            .with(eq(42), eq(2), eq(at("2025-03-01T00:00:00Z")))
+            .times(1)
+            .returning(|user_id, term_id, _| {
+                Ok(Some(UserAgreement {
+                    user_id,
+                    term_id,
+                    agreed_at: at("2025-02-10T09:30:00Z"),
+                    accepted_clauses: vec!["marketing-emails".to_string()],
+                }))
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo: history(),
+            agreement_repo,
+            group: None,
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &storage(),
+            42,
+            "privacy-policy",
+            at("2025-03-01T00:00:00Z"),
+        )
+        .await;
+
+        // Assert
+        let consent = result.unwrap();
+        assert_eq!(consent.term.version, 2);
+        assert_eq!(
+            consent.term.url,
+            "https://cdn.example.com/privacy-policy/v2.pdf"
+        );
+        assert_eq!(
+            consent.agreement,
+            Some(UserAgreement {
+                user_id: 42,
+                term_id: 2,
+                agreed_at: at("2025-02-10T09:30:00Z"),
+                accepted_clauses: vec!["marketing-emails".to_string()],
+            })
+        );
+        assert!(consent.has_consented);
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_reports_no_agreement_given_by_then() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreement_at()
+            .with(eq(42), eq(2), eq(at("2025-03-01T00:00:00Z")))
+            .returning(|_, _, _| Ok(None));
+
+        let repository = MockCombinedRepository {
+            term_repo: history(),
+            agreement_repo,
+            group: None,
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &storage(),
+            42,
+            "privacy-policy",
+            at("2025-03-01T00:00:00Z"),
+        )
+        .await;
+
+        // Assert
+        let consent = result.unwrap();
+        assert_eq!(consent.term.version, 2);
+        assert!(consent.agreement.is_none());
+        assert!(!consent.has_consented);
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_reports_agreements_expired_by_then() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreement_at()
+            .with(eq(42), eq(1), always())
+            .returning(|user_id, term_id, _| {
+                Ok(Some(UserAgreement {
+                    user_id,
+                    term_id,
+                    agreed_at: at("2024-01-02T00:00:00Z"),
+                    accepted_clauses: vec![],
+                }))
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo: history(),
+            agreement_repo,
+            group: Some(yearly_consent_group()),
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &storage(),
+            42,
+            "privacy-policy",
+            at("2025-01-10T00:00:00Z"),
+        )
+        .await;
+
+        // Assert
+        let consent = result.unwrap();
+        assert_eq!(consent.term.version, 1);
+        assert!(consent.agreement.is_some());
+        assert!(!consent.has_consented);
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_counts_consents_renewed_after_expiry() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreement_at()
+            .with(eq(42), eq(1), eq(at("2025-01-10T00:00:00Z")))
+            .returning(|user_id, term_id, _| {
+                Ok(Some(UserAgreement {
+                    user_id,
+                    term_id,
+                    agreed_at: at("2025-01-05T00:00:00Z"),
+                    accepted_clauses: vec![],
+                }))
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo: history(),
+            agreement_repo,
+            group: Some(yearly_consent_group()),
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &storage(),
+            42,
+            "privacy-policy",
+            at("2025-01-10T00:00:00Z"),
+        )
+        .await;
+
+        // Assert
+        let consent = result.unwrap();
+        assert_eq!(consent.term.version, 1);
+        assert_eq!(
+            consent.agreement.map(|agreement| agreement.agreed_at),
+            Some(at("2025-01-05T00:00:00Z"))
+        );
+        assert!(consent.has_consented);
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_returns_not_found_before_the_first_version() {
+        // Arrange
+        let repository = MockCombinedRepository {
+            term_repo: history(),
+            agreement_repo: MockUserAgreementRepository::new(),
+            group: None,
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &MockStorageService::new(),
+            42,
+            "privacy-policy",
+            at("2023-12-31T23:59:59Z"),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_consent_at_rejects_future_points_in_time() {
+        // Arrange
+        let repository = MockCombinedRepository {
+            term_repo: MockTermRepository::new(),
+            agreement_repo: MockUserAgreementRepository::new(),
+            group: None,
+        };
+
+        // Act
+        let result = get_consent_at_use_case(
+            &repository,
+            &MockStorageService::new(),
+            42,
+            "privacy-policy",
+            Utc::now().naive_utc() + TimeDelta::days(1),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    collections::HashSet,
+    path::{Path, PathBuf},
+};
+
+use tracing::{error, warn};
+
+use crate::{
+    data::{
+        repository::TermRepository,
+        service::{DocumentService, StorageService},
+    },
+    dto::{CopiedDocumentDTO, StorageCopyReportDTO},
+    entities::TermOfUse,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Copies the document of every term from `source` to `destination`, keeping its key,
+/// so terms keep pointing to their documents once the destination is configured.
+///
+/// Each copy is downloaded again and compared with the original by its SHA-256 hash,
+/// so a term never references a corrupted document. Documents already in the
+/// destination are compared the same way and only copied again when they differ,
+/// which lets an interrupted run be resumed by running it again. Failing terms are
+/// reported and do not stop the copy of the others.
+#[tracing::instrument(skip(repository, source, destination, documents, work_dir))]
+pub async fn copy_storage_use_case(
+    repository: &dyn TermRepository,
+    source: &dyn StorageService,
+    destination: &dyn StorageService,
+    documents: &dyn DocumentService,
+    work_dir: &Path,
+    dry_run: bool,
+) -> Result<StorageCopyReportDTO> {
+    let terms = repository.get_all_terms().await?;
+    let destination_files = destination.list_files().await?;
+
+    let stored_keys: HashSet<&str> = destination_files
+        .iter()
+        .map(|file| file.key.as_str())
+        .collect();
+
+    let mut report = StorageCopyReportDTO::default();
+
+    for term in terms {
+        let stored = stored_keys.contains(term.url.as_str());
+
+        if dry_run {
+            if stored {
+                report.skipped_terms.push(term);
+            } else {
+                report.copied_documents.push(CopiedDocumentDTO {
+                    term_id: term.id,
+                    key: term.url,
+                });
+            }
+            continue;
+        }
+
+        match copy_document(source, destination, documents, work_dir, &term, stored).await {
+            Ok(true) => report.copied_documents.push(CopiedDocumentDTO {
+                term_id: term.id,
+                key: term.url,
+            }),
+            Ok(false) => report.skipped_terms.push(term),
+            Err(err) => {
+                warn!("Failed to copy document of term {}: {err:?}", term.id);
+
+                report.failed_terms.push(term);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+/// Copies the document of a term, telling whether it had to be copied or an intact
+/// copy was already `stored` in the destination.
+async fn copy_document(
+    source: &dyn StorageService,
+    destination: &dyn StorageService,
+    documents: &dyn DocumentService,
+    work_dir: &Path,
+    term: &TermOfUse,
+    stored: bool,
+) -> Result<bool> {
+    let key = term.url.as_str();
+    let extension = Path::new(key)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or_default();
+
+    let content_type = content_type(extension);
+    let source_file = work_file(work_dir, term.id, "source", extension);
+    let verify_file = work_file(work_dir, term.id, "copy", extension);
+
+    let result = async {
+        source.download_file(key, &source_file).await?;
+        let checksum = documents.sha256(&source_file).await?;
+
+        let intact = stored && {
+            destination.download_file(key, &verify_file).await?;
+            documents.sha256(&verify_file).await? == checksum
+        };
+
+        if !intact {
+            // Keys are not overwritten, the unverified copy has to go first
+            if stored {
+                warn!("Copy of {key} does not match the original, copying it again");
+
+                destination.delete_file(key).await?;
+            }
+
+            destination
+                .put_file(key, &source_file, content_type, &term.group, term.version)
+                .await?;
+            destination.download_file(key, &verify_file).await?;
+
+            if documents.sha256(&verify_file).await? != checksum {
+                error!("Copy of {key} does not match the original");
+
+                // No term refers to the destination yet, so the copy can still be removed
+                if let Err(err) = destination.delete_file(key).await {
+                    error!("Failed to delete the unverified copy {key} ({err:?})");
+                }
+
+                return Err(TermsOfUseError::InternalServerError);
+            }
+        }
+
+        if let Err(err) = destination.publish_file(key, term.id).await {
+            error!(
+                "Failed to protect the copied document of term {}: {key} ({err:?})",
+                term.id
+            );
+        }
+
+        Ok(!intact)
+    }
+    .await;
+
+    let _ = std::fs::remove_file(&source_file);
+    let _ = std::fs::remove_file(&verify_file);
+
+    result
+}
+
+fn work_file(work_dir: &Path, term_id: i32, suffix: &str, extension: &str) -> PathBuf {
+    let name = format!("term-{term_id}-{suffix}");
+
+    if extension.is_empty() {
+        work_dir.join(name)
+    } else {
+        work_dir.join(format!("{name}.{extension}"))
+    }
+}
+
+/// Content type of a stored document, derived from its key as backends do not expose it.
+fn content_type(extension: &str) -> &'static str {
+    match extension {
+        "pdf" => "application/pdf",
+        "md" => "text/markdown",
+        "html" => "text/html",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        _ => "application/octet-stream",
+    }
+}
@@ -0,0 +1,368 @@
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::MockTermRepository,
+            service::{MockDocumentService, MockStorageService},
+        },
+        entities::{StoredFile, TermOfUse},
+        use_cases::copy_storage_use_case,
+    };
+
+    fn term(id: i32, url: &str) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version: id as u32,
+            url: url.to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn stored(key: &str) -> StoredFile {
+        StoredFile {
+            key: key.to_string(),
+            last_modified: Utc::now().naive_utc(),
+        }
+    }
+
+    fn work_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("copy-storage-test-{name}"));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        dir
+    }
+
+    fn write_download(content: &'static [u8]) -> impl Fn(&str, &Path) -> crate::errors::Result<()> {
+        move |_, destination| {
+            std::fs::write(destination, content).unwrap();
+
+            Ok(())
+        }
+    }
+
+    /// Digests files by their content, so downloaded copies compare like their bytes.
+    fn content_digests() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents.expect_sha256().returning(|file| {
+            Ok(String::from_utf8_lossy(&std::fs::read(file).unwrap()).into_owned())
+        });
+        documents
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_copies_documents_under_their_key() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_all_terms()
+            .returning(|| Ok(vec![term(1, "privacy-policy/v1.pdf")]));
+
+        let mut source = MockStorageService::new();
+        source
+            .expect_download_file()
+            .with(eq("privacy-policy/v1.pdf"), always())
+            .returning(write_download(b"%PDF-1.4"));
+
+        let mut destination = MockStorageService::new();
+        destination.expect_list_files().returning(|| Ok(vec![]));
+        destination
+            .expect_put_file()
+            .with(
+                eq("privacy-policy/v1.pdf"),
+                always(),
+                eq("application/pdf"),
+                eq("privacy-policy"),
+                eq(1),
+            )
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        destination
+            .expect_download_file()
+            .with(eq("privacy-policy/v1.pdf"), always())
+            .returning(write_download(b"%PDF-1.4"));
+        destination
+            .expect_publish_file()
+            .with(eq("privacy-policy/v1.pdf"), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        destination.expect_delete_file().never();
+
+        // Act
+        let result = copy_storage_use_case(
+            &repository,
+            &source,
+            &destination,
+            &content_digests(),
+            &work_dir("copy"),
+            false,
+        )
+        .await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.copied_documents.len(), 1);
+        assert_eq!(report.copied_documents[0].term_id, 1);
+        assert_eq!(report.copied_documents[0].key, "privacy-policy/v1.pdf");
+        assert!(report.skipped_terms.is_empty());
+        assert!(report.failed_terms.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_keeps_document_content_type() {
+        for (extension, expected) in [("md", "text/markdown"), ("html", "text/html")] {
+            // Arrange
+            let key = format!("privacy-policy/v1.{extension}");
+
+            let mut repository = MockTermRepository::new();
+            let term = term(1, &key);
+            repository
+                .expect_get_all_terms()
+                .returning(move || Ok(vec![term.clone()]));
+
+            let mut source = MockStorageService::new();
+            source
+                .expect_download_file()
+                .returning(write_download(b"# Terms"));
+
+            let mut destination = MockStorageService::new();
+            destination.expect_list_files().returning(|| Ok(vec![]));
+            destination
+                .expect_put_file()
+                .withf(move |_, _, content_type, _, _| content_type == expected)
+                .times(1)
+                .returning(|_, _, _, _, _| Ok(()));
+            destination
+                .expect_download_file()
+                .returning(write_download(b"# Terms"));
+            destination.expect_publish_file().returning(|_, _| Ok(()));
+
+            // Act
+            let result = copy_storage_use_case(
+                &repository,
+                &source,
+                &destination,
+                &content_digests(),
+                &work_dir(&format!("content-type-{extension}")),
+                false,
+            )
+            .await;
+
+            // Assert
+            let report = result.unwrap();
+            assert_eq!(report.copied_documents[0].key, key);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_skips_intact_copies() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_all_terms()
+            .returning(|| Ok(vec![term(1, "privacy-policy/v1.pdf")]));
+
+        let mut source = MockStorageService::new();
+        source
+            .expect_download_file()
+            .returning(write_download(b"%PDF-1.4"));
+
+        let mut destination = MockStorageService::new();
+        destination
+            .expect_list_files()
+            .returning(|| Ok(vec![stored("privacy-policy/v1.pdf")]));
+        destination
+            .expect_download_file()
+            .with(eq("privacy-policy/v1.pdf"), always())
+            .returning(write_download(b"%PDF-1.4"));
+        destination.expect_put_file().never();
+        // The previous run may have stopped before protecting the copy
+        destination
+            .expect_publish_file()
+            .with(eq("privacy-policy/v1.pdf"), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = copy_storage_use_case(
+            &repository,
+            &source,
+            &destination,
+            &content_digests(),
+            &work_dir("intact"),
+            false,
+        )
+        .await;
+
+        // Assert
+        let report = result.unwrap();
+        assert!(report.copied_documents.is_empty());
+        assert_eq!(report.skipped_terms.len(), 1);
+        assert!(report.failed_terms.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_dry_run_does_not_copy() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository.expect_get_all_terms().returning(|| {
+            Ok(vec![
+                term(1, "privacy-policy/v1.pdf"),
+                term(2, "privacy-policy/v2.pdf"),
+            ])
+        });
+
+        let mut source = MockStorageService::new();
+        source.expect_download_file().never();
+
+        let mut destination = MockStorageService::new();
+        destination
+            .expect_list_files()
+            .returning(|| Ok(vec![stored("privacy-policy/v2.pdf")]));
+        destination.expect_download_file().never();
+        destination.expect_put_file().never();
+
+        // Act
+        let result = copy_storage_use_case(
+            &repository,
+            &source,
+            &destination,
+            &content_digests(),
+            &work_dir("dry-run"),
+            true,
+        )
+        .await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.copied_documents.len(), 1);
+        assert_eq!(report.copied_documents[0].term_id, 1);
+        assert_eq!(report.skipped_terms.len(), 1);
+        assert_eq!(report.skipped_terms[0].id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_copies_mismatching_copy_again() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_all_terms()
+            .returning(|| Ok(vec![term(1, "privacy-policy/v1.pdf")]));
+
+        let mut source = MockStorageService::new();
+        source
+            .expect_download_file()
+            .returning(write_download(b"%PDF-1.4"));
+
+        // The previous run stopped in the middle of the copy
+        let mut destination = MockStorageService::new();
+        destination
+            .expect_list_files()
+            .returning(|| Ok(vec![stored("privacy-policy/v1.pdf")]));
+        let mut downloads = 0;
+        destination
+            .expect_download_file()
+            .returning(move |_, destination| {
+                downloads += 1;
+                let content: &[u8] = if downloads == 1 { b"%PDF" } else { b"%PDF-1.4" };
+                std::fs::write(destination, content).unwrap();
+
+                Ok(())
+            });
+        destination
+            .expect_put_file()
+            .with(
+                eq("privacy-policy/v1.pdf"),
+                always(),
+                always(),
+                always(),
+                always(),
+            )
+            .times(1)
+            .returning(|_, _, _, _, _| Ok(()));
+        destination
+            .expect_publish_file()
+            .times(1)
+            .returning(|_, _| Ok(()));
+        destination
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // Act
+        let result = copy_storage_use_case(
+            &repository,
+            &source,
+            &destination,
+            &content_digests(),
+            &work_dir("resume"),
+            false,
+        )
+        .await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.copied_documents.len(), 1);
+        assert!(report.skipped_terms.is_empty());
+        assert!(report.failed_terms.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_copy_storage_removes_copy_with_hash_mismatch() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_all_terms()
+            .returning(|| Ok(vec![term(1, "privacy-policy/v1.pdf")]));
+
+        let mut source = MockStorageService::new();
+        source
+            .expect_download_file()
+            .returning(write_download(b"%PDF-1.4"));
+
+        let mut destination = MockStorageService::new();
+        destination.expect_list_files().returning(|| Ok(vec![]));
+        destination
+            .expect_put_file()
+            .returning(|_, _, _, _, _| Ok(()));
+        destination
+            .expect_download_file()
+            .returning(write_download(b"truncated"));
+        destination
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+        destination.expect_publish_file().never();
+
+        // Act
+        let result = copy_storage_use_case(
+            &repository,
+            &source,
+            &destination,
+            &content_digests(),
+            &work_dir("mismatch"),
+            false,
+        )
+        .await;
+
+        // Assert
+        let report = result.unwrap();
+        assert!(report.copied_documents.is_empty());
+        assert_eq!(report.failed_terms.len(), 1);
+        assert_eq!(report.failed_terms[0].id, 1);
+    }
+}
@@ -5,30 +5,46 @@ use crate::{
     },
     dto::AcceptedTermOfUseDTO,
     errors::{Result, TermsOfUseError},
+    use_cases::{clauses::select_optional_clauses, has_agreed_to_terms::consent_status_for_term},
 };
 
-#[tracing::instrument(skip(repository, cache, publisher, user_id, term_id))]
+/// Records that a user agreed to a term, along with the optional clauses they accepted.
+#[tracing::instrument(skip(repository, cache, publisher, user_id, term_id, accepted_clauses))]
 pub async fn create_user_agreement_use_case(
     repository: &dyn DatabaseRepository,
     cache: &dyn CacheService,
     publisher: &dyn PublisherService,
     user_id: i32,
     term_id: i32,
+    accepted_clauses: &[String],
 ) -> Result<()> {
     let term = repository
         .get_term_by_id(term_id)
         .await?
         .ok_or(TermsOfUseError::NotFound)?;
 
-    repository.create_user_agreement(user_id, term_id).await?;
+    let accepted_clauses = select_optional_clauses(&term.clauses, accepted_clauses)?;
 
-    let _ = cache.store_user_agreement(user_id, &term.group, true).await;
+    repository
+        .create_user_agreement(user_id, term_id, &accepted_clauses)
+        .await?;
+
+    // Agreeing to an older version does not bring the user up to date
+    if let Ok(Some(latest_term)) = repository.get_latest_term_for_group(&term.group).await
+        && let Ok((status, expires_at)) =
+            consent_status_for_term(repository, user_id, &latest_term).await
+    {
+        let _ = cache
+            .store_user_agreement(user_id, &term.group, status, expires_at)
+            .await;
+    }
 
     let _ = publisher
         .publish_agreement(AcceptedTermOfUseDTO {
             term_id,
             user_id,
             group: term.group,
+            accepted_clauses,
         })
         .await;
 
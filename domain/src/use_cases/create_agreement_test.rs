@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use async_trait::async_trait;
-    use chrono::Utc;
+    use chrono::{TimeDelta, Utc};
     use mockall::predicate::*;
 
     use crate::{
@@ -10,7 +10,9 @@ mod tests {
             service::{MockCacheService, MockPublisherService},
         },
         dto::AcceptedTermOfUseDTO,
-        entities::TermOfUse,
+        entities::{
+            Bundle, Clause, ConsentStatus, Group, TermOfUse, TermReservation, UploadPolicy,
+        },
         errors::TermsOfUseError,
         use_cases::create_user_agreement_use_case,
     };
@@ -19,6 +21,7 @@ mod tests {
     struct MockCombinedRepository {
         term_repo: MockTermRepository,
         agreement_repo: MockUserAgreementRepository,
+        group: Option<Group>,
     }
 
     #[async_trait]
@@ -30,24 +33,91 @@ mod tests {
             self.term_repo.get_latest_term_for_group(group).await
         }
 
+        async fn get_latest_terms_for_groups(
+            &self,
+            groups: &[String],
+        ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
         async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>, TermsOfUseError> {
             self.term_repo.get_term_by_id(term_id).await
         }
 
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
         async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse, TermsOfUseError> {
             self.term_repo.create_term(term).await
         }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+            self.term_repo.get_all_terms().await
+        }
     }
 
     #[async_trait]
     impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
-        async fn has_user_agreed_to_term(
+        async fn get_agreed_at(
             &self,
             user_id: i32,
             term_id: i32,
-        ) -> Result<bool, TermsOfUseError> {
+        ) -> Result<Option<chrono::NaiveDateTime>, TermsOfUseError> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(
+            &self,
+            user_id: i32,
+            term_ids: &[i32],
+        ) -> Result<Vec<i32>, TermsOfUseError> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>, TermsOfUseError> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>, TermsOfUseError> {
             self.agreement_repo
-                .has_user_agreed_to_term(user_id, term_id)
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>, TermsOfUseError> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
                 .await
         }
 
@@ -55,13 +125,132 @@ mod tests {
             &self,
             user_id: i32,
             term_id: i32,
+            accepted_clauses: &[String],
         ) -> Result<(), TermsOfUseError> {
             self.agreement_repo
-                .create_user_agreement(user_id, term_id)
+                .create_user_agreement(user_id, term_id, accepted_clauses)
+                .await
+        }
+
+        async fn create_user_agreements(
+            &self,
+            user_id: i32,
+            term_ids: &[i32],
+        ) -> Result<(), TermsOfUseError> {
+            self.agreement_repo
+                .create_user_agreements(user_id, term_ids)
                 .await
         }
     }
 
+    // Reservations are not involved in agreements
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(
+            &self,
+            _reservation_id: i32,
+        ) -> Result<Option<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>, TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in agreements
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(
+            &self,
+            _group: &str,
+        ) -> Result<Option<UploadPolicy>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(
+            &self,
+            _policy: UploadPolicy,
+        ) -> Result<UploadPolicy, TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are only read for their consent expiry
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>, TermsOfUseError> {
+            Ok(self.group.clone())
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in creating single agreements
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle, TermsOfUseError> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<(), TermsOfUseError> {
+            unimplemented!()
+        }
+    }
+
     impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
 
     #[tokio::test]
@@ -74,33 +263,55 @@ mod tests {
             url: "uploads/privacy-v2.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
+        let latest_term = term.clone();
         let mut term_repo = MockTermRepository::new();
         term_repo
             .expect_get_term_by_id()
             .with(eq(10))
             .times(1)
             .returning(move |_| Ok(Some(term.clone())));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .returning(move |_| Ok(Some(latest_term.clone())));
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
             .expect_create_user_agreement()
-            .with(eq(42), eq(10))
+            .withf(|user_id, term_id, accepted_clauses| {
+                *user_id == 42 && *term_id == 10 && accepted_clauses.is_empty()
+            })
             .times(1)
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
         cache
             .expect_store_user_agreement()
-            .with(eq(42), eq("privacy-policy"), eq(true))
+            .with(
+                eq(42),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToLatest),
+                eq(None),
+            )
             .times(1)
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         let mut publisher = MockPublisherService::new();
         publisher
@@ -116,7 +327,85 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_agreement_renews_an_expired_consent() {
+        // Arrange
+        let term = TermOfUse {
+            id: 10,
+            group: "privacy-policy".to_string(),
+            version: 2,
+            url: "uploads/privacy-v2.pdf".to_string(),
+            created_at: Utc::now().naive_utc() - TimeDelta::days(500),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let latest_term = term.clone();
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_term_by_id()
+            .returning(move |_| Ok(Some(term.clone())));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(move |_| Ok(Some(latest_term.clone())));
+
+        // The earlier agreement expired, the renewal is the newest one
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_create_user_agreement()
+            .with(eq(42), eq(10), always())
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(42), eq(10))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: Some(Group {
+                name: "privacy-policy".to_string(),
+                description: None,
+                owner: None,
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: Some(365),
+            }),
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .withf(|user_id, group, status, expires_at| {
+                *user_id == 42
+                    && group == "privacy-policy"
+                    && *status == ConsentStatus::ConsentedToLatest
+                    && expires_at.is_some_and(|expires_at| {
+                        expires_at > Utc::now().naive_utc() + TimeDelta::days(364)
+                    })
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_publish_agreement().returning(|_| Ok(()));
+
+        // Act
+        let result =
+            create_user_agreement_use_case(&repository, &cache, &publisher, 42, 10, &[]).await;
 
         // Assert
         assert!(result.is_ok());
@@ -132,6 +421,7 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let cache = MockCacheService::new();
@@ -142,7 +432,8 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
 
         // Assert
         assert!(result.is_err());
@@ -161,6 +452,7 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let cache = MockCacheService::new();
@@ -171,7 +463,8 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
 
         // Assert
         assert!(result.is_err());
@@ -188,6 +481,12 @@ mod tests {
             url: "uploads/privacy-v2.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -198,11 +497,12 @@ mod tests {
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
             .expect_create_user_agreement()
-            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+            .returning(|_, _, _| Err(TermsOfUseError::InternalServerError));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let cache = MockCacheService::new();
@@ -213,7 +513,8 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
 
         // Assert
         assert!(result.is_err());
@@ -230,27 +531,41 @@ mod tests {
             url: "uploads/privacy-v2.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
+        let latest_term = term.clone();
         let mut term_repo = MockTermRepository::new();
         term_repo
             .expect_get_term_by_id()
             .returning(move |_| Ok(Some(term.clone())));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(move |_| Ok(Some(latest_term.clone())));
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
             .expect_create_user_agreement()
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
         cache
             .expect_store_user_agreement()
-            .returning(|_, _, _| Err(TermsOfUseError::InternalServerError));
+            .returning(|_, _, _, _| Err(TermsOfUseError::InternalServerError));
 
         let mut publisher = MockPublisherService::new();
         publisher.expect_publish_agreement().returning(|_| Ok(()));
@@ -260,7 +575,8 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
 
         // Assert - Should succeed despite cache failure
         assert!(result.is_ok());
@@ -276,27 +592,41 @@ mod tests {
             url: "uploads/privacy-v2.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
+        let latest_term = term.clone();
         let mut term_repo = MockTermRepository::new();
         term_repo
             .expect_get_term_by_id()
             .returning(move |_| Ok(Some(term.clone())));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(move |_| Ok(Some(latest_term.clone())));
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
             .expect_create_user_agreement()
-            .returning(|_, _| Ok(()));
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
         cache
             .expect_store_user_agreement()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         let mut publisher = MockPublisherService::new();
         publisher
@@ -308,9 +638,202 @@ mod tests {
 
         // Act
         let result =
-            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id).await;
+            create_user_agreement_use_case(&repository, &cache, &publisher, user_id, term_id, &[])
+                .await;
 
         // Assert - Should succeed despite publisher failure
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_create_user_agreement_to_older_version_is_not_up_to_date() {
+        // Arrange
+        let term = |id: i32, version: u32| TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version,
+            url: format!("uploads/privacy-v{version}.pdf"),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_term_by_id()
+            .returning(move |_| Ok(Some(term(10, 2))));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(move |_| Ok(Some(term(11, 3))));
+        term_repo
+            .expect_get_terms_for_group()
+            .returning(move |_, _| Ok(vec![term(11, 3), term(10, 2)]));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_create_user_agreement()
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(42), eq(11))
+            .returning(|_, _| Ok(None));
+        agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![10]));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(42),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToOlder { version: 2 }),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_publish_agreement().returning(|_| Ok(()));
+
+        // Act
+        let result =
+            create_user_agreement_use_case(&repository, &cache, &publisher, 42, 10, &[]).await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    fn term_with_clauses() -> TermOfUse {
+        TermOfUse {
+            id: 10,
+            group: "privacy-policy".to_string(),
+            version: 2,
+            url: "uploads/privacy-v2.pdf".to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![
+                Clause {
+                    key: "data-processing".to_string(),
+                    title: "Processing of personal data".to_string(),
+                    mandatory: true,
+                },
+                Clause {
+                    key: "marketing-emails".to_string(),
+                    title: "Marketing emails".to_string(),
+                    mandatory: false,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_user_agreement_records_optional_clauses() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_term_by_id()
+            .returning(|_| Ok(Some(term_with_clauses())));
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term_with_clauses())));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_create_user_agreement()
+            .withf(|_, _, accepted_clauses| accepted_clauses == ["marketing-emails".to_string()])
+            .times(1)
+            .returning(|_, _, _| Ok(()));
+        agreement_repo
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher
+            .expect_publish_agreement()
+            .withf(|dto: &AcceptedTermOfUseDTO| dto.accepted_clauses == ["marketing-emails"])
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let accepted_clauses = vec![
+            "data-processing".to_string(),
+            "marketing-emails".to_string(),
+        ];
+
+        // Act
+        let result = create_user_agreement_use_case(
+            &repository,
+            &cache,
+            &publisher,
+            42,
+            10,
+            &accepted_clauses,
+        )
+        .await;
+
+        // Assert
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_user_agreement_rejects_unknown_clauses() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_term_by_id()
+            .returning(|_| Ok(Some(term_with_clauses())));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo.expect_create_user_agreement().times(0);
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let cache = MockCacheService::new();
+        let publisher = MockPublisherService::new();
+
+        // Act
+        let result = create_user_agreement_use_case(
+            &repository,
+            &cache,
+            &publisher,
+            42,
+            10,
+            &["newsletter".to_string()],
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
 }
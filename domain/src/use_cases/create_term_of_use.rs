@@ -1,33 +1,79 @@
 use std::path::Path;
 
 use chrono::Utc;
+use tracing::error;
 
 use crate::{
     data::{
-        repository::TermRepository,
-        service::{CacheService, StorageService},
+        repository::DatabaseRepository,
+        service::{CacheService, DocumentService, ScannerService, SchemaService, StorageService},
     },
     dto::CreateTermOfUseDTO,
     entities::TermOfUse,
-    errors::Result,
+    errors::{Result, TermsOfUseError},
+    use_cases::{
+        change_summaries::build_change_summaries, clauses::validate_clauses,
+        group::check_group_use_case, term_document::read_term_document,
+        upload_policy::check_upload_policy_use_case,
+    },
 };
 
-#[tracing::instrument(skip(repository, upload_service, cache_service, term, file_path))]
+#[tracing::instrument(skip(
+    repository,
+    upload_service,
+    cache_service,
+    scanner,
+    documents,
+    schemas,
+    term,
+    file_path
+))]
+#[allow(clippy::too_many_arguments)]
 pub async fn create_term_of_use_use_case(
-    repository: &dyn TermRepository,
+    repository: &dyn DatabaseRepository,
     upload_service: &dyn StorageService,
     cache_service: &dyn CacheService,
+    scanner: &dyn ScannerService,
+    documents: &dyn DocumentService,
+    schemas: &dyn SchemaService,
     term: CreateTermOfUseDTO,
     file_path: &Path,
     content_type: &str,
 ) -> Result<TermOfUse> {
+    let size = std::fs::metadata(file_path)
+        .map_err(|err| {
+            error!("Failed to read the size of {}: {err}", file_path.display());
+
+            TermsOfUseError::InternalServerError
+        })?
+        .len();
+    check_group_use_case(repository, &term.group).await?;
+    check_upload_policy_use_case(
+        repository,
+        schemas,
+        &term.group,
+        content_type,
+        size,
+        term.info.as_deref(),
+        &term.change_summaries,
+        &term.metadata,
+    )
+    .await?;
+
+    validate_clauses(&term.clauses)?;
+    let change_summaries = build_change_summaries(documents, term.change_summaries)?;
+    let document =
+        read_term_document(scanner, documents, file_path, &term.group, content_type).await?;
+
     let latest_term = repository.get_latest_term_for_group(&term.group).await?;
     let next_version = match latest_term {
         Some(t) => t.version + 1,
         None => 1,
     };
 
-    let uploaded_file = upload_service.upload_file(file_path, content_type).await?;
+    let uploaded_file = upload_service
+        .upload_file(file_path, content_type, &term.group, next_version)
+        .await?;
 
     let new_term = TermOfUse {
         id: 0,
@@ -36,6 +82,12 @@ pub async fn create_term_of_use_use_case(
         url: uploaded_file.clone(),
         created_at: Utc::now().naive_utc(),
         info: term.info,
+        html: document.html,
+        text: document.text,
+        change_summaries,
+        pdf_metadata: document.pdf_metadata,
+        metadata: term.metadata,
+        clauses: term.clauses,
     };
 
     match repository.create_term(new_term).await {
@@ -44,12 +96,25 @@ pub async fn create_term_of_use_use_case(
                 .invalidate_cache_for_group(&created_term.group)
                 .await;
 
+            // The term is stored at this point, so a document left unprotected is only reported
+            if let Err(err) = upload_service
+                .publish_file(&created_term.url, created_term.id)
+                .await
+            {
+                error!(
+                    "Failed to protect the document of term {}: {} ({err:?})",
+                    created_term.id, created_term.url
+                );
+            }
+
             created_term.url = upload_service.get_file_url(&created_term.url).await?;
 
             Ok(created_term)
         }
         Err(e) => {
-            let _ = upload_service.delete_file(&uploaded_file).await;
+            if let Err(err) = upload_service.delete_file(&uploaded_file).await {
+                error!("Failed to delete the orphaned document {uploaded_file} ({err:?})");
+            }
 
             Err(e)
         }
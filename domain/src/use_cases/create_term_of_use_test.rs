@@ -1,20 +1,298 @@
 #[cfg(test)]
 mod tests {
+    use async_trait::async_trait;
     use chrono::Utc;
     use mockall::predicate::*;
-    use std::path::Path;
+    use serde_json::json;
+    use std::{collections::BTreeMap, path::Path};
 
     use crate::{
         data::{
-            repository::MockTermRepository,
-            service::{MockCacheService, MockStorageService},
+            repository::{MockGroupRepository, MockTermRepository, MockUploadPolicyRepository},
+            service::{
+                MockCacheService, MockDocumentService, MockScannerService, MockSchemaService,
+                MockStorageService,
+            },
         },
         dto::CreateTermOfUseDTO,
-        entities::TermOfUse,
-        errors::TermsOfUseError,
+        entities::{
+            Bundle, Group, PdfMetadata, ScanVerdict, TermDocument, TermOfUse, TermReservation,
+            UploadPolicy,
+        },
+        errors::{Result, TermsOfUseError},
         use_cases::create_term_of_use_use_case,
     };
 
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        policy_repo: MockUploadPolicyRepository,
+        group_repo: MockGroupRepository,
+    }
+
+    impl MockCombinedRepository {
+        /// Repository of a registered group without an upload policy.
+        fn without_policy(term_repo: MockTermRepository) -> Self {
+            let mut policy_repo = MockUploadPolicyRepository::new();
+            policy_repo
+                .expect_get_upload_policy()
+                .returning(|_| Ok(None));
+
+            Self {
+                term_repo,
+                policy_repo,
+                group_repo: registered_groups(),
+            }
+        }
+    }
+
+    /// Every group is registered.
+    fn registered_groups() -> MockGroupRepository {
+        let mut group_repo = MockGroupRepository::new();
+        group_repo.expect_get_group().returning(|name| {
+            Ok(Some(Group {
+                name: name.to_string(),
+                description: None,
+                owner: None,
+                mandatory: false,
+                default_locale: "en".to_string(),
+                max_consent_age_days: None,
+            }))
+        });
+        group_repo
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    // Agreements are not involved in creating terms
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Reservations are not involved in creating terms
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, group: &str) -> Result<Option<UploadPolicy>> {
+            self.policy_repo.get_upload_policy(group).await
+        }
+
+        async fn save_upload_policy(&self, policy: UploadPolicy) -> Result<UploadPolicy> {
+            self.policy_repo.save_upload_policy(policy).await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            self.group_repo.get_group(name).await
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            self.group_repo.get_groups().await
+        }
+
+        async fn create_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.create_group(group).await
+        }
+
+        async fn update_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.update_group(group).await
+        }
+
+        async fn delete_group(&self, name: &str) -> Result<()> {
+            self.group_repo.delete_group(name).await
+        }
+    }
+
+    // Bundles are not involved in creating terms
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn clean_scanner() -> MockScannerService {
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .returning(|_| Ok(ScanVerdict::Clean));
+        scanner
+    }
+
+    fn sample_documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents.expect_read_document().returning(|_, _| {
+            Ok(TermDocument {
+                html: None,
+                text: Some("Sample terms".to_string()),
+                pdf_metadata: Some(PdfMetadata {
+                    page_count: 1,
+                    title: None,
+                    producer: None,
+                }),
+            })
+        });
+        documents
+            .expect_render_markdown()
+            .returning(|markdown| format!("<p>{markdown}</p>"));
+        documents.expect_sha256().returning(|_| {
+            Ok("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_string())
+        });
+        documents
+    }
+
+    fn valid_schemas() -> MockSchemaService {
+        let mut schemas = MockSchemaService::new();
+        schemas.expect_violations().returning(|_, _| Ok(vec![]));
+        schemas
+    }
+
     #[tokio::test]
     async fn test_create_first_term_of_use_success() {
         // Arrange
@@ -37,7 +315,13 @@ mod tests {
         storage
             .expect_upload_file()
             .times(1)
-            .returning(|_, _| Ok("uploads/test-file.pdf".to_string()));
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
+
+        storage
+            .expect_publish_file()
+            .with(eq("uploads/test-file.pdf"), eq(123))
+            .times(1)
+            .returning(|_, _| Ok(()));
 
         storage
             .expect_get_file_url()
@@ -55,15 +339,21 @@ mod tests {
         let dto = CreateTermOfUseDTO {
             group: "privacy-policy".to_string(),
             info: Some("Initial version".to_string()),
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
-        let file_path = Path::new("/tmp/test.pdf");
+        let file_path = Path::new(SAMPLE_PDF);
 
         // Act
         let result = create_term_of_use_use_case(
-            &repository,
+            &MockCombinedRepository::without_policy(repository),
             &storage,
             &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
             dto,
             file_path,
             "application/pdf",
@@ -78,6 +368,7 @@ mod tests {
         assert_eq!(term.version, 1); // First version
         assert_eq!(term.url, "https://storage.example.com/test-file.pdf");
         assert_eq!(term.info, Some("Initial version".to_string()));
+        assert_eq!(term.pdf_metadata.unwrap().page_count, 1);
     }
 
     #[tokio::test]
@@ -90,6 +381,12 @@ mod tests {
             url: "uploads/old-file.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut repository = MockTermRepository::new();
@@ -105,7 +402,14 @@ mod tests {
         let mut storage = MockStorageService::new();
         storage
             .expect_upload_file()
-            .returning(|_, _| Ok("uploads/test-file.pdf".to_string()));
+            .with(always(), eq("application/pdf"), eq("privacy-policy"), eq(4))
+            .times(1)
+            .returning(|_, _, _, _| Ok("privacy-policy/v4/test-file.pdf".to_string()));
+
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v4/test-file.pdf"), eq(2))
+            .returning(|_, _| Ok(()));
 
         storage
             .expect_get_file_url()
@@ -119,15 +423,21 @@ mod tests {
         let dto = CreateTermOfUseDTO {
             group: "privacy-policy".to_string(),
             info: Some("New version".to_string()),
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
-        let file_path = Path::new("/tmp/test.pdf");
+        let file_path = Path::new(SAMPLE_PDF);
 
         // Act
         let result = create_term_of_use_use_case(
-            &repository,
+            &MockCombinedRepository::without_policy(repository),
             &storage,
             &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
             dto,
             file_path,
             "application/pdf",
@@ -151,22 +461,28 @@ mod tests {
         let mut storage = MockStorageService::new();
         storage
             .expect_upload_file()
-            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+            .returning(|_, _, _, _| Err(TermsOfUseError::InternalServerError));
 
         let cache = MockCacheService::new();
 
         let dto = CreateTermOfUseDTO {
             group: "privacy-policy".to_string(),
             info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
-        let file_path = Path::new("/tmp/test.pdf");
+        let file_path = Path::new(SAMPLE_PDF);
 
         // Act
         let result = create_term_of_use_use_case(
-            &repository,
+            &MockCombinedRepository::without_policy(repository),
             &storage,
             &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
             dto,
             file_path,
             "application/pdf",
@@ -193,7 +509,7 @@ mod tests {
         let mut storage = MockStorageService::new();
         storage
             .expect_upload_file()
-            .returning(|_, _| Ok("uploads/test-file.pdf".to_string()));
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
 
         storage
             .expect_delete_file()
@@ -206,15 +522,21 @@ mod tests {
         let dto = CreateTermOfUseDTO {
             group: "privacy-policy".to_string(),
             info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
-        let file_path = Path::new("/tmp/test.pdf");
+        let file_path = Path::new(SAMPLE_PDF);
 
         // Act
         let result = create_term_of_use_use_case(
-            &repository,
+            &MockCombinedRepository::without_policy(repository),
             &storage,
             &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
             dto,
             file_path,
             "application/pdf",
@@ -242,7 +564,9 @@ mod tests {
         let mut storage = MockStorageService::new();
         storage
             .expect_upload_file()
-            .returning(|_, _| Ok("uploads/test-file.pdf".to_string()));
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
+
+        storage.expect_publish_file().returning(|_, _| Ok(()));
 
         storage
             .expect_get_file_url()
@@ -256,15 +580,21 @@ mod tests {
         let dto = CreateTermOfUseDTO {
             group: "terms-of-service".to_string(),
             info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
-        let file_path = Path::new("/tmp/test.pdf");
+        let file_path = Path::new(SAMPLE_PDF);
 
         // Act
         let result = create_term_of_use_use_case(
-            &repository,
+            &MockCombinedRepository::without_policy(repository),
             &storage,
             &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
             dto,
             file_path,
             "application/pdf",
@@ -277,4 +607,433 @@ mod tests {
         assert_eq!(term.group, "terms-of-service");
         assert_eq!(term.info, None);
     }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_ignores_publish_failure() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        repository.expect_create_term().returning(|mut term| {
+            term.id = 7;
+            Ok(term)
+        });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
+
+        storage
+            .expect_publish_file()
+            .with(eq("uploads/test-file.pdf"), eq(7))
+            .times(1)
+            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+
+        storage.expect_delete_file().times(0);
+
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://storage.example.com/test-file.pdf".to_string()));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let file_path = Path::new(SAMPLE_PDF);
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            file_path,
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().id, 7);
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_stores_rendered_change_summaries() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        repository
+            .expect_create_term()
+            .withf(|term| {
+                term.change_summaries.len() == 1
+                    && term.change_summaries[0].locale == "en"
+                    && term.change_summaries[0].html == "<p>Data is kept for **30 days**.</p>"
+            })
+            .times(1)
+            .returning(|mut term| {
+                term.id = 1;
+                Ok(term)
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
+
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://storage.example.com/test-file.pdf".to_string()));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::from([(
+                "en".to_string(),
+                "Data is kept for **30 days**.".to_string(),
+            )]),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let file_path = Path::new(SAMPLE_PDF);
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            file_path,
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        let term = result.unwrap();
+        assert_eq!(
+            term.change_summaries[0].markdown,
+            "Data is kept for **30 days**."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_rejects_invalid_change_summary_before_upload() {
+        // Arrange
+        let repository = MockTermRepository::new();
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let cache = MockCacheService::new();
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::from([("english".to_string(), "Changed".to_string())]),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let file_path = Path::new(SAMPLE_PDF);
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            file_path,
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_rejects_unreadable_document_before_upload() {
+        // Arrange
+        let repository = MockTermRepository::new();
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let cache = MockCacheService::new();
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let mut documents = MockDocumentService::new();
+        documents.expect_read_document().returning(|_, _| {
+            Err(TermsOfUseError::Validation(
+                "The PDF document could not be read".to_string(),
+            ))
+        });
+
+        let file_path = Path::new(SAMPLE_PDF);
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &documents,
+            &valid_schemas(),
+            dto,
+            file_path,
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_rejects_infected_document_before_upload() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository.expect_get_latest_term_for_group().times(0);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let cache = MockCacheService::new();
+
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Ok(ScanVerdict::Infected("Eicar-Signature".to_string())));
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &scanner,
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            Path::new(SAMPLE_PDF),
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_rejects_documents_outside_the_group_policy() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().times(0);
+
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo
+            .expect_get_upload_policy()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|group| {
+                Ok(Some(UploadPolicy {
+                    group: group.to_string(),
+                    content_types: vec!["text/html".to_string()],
+                    max_size: 1024,
+                    required_fields: vec![],
+                    metadata_schema: None,
+                }))
+            });
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let mut scanner = MockScannerService::new();
+        scanner.expect_scan_file().times(0);
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository {
+                term_repo,
+                policy_repo,
+                group_repo: registered_groups(),
+            },
+            &storage,
+            &MockCacheService::new(),
+            &scanner,
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            Path::new(SAMPLE_PDF),
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_stores_metadata() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        repository
+            .expect_create_term()
+            .withf(|term| term.metadata.get("region") == Some(&json!("eu")))
+            .times(1)
+            .returning(|mut term| {
+                term.id = 1;
+                Ok(term)
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .returning(|_, _, _, _| Ok("uploads/test-file.pdf".to_string()));
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://storage.example.com/test-file.pdf".to_string()));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let dto = CreateTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: json!({ "region": "eu" }).as_object().unwrap().clone(),
+            clauses: vec![],
+        };
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository::without_policy(repository),
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            Path::new(SAMPLE_PDF),
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(result.unwrap().metadata["region"], "eu");
+    }
+
+    #[tokio::test]
+    async fn test_create_term_of_use_rejects_unregistered_group() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_create_term().times(0);
+
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_get_upload_policy().times(0);
+
+        let mut group_repo = MockGroupRepository::new();
+        group_repo
+            .expect_get_group()
+            .with(eq("unknown"))
+            .returning(|_| Ok(None));
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let mut scanner = MockScannerService::new();
+        scanner.expect_scan_file().times(0);
+
+        let dto = CreateTermOfUseDTO {
+            group: "unknown".to_string(),
+            info: None,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        // Act
+        let result = create_term_of_use_use_case(
+            &MockCombinedRepository {
+                term_repo,
+                policy_repo,
+                group_repo,
+            },
+            &storage,
+            &MockCacheService::new(),
+            &scanner,
+            &sample_documents(),
+            &valid_schemas(),
+            dto,
+            Path::new(SAMPLE_PDF),
+            "application/pdf",
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "Group 'unknown' is not registered"
+        ));
+    }
 }
@@ -0,0 +1,43 @@
+use crate::{
+    data::{repository::TermRepository, service::DocumentService},
+    dto::TermDiffDTO,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Unchanged lines shown around every change.
+const CONTEXT_LINES: usize = 3;
+
+#[tracing::instrument(skip(repository, documents, group))]
+pub async fn diff_terms_use_case(
+    repository: &dyn TermRepository,
+    documents: &dyn DocumentService,
+    group: &str,
+    from_version: u32,
+    to_version: u32,
+) -> Result<TermDiffDTO> {
+    let from_text = term_text(repository, group, from_version).await?;
+    let to_text = term_text(repository, group, to_version).await?;
+
+    let hunks = documents.diff_text(&from_text, &to_text, CONTEXT_LINES);
+
+    Ok(TermDiffDTO {
+        group: group.to_string(),
+        from_version,
+        to_version,
+        hunks,
+    })
+}
+
+async fn term_text(repository: &dyn TermRepository, group: &str, version: u32) -> Result<String> {
+    let term = repository
+        .get_term_by_version(group, version)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)?;
+
+    // Terminating every line keeps a line appended at the end from changing the previous one
+    term.text.map(|text| format!("{text}\n")).ok_or_else(|| {
+        TermsOfUseError::Validation(format!(
+            "Version {version} of {group} has no extracted text to compare"
+        ))
+    })
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{repository::MockTermRepository, service::MockDocumentService},
+        dto::{DiffHunk, DiffLine, DiffOperation},
+        entities::TermOfUse,
+        errors::TermsOfUseError,
+        use_cases::diff_terms_use_case,
+    };
+
+    fn term(version: u32, text: Option<&str>) -> TermOfUse {
+        TermOfUse {
+            id: version as i32,
+            group: "privacy-policy".to_string(),
+            url: format!("privacy-policy/v{version}.pdf"),
+            version,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: text.map(str::to_string),
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn repository_with(versions: Vec<TermOfUse>) -> MockTermRepository {
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_term_by_version()
+            .with(eq("privacy-policy"), always())
+            .returning(move |_, version| {
+                Ok(versions
+                    .iter()
+                    .find(|term| term.version == version)
+                    .cloned())
+            });
+
+        repository
+    }
+
+    fn hunk() -> DiffHunk {
+        DiffHunk {
+            old_start: 2,
+            old_lines: 1,
+            new_start: 2,
+            new_lines: 1,
+            lines: vec![DiffLine {
+                operation: DiffOperation::Insert,
+                old_line: None,
+                new_line: Some(2),
+                text: "We keep data for 90 days.".to_string(),
+            }],
+        }
+    }
+
+    fn unused_documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents.expect_diff_text().never();
+        documents
+    }
+
+    #[tokio::test]
+    async fn test_diff_terms_compares_terminated_texts_of_both_versions() {
+        // Arrange
+        let repository = repository_with(vec![
+            term(1, Some("Intro\nWe keep data for 30 days.")),
+            term(2, Some("Intro\nWe keep data for 90 days.")),
+        ]);
+
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_diff_text()
+            .with(
+                eq("Intro\nWe keep data for 30 days.\n"),
+                eq("Intro\nWe keep data for 90 days.\n"),
+                eq(3),
+            )
+            .times(1)
+            .returning(|_, _, _| vec![hunk()]);
+
+        // Act
+        let result = diff_terms_use_case(&repository, &documents, "privacy-policy", 1, 2).await;
+
+        // Assert
+        let diff = result.unwrap();
+        assert_eq!(diff.group, "privacy-policy");
+        assert_eq!(diff.from_version, 1);
+        assert_eq!(diff.to_version, 2);
+        assert_eq!(diff.hunks, vec![hunk()]);
+    }
+
+    #[tokio::test]
+    async fn test_diff_terms_requires_both_versions() {
+        // Arrange
+        let repository = repository_with(vec![term(1, Some("Intro"))]);
+
+        // Act
+        let result =
+            diff_terms_use_case(&repository, &unused_documents(), "privacy-policy", 1, 5).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_diff_terms_requires_extracted_text() {
+        // Arrange
+        let repository = repository_with(vec![term(1, None), term(2, Some("Intro"))]);
+
+        // Act
+        let result =
+            diff_terms_use_case(&repository, &unused_documents(), "privacy-policy", 1, 2).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
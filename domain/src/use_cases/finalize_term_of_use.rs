@@ -0,0 +1,237 @@
+use std::path::Path;
+
+use chrono::{TimeDelta, Utc};
+use tracing::error;
+
+use crate::{
+    data::{
+        repository::DatabaseRepository,
+        service::{CacheService, DocumentService, ScannerService, StorageService},
+    },
+    entities::{StoredFileInfo, TermOfUse, TermReservation},
+    errors::{Result, TermsOfUseError},
+    use_cases::term_document::read_term_document,
+};
+
+/// Second phase of a direct upload: verifies the document uploaded for a reservation
+/// and creates the reserved term.
+///
+/// The document must match the size, content type and SHA-256 digest announced when
+/// reserving, and goes through the same checks as documents uploaded with the term.
+/// A document that is rejected is removed, so the client can upload it again as long
+/// as the reservation is valid. A reservation is finalized by one call at a time, others
+/// are rejected meanwhile.
+#[tracing::instrument(skip(repository, upload_service, cache_service, scanner, documents))]
+pub async fn finalize_term_of_use_use_case(
+    repository: &dyn DatabaseRepository,
+    upload_service: &dyn StorageService,
+    cache_service: &dyn CacheService,
+    scanner: &dyn ScannerService,
+    documents: &dyn DocumentService,
+    reservation_id: i32,
+) -> Result<TermOfUse> {
+    let reservation = repository
+        .get_reservation(reservation_id)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)?;
+
+    // Only the holder of the claim deletes the reservation or its upload, so concurrent
+    // finalizations of the reservation cannot undo each other
+    if !repository
+        .claim_reservation(reservation.id, Utc::now().naive_utc() + CLAIM_DURATION)
+        .await?
+    {
+        return Err(TermsOfUseError::Validation(
+            "The reservation is already being finalized".to_string(),
+        ));
+    }
+
+    let created_term =
+        create_reserved_term(repository, upload_service, scanner, documents, reservation).await;
+
+    // A reservation that is left can be finalized again, e.g. once the document is uploaded
+    if created_term.is_err() {
+        let _ = repository.release_reservation(reservation_id).await;
+    }
+
+    let mut created_term = created_term?;
+
+    let _ = repository.delete_reservation(reservation_id).await;
+
+    let _ = cache_service
+        .invalidate_cache_for_group(&created_term.group)
+        .await;
+
+    // The term is stored at this point, so a document left unprotected is only reported
+    if let Err(err) = upload_service
+        .publish_file(&created_term.url, created_term.id)
+        .await
+    {
+        error!(
+            "Failed to protect the document of term {}: {} ({err:?})",
+            created_term.id, created_term.url
+        );
+    }
+
+    created_term.url = upload_service.get_file_url(&created_term.url).await?;
+
+    Ok(created_term)
+}
+
+/// How long a reservation stays claimed when its holder does not get to release it.
+pub(crate) const CLAIM_DURATION: TimeDelta = TimeDelta::minutes(15);
+
+/// Verifies the document of a claimed reservation and creates its term.
+async fn create_reserved_term(
+    repository: &dyn DatabaseRepository,
+    upload_service: &dyn StorageService,
+    scanner: &dyn ScannerService,
+    documents: &dyn DocumentService,
+    reservation: TermReservation,
+) -> Result<TermOfUse> {
+    if reservation.expires_at < Utc::now().naive_utc() {
+        let _ = repository.delete_reservation(reservation.id).await;
+        delete_upload(upload_service, &reservation.key).await;
+
+        return Err(TermsOfUseError::Validation(
+            "The reservation has expired, please reserve the term again".to_string(),
+        ));
+    }
+
+    let latest_term = repository
+        .get_latest_term_for_group(&reservation.group)
+        .await?;
+    let next_version = match latest_term {
+        Some(t) => t.version + 1,
+        None => 1,
+    };
+
+    // The uploaded key may be taken by the newer term, so only the reservation is dropped
+    if next_version != reservation.version {
+        let _ = repository.delete_reservation(reservation.id).await;
+
+        return Err(TermsOfUseError::Validation(
+            "A newer version of the group was created, please reserve the term again".to_string(),
+        ));
+    }
+
+    let Some(file) = upload_service.get_file_info(&reservation.key).await? else {
+        return Err(TermsOfUseError::Validation(
+            "The document has not been uploaded yet".to_string(),
+        ));
+    };
+
+    let path = std::env::temp_dir().join(format!("term-reservation-{}", reservation.id));
+
+    // The document is read once, both to verify it and to build the term from it
+    let document = async {
+        verify_upload(&reservation, &file)?;
+
+        upload_service
+            .download_file(&reservation.key, &path)
+            .await?;
+
+        verify_sha256(documents, &reservation, &file, &path).await?;
+
+        read_term_document(
+            scanner,
+            documents,
+            &path,
+            &reservation.group,
+            &reservation.content_type,
+        )
+        .await
+    }
+    .await;
+
+    let _ = std::fs::remove_file(&path);
+
+    let document = match document {
+        Ok(document) => document,
+        Err(err) => {
+            delete_upload(upload_service, &reservation.key).await;
+
+            return Err(err);
+        }
+    };
+
+    let created_term = repository
+        .create_term(TermOfUse {
+            id: 0,
+            group: reservation.group,
+            version: reservation.version,
+            url: reservation.key.clone(),
+            created_at: Utc::now().naive_utc(),
+            info: reservation.info,
+            html: document.html,
+            text: document.text,
+            change_summaries: reservation.change_summaries,
+            pdf_metadata: document.pdf_metadata,
+            metadata: reservation.metadata,
+            clauses: reservation.clauses,
+        })
+        .await;
+
+    match created_term {
+        Ok(term) => Ok(term),
+        // Another term took the version, like a newer one its document may be at the key
+        Err(err @ TermsOfUseError::Validation(_)) => {
+            let _ = repository.delete_reservation(reservation.id).await;
+
+            Err(err)
+        }
+        Err(err) => {
+            delete_upload(upload_service, &reservation.key).await;
+
+            Err(err)
+        }
+    }
+}
+
+pub(crate) async fn delete_upload(upload_service: &dyn StorageService, key: &str) {
+    if let Err(err) = upload_service.delete_file(key).await {
+        error!("Failed to delete the rejected upload {key} ({err:?})");
+    }
+}
+
+fn verify_upload(reservation: &TermReservation, file: &StoredFileInfo) -> Result<()> {
+    if file.size != reservation.size {
+        return Err(TermsOfUseError::Validation(format!(
+            "The uploaded document has {} bytes, {} were announced",
+            file.size, reservation.size
+        )));
+    }
+
+    if let Some(content_type) = &file.content_type
+        && content_type != &reservation.content_type
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "The uploaded document is {content_type}, {} was announced",
+            reservation.content_type
+        )));
+    }
+
+    Ok(())
+}
+
+/// Compares the digest computed by the backend during the upload, or the digest of the
+/// downloaded copy when the backend did not check it.
+async fn verify_sha256(
+    documents: &dyn DocumentService,
+    reservation: &TermReservation,
+    file: &StoredFileInfo,
+    path: &Path,
+) -> Result<()> {
+    let sha256 = match &file.sha256 {
+        Some(sha256) => sha256.to_lowercase(),
+        None => documents.sha256(path).await?,
+    };
+
+    if sha256 != reservation.sha256 {
+        return Err(TermsOfUseError::Validation(
+            "The uploaded document does not match the announced SHA-256 digest".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,1008 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{NaiveDateTime, TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{MockTermRepository, MockTermReservationRepository},
+            service::{
+                MockCacheService, MockDocumentService, MockScannerService, MockStorageService,
+            },
+        },
+        entities::{
+            Bundle, ChangeSummary, Group, PdfMetadata, ScanVerdict, StoredFileInfo, TermDocument,
+            TermOfUse, TermReservation, UploadPolicy,
+        },
+        errors::{Result, TermsOfUseError},
+        use_cases::finalize_term_of_use_use_case,
+    };
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    // SHA-256 of "hello"
+    const SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        reservation_repo: MockTermReservationRepository,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    // Agreements are not involved in reservations
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            self.reservation_repo.create_reservation(reservation).await
+        }
+
+        async fn get_reservation(&self, reservation_id: i32) -> Result<Option<TermReservation>> {
+            self.reservation_repo.get_reservation(reservation_id).await
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermReservation>> {
+            self.reservation_repo
+                .get_reservation_for_version(group, version)
+                .await
+        }
+
+        async fn claim_reservation(
+            &self,
+            reservation_id: i32,
+            until: NaiveDateTime,
+        ) -> Result<bool> {
+            self.reservation_repo
+                .claim_reservation(reservation_id, until)
+                .await
+        }
+
+        async fn release_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .release_reservation(reservation_id)
+                .await
+        }
+
+        async fn delete_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .delete_reservation(reservation_id)
+                .await
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            self.reservation_repo.get_all_reservations().await
+        }
+    }
+
+    // Upload policies are not involved in finalizing
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are not involved in finalizing terms
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>> {
+            unimplemented!()
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in finalizing terms
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn reservation(expires_at: NaiveDateTime) -> TermReservation {
+        TermReservation {
+            id: 7,
+            group: "privacy-policy".to_string(),
+            version: 1,
+            info: None,
+            key: "privacy-policy/v1.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 5,
+            sha256: SHA256.to_string(),
+            expires_at,
+            change_summaries: vec![ChangeSummary {
+                locale: "en".to_string(),
+                markdown: "Clarified *retention*".to_string(),
+                html: "<p>Clarified <em>retention</em></p>\n".to_string(),
+            }],
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn valid_reservation_repo() -> MockTermReservationRepository {
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation()
+            .with(eq(7))
+            .returning(|_| {
+                Ok(Some(reservation(
+                    Utc::now().naive_utc() + TimeDelta::hours(1),
+                )))
+            });
+        expect_claim(&mut reservation_repo);
+
+        reservation_repo
+    }
+
+    fn expect_claim(reservation_repo: &mut MockTermReservationRepository) {
+        reservation_repo
+            .expect_claim_reservation()
+            .withf(|reservation_id, until| *reservation_id == 7 && *until > Utc::now().naive_utc())
+            .times(1)
+            .returning(|_, _| Ok(true));
+        reservation_repo
+            .expect_release_reservation()
+            .returning(|_| Ok(()));
+    }
+
+    fn clean_scanner() -> MockScannerService {
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .returning(|_| Ok(ScanVerdict::Clean));
+        scanner
+    }
+
+    fn sample_documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents.expect_read_document().returning(|_, _| {
+            Ok(TermDocument {
+                html: None,
+                text: Some("Sample terms".to_string()),
+                pdf_metadata: Some(PdfMetadata {
+                    page_count: 1,
+                    title: None,
+                    producer: None,
+                }),
+            })
+        });
+        documents
+            .expect_render_markdown()
+            .returning(|markdown| format!("<p>{markdown}</p>"));
+        documents
+            .expect_sha256()
+            .returning(|_| Ok(SHA256.to_string()));
+        documents
+    }
+
+    fn first_version_term_repo() -> MockTermRepository {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .returning(|_| Ok(None));
+
+        term_repo
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_creates_verified_term() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo
+            .expect_create_term()
+            .withf(|term| {
+                term.version == 1
+                    && term.url == "privacy-policy/v1.pdf"
+                    && term.change_summaries.len() == 1
+                    && term.change_summaries[0].locale == "en"
+                    && term
+                        .pdf_metadata
+                        .as_ref()
+                        .is_some_and(|pdf| pdf.page_count == 1)
+            })
+            .times(1)
+            .returning(|term| Ok(TermOfUse { id: 3, ..term }));
+
+        let mut reservation_repo = valid_reservation_repo();
+        reservation_repo
+            .expect_delete_reservation()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("application/pdf".to_string()),
+                sha256: Some(SHA256.to_uppercase()),
+            }))
+        });
+        storage
+            .expect_download_file()
+            .with(eq("privacy-policy/v1.pdf"), always())
+            .times(1)
+            .returning(|_, destination| {
+                std::fs::copy(SAMPLE_PDF, destination).unwrap();
+
+                Ok(())
+            });
+        storage.expect_delete_file().never();
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v1.pdf"), eq(3))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://storage.example.com/{key}")));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        let term = result.unwrap();
+        assert_eq!(term.id, 3);
+        assert_eq!(
+            term.url,
+            "https://storage.example.com/privacy-policy/v1.pdf"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_stores_the_read_markdown_document() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo
+            .expect_create_term()
+            .withf(|term| {
+                term.html.as_deref() == Some("<p>hello</p>")
+                    && term.text.as_deref() == Some("hello")
+                    && term.pdf_metadata.is_none()
+            })
+            .times(1)
+            .returning(|term| Ok(TermOfUse { id: 3, ..term }));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        expect_claim(&mut reservation_repo);
+        reservation_repo.expect_get_reservation().returning(|_| {
+            Ok(Some(TermReservation {
+                key: "privacy-policy/v1.md".to_string(),
+                content_type: "text/markdown".to_string(),
+                ..reservation(Utc::now().naive_utc() + TimeDelta::hours(1))
+            }))
+        });
+        reservation_repo
+            .expect_delete_reservation()
+            .returning(|_| Ok(()));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("text/markdown".to_string()),
+                sha256: None,
+            }))
+        });
+        storage.expect_download_file().returning(|_, destination| {
+            std::fs::write(destination, b"hello").unwrap();
+
+            Ok(())
+        });
+        storage.expect_delete_file().never();
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://storage.example.com/{key}")));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_read_document()
+            .withf(|file, content_type| {
+                std::fs::read(file).unwrap() == b"hello" && content_type == "text/markdown"
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(TermDocument {
+                    html: Some("<p>hello</p>".to_string()),
+                    text: Some("hello".to_string()),
+                    pdf_metadata: None,
+                })
+            });
+        documents
+            .expect_sha256()
+            .returning(|_| Ok(SHA256.to_string()));
+        documents.expect_render_markdown().never();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &documents,
+            7,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(result.unwrap().html.as_deref(), Some("<p>hello</p>"));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_hashes_document_without_backend_digest() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo: valid_reservation_repo(),
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: None,
+                sha256: None,
+            }))
+        });
+        storage
+            .expect_download_file()
+            .with(eq("privacy-policy/v1.pdf"), always())
+            .times(1)
+            .returning(|_, destination| {
+                std::fs::write(destination, b"hallo").unwrap();
+
+                Ok(())
+            });
+        storage
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let cache = MockCacheService::new();
+
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_sha256()
+            .withf(|file| std::fs::read(file).unwrap() == b"hallo")
+            .times(1)
+            .returning(|_| {
+                Ok("d3751d33f9cd5049c4af2b462735457e4d3baf130bcbb87f389e349fbaeb20b9".to_string())
+            });
+        documents.expect_read_document().never();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &documents,
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_unreadable_documents() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo: valid_reservation_repo(),
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("application/pdf".to_string()),
+                sha256: Some(SHA256.to_string()),
+            }))
+        });
+        storage.expect_download_file().returning(|_, destination| {
+            std::fs::write(destination, b"%PDF-1.4\nnot really a PDF").unwrap();
+
+            Ok(())
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let cache = MockCacheService::new();
+
+        let mut documents = MockDocumentService::new();
+        documents.expect_read_document().returning(|_, _| {
+            Err(TermsOfUseError::Validation(
+                "The PDF document could not be read".to_string(),
+            ))
+        });
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &documents,
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_deletes_upload_when_term_creation_fails() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo
+            .expect_create_term()
+            .times(1)
+            .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+        let mut reservation_repo = valid_reservation_repo();
+        reservation_repo.expect_delete_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("application/pdf".to_string()),
+                sha256: Some(SHA256.to_string()),
+            }))
+        });
+        storage.expect_download_file().returning(|_, destination| {
+            std::fs::copy(SAMPLE_PDF, destination).unwrap();
+
+            Ok(())
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+        storage.expect_publish_file().never();
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_keeps_upload_when_the_version_was_taken() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().times(1).returning(|_| {
+            Err(TermsOfUseError::Validation(
+                "Version 1 of group 'privacy-policy' already exists".to_string(),
+            ))
+        });
+
+        let mut reservation_repo = valid_reservation_repo();
+        reservation_repo
+            .expect_delete_reservation()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("application/pdf".to_string()),
+                sha256: Some(SHA256.to_string()),
+            }))
+        });
+        storage.expect_download_file().returning(|_, destination| {
+            std::fs::copy(SAMPLE_PDF, destination).unwrap();
+
+            Ok(())
+        });
+        storage.expect_delete_file().never();
+        storage.expect_publish_file().never();
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_reservation_being_finalized() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_create_term().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo.expect_get_reservation().returning(|_| {
+            Ok(Some(reservation(
+                Utc::now().naive_utc() + TimeDelta::hours(1),
+            )))
+        });
+        reservation_repo
+            .expect_claim_reservation()
+            .times(1)
+            .returning(|_, _| Ok(false));
+        reservation_repo.expect_release_reservation().never();
+        reservation_repo.expect_delete_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().never();
+        storage.expect_delete_file().never();
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_infected_document() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo: valid_reservation_repo(),
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 5,
+                content_type: Some("application/pdf".to_string()),
+                sha256: Some(SHA256.to_string()),
+            }))
+        });
+        storage.expect_download_file().returning(|_, destination| {
+            std::fs::copy(SAMPLE_PDF, destination).unwrap();
+
+            Ok(())
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Ok(ScanVerdict::Infected("Eicar-Signature".to_string())));
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &scanner,
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(
+            matches!(result, Err(TermsOfUseError::Validation(detail)) if detail.contains("Eicar-Signature"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_size_mismatch() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo: valid_reservation_repo(),
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 4096,
+                content_type: Some("application/pdf".to_string()),
+                sha256: None,
+            }))
+        });
+        storage.expect_download_file().never();
+        storage.expect_delete_file().times(1).returning(|_| Ok(()));
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_requires_uploaded_document() {
+        // Arrange
+        let mut term_repo = first_version_term_repo();
+        term_repo.expect_create_term().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo.expect_get_reservation().returning(|_| {
+            Ok(Some(reservation(
+                Utc::now().naive_utc() + TimeDelta::hours(1),
+            )))
+        });
+        reservation_repo
+            .expect_claim_reservation()
+            .returning(|_, _| Ok(true));
+        reservation_repo
+            .expect_release_reservation()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(()));
+        reservation_repo.expect_delete_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| Ok(None));
+        storage.expect_delete_file().never();
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_outdated_version() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().returning(|_| {
+            Ok(Some(TermOfUse {
+                id: 2,
+                group: "privacy-policy".to_string(),
+                version: 1,
+                url: "privacy-policy/v1.pdf".to_string(),
+                created_at: Utc::now().naive_utc(),
+                info: None,
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
+            }))
+        });
+        term_repo.expect_create_term().never();
+
+        let mut reservation_repo = valid_reservation_repo();
+        reservation_repo
+            .expect_delete_reservation()
+            .with(eq(7))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().never();
+        storage.expect_delete_file().never();
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_finalize_term_of_use_rejects_expired_reservation() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_create_term().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        expect_claim(&mut reservation_repo);
+        reservation_repo.expect_get_reservation().returning(|_| {
+            Ok(Some(reservation(
+                Utc::now().naive_utc() - TimeDelta::minutes(1),
+            )))
+        });
+        reservation_repo
+            .expect_delete_reservation()
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_delete_file()
+            .times(1)
+            .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+        let cache = MockCacheService::new();
+
+        // Act
+        let result = finalize_term_of_use_use_case(
+            &repository,
+            &storage,
+            &cache,
+            &clean_scanner(),
+            &sample_documents(),
+            7,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
@@ -25,6 +25,8 @@ pub async fn get_latest_term_use_case(
         .ok_or(TermsOfUseError::NotFound)?;
 
     term.url = upload_service.get_file_url(&term.url).await?;
+    // Only diffs need the extracted text, and they read versions from the repository
+    term.text = None;
 
     let _ = cache_service.store_latest_term_for_group(&term).await;
 
@@ -23,6 +23,12 @@ mod tests {
             url: "https://storage.example.com/cached.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: Some("Cached version".to_string()),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let repository = MockTermRepository::new();
@@ -58,6 +64,12 @@ mod tests {
             url: "uploads/privacy-v3.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: Some("Latest version".to_string()),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut repository = MockTermRepository::new();
@@ -97,6 +109,52 @@ mod tests {
         assert_eq!(term.info, Some("Latest version".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_get_latest_term_caches_term_without_text() {
+        // Arrange
+        let db_term = TermOfUse {
+            id: 6,
+            group: "privacy-policy".to_string(),
+            version: 4,
+            url: "uploads/privacy-v4.md".to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: Some("<h1>Privacy</h1>".to_string()),
+            text: Some("Privacy".to_string()),
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(move |_| Ok(Some(db_term.clone())));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        cache
+            .expect_store_latest_term_for_group()
+            .withf(|term| term.html.as_deref() == Some("<h1>Privacy</h1>") && term.text.is_none())
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://storage.example.com/privacy-v4.md".to_string()));
+
+        // Act
+        let result =
+            get_latest_term_use_case(&repository, &cache, &storage, "privacy-policy").await;
+
+        // Assert
+        assert_eq!(result.unwrap().html.as_deref(), Some("<h1>Privacy</h1>"));
+    }
+
     #[tokio::test]
     async fn test_get_latest_term_not_found() {
         // Arrange
@@ -155,6 +213,12 @@ mod tests {
             url: "uploads/privacy-v3.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut repository = MockTermRepository::new();
@@ -196,6 +260,12 @@ mod tests {
             url: "uploads/privacy-v3.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut repository = MockTermRepository::new();
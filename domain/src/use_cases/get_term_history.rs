@@ -0,0 +1,31 @@
+use crate::{
+    data::{repository::TermRepository, service::StorageService},
+    entities::{TermMetadata, TermOfUse},
+    errors::{Result, TermsOfUseError},
+};
+
+/// Lists all versions of a group, newest first, e.g. to show what changed since a consent.
+///
+/// Only versions whose metadata contains every entry of `metadata` are listed.
+#[tracing::instrument(skip(repository, upload_service, group))]
+pub async fn get_term_history_use_case(
+    repository: &dyn TermRepository,
+    upload_service: &dyn StorageService,
+    group: &str,
+    metadata: &TermMetadata,
+) -> Result<Vec<TermOfUse>> {
+    let mut terms = repository.get_terms_for_group(group, metadata).await?;
+
+    // A filter matching no version is an empty history, an unknown group is not
+    if terms.is_empty()
+        && (metadata.is_empty() || repository.get_latest_term_for_group(group).await?.is_none())
+    {
+        return Err(TermsOfUseError::NotFound);
+    }
+
+    for term in &mut terms {
+        term.url = upload_service.get_file_url(&term.url).await?;
+    }
+
+    Ok(terms)
+}
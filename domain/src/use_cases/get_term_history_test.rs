@@ -0,0 +1,136 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{repository::MockTermRepository, service::MockStorageService},
+        entities::{ChangeSummary, TermMetadata, TermOfUse},
+        errors::TermsOfUseError,
+        use_cases::{get_term_history_use_case, parse_metadata_filter},
+    };
+
+    fn term(version: u32) -> TermOfUse {
+        TermOfUse {
+            id: version as i32,
+            group: "privacy-policy".to_string(),
+            url: format!("privacy-policy/v{version}.pdf"),
+            version,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![ChangeSummary {
+                locale: "en".to_string(),
+                markdown: format!("Version {version}"),
+                html: format!("<p>Version {version}</p>"),
+            }],
+            pdf_metadata: None,
+            metadata: TermMetadata::new(),
+            clauses: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_term_history_resolves_urls() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .with(eq("privacy-policy"), eq(TermMetadata::new()))
+            .times(1)
+            .returning(|_, _| Ok(vec![term(2), term(1)]));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .times(2)
+            .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+        // Act
+        let result = get_term_history_use_case(
+            &repository,
+            &storage,
+            "privacy-policy",
+            &TermMetadata::new(),
+        )
+        .await;
+
+        // Assert
+        let terms = result.unwrap();
+        assert_eq!(terms.len(), 2);
+        assert_eq!(terms[0].version, 2);
+        assert_eq!(
+            terms[0].url,
+            "https://cdn.example.com/privacy-policy/v2.pdf"
+        );
+        assert_eq!(terms[1].change_summaries[0].markdown, "Version 1");
+    }
+
+    #[tokio::test]
+    async fn test_get_term_history_returns_not_found_for_unknown_group() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .returning(|_, _| Ok(vec![]));
+
+        let storage = MockStorageService::new();
+
+        // Act
+        let result =
+            get_term_history_use_case(&repository, &storage, "unknown", &TermMetadata::new()).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_get_term_history_returns_empty_history_when_no_version_matches() {
+        // Arrange
+        let filter = parse_metadata_filter([("region".to_string(), "apac".to_string())]);
+
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .with(eq("privacy-policy"), eq(filter.clone()))
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        repository
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|_| Ok(Some(term(2))));
+
+        let storage = MockStorageService::new();
+
+        // Act
+        let result =
+            get_term_history_use_case(&repository, &storage, "privacy-policy", &filter).await;
+
+        // Assert
+        assert!(result.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_term_history_with_filter_returns_not_found_for_unknown_group() {
+        // Arrange
+        let filter = parse_metadata_filter([("region".to_string(), "eu".to_string())]);
+
+        let mut repository = MockTermRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .returning(|_, _| Ok(vec![]));
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let storage = MockStorageService::new();
+
+        // Act
+        let result = get_term_history_use_case(&repository, &storage, "unknown", &filter).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+}
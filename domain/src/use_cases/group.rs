@@ -0,0 +1,170 @@
+use crate::{
+    data::{
+        repository::{DatabaseRepository, GroupRepository},
+        service::CacheService,
+    },
+    entities::Group,
+    errors::{Result, TermsOfUseError},
+    use_cases::change_summaries::is_language_tag,
+};
+
+/// Longest accepted group name, in bytes.
+pub(crate) const MAX_GROUP_NAME_LENGTH: usize = 128;
+
+/// Fixed first segments of the terms-of-use routes. The routes of a group named like
+/// one of them would be answered by the fixed ones, e.g. `/pending/versions`.
+pub const RESERVED_GROUP_NAMES: [&str; 5] = [
+    "agreements",
+    "has-consent",
+    "pending",
+    "resumable-uploads",
+    "uploads",
+];
+
+/// Group names end up in URLs and storage keys, so they are limited to a safe alphabet.
+pub(crate) fn is_group_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_GROUP_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+fn check_reserved_name(name: &str) -> Result<()> {
+    if RESERVED_GROUP_NAMES.contains(&name) {
+        return Err(TermsOfUseError::Validation(format!(
+            "The group name '{name}' is reserved"
+        )));
+    }
+
+    Ok(())
+}
+
+fn validate_group(group: &Group) -> Result<()> {
+    if !is_group_name(&group.name) {
+        return Err(TermsOfUseError::Validation(format!(
+            "The group name must be 1 to {MAX_GROUP_NAME_LENGTH} letters, digits, '-', '_' or '.'"
+        )));
+    }
+
+    check_reserved_name(&group.name)?;
+
+    if !is_language_tag(&group.default_locale) {
+        return Err(TermsOfUseError::Validation(format!(
+            "'{}' is not a valid locale",
+            group.default_locale
+        )));
+    }
+
+    if group.max_consent_age_days == Some(0) {
+        return Err(TermsOfUseError::Validation(
+            "The maximum consent age must be at least one day".to_string(),
+        ));
+    }
+
+    if group
+        .owner
+        .as_deref()
+        .is_some_and(|owner| owner.trim().is_empty())
+    {
+        return Err(TermsOfUseError::Validation(
+            "The owner must not be empty".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn list_groups_use_case(repository: &dyn GroupRepository) -> Result<Vec<Group>> {
+    repository.get_groups().await
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn get_group_use_case(repository: &dyn GroupRepository, name: &str) -> Result<Group> {
+    repository
+        .get_group(name)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)
+}
+
+#[tracing::instrument(skip(repository))]
+pub async fn create_group_use_case(
+    repository: &dyn GroupRepository,
+    group: Group,
+) -> Result<Group> {
+    validate_group(&group)?;
+
+    if repository.get_group(&group.name).await?.is_some() {
+        return Err(TermsOfUseError::Validation(format!(
+            "Group '{}' already exists",
+            group.name
+        )));
+    }
+
+    repository.create_group(group).await
+}
+
+/// Updates a group. Cached consents to it are dropped when their maximum age changes, as
+/// they expire with the age they were cached with.
+#[tracing::instrument(skip(repository, cache))]
+pub async fn update_group_use_case(
+    repository: &dyn GroupRepository,
+    cache: &dyn CacheService,
+    group: Group,
+) -> Result<Group> {
+    validate_group(&group)?;
+
+    let existing = repository
+        .get_group(&group.name)
+        .await?
+        .ok_or(TermsOfUseError::NotFound)?;
+
+    let group = repository.update_group(group).await?;
+
+    if group.max_consent_age_days != existing.max_consent_age_days {
+        let _ = cache.invalidate_consents_for_group(&group.name).await;
+    }
+
+    Ok(group)
+}
+
+/// Removes a group that has no terms yet and is not part of any bundle.
+#[tracing::instrument(skip(repository))]
+pub async fn delete_group_use_case(repository: &dyn DatabaseRepository, name: &str) -> Result<()> {
+    if repository.get_group(name).await?.is_none() {
+        return Err(TermsOfUseError::NotFound);
+    }
+
+    if repository.get_latest_term_for_group(name).await?.is_some() {
+        return Err(TermsOfUseError::Validation(format!(
+            "Group '{name}' still has terms and cannot be deleted"
+        )));
+    }
+
+    if let Some(bundle) = repository
+        .get_bundles()
+        .await?
+        .into_iter()
+        .find(|bundle| bundle.groups.iter().any(|group| group == name))
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "Group '{name}' is part of bundle '{}' and cannot be deleted",
+            bundle.name
+        )));
+    }
+
+    repository.delete_group(name).await
+}
+
+/// Rejects terms of groups that are not registered, or whose terms could not be reached
+/// because of their reserved name.
+#[tracing::instrument(skip(repository))]
+pub async fn check_group_use_case(repository: &dyn GroupRepository, name: &str) -> Result<Group> {
+    check_reserved_name(name)?;
+
+    repository
+        .get_group(name)
+        .await?
+        .ok_or_else(|| TermsOfUseError::Validation(format!("Group '{name}' is not registered")))
+}
@@ -0,0 +1,529 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use mockall::predicate::eq;
+
+    use crate::{
+        data::{
+            repository::{MockBundleRepository, MockGroupRepository, MockTermRepository},
+            service::MockCacheService,
+        },
+        entities::{Bundle, Group, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::{
+            check_group_use_case, create_group_use_case, delete_group_use_case, get_group_use_case,
+            update_group_use_case,
+        },
+    };
+
+    fn privacy_policy() -> Group {
+        Group {
+            name: "privacy-policy".to_string(),
+            description: Some("How we process personal data".to_string()),
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: None,
+        }
+    }
+
+    fn repository_with(group: Option<Group>) -> MockGroupRepository {
+        let mut repository = MockGroupRepository::new();
+        repository
+            .expect_get_group()
+            .with(eq("privacy-policy"))
+            .returning(move |_| Ok(group.clone()));
+        repository
+    }
+
+    // Combined mock for deleting groups
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        group_repo: MockGroupRepository,
+        bundle_repo: MockBundleRepository,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    // Agreements are not involved in deleting groups
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Reservations are not involved in deleting groups
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in deleting groups
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            self.group_repo.get_group(name).await
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            self.group_repo.get_groups().await
+        }
+
+        async fn create_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.create_group(group).await
+        }
+
+        async fn update_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.update_group(group).await
+        }
+
+        async fn delete_group(&self, name: &str) -> Result<()> {
+            self.group_repo.delete_group(name).await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, name: &str) -> Result<Option<Bundle>> {
+            self.bundle_repo.get_bundle(name).await
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            self.bundle_repo.get_bundles().await
+        }
+
+        async fn save_bundle(&self, bundle: Bundle) -> Result<Bundle> {
+            self.bundle_repo.save_bundle(bundle).await
+        }
+
+        async fn delete_bundle(&self, name: &str) -> Result<()> {
+            self.bundle_repo.delete_bundle(name).await
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    #[tokio::test]
+    async fn create_group_stores_a_new_group() {
+        let mut repository = repository_with(None);
+        repository
+            .expect_create_group()
+            .with(eq(privacy_policy()))
+            .times(1)
+            .returning(Ok);
+
+        let group = create_group_use_case(&repository, privacy_policy())
+            .await
+            .unwrap();
+
+        assert_eq!(group, privacy_policy());
+    }
+
+    #[tokio::test]
+    async fn create_group_rejects_existing_groups() {
+        let mut repository = repository_with(Some(privacy_policy()));
+        repository.expect_create_group().times(0);
+
+        let result = create_group_use_case(&repository, privacy_policy()).await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "Group 'privacy-policy' already exists"
+        ));
+    }
+
+    #[tokio::test]
+    async fn create_group_rejects_invalid_names_and_locales() {
+        let mut repository = MockGroupRepository::new();
+        repository.expect_get_group().times(0);
+        repository.expect_create_group().times(0);
+
+        for group in [
+            Group {
+                name: "privacy policy".to_string(),
+                ..privacy_policy()
+            },
+            Group {
+                name: "legal/privacy".to_string(),
+                ..privacy_policy()
+            },
+            Group {
+                name: String::new(),
+                ..privacy_policy()
+            },
+            Group {
+                default_locale: "english".to_string(),
+                ..privacy_policy()
+            },
+            Group {
+                max_consent_age_days: Some(0),
+                ..privacy_policy()
+            },
+            Group {
+                owner: Some(" ".to_string()),
+                ..privacy_policy()
+            },
+            Group {
+                name: "pending".to_string(),
+                ..privacy_policy()
+            },
+            Group {
+                name: "has-consent".to_string(),
+                ..privacy_policy()
+            },
+            Group {
+                name: "resumable-uploads".to_string(),
+                ..privacy_policy()
+            },
+        ] {
+            let result = create_group_use_case(&repository, group).await;
+
+            assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn get_group_returns_not_found_for_unknown_groups() {
+        let repository = repository_with(None);
+
+        let result = get_group_use_case(&repository, "privacy-policy").await;
+
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn update_group_replaces_existing_groups() {
+        let updated = Group {
+            mandatory: false,
+            default_locale: "de-CH".to_string(),
+            ..privacy_policy()
+        };
+
+        let mut repository = repository_with(Some(privacy_policy()));
+        repository
+            .expect_update_group()
+            .with(eq(updated.clone()))
+            .times(1)
+            .returning(Ok);
+
+        let mut cache = MockCacheService::new();
+        cache.expect_invalidate_consents_for_group().times(0);
+
+        let group = update_group_use_case(&repository, &cache, updated.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(group, updated);
+    }
+
+    #[tokio::test]
+    async fn update_group_drops_cached_consents_when_their_age_changes() {
+        let updated = Group {
+            max_consent_age_days: Some(30),
+            ..privacy_policy()
+        };
+
+        let mut repository = repository_with(Some(privacy_policy()));
+        repository.expect_update_group().times(1).returning(Ok);
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_consents_for_group()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let group = update_group_use_case(&repository, &cache, updated.clone())
+            .await
+            .unwrap();
+
+        assert_eq!(group, updated);
+    }
+
+    #[tokio::test]
+    async fn update_group_returns_not_found_for_unknown_groups() {
+        let mut repository = repository_with(None);
+        repository.expect_update_group().times(0);
+
+        let result =
+            update_group_use_case(&repository, &MockCacheService::new(), privacy_policy()).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn delete_group_removes_groups_without_terms() {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .returning(|_| Ok(None));
+
+        let mut group_repo = repository_with(Some(privacy_policy()));
+        group_repo
+            .expect_delete_group()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut bundle_repo = MockBundleRepository::new();
+        bundle_repo.expect_get_bundles().returning(|| Ok(vec![]));
+
+        let result = delete_group_use_case(
+            &MockCombinedRepository {
+                term_repo,
+                group_repo,
+                bundle_repo,
+            },
+            "privacy-policy",
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn delete_group_keeps_groups_with_terms() {
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().returning(|_| {
+            Ok(Some(TermOfUse {
+                id: 1,
+                group: "privacy-policy".to_string(),
+                url: "privacy-policy/v1.pdf".to_string(),
+                version: 1,
+                info: None,
+                created_at: Utc::now().naive_utc(),
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
+            }))
+        });
+
+        let mut group_repo = repository_with(Some(privacy_policy()));
+        group_repo.expect_delete_group().times(0);
+
+        let result = delete_group_use_case(
+            &MockCombinedRepository {
+                term_repo,
+                group_repo,
+                bundle_repo: MockBundleRepository::new(),
+            },
+            "privacy-policy",
+        )
+        .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn delete_group_keeps_groups_of_bundles() {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut group_repo = repository_with(Some(privacy_policy()));
+        group_repo.expect_delete_group().times(0);
+
+        let mut bundle_repo = MockBundleRepository::new();
+        bundle_repo.expect_get_bundles().returning(|| {
+            Ok(vec![Bundle {
+                name: "sign-up".to_string(),
+                description: None,
+                groups: vec!["terms-of-service".to_string(), "privacy-policy".to_string()],
+            }])
+        });
+
+        let result = delete_group_use_case(
+            &MockCombinedRepository {
+                term_repo,
+                group_repo,
+                bundle_repo,
+            },
+            "privacy-policy",
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail))
+                if detail == "Group 'privacy-policy' is part of bundle 'sign-up' and cannot be deleted"
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_group_rejects_unregistered_groups() {
+        let repository = repository_with(None);
+
+        let result = check_group_use_case(&repository, "privacy-policy").await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "Group 'privacy-policy' is not registered"
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_group_rejects_resumable_uploads() {
+        let mut repository = MockGroupRepository::new();
+        repository.expect_get_group().times(0);
+
+        let result = check_group_use_case(&repository, "resumable-uploads").await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail))
+                if detail == "The group name 'resumable-uploads' is reserved"
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_group_rejects_reserved_names() {
+        let mut repository = MockGroupRepository::new();
+        repository.expect_get_group().times(0);
+
+        let result = check_group_use_case(&repository, "pending").await;
+
+        assert!(matches!(
+            result,
+            Err(TermsOfUseError::Validation(detail)) if detail == "The group name 'pending' is reserved"
+        ));
+    }
+}
@@ -1,8 +1,40 @@
+use chrono::{NaiveDateTime, Utc};
+
 use crate::{
     data::{repository::DatabaseRepository, service::CacheService},
+    entities::{ConsentStatus, TermMetadata, TermOfUse},
     errors::{Result, TermsOfUseError},
 };
 
+/// Where a user stands with the latest term of a group.
+#[tracing::instrument(skip(repository, cache, user_id, group))]
+pub async fn get_consent_status_use_case(
+    repository: &dyn DatabaseRepository,
+    cache: &dyn CacheService,
+    user_id: i32,
+    group: &str,
+) -> Result<ConsentStatus> {
+    if let Some(status) = cache
+        .find_user_agreement(user_id, group)
+        .await
+        .unwrap_or(None)
+    {
+        return Ok(status);
+    }
+
+    let (status, expires_at) = match repository.get_latest_term_for_group(group).await? {
+        Some(latest_term) => consent_status_for_term(repository, user_id, &latest_term).await?,
+        None => (ConsentStatus::NoTermsPublished, None),
+    };
+
+    let _ = cache
+        .store_user_agreement(user_id, group, status, expires_at)
+        .await;
+
+    Ok(status)
+}
+
+/// Whether a user agreed to the latest term of a group, `NotFound` when it has none.
 #[tracing::instrument(skip(repository, cache, user_id, group))]
 pub async fn has_user_agreed_to_term_use_case(
     repository: &dyn DatabaseRepository,
@@ -10,24 +42,117 @@ pub async fn has_user_agreed_to_term_use_case(
     user_id: i32,
     group: &str,
 ) -> Result<bool> {
-    if let Some(agreed) = cache
-        .find_user_agreement(user_id, group)
+    match get_consent_status_use_case(repository, cache, user_id, group).await? {
+        ConsentStatus::NoTermsPublished => Err(TermsOfUseError::NotFound),
+        status => Ok(status.has_consented()),
+    }
+}
+
+/// Whether a user agreed to any version of a group from `min_version` on, `NotFound` when
+/// the group has no terms.
+///
+/// Only the newest agreed version is cached, it answers every threshold up to itself.
+/// Agreements are never withdrawn, so a cached version can be too old but never too new.
+/// In groups whose consents expire, the agreement to the newest version decides.
+#[tracing::instrument(skip(repository, cache, user_id, group))]
+pub async fn has_user_agreed_to_version_use_case(
+    repository: &dyn DatabaseRepository,
+    cache: &dyn CacheService,
+    user_id: i32,
+    group: &str,
+    min_version: u32,
+) -> Result<bool> {
+    if min_version == 0 {
+        return Err(TermsOfUseError::Validation(
+            "The minimum version must be at least 1".to_string(),
+        ));
+    }
+
+    if let Some(version) = cache
+        .find_agreed_version(user_id, group)
         .await
         .unwrap_or(None)
+        && version >= min_version
     {
-        return Ok(agreed);
+        return Ok(true);
+    }
+
+    if let Some(version) = repository
+        .get_newest_agreed_version(user_id, group, min_version)
+        .await?
+    {
+        let expires_at = match repository.get_group(group).await? {
+            Some(registered_group) if registered_group.max_consent_age_days.is_some() => {
+                let agreed_at = match repository.get_term_by_version(group, version).await? {
+                    Some(term) => repository.get_agreed_at(user_id, term.id).await?,
+                    None => None,
+                };
+
+                match agreed_at.and_then(|agreed_at| registered_group.consent_expiry(agreed_at)) {
+                    Some(expires_at) if expires_at > Utc::now().naive_utc() => Some(expires_at),
+                    _ => return Ok(false),
+                }
+            }
+            _ => None,
+        };
+
+        let _ = cache
+            .store_agreed_version(user_id, group, version, expires_at)
+            .await;
+
+        return Ok(true);
     }
 
-    let latest_term = repository
+    repository
         .get_latest_term_for_group(group)
         .await?
-        .ok_or(TermsOfUseError::NotFound)?;
+        .map(|_| false)
+        .ok_or(TermsOfUseError::NotFound)
+}
+
+/// Status of a user with `latest_term`, along with the time a consent expires. Older
+/// versions of the group are only looked at when the user did not agree to the latest one.
+pub(crate) async fn consent_status_for_term(
+    repository: &dyn DatabaseRepository,
+    user_id: i32,
+    latest_term: &TermOfUse,
+) -> Result<(ConsentStatus, Option<NaiveDateTime>)> {
+    if let Some(agreed_at) = repository.get_agreed_at(user_id, latest_term.id).await? {
+        let expires_at = repository
+            .get_group(&latest_term.group)
+            .await?
+            .and_then(|group| group.consent_expiry(agreed_at));
+
+        return Ok(match expires_at {
+            Some(expires_at) if expires_at <= Utc::now().naive_utc() => {
+                (ConsentStatus::ConsentExpired, None)
+            }
+            expires_at => (ConsentStatus::ConsentedToLatest, expires_at),
+        });
+    }
+
+    let older_terms: Vec<TermOfUse> = repository
+        .get_terms_for_group(&latest_term.group, &TermMetadata::new())
+        .await?
+        .into_iter()
+        .filter(|term| term.version < latest_term.version)
+        .collect();
+
+    if older_terms.is_empty() {
+        return Ok((ConsentStatus::NeverConsented, None));
+    }
 
-    let agreed = repository
-        .has_user_agreed_to_term(user_id, latest_term.id)
-        .await?;
+    let term_ids: Vec<i32> = older_terms.iter().map(|term| term.id).collect();
+    let agreed_term_ids = repository.get_agreed_term_ids(user_id, &term_ids).await?;
 
-    let _ = cache.store_user_agreement(user_id, group, agreed).await;
+    let status = older_terms
+        .iter()
+        .filter(|term| agreed_term_ids.contains(&term.id))
+        .map(|term| term.version)
+        .max()
+        .map_or(ConsentStatus::NeverConsented, |version| {
+            ConsentStatus::ConsentedToOlder { version }
+        });
 
-    Ok(agreed)
+    Ok((status, None))
 }
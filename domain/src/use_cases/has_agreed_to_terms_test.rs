@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use async_trait::async_trait;
-    use chrono::Utc;
+    use chrono::{TimeDelta, Utc};
     use mockall::predicate::*;
 
     use crate::{
@@ -9,15 +9,19 @@ mod tests {
             repository::{MockTermRepository, MockUserAgreementRepository},
             service::MockCacheService,
         },
-        entities::TermOfUse,
+        entities::{Bundle, ConsentStatus, Group, TermOfUse, TermReservation, UploadPolicy},
         errors::{Result, TermsOfUseError},
-        use_cases::has_user_agreed_to_term_use_case,
+        use_cases::{
+            get_consent_status_use_case, has_user_agreed_to_term_use_case,
+            has_user_agreed_to_version_use_case,
+        },
     };
 
     // Combined mock for testing
     struct MockCombinedRepository {
         term_repo: MockTermRepository,
         agreement_repo: MockUserAgreementRepository,
+        group: Option<Group>,
     }
 
     #[async_trait]
@@ -26,30 +30,204 @@ mod tests {
             self.term_repo.get_latest_term_for_group(group).await
         }
 
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
         async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
             self.term_repo.get_term_by_id(term_id).await
         }
 
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
         async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
             self.term_repo.create_term(term).await
         }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
     }
 
     #[async_trait]
     impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
-        async fn has_user_agreed_to_term(&self, user_id: i32, term_id: i32) -> Result<bool> {
+        async fn get_agreed_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            self.agreement_repo
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
+                .await
+        }
+
+        async fn create_user_agreement(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            accepted_clauses: &[String],
+        ) -> Result<()> {
             self.agreement_repo
-                .has_user_agreed_to_term(user_id, term_id)
+                .create_user_agreement(user_id, term_id, accepted_clauses)
                 .await
         }
 
-        async fn create_user_agreement(&self, user_id: i32, term_id: i32) -> Result<()> {
+        async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
             self.agreement_repo
-                .create_user_agreement(user_id, term_id)
+                .create_user_agreements(user_id, term_ids)
                 .await
         }
     }
 
+    // Reservations are not involved in agreements
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in agreements
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are only read for their consent expiry
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>> {
+            Ok(self.group.clone())
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in checking agreements
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
     impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
 
     #[tokio::test]
@@ -60,6 +238,7 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -67,7 +246,7 @@ mod tests {
             .expect_find_user_agreement()
             .with(eq(100), eq("privacy-policy"))
             .times(1)
-            .returning(|_, _| Ok(Some(true)));
+            .returning(|_, _| Ok(Some(ConsentStatus::ConsentedToLatest)));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -88,12 +267,13 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
         cache
             .expect_find_user_agreement()
-            .returning(|_, _| Ok(Some(false)));
+            .returning(|_, _| Ok(Some(ConsentStatus::NeverConsented)));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -116,6 +296,12 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -127,14 +313,15 @@ mod tests {
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
+            .expect_get_agreed_at()
             .with(eq(100), eq(15))
             .times(1)
-            .returning(|_, _| Ok(true));
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -144,9 +331,14 @@ mod tests {
 
         cache
             .expect_store_user_agreement()
-            .with(eq(100), eq("privacy-policy"), eq(true))
+            .with(
+                eq(100),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToLatest),
+                eq(None),
+            )
             .times(1)
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -169,21 +361,33 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
+        let first_version = latest_term.clone();
         term_repo
             .expect_get_latest_term_for_group()
             .returning(move |_| Ok(Some(latest_term.clone())));
+        term_repo
+            .expect_get_terms_for_group()
+            .returning(move |_, _| Ok(vec![first_version.clone()]));
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
-            .returning(|_, _| Ok(false));
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(None));
+        agreement_repo.expect_get_agreed_term_ids().times(0);
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -193,9 +397,14 @@ mod tests {
 
         cache
             .expect_store_user_agreement()
-            .with(eq(100), eq("privacy-policy"), eq(false))
+            .with(
+                eq(100),
+                eq("privacy-policy"),
+                eq(ConsentStatus::NeverConsented),
+                eq(None),
+            )
             .times(1)
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -220,12 +429,23 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
         cache
             .expect_find_user_agreement()
             .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(100),
+                eq("non-existent-group"),
+                eq(ConsentStatus::NoTermsPublished),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
 
         let user_id = 100;
         let group = "non-existent-group";
@@ -250,6 +470,7 @@ mod tests {
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -278,6 +499,12 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -287,12 +514,13 @@ mod tests {
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
+            .expect_get_agreed_at()
             .returning(|_, _| Err(TermsOfUseError::InternalServerError));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -321,6 +549,12 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -330,12 +564,13 @@ mod tests {
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
-            .returning(|_, _| Ok(true));
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -345,7 +580,7 @@ mod tests {
 
         cache
             .expect_store_user_agreement()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -368,6 +603,12 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -377,12 +618,13 @@ mod tests {
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
-            .returning(|_, _| Ok(true));
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -392,7 +634,7 @@ mod tests {
 
         cache
             .expect_store_user_agreement()
-            .returning(|_, _, _| Err(TermsOfUseError::InternalServerError));
+            .returning(|_, _, _, _| Err(TermsOfUseError::InternalServerError));
 
         let user_id = 100;
         let group = "privacy-policy";
@@ -415,6 +657,12 @@ mod tests {
             url: "uploads/privacy-v4.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let mut term_repo = MockTermRepository::new();
@@ -424,12 +672,13 @@ mod tests {
 
         let mut agreement_repo = MockUserAgreementRepository::new();
         agreement_repo
-            .expect_has_user_agreed_to_term()
-            .returning(|_, _| Ok(true));
+            .expect_get_agreed_at()
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
 
         let repository = MockCombinedRepository {
             term_repo,
             agreement_repo,
+            group: None,
         };
 
         let mut cache = MockCacheService::new();
@@ -439,7 +688,7 @@ mod tests {
 
         cache
             .expect_store_user_agreement()
-            .returning(|_, _, _| Ok(()));
+            .returning(|_, _, _, _| Ok(()));
 
         // Act & Assert - User 1, Group A
         let result1 = has_user_agreed_to_term_use_case(&repository, &cache, 1, "group-a").await;
@@ -453,4 +702,426 @@ mod tests {
         let result3 = has_user_agreed_to_term_use_case(&repository, &cache, 1, "group-b").await;
         assert!(result3.is_ok());
     }
+
+    fn term(id: i32, version: u32) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version,
+            url: format!("uploads/privacy-v{version}.pdf"),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consent_status_reports_newest_older_version() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(15, 4))));
+        term_repo
+            .expect_get_terms_for_group()
+            .with(eq("privacy-policy"), always())
+            .times(1)
+            .returning(|_, _| Ok(vec![term(15, 4), term(12, 3), term(9, 2), term(4, 1)]));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(100), eq(15))
+            .returning(|_, _| Ok(None));
+        agreement_repo
+            .expect_get_agreed_term_ids()
+            .withf(|user_id, term_ids| *user_id == 100 && term_ids == [12, 9, 4])
+            .times(1)
+            .returning(|_, _| Ok(vec![4, 9]));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(100),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToOlder { version: 2 }),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result = get_consent_status_use_case(&repository, &cache, 100, "privacy-policy").await;
+
+        // Assert
+        assert_eq!(
+            result.unwrap(),
+            ConsentStatus::ConsentedToOlder { version: 2 }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_consent_status_without_terms() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo.expect_get_agreed_at().times(0);
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result = get_consent_status_use_case(&repository, &cache, 100, "cookie-policy").await;
+
+        // Assert
+        assert_eq!(result.unwrap(), ConsentStatus::NoTermsPublished);
+    }
+
+    #[tokio::test]
+    async fn test_consent_status_from_cache() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().times(0);
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo: MockUserAgreementRepository::new(),
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(Some(ConsentStatus::ConsentedToOlder { version: 3 })));
+
+        // Act
+        let status = get_consent_status_use_case(&repository, &cache, 100, "privacy-policy").await;
+        let agreed =
+            has_user_agreed_to_term_use_case(&repository, &cache, 100, "privacy-policy").await;
+
+        // Assert
+        assert_eq!(
+            status.unwrap(),
+            ConsentStatus::ConsentedToOlder { version: 3 }
+        );
+        assert!(!agreed.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_min_version_reads_cached_versions_first() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo.expect_get_newest_agreed_version().times(0);
+
+        let repository = MockCombinedRepository {
+            term_repo: MockTermRepository::new(),
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .with(eq(100), eq("privacy-policy"))
+            .returning(|_, _| Ok(Some(3)));
+
+        // Act
+        let result =
+            has_user_agreed_to_version_use_case(&repository, &cache, 100, "privacy-policy", 2)
+                .await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_min_version_looks_past_older_cached_versions() {
+        // Arrange
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_newest_agreed_version()
+            .with(eq(100), eq("privacy-policy"), eq(2))
+            .times(1)
+            .returning(|_, _, _| Ok(Some(4)));
+
+        let repository = MockCombinedRepository {
+            term_repo: MockTermRepository::new(),
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .returning(|_, _| Ok(Some(1)));
+        cache
+            .expect_store_agreed_version()
+            .with(eq(100), eq("privacy-policy"), eq(4), eq(None))
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result =
+            has_user_agreed_to_version_use_case(&repository, &cache, 100, "privacy-policy", 2)
+                .await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_min_version_without_agreements() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(15, 4))));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_newest_agreed_version()
+            .returning(|_, _, _| Ok(None));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .returning(|_, _| Ok(None));
+        cache.expect_store_agreed_version().times(0);
+
+        // Act
+        let result =
+            has_user_agreed_to_version_use_case(&repository, &cache, 100, "privacy-policy", 2)
+                .await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_min_version_without_terms() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_newest_agreed_version()
+            .returning(|_, _, _| Ok(None));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: None,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .returning(|_, _| Ok(None));
+
+        // Act
+        let result =
+            has_user_agreed_to_version_use_case(&repository, &cache, 100, "cookie-policy", 1).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::NotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_min_version_rejects_version_zero() {
+        // Arrange
+        let repository = MockCombinedRepository {
+            term_repo: MockTermRepository::new(),
+            agreement_repo: MockUserAgreementRepository::new(),
+            group: None,
+        };
+
+        // Act
+        let result = has_user_agreed_to_version_use_case(
+            &repository,
+            &MockCacheService::new(),
+            100,
+            "privacy-policy",
+            0,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    fn yearly_consent_group() -> Group {
+        Group {
+            name: "privacy-policy".to_string(),
+            description: None,
+            owner: None,
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: Some(365),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_consent_status_reports_expired_agreements() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(15, 4))));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(100), eq(15))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+        agreement_repo.expect_get_agreed_term_ids().times(0);
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: Some(yearly_consent_group()),
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(100),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentExpired),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let status = get_consent_status_use_case(&repository, &cache, 100, "privacy-policy").await;
+
+        // Assert
+        assert_eq!(status.unwrap(), ConsentStatus::ConsentExpired);
+    }
+
+    #[tokio::test]
+    async fn test_consent_status_caps_the_cache_at_the_expiry() {
+        // Arrange
+        let agreed_at = Utc::now().naive_utc() - TimeDelta::days(300);
+
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(15, 4))));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreed_at()
+            .returning(move |_, _| Ok(Some(agreed_at)));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: Some(yearly_consent_group()),
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(100),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToLatest),
+                eq(Some(agreed_at + TimeDelta::days(365))),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result =
+            has_user_agreed_to_term_use_case(&repository, &cache, 100, "privacy-policy").await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_min_version_ignores_expired_agreements() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_term_by_version()
+            .with(eq("privacy-policy"), eq(3))
+            .returning(|_, _| Ok(Some(term(12, 3))));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_newest_agreed_version()
+            .returning(|_, _, _| Ok(Some(3)));
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(100), eq(12))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group: Some(yearly_consent_group()),
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .returning(|_, _| Ok(None));
+        cache.expect_store_agreed_version().times(0);
+
+        // Act
+        let result =
+            has_user_agreed_to_version_use_case(&repository, &cache, 100, "privacy-policy", 2)
+                .await;
+
+        // Assert
+        assert!(!result.unwrap());
+    }
 }
@@ -0,0 +1,44 @@
+use serde_json::Value;
+
+use crate::{
+    data::service::SchemaService,
+    entities::TermMetadata,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Rejects metadata not satisfying the schema of its group, listing every violation.
+pub(crate) fn validate_metadata(
+    schemas: &dyn SchemaService,
+    group: &str,
+    schema: &Value,
+    metadata: &TermMetadata,
+) -> Result<()> {
+    let violations = schemas.violations(schema, &Value::Object(metadata.clone()))?;
+
+    if violations.is_empty() {
+        return Ok(());
+    }
+
+    Err(TermsOfUseError::Validation(format!(
+        "Metadata of group '{group}' does not match its schema: {}",
+        violations.join("; ")
+    )))
+}
+
+/// Builds a metadata filter from `key=value` pairs, e.g. query parameters.
+///
+/// Values are read as JSON when they are, so `2025` matches a number and `true` a boolean,
+/// and as strings otherwise. Quoting a value, e.g. `"2025"`, matches it as a string.
+pub fn parse_metadata_filter<I>(pairs: I) -> TermMetadata
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    pairs
+        .into_iter()
+        .map(|(key, value)| {
+            let value = serde_json::from_str(&value).unwrap_or(Value::String(value));
+
+            (key, value)
+        })
+        .collect()
+}
@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+    use serde_json::{Value, json};
+
+    use crate::{
+        data::service::MockSchemaService,
+        entities::TermMetadata,
+        errors::{Result, TermsOfUseError},
+        use_cases::metadata::{parse_metadata_filter, validate_metadata},
+    };
+
+    fn metadata(value: Value) -> TermMetadata {
+        value.as_object().unwrap().clone()
+    }
+
+    fn schemas(violations: Vec<&'static str>) -> MockSchemaService {
+        let mut schemas = MockSchemaService::new();
+        schemas
+            .expect_violations()
+            .with(
+                eq(json!({ "type": "object" })),
+                eq(json!({ "region": "apac", "year": "2025" })),
+            )
+            .returning(move |_, _| Ok(violations.iter().map(|v| v.to_string()).collect()));
+        schemas
+    }
+
+    fn validate(schemas: &MockSchemaService) -> Result<()> {
+        validate_metadata(
+            schemas,
+            "privacy-policy",
+            &json!({ "type": "object" }),
+            &metadata(json!({ "region": "apac", "year": "2025" })),
+        )
+    }
+
+    #[test]
+    fn test_validate_metadata_accepts_metadata_without_violations() {
+        assert!(validate(&schemas(vec![])).is_ok());
+    }
+
+    #[test]
+    fn test_validate_metadata_lists_every_violation() {
+        let result = validate(&schemas(vec![
+            "/region: \"apac\" is not one of [\"eu\",\"us\"]",
+            "/year: \"2025\" is not of type \"integer\"",
+        ]));
+
+        let Err(TermsOfUseError::Validation(message)) = result else {
+            panic!("Expected a validation error, got {result:?}");
+        };
+        assert_eq!(
+            message,
+            "Metadata of group 'privacy-policy' does not match its schema: \
+             /region: \"apac\" is not one of [\"eu\",\"us\"]; \
+             /year: \"2025\" is not of type \"integer\""
+        );
+    }
+
+    #[test]
+    fn test_validate_metadata_fails_when_the_schema_cannot_be_read() {
+        let mut schemas = MockSchemaService::new();
+        schemas
+            .expect_violations()
+            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+
+        assert!(matches!(
+            validate(&schemas),
+            Err(TermsOfUseError::InternalServerError)
+        ));
+    }
+
+    #[test]
+    fn test_parse_metadata_filter_reads_json_values() {
+        let filter = parse_metadata_filter([
+            ("region".to_string(), "eu".to_string()),
+            ("year".to_string(), "2025".to_string()),
+            ("code".to_string(), "\"2025\"".to_string()),
+            ("internal".to_string(), "false".to_string()),
+        ]);
+
+        assert_eq!(
+            Value::Object(filter),
+            json!({ "region": "eu", "year": 2025, "code": "2025", "internal": false })
+        );
+    }
+}
@@ -1,18 +1,93 @@
+mod bundle;
+mod change_summaries;
+mod checksum;
+mod clauses;
+mod consent_at;
+mod copy_storage;
 mod create_agreement;
 mod create_term_of_use;
+mod diff_terms;
+mod finalize_term_of_use;
 mod get_latest_term;
+mod get_term_history;
+mod group;
 mod has_agreed_to_terms;
+mod metadata;
+mod pending_terms;
+mod publish_documents;
+mod reconcile_storage;
+mod reserve_term_of_use;
+mod scanning;
+mod term_document;
+mod upload_policy;
 
+#[cfg(test)]
+mod bundle_test;
+#[cfg(test)]
+mod change_summaries_test;
+#[cfg(test)]
+mod clauses_test;
+#[cfg(test)]
+mod consent_at_test;
+#[cfg(test)]
+mod copy_storage_test;
 #[cfg(test)]
 mod create_agreement_test;
 #[cfg(test)]
 mod create_term_of_use_test;
 #[cfg(test)]
+mod diff_terms_test;
+#[cfg(test)]
+mod finalize_term_of_use_test;
+#[cfg(test)]
 mod get_latest_term_test;
 #[cfg(test)]
+mod get_term_history_test;
+#[cfg(test)]
+mod group_test;
+#[cfg(test)]
 mod has_agreed_to_terms_test;
+#[cfg(test)]
+mod metadata_test;
+#[cfg(test)]
+mod pending_terms_test;
+#[cfg(test)]
+mod publish_documents_test;
+#[cfg(test)]
+mod reconcile_storage_test;
+#[cfg(test)]
+mod reserve_term_of_use_test;
+#[cfg(test)]
+mod scanning_test;
+#[cfg(test)]
+mod upload_policy_test;
 
+pub use bundle::{
+    create_bundle_agreement_use_case, delete_bundle_use_case, get_bundle_use_case,
+    get_pending_bundle_groups_use_case, list_bundles_use_case, save_bundle_use_case,
+};
+pub use clauses::has_user_accepted_clause_use_case;
+pub use consent_at::{get_consent_at_use_case, parse_point_in_time};
+pub use copy_storage::copy_storage_use_case;
 pub use create_agreement::create_user_agreement_use_case;
 pub use create_term_of_use::create_term_of_use_use_case;
+pub use diff_terms::diff_terms_use_case;
+pub use finalize_term_of_use::finalize_term_of_use_use_case;
 pub use get_latest_term::get_latest_term_use_case;
-pub use has_agreed_to_terms::has_user_agreed_to_term_use_case;
+pub use get_term_history::get_term_history_use_case;
+pub use group::{
+    RESERVED_GROUP_NAMES, check_group_use_case, create_group_use_case, delete_group_use_case,
+    get_group_use_case, list_groups_use_case, update_group_use_case,
+};
+pub use has_agreed_to_terms::{
+    get_consent_status_use_case, has_user_agreed_to_term_use_case,
+    has_user_agreed_to_version_use_case,
+};
+pub use metadata::parse_metadata_filter;
+pub use pending_terms::get_pending_terms_use_case;
+pub use publish_documents::publish_documents_use_case;
+pub use reconcile_storage::reconcile_storage_use_case;
+pub use reserve_term_of_use::reserve_term_of_use_use_case;
+pub use upload_policy::{
+    check_upload_policy_use_case, get_upload_policy_use_case, set_upload_policy_use_case,
+};
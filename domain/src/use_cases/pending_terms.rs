@@ -0,0 +1,112 @@
+use chrono::Utc;
+
+use crate::{
+    data::{
+        repository::DatabaseRepository,
+        service::{CacheService, StorageService},
+    },
+    entities::{ConsentStatus, Group, TermOfUse},
+    errors::Result,
+};
+
+/// Latest terms of the mandatory groups a user has not agreed to yet, ordered by group.
+///
+/// Works like `get_latest_term_use_case` and `has_user_agreed_to_term_use_case` for every
+/// mandatory group, reading whatever the cache does not hold in one repository call each.
+/// Groups without terms have nothing to agree to and are left out, expired consents are
+/// pending again.
+#[tracing::instrument(skip(repository, cache, storage, user_id))]
+pub async fn get_pending_terms_use_case(
+    repository: &dyn DatabaseRepository,
+    cache: &dyn CacheService,
+    storage: &dyn StorageService,
+    user_id: i32,
+) -> Result<Vec<TermOfUse>> {
+    let groups: Vec<Group> = repository
+        .get_groups()
+        .await?
+        .into_iter()
+        .filter(|group| group.mandatory)
+        .collect();
+
+    let mut latest_terms = Vec::new();
+    let mut uncached_groups = Vec::new();
+    for group in &groups {
+        match cache.get_latest_term_for_group(&group.name).await {
+            Ok(Some(term)) => latest_terms.push(term),
+            _ => uncached_groups.push(group.name.clone()),
+        }
+    }
+
+    if !uncached_groups.is_empty() {
+        for mut term in repository
+            .get_latest_terms_for_groups(&uncached_groups)
+            .await?
+        {
+            term.url = storage.get_file_url(&term.url).await?;
+            term.text = None;
+
+            let _ = cache.store_latest_term_for_group(&term).await;
+
+            latest_terms.push(term);
+        }
+    }
+
+    let mut pending_terms = Vec::new();
+    let mut unknown_terms = Vec::new();
+    for term in latest_terms {
+        match cache.find_user_agreement(user_id, &term.group).await {
+            Ok(Some(ConsentStatus::ConsentedToLatest)) => {}
+            Ok(Some(
+                ConsentStatus::ConsentedToOlder { .. }
+                | ConsentStatus::ConsentExpired
+                | ConsentStatus::NeverConsented,
+            )) => pending_terms.push(term),
+            _ => unknown_terms.push(term),
+        }
+    }
+
+    if !unknown_terms.is_empty() {
+        let term_ids: Vec<i32> = unknown_terms.iter().map(|term| term.id).collect();
+        let agreed_term_ids = repository.get_agreed_term_ids(user_id, &term_ids).await?;
+
+        let now = Utc::now().naive_utc();
+        for term in unknown_terms {
+            if !agreed_term_ids.contains(&term.id) {
+                // Not cached, telling older consents apart takes a lookup per group
+                pending_terms.push(term);
+                continue;
+            }
+
+            // Only groups whose consents expire need the date of the agreement
+            let expires_at = match groups.iter().find(|group| group.name == term.group) {
+                Some(group) if group.max_consent_age_days.is_some() => repository
+                    .get_agreed_at(user_id, term.id)
+                    .await?
+                    .and_then(|agreed_at| group.consent_expiry(agreed_at)),
+                _ => None,
+            };
+
+            if expires_at.is_some_and(|expires_at| expires_at <= now) {
+                let _ = cache
+                    .store_user_agreement(user_id, &term.group, ConsentStatus::ConsentExpired, None)
+                    .await;
+
+                pending_terms.push(term);
+            } else {
+                let _ = cache
+                    .store_user_agreement(
+                        user_id,
+                        &term.group,
+                        ConsentStatus::ConsentedToLatest,
+                        expires_at,
+                    )
+                    .await;
+            }
+        }
+    }
+
+    pending_terms.sort_by(|a, b| a.group.cmp(&b.group));
+
+    Ok(pending_terms)
+}
@@ -0,0 +1,473 @@
+#[cfg(test)]
+mod tests {
+    use async_trait::async_trait;
+    use chrono::{TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{MockGroupRepository, MockTermRepository, MockUserAgreementRepository},
+            service::{MockCacheService, MockStorageService},
+        },
+        entities::{Bundle, ConsentStatus, Group, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::get_pending_terms_use_case,
+    };
+
+    // Combined mock for pending terms
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        agreement_repo: MockUserAgreementRepository,
+        group_repo: MockGroupRepository,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            self.agreement_repo.get_agreed_at(user_id, term_id).await
+        }
+
+        async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+            self.agreement_repo
+                .get_agreed_term_ids(user_id, term_ids)
+                .await
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            user_id: i32,
+            group: &str,
+            min_version: u32,
+        ) -> Result<Option<u32>> {
+            self.agreement_repo
+                .get_newest_agreed_version(user_id, group, min_version)
+                .await
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            user_id: i32,
+            term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            self.agreement_repo
+                .get_accepted_clauses(user_id, term_id)
+                .await
+        }
+
+        async fn get_agreement_at(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            self.agreement_repo
+                .get_agreement_at(user_id, term_id, at)
+                .await
+        }
+
+        async fn create_user_agreement(
+            &self,
+            user_id: i32,
+            term_id: i32,
+            accepted_clauses: &[String],
+        ) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreement(user_id, term_id, accepted_clauses)
+                .await
+        }
+
+        async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
+            self.agreement_repo
+                .create_user_agreements(user_id, term_ids)
+                .await
+        }
+    }
+
+    // Reservations are not involved in pending terms
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    // Upload policies are not involved in pending terms
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            self.group_repo.get_group(name).await
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            self.group_repo.get_groups().await
+        }
+
+        async fn create_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.create_group(group).await
+        }
+
+        async fn update_group(&self, group: Group) -> Result<Group> {
+            self.group_repo.update_group(group).await
+        }
+
+        async fn delete_group(&self, name: &str) -> Result<()> {
+            self.group_repo.delete_group(name).await
+        }
+    }
+
+    // Bundles are not involved in pending terms
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn group(name: &str, mandatory: bool) -> Group {
+        Group {
+            name: name.to_string(),
+            description: None,
+            owner: None,
+            mandatory,
+            default_locale: "en".to_string(),
+            max_consent_age_days: None,
+        }
+    }
+
+    fn latest_term(id: i32, group: &str) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: group.to_string(),
+            version: 2,
+            url: format!("{group}/v2.pdf"),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn repository(
+        term_repo: MockTermRepository,
+        agreement_repo: MockUserAgreementRepository,
+    ) -> MockCombinedRepository {
+        let mut group_repo = MockGroupRepository::new();
+        group_repo.expect_get_groups().returning(|| {
+            Ok(vec![
+                group("terms-of-service", true),
+                group("privacy-policy", true),
+                group("newsletter", false),
+                group("cookie-policy", true),
+            ])
+        });
+
+        MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group_repo,
+        }
+    }
+
+    fn signing_storage() -> MockStorageService {
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|path| Ok(format!("https://storage.example.com/{path}")));
+        storage
+    }
+
+    #[tokio::test]
+    async fn test_pending_terms_batches_uncached_lookups() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_terms_for_groups()
+            .withf(|groups| {
+                groups
+                    == [
+                        "terms-of-service".to_string(),
+                        "privacy-policy".to_string(),
+                        "cookie-policy".to_string(),
+                    ]
+            })
+            .times(1)
+            .returning(|_| {
+                // cookie-policy has no terms yet
+                Ok(vec![
+                    latest_term(1, "terms-of-service"),
+                    latest_term(2, "privacy-policy"),
+                ])
+            });
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreed_term_ids()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 2])
+            .times(1)
+            .returning(|_, _| Ok(vec![1]));
+
+        let repository = repository(term_repo, agreement_repo);
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        cache
+            .expect_store_latest_term_for_group()
+            .times(2)
+            .returning(|_| Ok(()));
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(42),
+                eq("terms-of-service"),
+                eq(ConsentStatus::ConsentedToLatest),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result = get_pending_terms_use_case(&repository, &cache, &signing_storage(), 42).await;
+
+        // Assert
+        let pending = result.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].id, 2);
+        assert_eq!(
+            pending[0].url,
+            "https://storage.example.com/privacy-policy/v2.pdf"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pending_terms_reads_the_cache_first() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_terms_for_groups().times(0);
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo.expect_get_agreed_term_ids().times(0);
+
+        let repository = repository(term_repo, agreement_repo);
+
+        let mut cache = MockCacheService::new();
+        cache.expect_get_latest_term_for_group().returning(|group| {
+            let id = match group {
+                "terms-of-service" => 1,
+                "privacy-policy" => 2,
+                _ => 3,
+            };
+            Ok(Some(latest_term(id, group)))
+        });
+        cache.expect_find_user_agreement().returning(|_, group| {
+            Ok(Some(match group {
+                "privacy-policy" => ConsentStatus::ConsentedToLatest,
+                "terms-of-service" => ConsentStatus::ConsentedToOlder { version: 1 },
+                _ => ConsentStatus::NeverConsented,
+            }))
+        });
+
+        // Act
+        let result =
+            get_pending_terms_use_case(&repository, &cache, &MockStorageService::new(), 42).await;
+
+        // Assert
+        let groups: Vec<String> = result.unwrap().into_iter().map(|term| term.group).collect();
+        assert_eq!(groups, ["cookie-policy", "terms-of-service"]);
+    }
+
+    #[tokio::test]
+    async fn test_pending_terms_propagates_repository_errors() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_terms_for_groups()
+            .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+        let repository = repository(term_repo, MockUserAgreementRepository::new());
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        // Act
+        let result =
+            get_pending_terms_use_case(&repository, &cache, &MockStorageService::new(), 42).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn test_pending_terms_lists_expired_agreements() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_terms_for_groups()
+            .returning(|_| Ok(vec![latest_term(1, "terms-of-service")]));
+
+        let mut agreement_repo = MockUserAgreementRepository::new();
+        agreement_repo
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![1]));
+        agreement_repo
+            .expect_get_agreed_at()
+            .with(eq(42), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+
+        let mut group_repo = MockGroupRepository::new();
+        group_repo.expect_get_groups().returning(|| {
+            Ok(vec![Group {
+                max_consent_age_days: Some(365),
+                ..group("terms-of-service", true)
+            }])
+        });
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            agreement_repo,
+            group_repo,
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        cache
+            .expect_store_latest_term_for_group()
+            .returning(|_| Ok(()));
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(42),
+                eq("terms-of-service"),
+                eq(ConsentStatus::ConsentExpired),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        // Act
+        let result = get_pending_terms_use_case(&repository, &cache, &signing_storage(), 42).await;
+
+        // Assert
+        let groups: Vec<String> = result.unwrap().into_iter().map(|term| term.group).collect();
+        assert_eq!(groups, ["terms-of-service"]);
+    }
+}
@@ -0,0 +1,35 @@
+use tracing::warn;
+
+use crate::{
+    data::{repository::TermRepository, service::StorageService},
+    dto::PublicationReportDTO,
+    errors::Result,
+};
+
+/// Publishes the document of every term, protecting documents stored before
+/// publishing did, e.g. from being deleted.
+///
+/// Publishing is repeatable, so documents already published are simply published
+/// again. Failing terms are reported and do not stop the others.
+#[tracing::instrument(skip(repository, upload_service))]
+pub async fn publish_documents_use_case(
+    repository: &dyn TermRepository,
+    upload_service: &dyn StorageService,
+) -> Result<PublicationReportDTO> {
+    let terms = repository.get_all_terms().await?;
+
+    let mut report = PublicationReportDTO::default();
+
+    for term in terms {
+        match upload_service.publish_file(&term.url, term.id).await {
+            Ok(()) => report.published_terms.push(term),
+            Err(err) => {
+                warn!("Failed to publish document of term {}: {err:?}", term.id);
+
+                report.failed_terms.push(term);
+            }
+        }
+    }
+
+    Ok(report)
+}
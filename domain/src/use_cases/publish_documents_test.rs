@@ -0,0 +1,94 @@
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{repository::MockTermRepository, service::MockStorageService},
+        entities::TermOfUse,
+        errors::TermsOfUseError,
+        use_cases::publish_documents_use_case,
+    };
+
+    fn term(id: i32, url: &str) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version: id as u32,
+            url: url.to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_publish_documents_publishes_every_term() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository.expect_get_all_terms().returning(|| {
+            Ok(vec![
+                term(1, "privacy-policy/v1.pdf"),
+                term(2, "privacy-policy/v2.pdf"),
+            ])
+        });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v1.pdf"), eq(1))
+            .times(1)
+            .returning(|_, _| Ok(()));
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v2.pdf"), eq(2))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = publish_documents_use_case(&repository, &storage).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.published_terms.len(), 2);
+        assert!(report.failed_terms.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_publish_documents_continues_after_failures() {
+        // Arrange
+        let mut repository = MockTermRepository::new();
+        repository.expect_get_all_terms().returning(|| {
+            Ok(vec![
+                term(1, "privacy-policy/v1.pdf"),
+                term(2, "privacy-policy/v2.pdf"),
+            ])
+        });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v1.pdf"), eq(1))
+            .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+        storage
+            .expect_publish_file()
+            .with(eq("privacy-policy/v2.pdf"), eq(2))
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        // Act
+        let result = publish_documents_use_case(&repository, &storage).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.published_terms.len(), 1);
+        assert_eq!(report.published_terms[0].id, 2);
+        assert_eq!(report.failed_terms.len(), 1);
+        assert_eq!(report.failed_terms[0].id, 1);
+    }
+}
@@ -0,0 +1,104 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use chrono::{NaiveDateTime, TimeDelta, Utc};
+use tracing::{error, warn};
+
+use crate::{
+    data::{repository::DatabaseRepository, service::StorageService},
+    dto::ReconciliationReportDTO,
+    errors::{Result, TermsOfUseError},
+};
+
+#[tracing::instrument(skip(repository, upload_service))]
+pub async fn reconcile_storage_use_case(
+    repository: &dyn DatabaseRepository,
+    upload_service: &dyn StorageService,
+    grace_period: Duration,
+    delete_orphans: bool,
+) -> Result<ReconciliationReportDTO> {
+    let grace_period = TimeDelta::from_std(grace_period).map_err(|err| {
+        error!("Invalid reconciliation grace period: {err}");
+
+        TermsOfUseError::InternalServerError
+    })?;
+
+    // Objects of others sharing the bucket would look like orphans
+    if delete_orphans && upload_service.lists_whole_bucket() {
+        return Err(TermsOfUseError::Validation(
+            "Orphans can only be deleted when the storage key template starts with a prefix"
+                .to_string(),
+        ));
+    }
+
+    let terms = repository.get_all_terms().await?;
+    let reservations = repository.get_all_reservations().await?;
+    let files = upload_service.list_files().await?;
+
+    let now = Utc::now().naive_utc();
+
+    // Documents of open reservations are referenced by the terms they will become
+    let referenced_keys: HashSet<&str> = terms
+        .iter()
+        .map(|term| term.url.as_str())
+        .chain(
+            reservations
+                .iter()
+                .filter(|reservation| reservation.expires_at > now)
+                .map(|reservation| reservation.key.as_str()),
+        )
+        .collect();
+
+    // Copies and newest modification of every stored document
+    let mut stored: HashMap<&str, (usize, NaiveDateTime)> = HashMap::new();
+    for file in &files {
+        let (copies, last_modified) = stored.entry(&file.key).or_insert((0, file.last_modified));
+        *copies += 1;
+        *last_modified = (*last_modified).max(file.last_modified);
+    }
+
+    // A document is missing as soon as one of its copies is gone
+    let copies = upload_service.copies();
+
+    // Recent uploads may belong to a term that is still being created
+    let cutoff = now - grace_period;
+
+    let mut report = ReconciliationReportDTO {
+        missing_documents: terms
+            .iter()
+            .filter(|term| {
+                stored
+                    .get(term.url.as_str())
+                    .is_none_or(|&(stored_copies, _)| stored_copies < copies)
+            })
+            .cloned()
+            .collect(),
+        ..Default::default()
+    };
+
+    for file in &files {
+        // Taken out, so documents with several copies are handled once
+        let Some((_, last_modified)) = stored.remove(file.key.as_str()) else {
+            continue;
+        };
+
+        if referenced_keys.contains(file.key.as_str()) || last_modified >= cutoff {
+            continue;
+        }
+
+        report.orphaned_files.push(file.key.clone());
+
+        if !delete_orphans {
+            continue;
+        }
+
+        match upload_service.delete_file(&file.key).await {
+            Ok(()) => report.deleted_files.push(file.key.clone()),
+            Err(err) => warn!("Failed to delete orphaned file {}: {err:?}", file.key),
+        }
+    }
+
+    Ok(report)
+}
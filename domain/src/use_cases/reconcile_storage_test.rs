@@ -0,0 +1,515 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use chrono::{NaiveDateTime, TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{MockTermRepository, MockTermReservationRepository},
+            service::MockStorageService,
+        },
+        entities::{Bundle, Group, StoredFile, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::reconcile_storage_use_case,
+    };
+
+    const GRACE_PERIOD: Duration = Duration::from_secs(24 * 60 * 60);
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        reservation_repo: MockTermReservationRepository,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    // Agreements are not involved in reconciliation
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            self.reservation_repo.create_reservation(reservation).await
+        }
+
+        async fn get_reservation(&self, reservation_id: i32) -> Result<Option<TermReservation>> {
+            self.reservation_repo.get_reservation(reservation_id).await
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermReservation>> {
+            self.reservation_repo
+                .get_reservation_for_version(group, version)
+                .await
+        }
+
+        async fn claim_reservation(
+            &self,
+            reservation_id: i32,
+            until: NaiveDateTime,
+        ) -> Result<bool> {
+            self.reservation_repo
+                .claim_reservation(reservation_id, until)
+                .await
+        }
+
+        async fn release_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .release_reservation(reservation_id)
+                .await
+        }
+
+        async fn delete_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .delete_reservation(reservation_id)
+                .await
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            self.reservation_repo.get_all_reservations().await
+        }
+    }
+
+    // Upload policies are not involved in reconciliation
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            unimplemented!()
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    // Groups are not involved in reconciliation
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, _name: &str) -> Result<Option<Group>> {
+            unimplemented!()
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in reconciliation
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn repository_with(terms: Vec<TermOfUse>) -> MockCombinedRepository {
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_all_terms()
+            .returning(move || Ok(terms.clone()));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_all_reservations()
+            .returning(|| Ok(vec![]));
+
+        MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        }
+    }
+
+    fn term(id: i32, url: &str) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            version: id as u32,
+            url: url.to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn file(key: &str, last_modified: NaiveDateTime) -> StoredFile {
+        StoredFile {
+            key: key.to_string(),
+            last_modified,
+        }
+    }
+
+    fn reservation(key: &str, expires_in: TimeDelta) -> TermReservation {
+        TermReservation {
+            id: 1,
+            group: "privacy-policy".to_string(),
+            version: 1,
+            info: None,
+            key: key.to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 1024,
+            sha256: String::new(),
+            expires_at: Utc::now().naive_utc() + expires_in,
+            change_summaries: vec![],
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn days_ago(days: i64) -> NaiveDateTime {
+        Utc::now().naive_utc() - TimeDelta::days(days)
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_orphans_and_missing_documents() {
+        // Arrange
+        let repository =
+            repository_with(vec![term(1, "privacy/v1.pdf"), term(2, "privacy/v2.pdf")]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(1usize);
+        storage.expect_list_files().times(1).returning(|| {
+            Ok(vec![
+                file("privacy/v1.pdf", days_ago(30)),
+                file("orphan.pdf", days_ago(2)),
+            ])
+        });
+        storage.expect_delete_file().never();
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, false).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.orphaned_files, vec!["orphan.pdf".to_string()]);
+        assert!(report.deleted_files.is_empty());
+        assert_eq!(report.missing_documents.len(), 1);
+        assert_eq!(report.missing_documents[0].id, 2);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_ignores_orphans_within_grace_period() {
+        // Arrange
+        let repository = repository_with(vec![]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(1usize);
+        storage
+            .expect_list_files()
+            .returning(|| Ok(vec![file("in-flight.pdf", Utc::now().naive_utc())]));
+        storage.expect_delete_file().never();
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert!(report.orphaned_files.is_empty());
+        assert!(report.deleted_files.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_deletes_orphans() {
+        // Arrange
+        let repository = repository_with(vec![term(1, "privacy/v1.pdf")]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(1usize);
+        storage.expect_list_files().returning(|| {
+            Ok(vec![
+                file("privacy/v1.pdf", days_ago(30)),
+                file("orphan-1.pdf", days_ago(2)),
+                file("orphan-2.pdf", days_ago(3)),
+            ])
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("orphan-1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+        storage
+            .expect_delete_file()
+            .with(eq("orphan-2.pdf"))
+            .times(1)
+            .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(
+            report.orphaned_files,
+            vec!["orphan-1.pdf".to_string(), "orphan-2.pdf".to_string()]
+        );
+        assert_eq!(report.deleted_files, vec!["orphan-1.pdf".to_string()]);
+        assert!(report.missing_documents.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_propagates_listing_error() {
+        // Arrange
+        let repository = repository_with(vec![]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(1usize);
+        storage
+            .expect_list_files()
+            .returning(|| Err(TermsOfUseError::InternalServerError));
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_keeps_documents_of_open_reservations() {
+        // Arrange
+        let mut repository = repository_with(vec![]);
+        repository.reservation_repo = MockTermReservationRepository::new();
+        repository
+            .reservation_repo
+            .expect_get_all_reservations()
+            .returning(|| {
+                Ok(vec![
+                    reservation("privacy/v1.pdf", TimeDelta::minutes(10)),
+                    reservation("privacy/v2.pdf", TimeDelta::minutes(-10)),
+                ])
+            });
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(1usize);
+        storage.expect_list_files().returning(|| {
+            Ok(vec![
+                file("privacy/v1.pdf", days_ago(2)),
+                file("privacy/v2.pdf", days_ago(2)),
+            ])
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("privacy/v2.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.orphaned_files, vec!["privacy/v2.pdf".to_string()]);
+        assert_eq!(report.deleted_files, vec!["privacy/v2.pdf".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_refuses_to_delete_across_the_whole_bucket() {
+        // Arrange
+        let repository = MockCombinedRepository {
+            term_repo: MockTermRepository::new(),
+            reservation_repo: MockTermReservationRepository::new(),
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(true);
+        storage.expect_list_files().never();
+        storage.expect_delete_file().never();
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_across_the_whole_bucket() {
+        // Arrange
+        let repository = repository_with(vec![]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(true);
+        storage.expect_copies().return_const(1usize);
+        storage
+            .expect_list_files()
+            .returning(|| Ok(vec![file("other-service.json", days_ago(2))]));
+        storage.expect_delete_file().never();
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, false).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(
+            report.orphaned_files,
+            vec!["other-service.json".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_reports_documents_missing_a_copy() {
+        // Arrange
+        let repository =
+            repository_with(vec![term(1, "privacy/v1.pdf"), term(2, "privacy/v2.pdf")]);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_lists_whole_bucket().return_const(false);
+        storage.expect_copies().return_const(2usize);
+        storage.expect_list_files().returning(|| {
+            Ok(vec![
+                file("privacy/v1.pdf", days_ago(30)),
+                file("privacy/v2.pdf", days_ago(30)),
+                file("orphan.pdf", days_ago(30)),
+                file("privacy/v1.pdf", days_ago(30)),
+                file("orphan.pdf", days_ago(30)),
+            ])
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("orphan.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        // Act
+        let result = reconcile_storage_use_case(&repository, &storage, GRACE_PERIOD, true).await;
+
+        // Assert
+        let report = result.unwrap();
+        assert_eq!(report.missing_documents.len(), 1);
+        assert_eq!(report.missing_documents[0].id, 2);
+        assert_eq!(report.orphaned_files, vec!["orphan.pdf".to_string()]);
+    }
+}
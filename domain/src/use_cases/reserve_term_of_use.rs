@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use chrono::{TimeDelta, Utc};
+use tracing::error;
+
+use crate::{
+    data::{
+        repository::DatabaseRepository,
+        service::{DocumentService, SchemaService, StorageService},
+    },
+    dto::{ReserveTermOfUseDTO, TermReservationDTO},
+    entities::TermReservation,
+    errors::{Result, TermsOfUseError},
+    use_cases::{
+        change_summaries::build_change_summaries,
+        checksum::is_sha256,
+        clauses::validate_clauses,
+        finalize_term_of_use::{CLAIM_DURATION, delete_upload},
+        group::check_group_use_case,
+        upload_policy::check_upload_policy_use_case,
+    },
+};
+
+/// First phase of a direct upload: reserves the next version of a group and presigns
+/// the upload of its document. The term is only created by
+/// `finalize_term_of_use_use_case`, once the uploaded document has been verified.
+#[tracing::instrument(skip(repository, upload_service, documents, schemas, term))]
+pub async fn reserve_term_of_use_use_case(
+    repository: &dyn DatabaseRepository,
+    upload_service: &dyn StorageService,
+    documents: &dyn DocumentService,
+    schemas: &dyn SchemaService,
+    term: ReserveTermOfUseDTO,
+    expires_in: Duration,
+) -> Result<TermReservationDTO> {
+    if term.size == 0 {
+        return Err(TermsOfUseError::Validation(
+            "The document must not be empty".to_string(),
+        ));
+    }
+
+    if !is_sha256(&term.sha256) {
+        return Err(TermsOfUseError::Validation(
+            "The document hash must be a hex encoded SHA-256 digest".to_string(),
+        ));
+    }
+
+    validate_clauses(&term.clauses)?;
+    check_group_use_case(repository, &term.group).await?;
+    check_upload_policy_use_case(
+        repository,
+        schemas,
+        &term.group,
+        &term.content_type,
+        term.size,
+        term.info.as_deref(),
+        &term.change_summaries,
+        &term.metadata,
+    )
+    .await?;
+    let change_summaries = build_change_summaries(documents, term.change_summaries)?;
+
+    let ttl = TimeDelta::from_std(expires_in).map_err(|err| {
+        error!("Invalid upload URL expiry: {err}");
+
+        TermsOfUseError::InternalServerError
+    })?;
+
+    let latest_term = repository.get_latest_term_for_group(&term.group).await?;
+    let next_version = match latest_term {
+        Some(t) => t.version + 1,
+        None => 1,
+    };
+
+    // Reservations of a version share its upload key, so rejecting the document of one of
+    // them would delete the document uploaded for the other
+    if let Some(open_reservation) = repository
+        .get_reservation_for_version(&term.group, next_version)
+        .await?
+    {
+        if open_reservation.expires_at >= Utc::now().naive_utc() {
+            return Err(TermsOfUseError::Validation(format!(
+                "Version {next_version} of the group is already being uploaded, please finalize it or reserve again once it expires"
+            )));
+        }
+
+        // Whoever claims the expired reservation removes it, the others go on to find the
+        // version reserved again
+        if repository
+            .claim_reservation(open_reservation.id, Utc::now().naive_utc() + CLAIM_DURATION)
+            .await?
+        {
+            let _ = repository.delete_reservation(open_reservation.id).await;
+            delete_upload(upload_service, &open_reservation.key).await;
+        }
+    }
+
+    let sha256 = term.sha256.to_lowercase();
+
+    let upload = upload_service
+        .create_upload_url(
+            &term.group,
+            next_version,
+            &term.content_type,
+            term.size,
+            &sha256,
+            expires_in,
+        )
+        .await?;
+
+    // Rejected when another call reserved the version in the meantime
+    let reservation = repository
+        .create_reservation(TermReservation {
+            id: 0,
+            group: term.group,
+            version: next_version,
+            info: term.info,
+            key: upload.key.clone(),
+            content_type: term.content_type,
+            size: term.size,
+            sha256,
+            expires_at: Utc::now().naive_utc() + ttl,
+            change_summaries,
+            metadata: term.metadata,
+            clauses: term.clauses,
+        })
+        .await?;
+
+    Ok(TermReservationDTO {
+        reservation_id: reservation.id,
+        version: reservation.version,
+        upload,
+        expires_at: reservation.expires_at,
+    })
+}
@@ -0,0 +1,702 @@
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, time::Duration};
+
+    use async_trait::async_trait;
+    use chrono::{NaiveDateTime, TimeDelta, Utc};
+    use mockall::predicate::*;
+
+    use crate::{
+        data::{
+            repository::{MockTermRepository, MockTermReservationRepository},
+            service::{MockDocumentService, MockSchemaService, MockStorageService},
+        },
+        dto::ReserveTermOfUseDTO,
+        entities::{Bundle, Group, PresignedUpload, TermOfUse, TermReservation, UploadPolicy},
+        errors::{Result, TermsOfUseError},
+        use_cases::reserve_term_of_use_use_case,
+    };
+
+    const SHA256: &str = "2CF24DBA5FB0A30E26E83B2AC5B9E29E1B161E5C1FA7425E73043362938B9824";
+    const EXPIRES_IN: Duration = Duration::from_secs(15 * 60);
+
+    fn sample_documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_render_markdown()
+            .returning(|markdown| format!("<p>{markdown}</p>"));
+        documents
+    }
+
+    fn valid_schemas() -> MockSchemaService {
+        let mut schemas = MockSchemaService::new();
+        schemas.expect_violations().returning(|_, _| Ok(vec![]));
+        schemas
+    }
+
+    // Combined mock for testing
+    struct MockCombinedRepository {
+        term_repo: MockTermRepository,
+        reservation_repo: MockTermReservationRepository,
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_latest_term_for_group(group).await
+        }
+
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_latest_terms_for_groups(groups).await
+        }
+
+        async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_id(term_id).await
+        }
+
+        async fn get_term_by_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            self.term_repo.get_term_by_version(group, version).await
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            group: &str,
+            metadata: &crate::entities::TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_terms_for_group(group, metadata).await
+        }
+
+        async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+            self.term_repo.create_term(term).await
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            self.term_repo.get_all_terms().await
+        }
+    }
+
+    // Agreements are not involved in reservations
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            self.reservation_repo.create_reservation(reservation).await
+        }
+
+        async fn get_reservation(&self, reservation_id: i32) -> Result<Option<TermReservation>> {
+            self.reservation_repo.get_reservation(reservation_id).await
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            group: &str,
+            version: u32,
+        ) -> Result<Option<TermReservation>> {
+            self.reservation_repo
+                .get_reservation_for_version(group, version)
+                .await
+        }
+
+        async fn claim_reservation(
+            &self,
+            reservation_id: i32,
+            until: NaiveDateTime,
+        ) -> Result<bool> {
+            self.reservation_repo
+                .claim_reservation(reservation_id, until)
+                .await
+        }
+
+        async fn release_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .release_reservation(reservation_id)
+                .await
+        }
+
+        async fn delete_reservation(&self, reservation_id: i32) -> Result<()> {
+            self.reservation_repo
+                .delete_reservation(reservation_id)
+                .await
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            self.reservation_repo.get_all_reservations().await
+        }
+    }
+
+    // No group of these tests has an upload policy
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, _group: &str) -> Result<Option<UploadPolicy>> {
+            Ok(None)
+        }
+
+        async fn save_upload_policy(&self, _policy: UploadPolicy) -> Result<UploadPolicy> {
+            unimplemented!()
+        }
+    }
+
+    // Only `privacy-policy` is registered in these tests
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            Ok((name == "privacy-policy").then(|| Group {
+                name: name.to_string(),
+                description: None,
+                owner: Some("legal".to_string()),
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: None,
+            }))
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in reserving terms
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    fn reserve_dto(size: u64, sha256: &str) -> ReserveTermOfUseDTO {
+        ReserveTermOfUseDTO {
+            group: "privacy-policy".to_string(),
+            info: Some("Direct upload".to_string()),
+            content_type: "application/pdf".to_string(),
+            size,
+            sha256: sha256.to_string(),
+            change_summaries: BTreeMap::from([(
+                "en".to_string(),
+                "Clarified *retention*".to_string(),
+            )]),
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn open_reservation(expires_at: NaiveDateTime) -> TermReservation {
+        TermReservation {
+            id: 5,
+            group: "privacy-policy".to_string(),
+            version: 1,
+            info: None,
+            key: "privacy-policy/v1.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 1024,
+            sha256: SHA256.to_lowercase(),
+            expires_at,
+            change_summaries: vec![],
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_presigns_next_version() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .with(eq("privacy-policy"))
+            .times(1)
+            .returning(|_| {
+                Ok(Some(TermOfUse {
+                    id: 1,
+                    group: "privacy-policy".to_string(),
+                    version: 2,
+                    url: "privacy-policy/v2.pdf".to_string(),
+                    created_at: Utc::now().naive_utc(),
+                    info: None,
+                    html: None,
+                    text: None,
+                    change_summaries: vec![],
+                    pdf_metadata: None,
+                    metadata: Default::default(),
+                    clauses: vec![],
+                }))
+            });
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation_for_version()
+            .with(eq("privacy-policy"), eq(3))
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        reservation_repo
+            .expect_create_reservation()
+            .withf(|reservation| {
+                reservation.version == 3
+                    && reservation.key == "privacy-policy/v3.pdf"
+                    && reservation.size == 1024
+                    && reservation.sha256 == SHA256.to_lowercase()
+                    && reservation.expires_at > Utc::now().naive_utc()
+                    && reservation.change_summaries.len() == 1
+                    && reservation.change_summaries[0].html == "<p>Clarified *retention*</p>"
+            })
+            .times(1)
+            .returning(|reservation| {
+                Ok(TermReservation {
+                    id: 7,
+                    ..reservation
+                })
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_create_upload_url()
+            .withf(|group, version, content_type, size, sha256, expires_in| {
+                group == "privacy-policy"
+                    && *version == 3
+                    && content_type == "application/pdf"
+                    && *size == 1024
+                    && sha256 == SHA256.to_lowercase()
+                    && *expires_in == EXPIRES_IN
+            })
+            .times(1)
+            .returning(|_, _, _, _, _, _| {
+                Ok(PresignedUpload {
+                    key: "privacy-policy/v3.pdf".to_string(),
+                    method: "PUT".to_string(),
+                    url: "https://storage.example.com/privacy-policy/v3.pdf?signature".to_string(),
+                    headers: vec![("content-type".to_string(), "application/pdf".to_string())],
+                })
+            });
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, SHA256),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        let reservation = result.unwrap();
+        assert_eq!(reservation.reservation_id, 7);
+        assert_eq!(reservation.version, 3);
+        assert_eq!(reservation.upload.method, "PUT");
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_rejects_invalid_hash() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo.expect_create_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_create_upload_url().never();
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, "not-a-digest"),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_rejects_invalid_change_summary() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo.expect_create_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_create_upload_url().never();
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            ReserveTermOfUseDTO {
+                change_summaries: BTreeMap::from([("english".to_string(), "Changed".to_string())]),
+                ..reserve_dto(1024, SHA256)
+            },
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_propagates_presign_error() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation_for_version()
+            .returning(|_, _| Ok(None));
+        reservation_repo.expect_create_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_create_upload_url()
+            .withf(|_, version, _, _, _, _| *version == 1)
+            .returning(|_, _, _, _, _, _| Err(TermsOfUseError::InternalServerError));
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, SHA256),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_rejects_unregistered_group() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo.expect_get_latest_term_for_group().never();
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo.expect_create_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_create_upload_url().never();
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            ReserveTermOfUseDTO {
+                group: "unknown".to_string(),
+                ..reserve_dto(1024, SHA256)
+            },
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_rejects_version_already_reserved() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation_for_version()
+            .with(eq("privacy-policy"), eq(1))
+            .returning(|_, _| {
+                Ok(Some(open_reservation(
+                    Utc::now().naive_utc() + TimeDelta::minutes(5),
+                )))
+            });
+        reservation_repo.expect_delete_reservation().never();
+        reservation_repo.expect_create_reservation().never();
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_delete_file().never();
+        storage.expect_create_upload_url().never();
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, SHA256),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_replaces_expired_reservation() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation_for_version()
+            .returning(|_, _| {
+                Ok(Some(open_reservation(
+                    Utc::now().naive_utc() - TimeDelta::minutes(1),
+                )))
+            });
+        reservation_repo
+            .expect_claim_reservation()
+            .withf(|reservation_id, _| *reservation_id == 5)
+            .times(1)
+            .returning(|_, _| Ok(true));
+        reservation_repo
+            .expect_delete_reservation()
+            .with(eq(5))
+            .times(1)
+            .returning(|_| Ok(()));
+        reservation_repo
+            .expect_create_reservation()
+            .times(1)
+            .returning(|reservation| {
+                Ok(TermReservation {
+                    id: 6,
+                    ..reservation
+                })
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_delete_file()
+            .with(eq("privacy-policy/v1.pdf"))
+            .times(1)
+            .returning(|_| Ok(()));
+        storage
+            .expect_create_upload_url()
+            .times(1)
+            .returning(|_, _, _, _, _, _| {
+                Ok(PresignedUpload {
+                    key: "privacy-policy/v1.pdf".to_string(),
+                    method: "PUT".to_string(),
+                    url: "https://storage.example.com/privacy-policy/v1.pdf?signature".to_string(),
+                    headers: vec![],
+                })
+            });
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, SHA256),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert_eq!(result.unwrap().reservation_id, 6);
+    }
+
+    #[tokio::test]
+    async fn test_reserve_term_of_use_leaves_expired_reservation_claimed_elsewhere() {
+        // Arrange
+        let mut term_repo = MockTermRepository::new();
+        term_repo
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+
+        let mut reservation_repo = MockTermReservationRepository::new();
+        reservation_repo
+            .expect_get_reservation_for_version()
+            .returning(|_, _| {
+                Ok(Some(open_reservation(
+                    Utc::now().naive_utc() - TimeDelta::minutes(1),
+                )))
+            });
+        reservation_repo
+            .expect_claim_reservation()
+            .times(1)
+            .returning(|_, _| Ok(false));
+        reservation_repo.expect_delete_reservation().never();
+        reservation_repo
+            .expect_create_reservation()
+            .times(1)
+            .returning(|_| {
+                Err(TermsOfUseError::Validation(
+                    "Version 1 of group 'privacy-policy' is already being uploaded".to_string(),
+                ))
+            });
+
+        let repository = MockCombinedRepository {
+            term_repo,
+            reservation_repo,
+        };
+
+        let mut storage = MockStorageService::new();
+        storage.expect_delete_file().never();
+        storage
+            .expect_create_upload_url()
+            .returning(|_, _, _, _, _, _| {
+                Ok(PresignedUpload {
+                    key: "privacy-policy/v1.pdf".to_string(),
+                    method: "PUT".to_string(),
+                    url: "https://storage.example.com/privacy-policy/v1.pdf?signature".to_string(),
+                    headers: vec![],
+                })
+            });
+
+        // Act
+        let result = reserve_term_of_use_use_case(
+            &repository,
+            &storage,
+            &sample_documents(),
+            &valid_schemas(),
+            reserve_dto(1024, SHA256),
+            EXPIRES_IN,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use tracing::warn;
+
+use crate::{
+    data::service::{DocumentService, ScannerService},
+    entities::ScanVerdict,
+    errors::{Result, TermsOfUseError},
+};
+
+/// Rejects documents flagged by the scanner, recording each detection in the `audit` log.
+pub(crate) async fn scan_document(
+    scanner: &dyn ScannerService,
+    documents: &dyn DocumentService,
+    file_path: &Path,
+    group: &str,
+    content_type: &str,
+) -> Result<()> {
+    let ScanVerdict::Infected(signature) = scanner.scan_file(file_path).await? else {
+        return Ok(());
+    };
+
+    warn!(
+        target: "audit",
+        event = "malware_detected",
+        group,
+        content_type,
+        signature = signature.as_str(),
+        sha256 = documents.sha256(file_path).await.unwrap_or_default(),
+        "Rejected a term document flagged by the malware scanner"
+    );
+
+    Err(TermsOfUseError::Validation(format!(
+        "The document was rejected by the malware scanner ({signature})"
+    )))
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use crate::{
+        data::service::{MockDocumentService, MockScannerService},
+        entities::ScanVerdict,
+        errors::TermsOfUseError,
+        use_cases::scanning::scan_document,
+    };
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    fn documents() -> MockDocumentService {
+        let mut documents = MockDocumentService::new();
+        documents.expect_sha256().returning(|_| {
+            Ok("9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08".to_string())
+        });
+        documents
+    }
+
+    #[tokio::test]
+    async fn accepts_clean_documents() {
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Ok(ScanVerdict::Clean));
+
+        let result = scan_document(
+            &scanner,
+            &documents(),
+            Path::new(SAMPLE_PDF),
+            "privacy-policy",
+            "application/pdf",
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn rejects_infected_documents_naming_the_signature() {
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Ok(ScanVerdict::Infected("Eicar-Signature".to_string())));
+
+        let result = scan_document(
+            &scanner,
+            &documents(),
+            Path::new(SAMPLE_PDF),
+            "privacy-policy",
+            "application/pdf",
+        )
+        .await;
+
+        match result {
+            Err(TermsOfUseError::Validation(detail)) => assert!(detail.contains("Eicar-Signature")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn propagates_scanner_failures() {
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+        let result = scan_document(
+            &scanner,
+            &documents(),
+            Path::new(SAMPLE_PDF),
+            "privacy-policy",
+            "application/pdf",
+        )
+        .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
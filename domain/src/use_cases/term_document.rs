@@ -0,0 +1,21 @@
+use std::path::Path;
+
+use crate::{
+    data::service::{DocumentService, ScannerService},
+    entities::TermDocument,
+    errors::Result,
+    use_cases::scanning::scan_document,
+};
+
+/// Reads a local copy of a term document, rejecting documents that cannot be published.
+pub(crate) async fn read_term_document(
+    scanner: &dyn ScannerService,
+    documents: &dyn DocumentService,
+    path: &Path,
+    group: &str,
+    content_type: &str,
+) -> Result<TermDocument> {
+    scan_document(scanner, documents, path, group, content_type).await?;
+
+    documents.read_document(path, content_type).await
+}
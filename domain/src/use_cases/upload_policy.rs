@@ -0,0 +1,142 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    data::{
+        repository::{DatabaseRepository, UploadPolicyRepository},
+        service::SchemaService,
+    },
+    entities::{
+        DOCUMENT_CONTENT_TYPES, MAX_DOCUMENT_SIZE, REQUIRABLE_TERM_FIELDS, TermMetadata,
+        UploadPolicy,
+    },
+    errors::{Result, TermsOfUseError},
+    use_cases::{group::check_group_use_case, metadata::validate_metadata},
+};
+
+/// Upload policy of a group, falling back to the default policy when none was set.
+#[tracing::instrument(skip(repository))]
+pub async fn get_upload_policy_use_case(
+    repository: &dyn UploadPolicyRepository,
+    group: &str,
+) -> Result<UploadPolicy> {
+    Ok(repository
+        .get_upload_policy(group)
+        .await?
+        .unwrap_or_else(|| UploadPolicy::default_for(group)))
+}
+
+/// Sets the upload policy of a registered group.
+#[tracing::instrument(skip(repository, schemas))]
+pub async fn set_upload_policy_use_case(
+    repository: &dyn DatabaseRepository,
+    schemas: &dyn SchemaService,
+    mut policy: UploadPolicy,
+) -> Result<UploadPolicy> {
+    if policy.group.trim().is_empty() {
+        return Err(TermsOfUseError::Validation(
+            "The group must not be empty".to_string(),
+        ));
+    }
+
+    check_group_use_case(repository, &policy.group).await?;
+
+    if policy.content_types.is_empty() {
+        return Err(TermsOfUseError::Validation(
+            "The policy must accept at least one content type".to_string(),
+        ));
+    }
+
+    if let Some(content_type) = policy
+        .content_types
+        .iter()
+        .find(|content_type| !DOCUMENT_CONTENT_TYPES.contains(&content_type.as_str()))
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "'{content_type}' is not a supported document type, expected one of: {}",
+            DOCUMENT_CONTENT_TYPES.join(", ")
+        )));
+    }
+
+    if policy.max_size == 0 || policy.max_size > MAX_DOCUMENT_SIZE {
+        return Err(TermsOfUseError::Validation(format!(
+            "The maximum size must be between 1 and {MAX_DOCUMENT_SIZE} bytes"
+        )));
+    }
+
+    if let Some(field) = policy
+        .required_fields
+        .iter()
+        .find(|field| !REQUIRABLE_TERM_FIELDS.contains(&field.as_str()))
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "'{field}' cannot be required, expected any of: {}",
+            REQUIRABLE_TERM_FIELDS.join(", ")
+        )));
+    }
+
+    if let Some(schema) = &policy.metadata_schema {
+        schemas.check_schema(schema)?;
+    }
+
+    policy.content_types.sort();
+    policy.content_types.dedup();
+    policy.required_fields.sort();
+    policy.required_fields.dedup();
+
+    repository.save_upload_policy(policy).await
+}
+
+/// Rejects documents the upload policy of their group does not accept.
+#[tracing::instrument(skip(repository, schemas, info, change_summaries, metadata))]
+#[allow(clippy::too_many_arguments)]
+pub async fn check_upload_policy_use_case(
+    repository: &dyn UploadPolicyRepository,
+    schemas: &dyn SchemaService,
+    group: &str,
+    content_type: &str,
+    size: u64,
+    info: Option<&str>,
+    change_summaries: &BTreeMap<String, String>,
+    metadata: &TermMetadata,
+) -> Result<()> {
+    let policy = get_upload_policy_use_case(repository, group).await?;
+
+    if !policy
+        .content_types
+        .iter()
+        .any(|accepted| accepted == content_type)
+    {
+        return Err(TermsOfUseError::Validation(format!(
+            "Documents of group '{group}' must be one of: {}",
+            policy.content_types.join(", ")
+        )));
+    }
+
+    if size > policy.max_size {
+        return Err(TermsOfUseError::Validation(format!(
+            "Documents of group '{group}' must not exceed {} bytes",
+            policy.max_size
+        )));
+    }
+
+    for field in &policy.required_fields {
+        let is_set = match field.as_str() {
+            "info" => info.is_some_and(|info| !info.trim().is_empty()),
+            "changeSummaries" => !change_summaries.is_empty(),
+            "metadata" => !metadata.is_empty(),
+            _ => true,
+        };
+
+        if !is_set {
+            return Err(TermsOfUseError::Validation(format!(
+                "Terms of group '{group}' must include {field}"
+            )));
+        }
+    }
+
+    if let Some(schema) = &policy.metadata_schema {
+        validate_metadata(schemas, group, schema, metadata)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,651 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use async_trait::async_trait;
+    use mockall::predicate::eq;
+    use serde_json::json;
+
+    use crate::{
+        data::{repository::MockUploadPolicyRepository, service::MockSchemaService},
+        entities::{
+            Bundle, DEFAULT_MAX_DOCUMENT_SIZE, Group, MAX_DOCUMENT_SIZE, TermMetadata, TermOfUse,
+            TermReservation, UploadPolicy,
+        },
+        errors::{Result, TermsOfUseError},
+        use_cases::{
+            check_upload_policy_use_case, get_upload_policy_use_case, set_upload_policy_use_case,
+        },
+    };
+
+    fn valid_schemas() -> MockSchemaService {
+        let mut schemas = MockSchemaService::new();
+        schemas.expect_check_schema().returning(|_| Ok(()));
+        schemas.expect_violations().returning(|_, _| Ok(vec![]));
+        schemas
+    }
+
+    fn pdf_only_policy() -> UploadPolicy {
+        UploadPolicy {
+            group: "privacy-policy".to_string(),
+            content_types: vec!["application/pdf".to_string()],
+            max_size: 2 * 1024 * 1024,
+            required_fields: vec!["info".to_string()],
+            metadata_schema: None,
+        }
+    }
+
+    fn repository_with(policy: Option<UploadPolicy>) -> MockUploadPolicyRepository {
+        let mut repository = MockUploadPolicyRepository::new();
+        repository
+            .expect_get_upload_policy()
+            .with(eq("privacy-policy"))
+            .returning(move |_| Ok(policy.clone()));
+        repository
+    }
+
+    // Combined mock for setting policies, which checks the group first
+    struct MockCombinedRepository {
+        policy_repo: MockUploadPolicyRepository,
+    }
+
+    // Terms are not involved in setting policies
+    #[async_trait]
+    impl crate::data::repository::TermRepository for MockCombinedRepository {
+        async fn get_latest_term_for_group(&self, _group: &str) -> Result<Option<TermOfUse>> {
+            unimplemented!()
+        }
+
+        async fn get_latest_terms_for_groups(&self, _groups: &[String]) -> Result<Vec<TermOfUse>> {
+            unimplemented!()
+        }
+
+        async fn get_term_by_id(&self, _term_id: i32) -> Result<Option<TermOfUse>> {
+            unimplemented!()
+        }
+
+        async fn get_term_by_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermOfUse>> {
+            unimplemented!()
+        }
+
+        async fn get_terms_for_group(
+            &self,
+            _group: &str,
+            _metadata: &TermMetadata,
+        ) -> Result<Vec<TermOfUse>> {
+            unimplemented!()
+        }
+
+        async fn create_term(&self, _term: TermOfUse) -> Result<TermOfUse> {
+            unimplemented!()
+        }
+
+        async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+            unimplemented!()
+        }
+    }
+
+    // Agreements are not involved in setting policies
+    #[async_trait]
+    impl crate::data::repository::UserAgreementRepository for MockCombinedRepository {
+        async fn get_agreed_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<chrono::NaiveDateTime>> {
+            unimplemented!()
+        }
+
+        async fn get_agreed_term_ids(&self, _user_id: i32, _term_ids: &[i32]) -> Result<Vec<i32>> {
+            unimplemented!()
+        }
+
+        async fn get_newest_agreed_version(
+            &self,
+            _user_id: i32,
+            _group: &str,
+            _min_version: u32,
+        ) -> Result<Option<u32>> {
+            unimplemented!()
+        }
+
+        async fn get_accepted_clauses(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+        ) -> Result<Option<Vec<String>>> {
+            unimplemented!()
+        }
+
+        async fn get_agreement_at(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _at: chrono::NaiveDateTime,
+        ) -> Result<Option<crate::entities::UserAgreement>> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreement(
+            &self,
+            _user_id: i32,
+            _term_id: i32,
+            _accepted_clauses: &[String],
+        ) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn create_user_agreements(&self, _user_id: i32, _term_ids: &[i32]) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Reservations are not involved in setting policies
+    #[async_trait]
+    impl crate::data::repository::TermReservationRepository for MockCombinedRepository {
+        async fn create_reservation(
+            &self,
+            _reservation: TermReservation,
+        ) -> Result<TermReservation> {
+            unimplemented!()
+        }
+
+        async fn get_reservation(&self, _reservation_id: i32) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn get_reservation_for_version(
+            &self,
+            _group: &str,
+            _version: u32,
+        ) -> Result<Option<TermReservation>> {
+            unimplemented!()
+        }
+
+        async fn claim_reservation(
+            &self,
+            _reservation_id: i32,
+            _until: chrono::NaiveDateTime,
+        ) -> Result<bool> {
+            unimplemented!()
+        }
+
+        async fn release_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn delete_reservation(&self, _reservation_id: i32) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+            unimplemented!()
+        }
+    }
+
+    #[async_trait]
+    impl crate::data::repository::UploadPolicyRepository for MockCombinedRepository {
+        async fn get_upload_policy(&self, group: &str) -> Result<Option<UploadPolicy>> {
+            self.policy_repo.get_upload_policy(group).await
+        }
+
+        async fn save_upload_policy(&self, policy: UploadPolicy) -> Result<UploadPolicy> {
+            self.policy_repo.save_upload_policy(policy).await
+        }
+    }
+
+    // Only `privacy-policy` is registered in these tests
+    #[async_trait]
+    impl crate::data::repository::GroupRepository for MockCombinedRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+            Ok((name == "privacy-policy").then(|| Group {
+                name: name.to_string(),
+                description: None,
+                owner: Some("legal".to_string()),
+                mandatory: true,
+                default_locale: "en".to_string(),
+                max_consent_age_days: None,
+            }))
+        }
+
+        async fn get_groups(&self) -> Result<Vec<Group>> {
+            unimplemented!()
+        }
+
+        async fn create_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn update_group(&self, _group: Group) -> Result<Group> {
+            unimplemented!()
+        }
+
+        async fn delete_group(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    // Bundles are not involved in setting policies
+    #[async_trait]
+    impl crate::data::repository::BundleRepository for MockCombinedRepository {
+        async fn get_bundle(&self, _name: &str) -> Result<Option<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+            unimplemented!()
+        }
+
+        async fn save_bundle(&self, _bundle: Bundle) -> Result<Bundle> {
+            unimplemented!()
+        }
+
+        async fn delete_bundle(&self, _name: &str) -> Result<()> {
+            unimplemented!()
+        }
+    }
+
+    impl crate::data::repository::DatabaseRepository for MockCombinedRepository {}
+
+    #[tokio::test]
+    async fn get_upload_policy_falls_back_to_the_default() {
+        let repository = repository_with(None);
+
+        let policy = get_upload_policy_use_case(&repository, "privacy-policy")
+            .await
+            .unwrap();
+
+        assert_eq!(policy, UploadPolicy::default_for("privacy-policy"));
+        assert_eq!(policy.max_size, DEFAULT_MAX_DOCUMENT_SIZE);
+        assert!(policy.required_fields.is_empty());
+    }
+
+    #[tokio::test]
+    async fn get_upload_policy_returns_the_stored_policy() {
+        let repository = repository_with(Some(pdf_only_policy()));
+
+        let policy = get_upload_policy_use_case(&repository, "privacy-policy")
+            .await
+            .unwrap();
+
+        assert_eq!(policy, pdf_only_policy());
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_saves_deduplicated_content_types() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo
+            .expect_save_upload_policy()
+            .withf(|policy| policy.content_types == ["application/pdf", "text/html"])
+            .times(1)
+            .returning(Ok);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            content_types: vec![
+                "text/html".to_string(),
+                "application/pdf".to_string(),
+                "text/html".to_string(),
+            ],
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_saves_deduplicated_required_fields() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo
+            .expect_save_upload_policy()
+            .withf(|policy| policy.required_fields == ["info", "metadata"])
+            .times(1)
+            .returning(Ok);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            required_fields: vec![
+                "metadata".to_string(),
+                "info".to_string(),
+                "metadata".to_string(),
+            ],
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_unknown_required_fields() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            required_fields: vec!["info".to_string(), "author".to_string()],
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        match result {
+            Err(TermsOfUseError::Validation(detail)) => assert!(detail.contains("'author'")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_invalid_metadata_schema() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            metadata_schema: Some(json!({ "type": "no-such-type" })),
+            ..pdf_only_policy()
+        };
+
+        let mut schemas = MockSchemaService::new();
+        schemas
+            .expect_check_schema()
+            .with(eq(json!({ "type": "no-such-type" })))
+            .returning(|_| {
+                Err(TermsOfUseError::Validation(
+                    "The metadata schema is invalid".to_string(),
+                ))
+            });
+
+        let result = set_upload_policy_use_case(&repository, &schemas, policy).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_unsupported_content_types() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            content_types: vec!["application/zip".to_string()],
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        match result {
+            Err(TermsOfUseError::Validation(detail)) => assert!(detail.contains("application/zip")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_empty_content_types() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            content_types: vec![],
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_sizes_out_of_range() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        for max_size in [0, MAX_DOCUMENT_SIZE + 1] {
+            let policy = UploadPolicy {
+                max_size,
+                ..pdf_only_policy()
+            };
+
+            let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+            assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn set_upload_policy_rejects_unregistered_groups() {
+        let mut policy_repo = MockUploadPolicyRepository::new();
+        policy_repo.expect_save_upload_policy().times(0);
+        let repository = MockCombinedRepository { policy_repo };
+
+        let policy = UploadPolicy {
+            group: "unknown".to_string(),
+            ..pdf_only_policy()
+        };
+
+        let result = set_upload_policy_use_case(&repository, &valid_schemas(), policy).await;
+
+        match result {
+            Err(TermsOfUseError::Validation(detail)) => assert!(detail.contains("unknown")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_accepts_matching_documents() {
+        let repository = repository_with(Some(pdf_only_policy()));
+
+        let result = check_upload_policy_use_case(
+            &repository,
+            &valid_schemas(),
+            "privacy-policy",
+            "application/pdf",
+            1024,
+            Some("2026 update"),
+            &BTreeMap::new(),
+            &TermMetadata::new(),
+        )
+        .await;
+
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_rejects_other_content_types() {
+        let repository = repository_with(Some(pdf_only_policy()));
+
+        let result = check_upload_policy_use_case(
+            &repository,
+            &valid_schemas(),
+            "privacy-policy",
+            "text/html",
+            1024,
+            Some("2026 update"),
+            &BTreeMap::new(),
+            &TermMetadata::new(),
+        )
+        .await;
+
+        match result {
+            Err(TermsOfUseError::Validation(detail)) => assert!(detail.contains("application/pdf")),
+            other => panic!("expected a validation error, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_rejects_documents_over_the_limit() {
+        let repository = repository_with(Some(pdf_only_policy()));
+
+        let result = check_upload_policy_use_case(
+            &repository,
+            &valid_schemas(),
+            "privacy-policy",
+            "application/pdf",
+            2 * 1024 * 1024 + 1,
+            Some("2026 update"),
+            &BTreeMap::new(),
+            &TermMetadata::new(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_requires_info_when_configured() {
+        let repository = repository_with(Some(pdf_only_policy()));
+
+        for info in [None, Some("  ")] {
+            let result = check_upload_policy_use_case(
+                &repository,
+                &valid_schemas(),
+                "privacy-policy",
+                "application/pdf",
+                1024,
+                info,
+                &BTreeMap::new(),
+                &TermMetadata::new(),
+            )
+            .await;
+
+            assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_requires_every_configured_field() {
+        let repository = repository_with(Some(UploadPolicy {
+            required_fields: vec![
+                "changeSummaries".to_string(),
+                "info".to_string(),
+                "metadata".to_string(),
+            ],
+            ..pdf_only_policy()
+        }));
+        let change_summaries = BTreeMap::from([("en".to_string(), "Changed".to_string())]);
+        let metadata = json!({ "region": "eu" }).as_object().unwrap().clone();
+
+        let check = |change_summaries: BTreeMap<String, String>, metadata: TermMetadata| {
+            let repository = &repository;
+
+            async move {
+                check_upload_policy_use_case(
+                    repository,
+                    &valid_schemas(),
+                    "privacy-policy",
+                    "application/pdf",
+                    1024,
+                    Some("2026 update"),
+                    &change_summaries,
+                    &metadata,
+                )
+                .await
+            }
+        };
+
+        assert!(
+            check(change_summaries.clone(), metadata.clone())
+                .await
+                .is_ok()
+        );
+        assert!(matches!(
+            check(BTreeMap::new(), metadata).await,
+            Err(TermsOfUseError::Validation(detail)) if detail.contains("changeSummaries")
+        ));
+        assert!(matches!(
+            check(change_summaries, TermMetadata::new()).await,
+            Err(TermsOfUseError::Validation(detail)) if detail.contains("metadata")
+        ));
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_applies_the_default_policy() {
+        let repository = repository_with(None);
+
+        let markdown = check_upload_policy_use_case(
+            &repository,
+            &valid_schemas(),
+            "privacy-policy",
+            "text/markdown",
+            1024,
+            None,
+            &BTreeMap::new(),
+            &TermMetadata::new(),
+        )
+        .await;
+        let too_large = check_upload_policy_use_case(
+            &repository,
+            &valid_schemas(),
+            "privacy-policy",
+            "application/pdf",
+            DEFAULT_MAX_DOCUMENT_SIZE + 1,
+            None,
+            &BTreeMap::new(),
+            &TermMetadata::new(),
+        )
+        .await;
+
+        assert!(markdown.is_ok());
+        assert!(matches!(too_large, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn check_upload_policy_validates_metadata_against_the_schema() {
+        let repository = repository_with(Some(UploadPolicy {
+            metadata_schema: Some(json!({
+                "type": "object",
+                "properties": { "region": { "enum": ["eu", "us"] } },
+                "required": ["region"]
+            })),
+            ..pdf_only_policy()
+        }));
+
+        let mut schemas = MockSchemaService::new();
+        schemas
+            .expect_violations()
+            .returning(|_, instance| match instance.get("region") {
+                Some(region) if region == "eu" => Ok(vec![]),
+                Some(_) => Ok(vec!["/region: not one of \"eu\" or \"us\"".to_string()]),
+                None => Ok(vec!["\"region\" is a required property".to_string()]),
+            });
+
+        let check = |metadata: serde_json::Value| {
+            let metadata = metadata.as_object().unwrap().clone();
+            let repository = &repository;
+            let schemas = &schemas;
+
+            async move {
+                check_upload_policy_use_case(
+                    repository,
+                    schemas,
+                    "privacy-policy",
+                    "application/pdf",
+                    1024,
+                    Some("2026 update"),
+                    &BTreeMap::new(),
+                    &metadata,
+                )
+                .await
+            }
+        };
+
+        assert!(check(json!({ "region": "eu" })).await.is_ok());
+        assert!(matches!(
+            check(json!({ "region": "apac" })).await,
+            Err(TermsOfUseError::Validation(detail)) if detail.contains("/region")
+        ));
+        assert!(matches!(
+            check(json!({})).await,
+            Err(TermsOfUseError::Validation(_))
+        ));
+    }
+}
@@ -3,7 +3,12 @@ fn compile_protos() {
     let out_dir = std::path::PathBuf::from(std::env::var("OUT_DIR").unwrap());
     tonic_prost_build::configure()
         .file_descriptor_set_path(out_dir.join("terms_of_use_descriptor.bin"))
-        .compile_protos(&["../proto/service.proto"], &["../proto"])
+        // Keeps the URL-only variant of the latest term response small
+        .boxed(".terms_of_use.GetLatestTermsResponse.term_of_use_content.term")
+        .compile_protos(
+            &["../proto/service.proto", "../proto/v2/service.proto"],
+            &["../proto"],
+        )
         .unwrap();
 }
 
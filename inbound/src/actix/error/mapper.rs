@@ -16,6 +16,10 @@ impl From<TermsOfUseError> for ProblemDetails {
                 ProblemDetails::not_found().with_detail("The requested terms of use was not found.")
             }
 
+            TermsOfUseError::Validation(detail) => {
+                ProblemDetails::bad_request().with_detail(detail)
+            }
+
             TermsOfUseError::InternalServerError => ProblemDetails::internal_server_error()
                 .with_detail("An unexpected error occurred. Please try again later."),
         }
@@ -139,6 +143,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validation_error_mapping() {
+        let problem: ProblemDetails =
+            TermsOfUseError::Validation("The document hash does not match".to_string()).into();
+
+        assert_eq!(problem.status, 400);
+        assert_eq!(
+            problem.detail,
+            Some("The document hash does not match".to_string())
+        );
+    }
+
     // ResponseError trait tests
     #[test]
     fn test_problem_details_response_error() {
@@ -0,0 +1,96 @@
+use std::path::{Path, PathBuf};
+
+use actix_files::Files;
+use actix_web::{
+    http::header::CONTENT_SECURITY_POLICY,
+    middleware::DefaultHeaders,
+    web::{self, ServiceConfig},
+};
+
+/// Route under which documents from the filesystem storage adapter are served.
+const FILES_ROUTE: &str = "/files";
+
+pub fn configure(cfg: &mut ServiceConfig) {
+    let root = std::env::var("FILESYSTEM_STORAGE_PATH")
+        .map(PathBuf::from)
+        .expect("FILESYSTEM_STORAGE_PATH must be set in env vars");
+
+    configure_with_root(cfg, &root);
+}
+
+fn configure_with_root(cfg: &mut ServiceConfig, root: &Path) {
+    // Uploaded HTML documents are served as stored, so they must not run scripts on this origin
+    cfg.service(
+        web::scope(FILES_ROUTE)
+            .wrap(DefaultHeaders::new().add((CONTENT_SECURITY_POLICY, "sandbox")))
+            .service(Files::new("", root).use_etag(true)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{App, http::StatusCode, test};
+
+    use super::configure_with_root;
+
+    #[actix_web::test]
+    async fn serves_stored_documents() {
+        let root = std::env::temp_dir().join(format!("files-route-{}", unique_suffix()));
+        std::fs::create_dir_all(root.join("privacy-policy")).unwrap();
+        std::fs::write(root.join("privacy-policy/v1.pdf"), b"%PDF-1.4").unwrap();
+
+        let app =
+            test::init_service(App::new().configure(|cfg| configure_with_root(cfg, &root))).await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/files/privacy-policy/v1.pdf")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(
+            response.headers().get("content-type").unwrap(),
+            "application/pdf"
+        );
+        assert_eq!(
+            response.headers().get("content-security-policy").unwrap(),
+            "sandbox"
+        );
+
+        let body = test::read_body(response).await;
+        assert_eq!(body.as_ref(), b"%PDF-1.4");
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    #[actix_web::test]
+    async fn returns_not_found_for_missing_documents() {
+        let root = std::env::temp_dir().join(format!("files-route-{}", unique_suffix()));
+        std::fs::create_dir_all(&root).unwrap();
+
+        let app =
+            test::init_service(App::new().configure(|cfg| configure_with_root(cfg, &root))).await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/files/missing.pdf")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        std::fs::remove_dir_all(&root).ok();
+    }
+
+    fn unique_suffix() -> u128 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos()
+    }
+}
@@ -53,6 +53,9 @@ mod tests {
             cache: Arc::new(cache),
             storage: Arc::new(storage),
             publisher: Arc::new(publisher),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
         }
     }
 
@@ -91,6 +94,7 @@ mod tests {
         assert!(payload["services"]["cache"].is_boolean());
         assert!(payload["services"]["storage"].is_boolean());
         assert!(payload["services"]["publisher"].is_boolean());
+        assert!(payload["services"]["scanner"].is_boolean());
     }
 
     #[actix_web::test]
@@ -4,16 +4,29 @@ use actix_web::{
     middleware::{Compress, Logger},
     web::{Data, JsonConfig, QueryConfig},
 };
+use domain::entities::MAX_DOCUMENT_SIZE;
 use opentelemetry_instrumentation_actix_web::{RequestMetrics, RequestTracing};
 
 use crate::{
-    actix::error::{json_error_handler, multipart_error_handler, query_error_handler},
+    actix::{
+        error::{json_error_handler, multipart_error_handler, query_error_handler},
+        v1::upload_store::UploadStore,
+    },
     config::Config,
 };
 
 mod error;
+#[cfg(feature = "filesystem")]
+mod files;
 mod healthcheck;
 mod v1;
+mod v2;
+
+#[cfg(feature = "filesystem")]
+use files::configure as configure_files;
+
+#[cfg(not(feature = "filesystem"))]
+fn configure_files(_: &mut actix_web::web::ServiceConfig) {}
 
 pub async fn start_actix_server(config: Config) -> std::io::Result<()> {
     let host = std::env::var("HOST").unwrap_or_else(|_| "127.0.0.1".to_string());
@@ -22,6 +35,9 @@ pub async fn start_actix_server(config: Config) -> std::io::Result<()> {
         .parse::<u16>()
         .expect("PORT must be a valid u16 number");
 
+    // Shared by all workers so uploads are locked across them
+    let uploads = Data::new(UploadStore::from_env());
+
     HttpServer::new(move || {
         App::new()
             .wrap(Logger::default())
@@ -30,10 +46,20 @@ pub async fn start_actix_server(config: Config) -> std::io::Result<()> {
             .wrap(RequestMetrics::default())
             .app_data(JsonConfig::default().error_handler(json_error_handler))
             .app_data(QueryConfig::default().error_handler(query_error_handler))
-            .app_data(MultipartFormConfig::default().error_handler(multipart_error_handler))
+            .app_data(
+                // Group policies decide the actual limit, this only bounds the whole form
+                MultipartFormConfig::default()
+                    .total_limit(MAX_DOCUMENT_SIZE as usize + 1024 * 1024)
+                    .error_handler(multipart_error_handler),
+            )
             .app_data(Data::new(config.clone()))
+            .app_data(uploads.clone())
             .configure(healthcheck::configure)
             .configure(v1::controller::configure)
+            .configure(v1::groups::configure)
+            .configure(v1::bundles::configure)
+            .configure(v2::controller::configure)
+            .configure(configure_files)
     })
     .bind((host.as_str(), port))?
     .run()
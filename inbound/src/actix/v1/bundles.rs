@@ -0,0 +1,344 @@
+use actix_web::{
+    HttpResponse, delete, get, post, put,
+    web::{self, Path},
+};
+use domain::use_cases::{
+    create_bundle_agreement_use_case, delete_bundle_use_case, get_bundle_use_case,
+    get_pending_bundle_groups_use_case, list_bundles_use_case, save_bundle_use_case,
+};
+
+use crate::{
+    actix::{
+        error::response::ProblemDetails,
+        v1::{
+            payload::{BundlePayload, CreateBundleAgreementPayload},
+            response::{BundleConsentResponse, BundleResponse},
+        },
+    },
+    config::Config,
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/v1/bundles")
+            .service(list_bundles)
+            .service(get_bundle)
+            .service(save_bundle)
+            .service(delete_bundle)
+            .service(create_bundle_agreement)
+            .service(has_user_consented_to_bundle),
+    );
+}
+
+#[tracing::instrument(skip(config))]
+#[get("")]
+async fn list_bundles(config: web::Data<Config>) -> Result<HttpResponse, ProblemDetails> {
+    let bundles = list_bundles_use_case(config.repository.as_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(
+        bundles
+            .into_iter()
+            .map(BundleResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[tracing::instrument(skip(config))]
+#[get("/{name}")]
+async fn get_bundle(
+    name: Path<String>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let bundle = get_bundle_use_case(config.repository.as_ref(), &name).await?;
+
+    Ok(HttpResponse::Ok().json(BundleResponse::from(bundle)))
+}
+
+#[tracing::instrument(skip(config, body))]
+#[put("/{name}")]
+async fn save_bundle(
+    name: Path<String>,
+    config: web::Data<Config>,
+    body: web::Json<BundlePayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let bundle = save_bundle_use_case(
+        config.repository.as_ref(),
+        body.into_inner().into_bundle(name.into_inner()),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(BundleResponse::from(bundle)))
+}
+
+#[tracing::instrument(skip(config))]
+#[delete("/{name}")]
+async fn delete_bundle(
+    name: Path<String>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    delete_bundle_use_case(config.repository.as_ref(), &name).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[tracing::instrument(skip(config, body))]
+#[post("/{name}/agreements")]
+async fn create_bundle_agreement(
+    name: Path<String>,
+    config: web::Data<Config>,
+    body: web::Json<CreateBundleAgreementPayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    create_bundle_agreement_use_case(
+        config.repository.as_ref(),
+        config.cache.as_ref(),
+        config.publisher.as_ref(),
+        body.user_id,
+        &name,
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().finish())
+}
+
+#[tracing::instrument(skip(config, path))]
+#[get("/{name}/has-consent/{user_id}")]
+async fn has_user_consented_to_bundle(
+    path: Path<(String, i32)>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let (name, user_id) = path.into_inner();
+
+    let pending_groups = get_pending_bundle_groups_use_case(
+        config.repository.as_ref(),
+        config.cache.as_ref(),
+        user_id,
+        &name,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(BundleConsentResponse {
+        has_consented: pending_groups.is_empty(),
+        pending_groups,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{App, http::StatusCode, test, web};
+    use chrono::Utc;
+    use domain::entities::{Bundle, TermOfUse};
+    use mockall::predicate::eq;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+
+    use crate::{Config, actix::v1::bundles::configure, mocks::*};
+
+    fn build_config(
+        repository: MockDatabaseRepository,
+        cache: MockCacheService,
+        publisher: MockPublisherService,
+    ) -> Config {
+        Config {
+            repository: Arc::new(repository),
+            cache: Arc::new(cache),
+            storage: Arc::new(MockStorageService::new()),
+            publisher: Arc::new(publisher),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
+        }
+    }
+
+    fn sign_up() -> Bundle {
+        Bundle {
+            name: "sign-up".to_string(),
+            description: None,
+            groups: vec!["terms-of-service".to_string(), "privacy-policy".to_string()],
+        }
+    }
+
+    fn latest_term(group: &str) -> TermOfUse {
+        TermOfUse {
+            id: if group == "terms-of-service" { 1 } else { 2 },
+            group: group.to_string(),
+            url: format!("{group}/v1.pdf"),
+            version: 1,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    fn repository_with_sign_up() -> MockDatabaseRepository {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_bundle()
+            .with(eq("sign-up"))
+            .returning(|_| Ok(Some(sign_up())));
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(latest_term(group))));
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+    }
+
+    #[actix_web::test]
+    async fn save_bundle_stores_registered_groups() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_save_bundle()
+            .with(eq(sign_up()))
+            .times(1)
+            .returning(Ok);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri("/v1/bundles/sign-up")
+                .set_json(json!({ "groups": ["terms-of-service", "privacy-policy"] }))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["name"], "sign-up");
+        assert_eq!(body["groups"][1], "privacy-policy");
+    }
+
+    #[actix_web::test]
+    async fn create_bundle_agreement_records_every_group() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![]));
+        repository
+            .expect_create_user_agreements()
+            .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 2])
+            .times(1)
+            .returning(|_, _| Ok(()));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_store_user_agreement()
+            .times(2)
+            .returning(|_, _, _, _| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher
+            .expect_publish_agreement()
+            .times(2)
+            .returning(|_| Ok(()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository, cache, publisher)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/bundles/sign-up/agreements")
+                .set_json(json!({ "userId": 42 }))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn has_consent_lists_pending_groups() {
+        let mut repository = repository_with_sign_up();
+        repository
+            .expect_get_agreed_at()
+            .returning(|_, term_id| Ok((term_id == 1).then(|| Utc::now().naive_utc())));
+        repository
+            .expect_get_terms_for_group()
+            .returning(|group, _| Ok(vec![latest_term(group)]));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .returning(|_, _, _, _| Ok(()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    cache,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/bundles/sign-up/has-consent/42")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["hasConsented"], false);
+        assert_eq!(body["pendingGroups"], json!(["privacy-policy"]));
+    }
+
+    #[actix_web::test]
+    async fn get_bundle_returns_not_found_for_unknown_bundles() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_get_bundle().returning(|_| Ok(None));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/bundles/sign-up")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
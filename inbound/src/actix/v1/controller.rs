@@ -1,19 +1,34 @@
+use std::time::Duration;
+
 use actix_multipart::form::MultipartForm;
 use actix_web::{
-    HttpResponse, get, post,
+    HttpResponse, get, post, put,
     web::{self, Path},
 };
 use domain::use_cases::{
-    create_term_of_use_use_case, create_user_agreement_use_case, get_latest_term_use_case,
-    has_user_agreed_to_term_use_case,
+    create_term_of_use_use_case, create_user_agreement_use_case, diff_terms_use_case,
+    finalize_term_of_use_use_case, get_consent_at_use_case, get_latest_term_use_case,
+    get_pending_terms_use_case, get_term_history_use_case, get_upload_policy_use_case,
+    has_user_accepted_clause_use_case, has_user_agreed_to_term_use_case,
+    has_user_agreed_to_version_use_case, parse_point_in_time, reserve_term_of_use_use_case,
+    set_upload_policy_use_case,
 };
 
 use crate::{
     actix::{
         error::response::ProblemDetails,
         v1::{
-            payload::{CreateAgreementPayload, CreateTermForm, GetLatestTermPayload},
-            response::{HasConsentedResponse, TermOfUseResponse, TermOfUseUrlResponse},
+            payload::{
+                ConsentAtPayload, CreateAgreementPayload, CreateTermForm, GetLatestTermPayload,
+                HasConsentPayload, ReserveTermPayload, TermDiffPayload, TermHistoryPayload,
+                UploadPolicyPayload,
+            },
+            response::{
+                ConsentAtResponse, HasConsentedResponse, PendingTermResponse, TermDiffResponse,
+                TermHistoryResponse, TermOfUseResponse, TermOfUseUrlResponse,
+                TermReservationResponse, UploadPolicyResponse,
+            },
+            resumable,
         },
     },
     config::Config,
@@ -23,40 +38,86 @@ pub fn configure(cfg: &mut web::ServiceConfig) {
     cfg.service(
         web::scope("/v1/terms-of-use")
             .service(has_user_consented_to_latest_term)
+            .service(has_user_accepted_clause)
             .service(create_agreement)
             .service(create_term_of_use)
+            .service(reserve_term_of_use)
+            .service(finalize_term_of_use)
+            .configure(resumable::configure)
+            .service(get_term_diff)
+            .service(get_term_history)
+            .service(get_consent_at)
+            .service(get_upload_policy)
+            .service(set_upload_policy)
+            .service(get_pending_terms)
             .service(get_latest_term_for_group),
     );
 }
 
-#[tracing::instrument(skip(config, group))]
+#[tracing::instrument(skip(config, group, payload))]
 #[get("/has-consent/{group}/{user_id}")]
 async fn has_user_consented_to_latest_term(
     group: Path<(String, i32)>,
+    payload: web::Query<HasConsentPayload>,
     config: web::Data<Config>,
 ) -> Result<HttpResponse, ProblemDetails> {
     let (group, user_id) = group.into_inner();
 
-    let term = has_user_agreed_to_term_use_case(
-        config.repository.as_ref(),
-        config.cache.as_ref(),
-        user_id,
-        &group,
-    )
-    .await?;
+    let term = match payload.min_version {
+        Some(min_version) => {
+            has_user_agreed_to_version_use_case(
+                config.repository.as_ref(),
+                config.cache.as_ref(),
+                user_id,
+                &group,
+                min_version,
+            )
+            .await?
+        }
+        None => {
+            has_user_agreed_to_term_use_case(
+                config.repository.as_ref(),
+                config.cache.as_ref(),
+                user_id,
+                &group,
+            )
+            .await?
+        }
+    };
 
     Ok(HttpResponse::Ok().json(HasConsentedResponse {
         has_consented: term,
     }))
 }
 
+#[tracing::instrument(skip(config, path))]
+#[get("/has-consent/{group}/{user_id}/clauses/{clause}")]
+async fn has_user_accepted_clause(
+    path: Path<(String, i32, String)>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let (group, user_id, clause) = path.into_inner();
+
+    let accepted =
+        has_user_accepted_clause_use_case(config.repository.as_ref(), user_id, &group, &clause)
+            .await?;
+
+    Ok(HttpResponse::Ok().json(HasConsentedResponse {
+        has_consented: accepted,
+    }))
+}
+
 #[tracing::instrument(skip(config, body))]
 #[post("/agreements")]
 async fn create_agreement(
     config: web::Data<Config>,
     body: web::Json<CreateAgreementPayload>,
 ) -> Result<HttpResponse, ProblemDetails> {
-    let CreateAgreementPayload { user_id, term_id } = body.into_inner();
+    let CreateAgreementPayload {
+        user_id,
+        term_id,
+        accepted_clauses,
+    } = body.into_inner();
 
     create_user_agreement_use_case(
         config.repository.as_ref(),
@@ -64,6 +125,7 @@ async fn create_agreement(
         config.publisher.as_ref(),
         user_id,
         term_id,
+        &accepted_clauses,
     )
     .await?;
 
@@ -83,16 +145,13 @@ async fn create_term_of_use(
         .map(|ct| ct.to_string())
         .unwrap_or_default();
 
-    if content_type.is_empty() || content_type != "application/pdf" {
-        return Err(
-            ProblemDetails::bad_request().with_detail("Term of use file must be a valid PDF")
-        );
-    }
-
     create_term_of_use_use_case(
         config.repository.as_ref(),
         config.storage.as_ref(),
         config.cache.as_ref(),
+        config.scanner.as_ref(),
+        config.documents.as_ref(),
+        config.schemas.as_ref(),
         data.into_inner().into(),
         file.file.path(),
         &content_type,
@@ -102,6 +161,165 @@ async fn create_term_of_use(
     Ok(HttpResponse::Created().finish())
 }
 
+/// Lifetime of presigned upload URLs, read from `DIRECT_UPLOAD_URL_TTL_SECONDS`.
+fn upload_url_ttl() -> Duration {
+    let seconds = std::env::var("DIRECT_UPLOAD_URL_TTL_SECONDS")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(900);
+
+    Duration::from_secs(seconds)
+}
+
+#[tracing::instrument(skip(config, body))]
+#[post("/uploads")]
+async fn reserve_term_of_use(
+    config: web::Data<Config>,
+    body: web::Json<ReserveTermPayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let reservation = reserve_term_of_use_use_case(
+        config.repository.as_ref(),
+        config.storage.as_ref(),
+        config.documents.as_ref(),
+        config.schemas.as_ref(),
+        body.into_inner().into(),
+        upload_url_ttl(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(TermReservationResponse::from(reservation)))
+}
+
+#[tracing::instrument(skip(config))]
+#[post("/uploads/{reservation_id}/finalize")]
+async fn finalize_term_of_use(
+    reservation_id: Path<i32>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let term = finalize_term_of_use_use_case(
+        config.repository.as_ref(),
+        config.storage.as_ref(),
+        config.cache.as_ref(),
+        config.scanner.as_ref(),
+        config.documents.as_ref(),
+        reservation_id.into_inner(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(TermOfUseResponse::from(term)))
+}
+
+#[tracing::instrument(skip(config, group))]
+#[get("/{group}/diff")]
+async fn get_term_diff(
+    group: Path<String>,
+    payload: web::Query<TermDiffPayload>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let diff = diff_terms_use_case(
+        config.repository.as_ref(),
+        config.documents.as_ref(),
+        &group,
+        payload.from,
+        payload.to,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(TermDiffResponse::from(diff)))
+}
+
+#[tracing::instrument(skip(config, group))]
+#[get("/{group}/versions")]
+async fn get_term_history(
+    group: Path<String>,
+    payload: web::Query<TermHistoryPayload>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let terms = get_term_history_use_case(
+        config.repository.as_ref(),
+        config.storage.as_ref(),
+        &group,
+        &payload.into_inner().metadata_filter(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(TermHistoryResponse {
+        group: group.into_inner(),
+        versions: terms.into_iter().map(Into::into).collect(),
+    }))
+}
+
+#[tracing::instrument(skip(config, path, payload))]
+#[get("/{group}/consents/{user_id}")]
+async fn get_consent_at(
+    path: Path<(String, i32)>,
+    payload: web::Query<ConsentAtPayload>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let (group, user_id) = path.into_inner();
+
+    let consent = get_consent_at_use_case(
+        config.repository.as_ref(),
+        config.storage.as_ref(),
+        user_id,
+        &group,
+        parse_point_in_time(&payload.at)?,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(ConsentAtResponse::from(consent)))
+}
+
+#[tracing::instrument(skip(config, group))]
+#[get("/{group}/upload-policy")]
+async fn get_upload_policy(
+    group: Path<String>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let policy = get_upload_policy_use_case(config.repository.as_ref(), &group).await?;
+
+    Ok(HttpResponse::Ok().json(UploadPolicyResponse::from(policy)))
+}
+
+#[tracing::instrument(skip(config, group, body))]
+#[put("/{group}/upload-policy")]
+async fn set_upload_policy(
+    group: Path<String>,
+    config: web::Data<Config>,
+    body: web::Json<UploadPolicyPayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let policy = set_upload_policy_use_case(
+        config.repository.as_ref(),
+        config.schemas.as_ref(),
+        body.into_inner().into_policy(group.into_inner()),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(UploadPolicyResponse::from(policy)))
+}
+
+#[tracing::instrument(skip(config, user_id))]
+#[get("/pending/{user_id}")]
+async fn get_pending_terms(
+    user_id: Path<i32>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let terms = get_pending_terms_use_case(
+        config.repository.as_ref(),
+        config.cache.as_ref(),
+        config.storage.as_ref(),
+        user_id.into_inner(),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(
+        terms
+            .into_iter()
+            .map(PendingTermResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
 #[tracing::instrument(skip(config, group, payload))]
 #[get("/{group}")]
 async fn get_latest_term_for_group(
@@ -109,7 +327,7 @@ async fn get_latest_term_for_group(
     payload: web::Query<GetLatestTermPayload>,
     config: web::Data<Config>,
 ) -> Result<HttpResponse, ProblemDetails> {
-    let term = get_latest_term_use_case(
+    let mut term = get_latest_term_use_case(
         config.repository.as_ref(),
         config.cache.as_ref(),
         config.storage.as_ref(),
@@ -117,6 +335,10 @@ async fn get_latest_term_for_group(
     )
     .await?;
 
+    if !payload.include_html {
+        term.html = None;
+    }
+
     if payload.only_url {
         return Ok(HttpResponse::Ok().json(TermOfUseUrlResponse::from(term)));
     }
@@ -128,14 +350,23 @@ async fn get_latest_term_for_group(
 mod tests {
     use actix_web::{App, http::StatusCode, test, web};
     use chrono::Utc;
-    use domain::entities::TermOfUse;
-    use mockall::predicate::eq;
+    use domain::entities::{
+        ChangeSummary, Clause, ConsentStatus, DEFAULT_MAX_DOCUMENT_SIZE, Group, PresignedUpload,
+        ScanVerdict, StoredFileInfo, TermDocument, TermMetadata, TermOfUse, TermReservation,
+        UploadPolicy, UserAgreement,
+    };
+    use domain::errors::TermsOfUseError;
+    use domain::use_cases::parse_point_in_time;
+    use mockall::predicate::{always, eq};
     use serde_json::Value;
     use std::sync::Arc;
 
     use crate::{
         Config,
-        actix::v1::{controller::configure, payload::CreateAgreementPayload},
+        actix::v1::{
+            controller::configure,
+            payload::{CreateAgreementPayload, ReserveTermPayload, UploadPolicyPayload},
+        },
         mocks::*,
     };
 
@@ -150,9 +381,42 @@ mod tests {
             cache: Arc::new(cache),
             storage: Arc::new(storage),
             publisher: Arc::new(publisher),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
         }
     }
 
+    /// Repository of registered groups that have no upload policy of their own.
+    fn repository_without_policy() -> MockDatabaseRepository {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_get_upload_policy()
+            .returning(|_| Ok(None));
+        repository
+    }
+
+    const SAMPLE_PDF: &[u8] = include_bytes!("../../../../example/sample.pdf");
+
+    /// Multipart body uploading a PDF document with the given `data` part.
+    fn pdf_upload(boundary: &str, pdf: &[u8], data: &str) -> Vec<u8> {
+        [
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"terms.pdf\"\r\nContent-Type: application/pdf\r\n\r\n"
+            )
+            .as_bytes(),
+            pdf,
+            format!(
+                "\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\nContent-Type: application/json\r\n\r\n{data}\r\n--{boundary}--\r\n"
+            )
+            .as_bytes(),
+        ]
+        .concat()
+    }
+
     fn sample_term(group: &str) -> TermOfUse {
         TermOfUse {
             id: 1,
@@ -161,6 +425,12 @@ mod tests {
             version: 1,
             info: Some("info".to_string()),
             created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         }
     }
 
@@ -168,13 +438,13 @@ mod tests {
     async fn has_user_consented_reads_cache_first() {
         let mut repository = MockDatabaseRepository::new();
         repository.expect_get_latest_term_for_group().times(0);
-        repository.expect_has_user_agreed_to_term().times(0);
+        repository.expect_get_agreed_at().times(0);
 
         let mut cache = MockCacheService::new();
         cache
             .expect_find_user_agreement()
             .with(eq(7), eq("alpha"))
-            .returning(|_, _| Ok(Some(true)));
+            .returning(|_, _| Ok(Some(ConsentStatus::ConsentedToLatest)));
 
         let app = test::init_service(
             App::new()
@@ -203,6 +473,99 @@ mod tests {
         assert_eq!(payload["hasConsented"], true);
     }
 
+    #[actix_web::test]
+    async fn has_user_consented_accepts_versions_from_the_minimum() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_newest_agreed_version()
+            .with(eq(7), eq("alpha"), eq(2))
+            .times(1)
+            .returning(|_, _, _| Ok(None));
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|group| Ok(Some(sample_term(group))));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_agreed_version()
+            .with(eq(7), eq("alpha"))
+            .returning(|_, _| Ok(Some(1)));
+        cache.expect_find_user_agreement().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    cache,
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/has-consent/alpha/7?min_version=2")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["hasConsented"], false);
+    }
+
+    #[actix_web::test]
+    async fn has_user_accepted_clause_checks_optional_clauses() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .with(eq("alpha"))
+            .returning(|group| {
+                Ok(Some(TermOfUse {
+                    clauses: vec![Clause {
+                        key: "marketing-emails".to_string(),
+                        title: "Marketing emails".to_string(),
+                        mandatory: false,
+                    }],
+                    ..sample_term(group)
+                }))
+            });
+        repository
+            .expect_get_accepted_clauses()
+            .with(eq(7), eq(1))
+            .returning(|_, _| Ok(Some(vec!["marketing-emails".to_string()])));
+        repository.expect_get_group().returning(|_| Ok(None));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/has-consent/alpha/7/clauses/marketing-emails")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["hasConsented"], true);
+    }
+
     #[actix_web::test]
     async fn create_agreement_publishes_and_caches() {
         let mut repository = MockDatabaseRepository::new();
@@ -210,16 +573,33 @@ mod tests {
             .expect_get_term_by_id()
             .with(eq(3))
             .returning(|_| Ok(Some(sample_term("legal"))));
+        repository
+            .expect_get_latest_term_for_group()
+            .with(eq("legal"))
+            .returning(|_| Ok(Some(sample_term("legal"))));
         repository
             .expect_create_user_agreement()
-            .with(eq(42), eq(3))
-            .returning(|_, _| Ok(()));
+            .with(eq(42), eq(3), always())
+            .returning(|_, _, _| Ok(()));
+        repository
+            .expect_get_agreed_at()
+            .with(eq(42), eq(sample_term("legal").id))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+        repository
+            .expect_get_group()
+            .with(eq("legal"))
+            .returning(|name| Ok(Some(registered_group(name))));
 
         let mut cache = MockCacheService::new();
         cache
             .expect_store_user_agreement()
-            .with(eq(42), eq("legal"), eq(true))
-            .returning(|_, _, _| Ok(()));
+            .with(
+                eq(42),
+                eq("legal"),
+                eq(ConsentStatus::ConsentedToLatest),
+                eq(None),
+            )
+            .returning(|_, _, _, _| Ok(()));
 
         let mut publisher = MockPublisherService::new();
         publisher.expect_publish_agreement().returning(|_| Ok(()));
@@ -243,6 +623,7 @@ mod tests {
                 .set_json(&CreateAgreementPayload {
                     user_id: 42,
                     term_id: 3,
+                    accepted_clauses: vec![],
                 })
                 .to_request(),
         )
@@ -253,15 +634,22 @@ mod tests {
 
     #[actix_web::test]
     async fn create_term_of_use_accepts_pdf_upload() {
-        let mut repository = MockDatabaseRepository::new();
+        let mut repository = repository_without_policy();
         repository
             .expect_get_latest_term_for_group()
             .with(eq("legal"))
             .returning(|_| Ok(None));
-        repository.expect_create_term().returning(|mut term| {
-            term.id = 10;
-            Ok(term)
-        });
+        repository
+            .expect_create_term()
+            .withf(|term| {
+                term.pdf_metadata
+                    .as_ref()
+                    .is_some_and(|metadata| metadata.page_count == 1)
+            })
+            .returning(|mut term| {
+                term.id = 10;
+                Ok(term)
+            });
 
         let mut cache = MockCacheService::new();
         cache
@@ -272,8 +660,14 @@ mod tests {
         let mut storage = MockStorageService::new();
         storage
             .expect_upload_file()
-            .withf(|_, content_type| content_type == "application/pdf")
-            .returning(|_, _| Ok("stored/path.pdf".to_string()));
+            .withf(|_, content_type, group, version| {
+                content_type == "application/pdf" && group == "legal" && *version == 1
+            })
+            .returning(|_, _, _, _| Ok("stored/path.pdf".to_string()));
+        storage
+            .expect_publish_file()
+            .with(eq("stored/path.pdf"), eq(10))
+            .returning(|_, _| Ok(()));
         storage
             .expect_get_file_url()
             .with(eq("stored/path.pdf"))
@@ -292,9 +686,7 @@ mod tests {
         .await;
 
         let boundary = "boundary123";
-        let payload = format!(
-            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"example/sample.pdf\"\r\nContent-Type: application/pdf\r\n\r\nPDF\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\nContent-Type: application/json\r\n\r\n{{\"group\":\"legal\",\"info\":\"v1\"}}\r\n--{boundary}--\r\n"
-        );
+        let payload = pdf_upload(boundary, SAMPLE_PDF, r#"{"group":"legal","info":"v1"}"#);
 
         let response = test::call_service(
             &app,
@@ -313,22 +705,65 @@ mod tests {
     }
 
     #[actix_web::test]
-    async fn create_term_of_use_rejects_non_pdf() {
+    async fn create_term_of_use_stores_markdown_document() {
+        let mut repository = repository_without_policy();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        repository
+            .expect_create_term()
+            .withf(|term| {
+                term.html.as_deref() == Some("<h1>Terms</h1>")
+                    && term.text.as_deref() == Some("Terms")
+                    && term.pdf_metadata.is_none()
+            })
+            .times(1)
+            .returning(|mut term| {
+                term.id = 11;
+                Ok(term)
+            });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .withf(|_, content_type, _, _| content_type == "text/markdown")
+            .returning(|_, _, _, _| Ok("legal/v1.md".to_string()));
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://files/legal/v1.md".to_string()));
+
+        let mut documents = MockDocumentService::new();
+        documents
+            .expect_read_document()
+            .withf(|_, content_type| content_type == "text/markdown")
+            .times(1)
+            .returning(|_, _| {
+                Ok(TermDocument {
+                    html: Some("<h1>Terms</h1>".to_string()),
+                    text: Some("Terms".to_string()),
+                    pdf_metadata: None,
+                })
+            });
+
+        let mut config = build_config(repository, cache, storage, MockPublisherService::new());
+        config.documents = Arc::new(documents);
+
         let app = test::init_service(
             App::new()
-                .app_data(web::Data::new(build_config(
-                    MockDatabaseRepository::new(),
-                    MockCacheService::new(),
-                    MockStorageService::new(),
-                    MockPublisherService::new(),
-                )))
+                .app_data(web::Data::new(config))
                 .configure(configure),
         )
         .await;
 
-        let boundary = "boundary456";
+        let boundary = "boundary789";
         let payload = format!(
-            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"terms.txt\"\r\nContent-Type: text/plain\r\n\r\nnot pdf\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\nContent-Type: application/json\r\n\r\n{{\"group\":\"legal\"}}\r\n--{boundary}--\r\n"
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"terms.md\"\r\nContent-Type: text/markdown\r\n\r\n# Terms\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\nContent-Type: application/json\r\n\r\n{{\"group\":\"legal\"}}\r\n--{boundary}--\r\n"
         );
 
         let response = test::call_service(
@@ -344,41 +779,173 @@ mod tests {
         )
         .await;
 
-        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
-
-        let body = test::read_body(response).await;
-        let problem: Value = serde_json::from_slice(&body).unwrap();
-        assert_eq!(problem["detail"], "Term of use file must be a valid PDF");
+        assert_eq!(response.status(), StatusCode::CREATED);
     }
 
     #[actix_web::test]
-    async fn get_latest_term_returns_full_payload() {
-        let term = sample_term("finance");
+    async fn create_term_of_use_rejects_unreadable_document() {
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
 
-        let mut repository = MockDatabaseRepository::new();
-        repository.expect_get_latest_term_for_group().times(0);
+        let mut documents = MockDocumentService::new();
+        documents.expect_read_document().returning(|_, _| {
+            Err(TermsOfUseError::Validation(
+                "The PDF document could not be read".to_string(),
+            ))
+        });
 
-        let mut cache = MockCacheService::new();
-        cache
-            .expect_get_latest_term_for_group()
-            .with(eq("finance"))
-            .returning(move |_| Ok(Some(term.clone())));
+        let mut config = build_config(
+            repository_without_policy(),
+            MockCacheService::new(),
+            storage,
+            MockPublisherService::new(),
+        );
+        config.documents = Arc::new(documents);
 
         let app = test::init_service(
             App::new()
-                .app_data(web::Data::new(build_config(
-                    repository,
-                    cache,
-                    MockStorageService::new(),
-                    MockPublisherService::new(),
-                )))
+                .app_data(web::Data::new(config))
                 .configure(configure),
         )
         .await;
 
+        let boundary = "boundary654";
+        let payload = pdf_upload(boundary, b"%PDF-1.4\nbroken", r#"{"group":"legal"}"#);
+
         let response = test::call_service(
             &app,
-            test::TestRequest::get()
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/")
+                .insert_header((
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                ))
+                .set_payload(payload)
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn create_term_of_use_rejects_infected_document() {
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_scan_file()
+            .times(1)
+            .returning(|_| Ok(ScanVerdict::Infected("Eicar-Signature".to_string())));
+
+        let mut config = build_config(
+            repository_without_policy(),
+            MockCacheService::new(),
+            storage,
+            MockPublisherService::new(),
+        );
+        config.scanner = Arc::new(scanner);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(config))
+                .configure(configure),
+        )
+        .await;
+
+        let boundary = "boundary655";
+        let payload = pdf_upload(boundary, SAMPLE_PDF, r#"{"group":"legal"}"#);
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/")
+                .insert_header((
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                ))
+                .set_payload(payload)
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(response).await;
+        assert!(body["detail"].as_str().unwrap().contains("Eicar-Signature"));
+    }
+
+    #[actix_web::test]
+    async fn create_term_of_use_rejects_non_pdf() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository_without_policy(),
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let boundary = "boundary456";
+        let payload = format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"file\"; filename=\"terms.txt\"\r\nContent-Type: text/plain\r\n\r\nnot pdf\r\n--{boundary}\r\nContent-Disposition: form-data; name=\"data\"\r\nContent-Type: application/json\r\n\r\n{{\"group\":\"legal\"}}\r\n--{boundary}--\r\n"
+        );
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/")
+                .insert_header((
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                ))
+                .set_payload(payload)
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = test::read_body(response).await;
+        let problem: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem["detail"],
+            "Documents of group 'legal' must be one of: application/pdf, text/markdown, text/html"
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_latest_term_returns_full_payload() {
+        let term = sample_term("finance");
+
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_get_latest_term_for_group().times(0);
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .with(eq("finance"))
+            .returning(move |_| Ok(Some(term.clone())));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    cache,
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
                 .uri("/v1/terms-of-use/finance")
                 .to_request(),
         )
@@ -426,4 +993,949 @@ mod tests {
         let payload: Value = serde_json::from_slice(&body).unwrap();
         assert_eq!(payload["url"], "stored/path.pdf");
     }
+
+    #[actix_web::test]
+    async fn get_latest_term_includes_html_on_request() {
+        let term = TermOfUse {
+            html: Some("<h1>Terms</h1>".to_string()),
+            ..sample_term("legal")
+        };
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .with(eq("legal"))
+            .returning(move |_| Ok(Some(term.clone())));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    MockDatabaseRepository::new(),
+                    cache,
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal")
+                .to_request(),
+        )
+        .await;
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert!(payload.get("html").is_none());
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal?include_html=true")
+                .to_request(),
+        )
+        .await;
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["html"], "<h1>Terms</h1>");
+    }
+
+    #[actix_web::test]
+    async fn get_pending_terms_lists_unaccepted_mandatory_terms() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_get_groups().returning(|| {
+            Ok(vec![
+                registered_group("privacy"),
+                Group {
+                    mandatory: true,
+                    ..registered_group("finance")
+                },
+            ])
+        });
+        repository
+            .expect_get_latest_terms_for_groups()
+            .withf(|groups| groups == ["finance".to_string()])
+            .returning(|_| Ok(vec![sample_term("finance")]));
+        repository
+            .expect_get_agreed_term_ids()
+            .withf(|user_id, term_ids| *user_id == 7 && term_ids == [1])
+            .returning(|_, _| Ok(vec![]));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        cache
+            .expect_store_latest_term_for_group()
+            .returning(|_| Ok(()));
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache.expect_store_user_agreement().times(0);
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|path| Ok(format!("https://cdn.example.com/{path}")));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    cache,
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/pending/7")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.as_array().unwrap().len(), 1);
+        assert_eq!(payload[0]["group"], "finance");
+        assert_eq!(payload[0]["termId"], 1);
+        assert_eq!(payload[0]["version"], 1);
+        assert_eq!(payload[0]["url"], "https://cdn.example.com/stored/path.pdf");
+    }
+
+    #[actix_web::test]
+    async fn get_term_diff_returns_changed_lines() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_term_by_version()
+            .with(eq("legal"), eq(1))
+            .returning(|_, _| {
+                Ok(Some(TermOfUse {
+                    text: Some("Intro\nOld clause".to_string()),
+                    ..sample_term("legal")
+                }))
+            });
+        repository
+            .expect_get_term_by_version()
+            .with(eq("legal"), eq(2))
+            .returning(|_, _| {
+                Ok(Some(TermOfUse {
+                    version: 2,
+                    text: Some("Intro\nNew clause".to_string()),
+                    ..sample_term("legal")
+                }))
+            });
+
+        let mut config = build_config(
+            repository,
+            MockCacheService::new(),
+            MockStorageService::new(),
+            MockPublisherService::new(),
+        );
+        config.documents = Arc::new(replaced_clause_documents());
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(config))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal/diff?from=1&to=2")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["fromVersion"], 1);
+        assert_eq!(payload["toVersion"], 2);
+
+        let lines = payload["hunks"][0]["lines"].as_array().unwrap();
+        assert!(lines.iter().any(|line| line["operation"] == "delete"
+            && line["oldLine"] == 2
+            && line["text"] == "Old clause"));
+        assert!(lines.iter().any(|line| line["operation"] == "insert"
+            && line["newLine"] == 2
+            && line["text"] == "New clause"));
+    }
+
+    #[actix_web::test]
+    async fn create_term_of_use_stores_change_summaries() {
+        let mut repository = repository_without_policy();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(sample_term("legal"))));
+        repository
+            .expect_create_term()
+            .withf(|term| {
+                term.change_summaries.len() == 2
+                    && term.change_summaries[0].locale == "de"
+                    && term.change_summaries[1].locale == "en"
+            })
+            .times(1)
+            .returning(|mut term| {
+                term.id = 12;
+                Ok(term)
+            });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .returning(|_| Ok(()));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .returning(|_, _, _, _| Ok("legal/v2.pdf".to_string()));
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://files/legal/v2.pdf".to_string()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    cache,
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let boundary = "boundary321";
+        let payload = pdf_upload(
+            boundary,
+            SAMPLE_PDF,
+            r#"{"group":"legal","changeSummaries":{"en":"We keep data for **30 days**.","de":"Daten werden **30 Tage** gespeichert."}}"#,
+        );
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/")
+                .insert_header((
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                ))
+                .set_payload(payload)
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn get_term_history_lists_versions_with_change_summaries() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .with(eq("legal"), eq(TermMetadata::new()))
+            .returning(|_, _| {
+                Ok(vec![
+                    TermOfUse {
+                        id: 2,
+                        version: 2,
+                        url: "legal/v2.pdf".to_string(),
+                        change_summaries: vec![ChangeSummary {
+                            locale: "en".to_string(),
+                            markdown: "Shorter **retention**".to_string(),
+                            html: "<p>Shorter <strong>retention</strong></p>".to_string(),
+                        }],
+                        ..sample_term("legal")
+                    },
+                    sample_term("legal"),
+                ])
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://files/{key}")));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal/versions")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["group"], "legal");
+
+        let versions = payload["versions"].as_array().unwrap();
+        assert_eq!(versions.len(), 2);
+        assert_eq!(versions[0]["version"], 2);
+        assert_eq!(versions[0]["url"], "https://files/legal/v2.pdf");
+        assert_eq!(versions[0]["changeSummaries"][0]["locale"], "en");
+        assert_eq!(
+            versions[0]["changeSummaries"][0]["html"],
+            "<p>Shorter <strong>retention</strong></p>"
+        );
+        assert_eq!(versions[1]["changeSummaries"].as_array().unwrap().len(), 0);
+    }
+
+    #[actix_web::test]
+    async fn get_term_history_returns_not_found_for_unknown_group() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .returning(|_, _| Ok(vec![]));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/unknown/versions")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn get_consent_at_returns_the_version_in_effect() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .returning(|group, _| {
+                Ok(vec![
+                    TermOfUse {
+                        id: 2,
+                        version: 2,
+                        created_at: parse_point_in_time("2025-06-01T00:00:00Z").unwrap(),
+                        ..sample_term(group)
+                    },
+                    TermOfUse {
+                        id: 1,
+                        created_at: parse_point_in_time("2025-01-01T00:00:00Z").unwrap(),
+                        ..sample_term(group)
+                    },
+                ])
+            });
+        repository
+            .expect_get_agreement_at()
+            .with(eq(42), eq(1), always())
+            .returning(|user_id, term_id, _| {
+                Ok(Some(UserAgreement {
+                    user_id,
+                    term_id,
+                    agreed_at: parse_point_in_time("2025-02-10T09:30:00Z").unwrap(),
+                    accepted_clauses: vec![],
+                }))
+            });
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/privacy-policy/consents/42?at=2025-03-01T00:00:00Z")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["group"], "privacy-policy");
+        assert_eq!(body["hasConsented"], true);
+        assert_eq!(body["term"]["version"], 1);
+        assert_eq!(body["agreement"]["agreedAt"], "2025-02-10T09:30:00+00:00");
+    }
+
+    #[actix_web::test]
+    async fn get_consent_at_rejects_invalid_timestamps() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_get_terms_for_group().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/privacy-policy/consents/42?at=2025-03-01")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn get_term_history_filters_by_metadata_query_parameters() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_terms_for_group()
+            .withf(|group, metadata| {
+                group == "legal"
+                    && metadata.get("region") == Some(&Value::from("eu"))
+                    && metadata.get("seats") == Some(&Value::from(5))
+                    && metadata.len() == 2
+            })
+            .returning(|_, _| {
+                Ok(vec![TermOfUse {
+                    metadata: serde_json::json!({ "region": "eu", "seats": 5 })
+                        .as_object()
+                        .cloned()
+                        .unwrap(),
+                    ..sample_term("legal")
+                }])
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_get_file_url()
+            .returning(|key| Ok(format!("https://files/{key}")));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal/versions?metadata.region=eu&metadata.seats=5&page=1")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["versions"][0]["metadata"]["region"], "eu");
+    }
+
+    fn reserve_payload(content_type: &str) -> ReserveTermPayload {
+        ReserveTermPayload {
+            group: "legal".to_string(),
+            info: None,
+            content_type: content_type.to_string(),
+            size: 2048,
+            sha256: SHA256.to_string(),
+            change_summaries: Default::default(),
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    const SHA256: &str = "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    #[actix_web::test]
+    async fn reserve_term_of_use_returns_presigned_upload() {
+        let mut repository = repository_without_policy();
+        repository
+            .expect_get_latest_term_for_group()
+            .with(eq("legal"))
+            .returning(|_| Ok(Some(sample_term("legal"))));
+        repository
+            .expect_get_reservation_for_version()
+            .returning(|_, _| Ok(None));
+        repository
+            .expect_create_reservation()
+            .returning(|reservation| {
+                Ok(TermReservation {
+                    id: 5,
+                    ..reservation
+                })
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_create_upload_url()
+            .withf(|group, version, _, size, _, _| {
+                group == "legal" && *version == 2 && *size == 2048
+            })
+            .returning(|_, _, _, _, _, _| {
+                Ok(PresignedUpload {
+                    key: "legal/v2.pdf".to_string(),
+                    method: "PUT".to_string(),
+                    url: "https://bucket/legal/v2.pdf?signature".to_string(),
+                    headers: vec![("content-type".to_string(), "application/pdf".to_string())],
+                })
+            });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/uploads")
+                .set_json(reserve_payload("application/pdf"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body = test::read_body(response).await;
+        let payload: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload["reservationId"], 5);
+        assert_eq!(payload["version"], 2);
+        assert_eq!(
+            payload["uploadUrl"],
+            "https://bucket/legal/v2.pdf?signature"
+        );
+        assert_eq!(payload["headers"]["content-type"], "application/pdf");
+    }
+
+    #[actix_web::test]
+    async fn reserve_term_of_use_rejects_documents_outside_the_group_policy() {
+        let mut storage = MockStorageService::new();
+        storage.expect_create_upload_url().never();
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository_without_policy(),
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/uploads")
+                .set_json(reserve_payload("text/plain"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body = test::read_body(response).await;
+        let problem: Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(
+            problem["detail"],
+            "Documents of group 'legal' must be one of: application/pdf, text/markdown, text/html"
+        );
+    }
+
+    #[actix_web::test]
+    async fn reserve_term_of_use_accepts_markdown_allowed_by_the_group_policy() {
+        let mut repository = repository_without_policy();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        repository
+            .expect_get_reservation_for_version()
+            .returning(|_, _| Ok(None));
+        repository
+            .expect_create_reservation()
+            .withf(|reservation| reservation.content_type == "text/markdown")
+            .times(1)
+            .returning(|reservation| {
+                Ok(TermReservation {
+                    id: 6,
+                    ..reservation
+                })
+            });
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_create_upload_url()
+            .withf(|_, _, content_type, _, _, _| content_type == "text/markdown")
+            .times(1)
+            .returning(|_, _, _, _, _, _| {
+                Ok(PresignedUpload {
+                    key: "legal/v1.md".to_string(),
+                    method: "PUT".to_string(),
+                    url: "https://bucket/legal/v1.md?signature".to_string(),
+                    headers: vec![("content-type".to_string(), "text/markdown".to_string())],
+                })
+            });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/uploads")
+                .set_json(reserve_payload("text/markdown"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn finalize_term_of_use_reports_mismatching_upload() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_reservation()
+            .with(eq(5))
+            .returning(|_| {
+                Ok(Some(TermReservation {
+                    id: 5,
+                    group: "legal".to_string(),
+                    version: 1,
+                    info: None,
+                    key: "legal/v1.pdf".to_string(),
+                    content_type: "application/pdf".to_string(),
+                    size: 2048,
+                    sha256: SHA256.to_string(),
+                    expires_at: Utc::now().naive_utc() + chrono::TimeDelta::minutes(10),
+                    change_summaries: vec![],
+                    metadata: Default::default(),
+                    clauses: vec![],
+                }))
+            });
+        repository
+            .expect_claim_reservation()
+            .returning(|_, _| Ok(true));
+        repository
+            .expect_release_reservation()
+            .with(eq(5))
+            .returning(|_| Ok(()));
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(None));
+        repository.expect_create_term().times(0);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_get_file_info().returning(|_| {
+            Ok(Some(StoredFileInfo {
+                size: 10,
+                content_type: Some("application/pdf".to_string()),
+                sha256: None,
+            }))
+        });
+        storage
+            .expect_delete_file()
+            .with(eq("legal/v1.pdf"))
+            .returning(|_| Ok(()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/uploads/5/finalize")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn create_term_of_use_rejects_documents_above_group_limit() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_get_upload_policy()
+            .with(eq("legal"))
+            .returning(|group| {
+                Ok(Some(UploadPolicy {
+                    group: group.to_string(),
+                    content_types: vec!["application/pdf".to_string()],
+                    max_size: 100,
+                    required_fields: vec![],
+                    metadata_schema: None,
+                }))
+            });
+        repository.expect_create_term().times(0);
+
+        let mut storage = MockStorageService::new();
+        storage.expect_upload_file().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    storage,
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let boundary = "boundary777";
+        let payload = pdf_upload(boundary, SAMPLE_PDF, r#"{"group":"legal"}"#);
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/terms-of-use/")
+                .insert_header((
+                    "Content-Type",
+                    format!("multipart/form-data; boundary={boundary}"),
+                ))
+                .set_payload(payload)
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(
+            body["detail"],
+            "Documents of group 'legal' must not exceed 100 bytes"
+        );
+    }
+
+    #[actix_web::test]
+    async fn get_upload_policy_falls_back_to_default() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository_without_policy(),
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/terms-of-use/legal/upload-policy")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["group"], "legal");
+        assert_eq!(body["maxSize"], DEFAULT_MAX_DOCUMENT_SIZE);
+        assert_eq!(body["requiredFields"], serde_json::json!([]));
+        assert_eq!(body["contentTypes"].as_array().unwrap().len(), 3);
+    }
+
+    #[actix_web::test]
+    async fn set_upload_policy_saves_the_group_policy() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_save_upload_policy()
+            .withf(|policy| {
+                policy.group == "legal"
+                    && policy.content_types == ["application/pdf"]
+                    && policy.max_size == 2_000_000
+                    && policy.required_fields == ["info"]
+            })
+            .times(1)
+            .returning(Ok);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri("/v1/terms-of-use/legal/upload-policy")
+                .set_json(UploadPolicyPayload {
+                    content_types: vec!["application/pdf".to_string()],
+                    max_size: 2_000_000,
+                    required_fields: vec!["info".to_string()],
+                    metadata_schema: None,
+                })
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["contentTypes"][0], "application/pdf");
+        assert_eq!(body["requiredFields"], serde_json::json!(["info"]));
+    }
+
+    #[actix_web::test]
+    async fn set_upload_policy_rejects_unsupported_content_types() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository.expect_save_upload_policy().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                    MockPublisherService::new(),
+                )))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::put()
+                .uri("/v1/terms-of-use/legal/upload-policy")
+                .set_json(UploadPolicyPayload {
+                    content_types: vec!["image/png".to_string()],
+                    max_size: 2_000_000,
+                    required_fields: vec![],
+                    metadata_schema: None,
+                })
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn fixed_route_segments_are_reserved_group_names() {
+        // Routes below `/v1/terms-of-use` are registered here and in the resumable scope.
+        let routes = include_str!("controller.rs").lines().filter_map(|line| {
+            let line = line.trim();
+            line.strip_prefix("#[get(\"/")
+                .or_else(|| line.strip_prefix("#[post(\"/"))
+                .or_else(|| line.strip_prefix("#[put(\"/"))
+        });
+        let scopes = include_str!("resumable.rs")
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("web::scope(\"/"));
+
+        for path in routes.chain(scopes) {
+            let segment = path.split(['/', '"']).next().unwrap_or_default();
+            if segment.is_empty() || segment.starts_with('{') {
+                continue;
+            }
+
+            assert!(
+                domain::use_cases::RESERVED_GROUP_NAMES.contains(&segment),
+                "'{segment}' is not a reserved group name"
+            );
+        }
+    }
 }
@@ -0,0 +1,297 @@
+use actix_web::{
+    HttpResponse, delete, get, post, put,
+    web::{self, Path},
+};
+use domain::use_cases::{
+    create_group_use_case, delete_group_use_case, get_group_use_case, list_groups_use_case,
+    update_group_use_case,
+};
+
+use crate::{
+    actix::{
+        error::response::ProblemDetails,
+        v1::{
+            payload::{CreateGroupPayload, GroupPayload},
+            response::GroupResponse,
+        },
+    },
+    config::Config,
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/v1/groups")
+            .service(list_groups)
+            .service(create_group)
+            .service(get_group)
+            .service(update_group)
+            .service(delete_group),
+    );
+}
+
+#[tracing::instrument(skip(config))]
+#[get("")]
+async fn list_groups(config: web::Data<Config>) -> Result<HttpResponse, ProblemDetails> {
+    let groups = list_groups_use_case(config.repository.as_ref()).await?;
+
+    Ok(HttpResponse::Ok().json(
+        groups
+            .into_iter()
+            .map(GroupResponse::from)
+            .collect::<Vec<_>>(),
+    ))
+}
+
+#[tracing::instrument(skip(config, body))]
+#[post("")]
+async fn create_group(
+    config: web::Data<Config>,
+    body: web::Json<CreateGroupPayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let payload = body.into_inner();
+    let group = create_group_use_case(
+        config.repository.as_ref(),
+        payload.group.into_group(payload.name),
+    )
+    .await?;
+
+    Ok(HttpResponse::Created().json(GroupResponse::from(group)))
+}
+
+#[tracing::instrument(skip(config))]
+#[get("/{name}")]
+async fn get_group(
+    name: Path<String>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let group = get_group_use_case(config.repository.as_ref(), &name).await?;
+
+    Ok(HttpResponse::Ok().json(GroupResponse::from(group)))
+}
+
+#[tracing::instrument(skip(config, body))]
+#[put("/{name}")]
+async fn update_group(
+    name: Path<String>,
+    config: web::Data<Config>,
+    body: web::Json<GroupPayload>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let group = update_group_use_case(
+        config.repository.as_ref(),
+        config.cache.as_ref(),
+        body.into_inner().into_group(name.into_inner()),
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(GroupResponse::from(group)))
+}
+
+#[tracing::instrument(skip(config))]
+#[delete("/{name}")]
+async fn delete_group(
+    name: Path<String>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    delete_group_use_case(config.repository.as_ref(), &name).await?;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{App, http::StatusCode, test, web};
+    use chrono::Utc;
+    use domain::entities::{Group, TermOfUse};
+    use mockall::predicate::eq;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+
+    use crate::{Config, actix::v1::groups::configure, mocks::*};
+
+    fn build_config(repository: MockDatabaseRepository) -> Config {
+        Config {
+            repository: Arc::new(repository),
+            cache: Arc::new(MockCacheService::new()),
+            storage: Arc::new(MockStorageService::new()),
+            publisher: Arc::new(MockPublisherService::new()),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
+        }
+    }
+
+    fn privacy_policy() -> Group {
+        Group {
+            name: "privacy-policy".to_string(),
+            description: Some("How we process personal data".to_string()),
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: Some(365),
+        }
+    }
+
+    #[actix_web::test]
+    async fn create_group_registers_the_group() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .with(eq("privacy-policy"))
+            .returning(|_| Ok(None));
+        repository
+            .expect_create_group()
+            .with(eq(privacy_policy()))
+            .times(1)
+            .returning(Ok);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/groups")
+                .set_json(json!({
+                    "name": "privacy-policy",
+                    "description": "How we process personal data",
+                    "owner": "legal",
+                    "mandatory": true,
+                    "maxConsentAgeDays": 365
+                }))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["name"], "privacy-policy");
+        assert_eq!(body["defaultLocale"], "en");
+        assert_eq!(body["mandatory"], true);
+        assert_eq!(body["maxConsentAgeDays"], 365);
+    }
+
+    #[actix_web::test]
+    async fn create_group_rejects_invalid_names() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_create_group().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri("/v1/groups")
+                .set_json(json!({ "name": "privacy policy" }))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn list_groups_returns_every_group() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_groups()
+            .returning(|| Ok(vec![registered_group("cookies"), privacy_policy()]));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get().uri("/v1/groups").to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body[0]["name"], "cookies");
+        assert!(body[0].get("description").is_none());
+        assert!(body[0].get("maxConsentAgeDays").is_none());
+        assert_eq!(body[1]["owner"], "legal");
+    }
+
+    #[actix_web::test]
+    async fn get_group_returns_not_found_for_unknown_groups() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_get_group().returning(|_| Ok(None));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v1/groups/privacy-policy")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn delete_group_keeps_groups_with_terms() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|_| Ok(Some(privacy_policy())));
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| {
+                Ok(Some(TermOfUse {
+                    id: 1,
+                    group: "privacy-policy".to_string(),
+                    url: "privacy-policy/v1.pdf".to_string(),
+                    version: 1,
+                    info: None,
+                    created_at: Utc::now().naive_utc(),
+                    html: None,
+                    text: None,
+                    change_summaries: vec![],
+                    pdf_metadata: None,
+                    metadata: Default::default(),
+                    clauses: vec![],
+                }))
+            });
+        repository.expect_delete_group().times(0);
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::delete()
+                .uri("/v1/groups/privacy-policy")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
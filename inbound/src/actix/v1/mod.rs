@@ -1,3 +1,7 @@
+pub mod bundles;
 pub mod controller;
+pub mod groups;
 mod payload;
 mod response;
+mod resumable;
+pub mod upload_store;
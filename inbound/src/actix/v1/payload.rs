@@ -1,5 +1,11 @@
+use std::collections::{BTreeMap, HashMap};
+
 use actix_multipart::form::{MultipartForm, json::Json, tempfile::TempFile};
-use domain::dto::CreateTermOfUseDTO;
+use domain::{
+    dto::{CreateTermOfUseDTO, ReserveTermOfUseDTO},
+    entities::{Bundle, Clause, Group, TermMetadata, UploadPolicy},
+    use_cases::parse_metadata_filter,
+};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -7,13 +13,44 @@ use serde::{Deserialize, Serialize};
 pub struct CreateAgreementPayload {
     pub user_id: i32,
     pub term_id: i32,
+    /// Keys of the optional clauses the user accepted.
+    #[serde(default)]
+    pub accepted_clauses: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ClausePayload {
+    pub key: String,
+    pub title: String,
+    #[serde(default)]
+    pub mandatory: bool,
+}
+
+impl From<ClausePayload> for Clause {
+    fn from(payload: ClausePayload) -> Self {
+        Clause {
+            key: payload.key,
+            title: payload.title,
+            mandatory: payload.mandatory,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateTermPayload {
     pub group: String,
     #[serde(default)]
     pub info: Option<String>,
+    /// Markdown summaries of what changed, keyed by locale.
+    #[serde(default)]
+    pub change_summaries: BTreeMap<String, String>,
+    /// Structured metadata, validated against the group's schema.
+    #[serde(default)]
+    pub metadata: TermMetadata,
+    /// Named parts of the term, optional ones can be declined by users.
+    #[serde(default)]
+    pub clauses: Vec<ClausePayload>,
 }
 
 impl From<CreateTermPayload> for CreateTermOfUseDTO {
@@ -21,13 +58,16 @@ impl From<CreateTermPayload> for CreateTermOfUseDTO {
         Self {
             group: payload.group,
             info: payload.info,
+            change_summaries: payload.change_summaries,
+            metadata: payload.metadata,
+            clauses: payload.clauses.into_iter().map(Into::into).collect(),
         }
     }
 }
 
 #[derive(Debug, MultipartForm)]
 pub struct CreateTermForm {
-    #[multipart(limit = "20MB")]
+    #[multipart(limit = "100MiB")]
     pub file: TempFile,
     pub data: Json<CreateTermPayload>,
 }
@@ -36,4 +76,163 @@ pub struct CreateTermForm {
 pub struct GetLatestTermPayload {
     #[serde(default)]
     pub only_url: bool,
+    /// Includes the rendered HTML body of Markdown and HTML documents.
+    #[serde(default)]
+    pub include_html: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HasConsentPayload {
+    /// Accepts agreements to this version or any later one instead of the latest only.
+    #[serde(default)]
+    pub min_version: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ConsentAtPayload {
+    /// RFC 3339 timestamp, e.g. `2025-03-01T00:00:00Z`.
+    pub at: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TermDiffPayload {
+    pub from: u32,
+    pub to: u32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TermHistoryPayload {
+    /// `metadata.<key>=<value>` parameters filtering the versions by metadata.
+    #[serde(flatten)]
+    pub params: HashMap<String, String>,
+}
+
+impl TermHistoryPayload {
+    pub fn metadata_filter(self) -> TermMetadata {
+        parse_metadata_filter(self.params.into_iter().filter_map(|(key, value)| {
+            key.strip_prefix("metadata.")
+                .map(|key| (key.to_string(), value))
+        }))
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReserveTermPayload {
+    pub group: String,
+    #[serde(default)]
+    pub info: Option<String>,
+    pub content_type: String,
+    pub size: u64,
+    pub sha256: String,
+    /// Markdown summaries of what changed, keyed by locale.
+    #[serde(default)]
+    pub change_summaries: BTreeMap<String, String>,
+    #[serde(default)]
+    pub metadata: TermMetadata,
+    #[serde(default)]
+    pub clauses: Vec<ClausePayload>,
+}
+
+impl From<ReserveTermPayload> for ReserveTermOfUseDTO {
+    fn from(payload: ReserveTermPayload) -> Self {
+        Self {
+            group: payload.group,
+            info: payload.info,
+            content_type: payload.content_type,
+            size: payload.size,
+            sha256: payload.sha256,
+            change_summaries: payload.change_summaries,
+            metadata: payload.metadata,
+            clauses: payload.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPolicyPayload {
+    pub content_types: Vec<String>,
+    pub max_size: u64,
+    /// Fields every version must set, any of `info`, `changeSummaries` and `metadata`.
+    #[serde(default)]
+    pub required_fields: Vec<String>,
+    /// JSON Schema the metadata of every version must satisfy.
+    #[serde(default)]
+    pub metadata_schema: Option<serde_json::Value>,
+}
+
+impl UploadPolicyPayload {
+    pub fn into_policy(self, group: String) -> UploadPolicy {
+        UploadPolicy {
+            group,
+            content_types: self.content_types,
+            max_size: self.max_size,
+            required_fields: self.required_fields,
+            metadata_schema: self.metadata_schema,
+        }
+    }
+}
+
+fn default_locale() -> String {
+    "en".to_string()
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupPayload {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub owner: Option<String>,
+    #[serde(default)]
+    pub mandatory: bool,
+    #[serde(default = "default_locale")]
+    pub default_locale: String,
+    #[serde(default)]
+    pub max_consent_age_days: Option<u32>,
+}
+
+impl GroupPayload {
+    pub fn into_group(self, name: String) -> Group {
+        Group {
+            name,
+            description: self.description,
+            owner: self.owner,
+            mandatory: self.mandatory,
+            default_locale: self.default_locale,
+            max_consent_age_days: self.max_consent_age_days,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct CreateGroupPayload {
+    pub name: String,
+    #[serde(flatten)]
+    pub group: GroupPayload,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundlePayload {
+    #[serde(default)]
+    pub description: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl BundlePayload {
+    pub fn into_bundle(self, name: String) -> Bundle {
+        Bundle {
+            name,
+            description: self.description,
+            groups: self.groups,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateBundleAgreementPayload {
+    pub user_id: i32,
 }
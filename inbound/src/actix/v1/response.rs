@@ -1,4 +1,12 @@
-use domain::entities::TermOfUse;
+use std::collections::BTreeMap;
+
+use domain::{
+    dto::{ConsentAtDTO, DiffHunk, DiffLine, DiffOperation, TermDiffDTO, TermReservationDTO},
+    entities::{
+        Bundle, ChangeSummary, Clause, Group, PdfMetadata, TermMetadata, TermOfUse, UploadPolicy,
+        UserAgreement,
+    },
+};
 use serde::Serialize;
 
 #[derive(Debug, Serialize)]
@@ -13,11 +21,71 @@ impl From<TermOfUse> for TermOfUseUrlResponse {
 }
 
 #[derive(Debug, Serialize)]
+pub struct ChangeSummaryResponse {
+    pub locale: String,
+    pub markdown: String,
+    pub html: String,
+}
+
+impl From<ChangeSummary> for ChangeSummaryResponse {
+    fn from(summary: ChangeSummary) -> Self {
+        ChangeSummaryResponse {
+            locale: summary.locale,
+            markdown: summary.markdown,
+            html: summary.html,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfMetadataResponse {
+    pub page_count: u32,
+    pub title: Option<String>,
+    pub producer: Option<String>,
+}
+
+impl From<PdfMetadata> for PdfMetadataResponse {
+    fn from(metadata: PdfMetadata) -> Self {
+        PdfMetadataResponse {
+            page_count: metadata.page_count,
+            title: metadata.title,
+            producer: metadata.producer,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ClauseResponse {
+    pub key: String,
+    pub title: String,
+    pub mandatory: bool,
+}
+
+impl From<Clause> for ClauseResponse {
+    fn from(clause: Clause) -> Self {
+        ClauseResponse {
+            key: clause.key,
+            title: clause.title,
+            mandatory: clause.mandatory,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TermOfUseResponse {
     pub id: i32,
     pub url: String,
     pub group: String,
     pub info: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html: Option<String>,
+    pub change_summaries: Vec<ChangeSummaryResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf_metadata: Option<PdfMetadataResponse>,
+    pub metadata: TermMetadata,
+    pub clauses: Vec<ClauseResponse>,
 }
 
 impl From<TermOfUse> for TermOfUseResponse {
@@ -27,6 +95,110 @@ impl From<TermOfUse> for TermOfUseResponse {
             url: term.url,
             group: term.group,
             info: term.info,
+            html: term.html,
+            change_summaries: term.change_summaries.into_iter().map(Into::into).collect(),
+            pdf_metadata: term.pdf_metadata.map(Into::into),
+            metadata: term.metadata,
+            clauses: term.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermVersionResponse {
+    pub id: i32,
+    pub version: u32,
+    pub url: String,
+    pub info: Option<String>,
+    pub created_at: String,
+    pub change_summaries: Vec<ChangeSummaryResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pdf_metadata: Option<PdfMetadataResponse>,
+    pub metadata: TermMetadata,
+    pub clauses: Vec<ClauseResponse>,
+}
+
+impl From<TermOfUse> for TermVersionResponse {
+    fn from(term: TermOfUse) -> Self {
+        TermVersionResponse {
+            id: term.id,
+            version: term.version,
+            url: term.url,
+            info: term.info,
+            created_at: term.created_at.and_utc().to_rfc3339(),
+            change_summaries: term.change_summaries.into_iter().map(Into::into).collect(),
+            pdf_metadata: term.pdf_metadata.map(Into::into),
+            metadata: term.metadata,
+            clauses: term.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct TermHistoryResponse {
+    pub group: String,
+    pub versions: Vec<TermVersionResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AgreementResponse {
+    pub user_id: i32,
+    pub term_id: i32,
+    pub agreed_at: String,
+    pub accepted_clauses: Vec<String>,
+}
+
+impl From<UserAgreement> for AgreementResponse {
+    fn from(agreement: UserAgreement) -> Self {
+        AgreementResponse {
+            user_id: agreement.user_id,
+            term_id: agreement.term_id,
+            agreed_at: agreement.agreed_at.and_utc().to_rfc3339(),
+            accepted_clauses: agreement.accepted_clauses,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsentAtResponse {
+    pub group: String,
+    pub has_consented: bool,
+    /// Version in effect at the requested time.
+    pub term: TermVersionResponse,
+    /// `null` when the user had not agreed to that version by then.
+    pub agreement: Option<AgreementResponse>,
+}
+
+impl From<ConsentAtDTO> for ConsentAtResponse {
+    fn from(consent: ConsentAtDTO) -> Self {
+        ConsentAtResponse {
+            group: consent.term.group.clone(),
+            has_consented: consent.has_consented,
+            term: consent.term.into(),
+            agreement: consent.agreement.map(Into::into),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingTermResponse {
+    pub group: String,
+    pub term_id: i32,
+    pub version: u32,
+    pub url: String,
+}
+
+impl From<TermOfUse> for PendingTermResponse {
+    fn from(term: TermOfUse) -> Self {
+        PendingTermResponse {
+            group: term.group,
+            term_id: term.id,
+            version: term.version,
+            url: term.url,
         }
     }
 }
@@ -36,3 +208,172 @@ impl From<TermOfUse> for TermOfUseResponse {
 pub struct HasConsentedResponse {
     pub has_consented: bool,
 }
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermReservationResponse {
+    pub reservation_id: i32,
+    pub version: u32,
+    pub key: String,
+    pub method: String,
+    pub upload_url: String,
+    pub headers: BTreeMap<String, String>,
+    pub expires_at: String,
+}
+
+impl From<TermReservationDTO> for TermReservationResponse {
+    fn from(reservation: TermReservationDTO) -> Self {
+        TermReservationResponse {
+            reservation_id: reservation.reservation_id,
+            version: reservation.version,
+            key: reservation.upload.key,
+            method: reservation.upload.method,
+            upload_url: reservation.upload.url,
+            headers: reservation.upload.headers.into_iter().collect(),
+            expires_at: reservation.expires_at.and_utc().to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLineResponse {
+    pub operation: &'static str,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub text: String,
+}
+
+impl From<DiffLine> for DiffLineResponse {
+    fn from(line: DiffLine) -> Self {
+        DiffLineResponse {
+            operation: match line.operation {
+                DiffOperation::Equal => "equal",
+                DiffOperation::Insert => "insert",
+                DiffOperation::Delete => "delete",
+            },
+            old_line: line.old_line,
+            new_line: line.new_line,
+            text: line.text,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffHunkResponse {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    pub lines: Vec<DiffLineResponse>,
+}
+
+impl From<DiffHunk> for DiffHunkResponse {
+    fn from(hunk: DiffHunk) -> Self {
+        DiffHunkResponse {
+            old_start: hunk.old_start,
+            old_lines: hunk.old_lines,
+            new_start: hunk.new_start,
+            new_lines: hunk.new_lines,
+            lines: hunk.lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TermDiffResponse {
+    pub group: String,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub hunks: Vec<DiffHunkResponse>,
+}
+
+impl From<TermDiffDTO> for TermDiffResponse {
+    fn from(diff: TermDiffDTO) -> Self {
+        TermDiffResponse {
+            group: diff.group,
+            from_version: diff.from_version,
+            to_version: diff.to_version,
+            hunks: diff.hunks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadPolicyResponse {
+    pub group: String,
+    pub content_types: Vec<String>,
+    pub max_size: u64,
+    pub required_fields: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_schema: Option<serde_json::Value>,
+}
+
+impl From<UploadPolicy> for UploadPolicyResponse {
+    fn from(policy: UploadPolicy) -> Self {
+        UploadPolicyResponse {
+            group: policy.group,
+            content_types: policy.content_types,
+            max_size: policy.max_size,
+            required_fields: policy.required_fields,
+            metadata_schema: policy.metadata_schema,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupResponse {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    pub mandatory: bool,
+    pub default_locale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_consent_age_days: Option<u32>,
+}
+
+impl From<Group> for GroupResponse {
+    fn from(group: Group) -> Self {
+        GroupResponse {
+            name: group.name,
+            description: group.description,
+            owner: group.owner,
+            mandatory: group.mandatory,
+            default_locale: group.default_locale,
+            max_consent_age_days: group.max_consent_age_days,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleResponse {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub groups: Vec<String>,
+}
+
+impl From<Bundle> for BundleResponse {
+    fn from(bundle: Bundle) -> Self {
+        BundleResponse {
+            name: bundle.name,
+            description: bundle.description,
+            groups: bundle.groups,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BundleConsentResponse {
+    pub has_consented: bool,
+    /// Groups whose latest term the user has not agreed to yet.
+    pub pending_groups: Vec<String>,
+}
@@ -0,0 +1,682 @@
+//! Resumable uploads following the tus 1.0.0 protocol (https://tus.io/protocols/resumable-upload)
+//! with the `creation`, `expiration` and `termination` extensions.
+//!
+//! Clients announce a document with `POST`, append chunks with `PATCH` and ask for the
+//! current offset with `HEAD` after a dropped connection. Once the last chunk arrives the
+//! document goes through the same use case as the multipart upload.
+
+use std::{collections::HashMap, io};
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        Method, StatusCode,
+        header::{self, HttpDate},
+    },
+    middleware::DefaultHeaders,
+    web::{self, Path},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use domain::{
+    dto::CreateTermOfUseDTO,
+    entities::TermMetadata,
+    use_cases::{check_group_use_case, check_upload_policy_use_case, create_term_of_use_use_case},
+};
+use futures::StreamExt;
+use tracing::error;
+
+use crate::{
+    actix::{
+        error::response::ProblemDetails,
+        v1::{
+            payload::ClausePayload,
+            upload_store::{UploadInfo, UploadStore, unix_now},
+        },
+    },
+    config::Config,
+};
+
+const TUS_VERSION: &str = "1.0.0";
+const TUS_EXTENSIONS: &str = "creation,expiration,termination";
+const OFFSET_OCTET_STREAM: &str = "application/offset+octet-stream";
+/// Prefix of `Upload-Metadata` keys carrying a change summary, e.g. `summary-en`.
+const SUMMARY_PREFIX: &str = "summary-";
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/resumable-uploads")
+            .wrap(DefaultHeaders::new().add(("Tus-Resumable", TUS_VERSION)))
+            .route("", web::method(Method::OPTIONS).to(describe_server))
+            .route("", web::post().to(create_upload))
+            .route("/{upload_id}", web::head().to(get_upload_offset))
+            .route("/{upload_id}", web::patch().to(append_upload_chunk))
+            .route("/{upload_id}", web::delete().to(terminate_upload)),
+    );
+}
+
+fn header_value<'a>(req: &'a HttpRequest, name: &str) -> Option<&'a str> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+}
+
+fn check_tus_version(req: &HttpRequest) -> Result<(), ProblemDetails> {
+    if header_value(req, "Tus-Resumable") != Some(TUS_VERSION) {
+        return Err(ProblemDetails::blank(StatusCode::PRECONDITION_FAILED)
+            .with_detail("Tus-Resumable must be 1.0.0"));
+    }
+
+    Ok(())
+}
+
+fn storage_error(err: io::Error) -> ProblemDetails {
+    error!(error = ?err, "resumable upload storage error");
+
+    ProblemDetails::internal_server_error()
+}
+
+/// Parses `Upload-Metadata`, a comma separated list of `key base64(value)` pairs.
+fn parse_metadata(value: &str) -> Result<HashMap<String, String>, ProblemDetails> {
+    value
+        .split(',')
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (key, encoded) = pair.split_once(' ').unwrap_or((pair, ""));
+            let decoded = STANDARD
+                .decode(encoded.trim())
+                .ok()
+                .and_then(|bytes| String::from_utf8(bytes).ok())
+                .ok_or_else(|| {
+                    ProblemDetails::bad_request()
+                        .with_detail(format!("Upload-Metadata value of {key} must be base64"))
+                })?;
+
+            Ok((key.to_string(), decoded))
+        })
+        .collect()
+}
+
+/// Returns the upload info, treating expired uploads as unknown.
+async fn find_upload(uploads: &UploadStore, upload_id: &str) -> Result<UploadInfo, ProblemDetails> {
+    match uploads.info(upload_id).await.map_err(storage_error)? {
+        Some(info) if uploads.expires_at(&info) > std::time::SystemTime::now() => Ok(info),
+        _ => Err(ProblemDetails::not_found().with_detail("The requested upload was not found.")),
+    }
+}
+
+#[tracing::instrument(skip(uploads))]
+async fn describe_server(uploads: web::Data<UploadStore>) -> HttpResponse {
+    HttpResponse::NoContent()
+        .insert_header(("Tus-Version", TUS_VERSION))
+        .insert_header(("Tus-Extension", TUS_EXTENSIONS))
+        .insert_header(("Tus-Max-Size", uploads.max_size.to_string()))
+        .finish()
+}
+
+#[tracing::instrument(skip(req, uploads, config))]
+async fn create_upload(
+    req: HttpRequest,
+    uploads: web::Data<UploadStore>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    check_tus_version(&req)?;
+
+    let length: u64 = header_value(&req, "Upload-Length")
+        .and_then(|value| value.parse().ok())
+        .filter(|length| *length > 0)
+        .ok_or_else(|| {
+            ProblemDetails::bad_request().with_detail("Upload-Length must be a positive integer")
+        })?;
+
+    if length > uploads.max_size {
+        return Err(ProblemDetails::blank(StatusCode::PAYLOAD_TOO_LARGE)
+            .with_detail(format!("Uploads are limited to {} bytes", uploads.max_size)));
+    }
+
+    let mut metadata = parse_metadata(header_value(&req, "Upload-Metadata").unwrap_or_default())?;
+
+    let group = metadata
+        .remove("group")
+        .filter(|group| !group.is_empty())
+        .ok_or_else(|| {
+            ProblemDetails::bad_request().with_detail("Upload-Metadata must name a group")
+        })?;
+
+    let content_type = metadata.remove("filetype").unwrap_or_default();
+    let info = metadata.remove("info");
+    let term_metadata: TermMetadata = match metadata.remove("metadata") {
+        Some(value) => serde_json::from_str(&value).map_err(|_| {
+            ProblemDetails::bad_request()
+                .with_detail("Upload-Metadata value of metadata must be a JSON object")
+        })?,
+        None => TermMetadata::new(),
+    };
+    let clauses: Vec<ClausePayload> = match metadata.remove("clauses") {
+        Some(value) => serde_json::from_str(&value).map_err(|_| {
+            ProblemDetails::bad_request()
+                .with_detail("Upload-Metadata value of clauses must be a JSON array of clauses")
+        })?,
+        None => vec![],
+    };
+
+    let change_summaries = metadata
+        .iter()
+        .filter_map(|(key, summary)| {
+            let locale = key.strip_prefix(SUMMARY_PREFIX)?;
+
+            Some((locale.to_string(), summary.clone()))
+        })
+        .collect();
+
+    // Rejects documents before the client starts sending them
+    check_group_use_case(config.repository.as_ref(), &group).await?;
+    check_upload_policy_use_case(
+        config.repository.as_ref(),
+        config.schemas.as_ref(),
+        &group,
+        &content_type,
+        length,
+        info.as_deref(),
+        &change_summaries,
+        &term_metadata,
+    )
+    .await?;
+
+    let info = UploadInfo {
+        length,
+        group,
+        info,
+        content_type,
+        created_at: unix_now(),
+        change_summaries,
+        metadata: term_metadata,
+        clauses,
+    };
+
+    let upload_id = uploads.create(&info).await.map_err(storage_error)?;
+
+    Ok(HttpResponse::Created()
+        .insert_header((
+            header::LOCATION,
+            format!("{}/{upload_id}", req.path().trim_end_matches('/')),
+        ))
+        .insert_header((
+            "Upload-Expires",
+            HttpDate::from(uploads.expires_at(&info)).to_string(),
+        ))
+        .finish())
+}
+
+#[tracing::instrument(skip(req, uploads))]
+async fn get_upload_offset(
+    req: HttpRequest,
+    upload_id: Path<String>,
+    uploads: web::Data<UploadStore>,
+) -> Result<HttpResponse, ProblemDetails> {
+    check_tus_version(&req)?;
+
+    let info = find_upload(&uploads, &upload_id).await?;
+    let offset = uploads.offset(&upload_id).await.map_err(storage_error)?;
+
+    Ok(HttpResponse::Ok()
+        .insert_header(("Upload-Offset", offset.to_string()))
+        .insert_header(("Upload-Length", info.length.to_string()))
+        .insert_header((header::CACHE_CONTROL, "no-store"))
+        .finish())
+}
+
+#[tracing::instrument(skip(req, body, uploads, config))]
+async fn append_upload_chunk(
+    req: HttpRequest,
+    upload_id: Path<String>,
+    mut body: web::Payload,
+    uploads: web::Data<UploadStore>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    check_tus_version(&req)?;
+
+    if header_value(&req, header::CONTENT_TYPE.as_str()) != Some(OFFSET_OCTET_STREAM) {
+        return Err(ProblemDetails::blank(StatusCode::UNSUPPORTED_MEDIA_TYPE)
+            .with_detail(format!("Content-Type must be {OFFSET_OCTET_STREAM}")));
+    }
+
+    let info = find_upload(&uploads, &upload_id).await?;
+
+    let Some(_lock) = uploads.lock(&upload_id) else {
+        return Err(ProblemDetails::blank(StatusCode::LOCKED)
+            .with_detail("The upload is being written by another request"));
+    };
+
+    let offset = uploads.offset(&upload_id).await.map_err(storage_error)?;
+
+    if header_value(&req, "Upload-Offset").and_then(|value| value.parse::<u64>().ok())
+        != Some(offset)
+    {
+        return Err(
+            ProblemDetails::blank(StatusCode::CONFLICT).with_detail(format!(
+                "Upload-Offset must match the current offset {offset}"
+            )),
+        );
+    }
+
+    let mut received = offset;
+
+    while let Some(chunk) = body.next().await {
+        // Keep what was written so far, the client resumes from the stored offset
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => {
+                error!(error = ?err, "resumable upload interrupted");
+                break;
+            }
+        };
+
+        received += chunk.len() as u64;
+
+        if received > info.length {
+            uploads
+                .truncate(&upload_id, offset)
+                .await
+                .map_err(storage_error)?;
+
+            return Err(ProblemDetails::bad_request()
+                .with_detail("The chunk exceeds the announced Upload-Length"));
+        }
+
+        uploads
+            .append(&upload_id, &chunk)
+            .await
+            .map_err(storage_error)?;
+    }
+
+    // A failed creation keeps the upload, so an empty PATCH at the final offset retries it
+    if received == info.length {
+        create_term_of_use_use_case(
+            config.repository.as_ref(),
+            config.storage.as_ref(),
+            config.cache.as_ref(),
+            config.scanner.as_ref(),
+            config.documents.as_ref(),
+            config.schemas.as_ref(),
+            CreateTermOfUseDTO {
+                group: info.group,
+                info: info.info,
+                change_summaries: info.change_summaries,
+                metadata: info.metadata,
+                clauses: info.clauses.into_iter().map(Into::into).collect(),
+            },
+            &uploads.data_path(&upload_id),
+            &info.content_type,
+        )
+        .await?;
+
+        uploads.remove(&upload_id).await;
+    }
+
+    Ok(HttpResponse::NoContent()
+        .insert_header(("Upload-Offset", received.to_string()))
+        .finish())
+}
+
+#[tracing::instrument(skip(req, uploads))]
+async fn terminate_upload(
+    req: HttpRequest,
+    upload_id: Path<String>,
+    uploads: web::Data<UploadStore>,
+) -> Result<HttpResponse, ProblemDetails> {
+    check_tus_version(&req)?;
+
+    find_upload(&uploads, &upload_id).await?;
+
+    let Some(_lock) = uploads.lock(&upload_id) else {
+        return Err(ProblemDetails::blank(StatusCode::LOCKED)
+            .with_detail("The upload is being written by another request"));
+    };
+
+    uploads.remove(&upload_id).await;
+
+    Ok(HttpResponse::NoContent().finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use actix_web::{
+        App,
+        http::{Method, StatusCode},
+        test, web,
+    };
+    use base64::{Engine, engine::general_purpose::STANDARD};
+    use domain::entities::UploadPolicy;
+    use mockall::predicate::eq;
+    use serde_json::Value;
+
+    use crate::{
+        Config,
+        actix::v1::{controller::configure, upload_store::temp_store},
+        mocks::*,
+    };
+
+    const UPLOADS: &str = "/v1/terms-of-use/resumable-uploads";
+
+    fn build_config(
+        repository: MockDatabaseRepository,
+        cache: MockCacheService,
+        storage: MockStorageService,
+    ) -> Config {
+        Config {
+            repository: Arc::new(repository),
+            cache: Arc::new(cache),
+            storage: Arc::new(storage),
+            publisher: Arc::new(MockPublisherService::new()),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
+        }
+    }
+
+    fn metadata(filetype: &str) -> String {
+        format!(
+            "group {},filetype {},summary-en {}",
+            STANDARD.encode("legal"),
+            STANDARD.encode(filetype),
+            STANDARD.encode("Shorter **retention**")
+        )
+    }
+
+    /// Repository of registered groups that have no upload policy of their own.
+    fn repository_without_policy() -> MockDatabaseRepository {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_get_upload_policy()
+            .returning(|_| Ok(None));
+        repository
+    }
+
+    fn empty_config() -> Config {
+        build_config(
+            repository_without_policy(),
+            MockCacheService::new(),
+            MockStorageService::new(),
+        )
+    }
+
+    fn create_request(length: u64, filetype: &str) -> test::TestRequest {
+        test::TestRequest::post()
+            .uri(UPLOADS)
+            .insert_header(("Tus-Resumable", "1.0.0"))
+            .insert_header(("Upload-Length", length.to_string()))
+            .insert_header(("Upload-Metadata", metadata(filetype)))
+    }
+
+    fn patch_request(location: &str, offset: u64, chunk: &'static [u8]) -> test::TestRequest {
+        test::TestRequest::patch()
+            .uri(location)
+            .insert_header(("Tus-Resumable", "1.0.0"))
+            .insert_header(("Content-Type", "application/offset+octet-stream"))
+            .insert_header(("Upload-Offset", offset.to_string()))
+            .set_payload(chunk)
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_creates_term_once_complete() {
+        let mut repository = repository_without_policy();
+        repository
+            .expect_get_latest_term_for_group()
+            .with(eq("legal"))
+            .returning(|_| Ok(None));
+        repository
+            .expect_create_term()
+            .withf(|term| {
+                term.change_summaries.len() == 1
+                    && term.change_summaries[0].locale == "en"
+                    && term.change_summaries[0].markdown == "Shorter **retention**"
+            })
+            .times(1)
+            .returning(|mut term| {
+                term.id = 10;
+                Ok(term)
+            });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_invalidate_cache_for_group()
+            .with(eq("legal"))
+            .returning(|_| Ok(()));
+
+        let mut storage = MockStorageService::new();
+        storage
+            .expect_upload_file()
+            .withf(|path, content_type, group, version| {
+                std::fs::read(path).unwrap() == b"# Terms\n"
+                    && content_type == "text/markdown"
+                    && group == "legal"
+                    && *version == 1
+            })
+            .times(1)
+            .returning(|_, _, _, _| Ok("legal/v1.md".to_string()));
+        storage.expect_publish_file().returning(|_, _| Ok(()));
+        storage
+            .expect_get_file_url()
+            .returning(|_| Ok("https://files/legal/v1.md".to_string()));
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository, cache, storage)))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response =
+            test::call_service(&app, create_request(8, "text/markdown").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers().get("Tus-Resumable").unwrap(), "1.0.0");
+
+        let location = response
+            .headers()
+            .get("Location")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let response =
+            test::call_service(&app, patch_request(&location, 0, b"# Te").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(response.headers().get("Upload-Offset").unwrap(), "4");
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::default()
+                .method(Method::HEAD)
+                .uri(&location)
+                .insert_header(("Tus-Resumable", "1.0.0"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers().get("Upload-Offset").unwrap(), "4");
+        assert_eq!(response.headers().get("Upload-Length").unwrap(), "8");
+
+        let response =
+            test::call_service(&app, patch_request(&location, 4, b"rms\n").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+        assert_eq!(response.headers().get("Upload-Offset").unwrap(), "8");
+
+        let response =
+            test::call_service(&app, patch_request(&location, 8, b"").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_rejects_offset_mismatch() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(empty_config()))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response =
+            test::call_service(&app, create_request(8, "application/pdf").to_request()).await;
+        let location = response
+            .headers()
+            .get("Location")
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .to_string();
+
+        let response =
+            test::call_service(&app, patch_request(&location, 4, b"-1.4").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_rejects_non_pdf_and_oversized_uploads() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(empty_config()))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(&app, create_request(8, "text/plain").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let response =
+            test::call_service(&app, create_request(4096, "application/pdf").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_enforces_group_policy_on_creation() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_group()
+            .returning(|name| Ok(Some(registered_group(name))));
+        repository
+            .expect_get_upload_policy()
+            .with(eq("legal"))
+            .returning(|group| {
+                Ok(Some(UploadPolicy {
+                    group: group.to_string(),
+                    content_types: vec!["text/markdown".to_string()],
+                    max_size: 1024,
+                    required_fields: vec!["info".to_string()],
+                    metadata_schema: None,
+                }))
+            });
+
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(
+                    repository,
+                    MockCacheService::new(),
+                    MockStorageService::new(),
+                )))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response =
+            test::call_service(&app, create_request(8, "text/markdown").to_request()).await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        assert!(response.headers().get("Location").is_none());
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(body["detail"], "Terms of group 'legal' must include info");
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_rejects_metadata_that_is_not_a_json_object() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(empty_config()))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(UPLOADS)
+                .insert_header(("Tus-Resumable", "1.0.0"))
+                .insert_header(("Upload-Length", "8"))
+                .insert_header((
+                    "Upload-Metadata",
+                    format!(
+                        "{},metadata {}",
+                        metadata("application/pdf"),
+                        STANDARD.encode("[\"eu\"]")
+                    ),
+                ))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(
+            body["detail"],
+            "Upload-Metadata value of metadata must be a JSON object"
+        );
+    }
+
+    #[actix_web::test]
+    async fn resumable_upload_rejects_malformed_clauses() {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(empty_config()))
+                .app_data(web::Data::new(temp_store(1024)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::post()
+                .uri(UPLOADS)
+                .insert_header(("Tus-Resumable", "1.0.0"))
+                .insert_header(("Upload-Length", "8"))
+                .insert_header((
+                    "Upload-Metadata",
+                    format!(
+                        "{},clauses {}",
+                        metadata("application/pdf"),
+                        STANDARD.encode("{\"key\":\"marketing-emails\"}")
+                    ),
+                ))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body: Value = test::read_body_json(response).await;
+        assert_eq!(
+            body["detail"],
+            "Upload-Metadata value of clauses must be a JSON array of clauses"
+        );
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    io,
+    path::PathBuf,
+    sync::Mutex,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use domain::entities::TermMetadata;
+use serde::{Deserialize, Serialize};
+use tokio::{fs, io::AsyncWriteExt};
+
+use crate::actix::v1::payload::ClausePayload;
+
+/// Properties of a resumable upload, announced when it is created.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UploadInfo {
+    pub length: u64,
+    pub group: String,
+    pub info: Option<String>,
+    pub content_type: String,
+    /// Unix timestamp in seconds.
+    pub created_at: u64,
+    /// Markdown summaries of what changed, keyed by locale.
+    #[serde(default)]
+    pub change_summaries: BTreeMap<String, String>,
+    #[serde(default)]
+    pub metadata: TermMetadata,
+    #[serde(default)]
+    pub clauses: Vec<ClausePayload>,
+}
+
+/// Keeps partial uploads on local disk until they are complete.
+///
+/// Every upload is stored as `{id}.bin` with its content received so far and
+/// `{id}.json` with its [`UploadInfo`], so uploads survive restarts of the service.
+/// The offset of an upload is the size of its content file.
+#[derive(Debug)]
+pub struct UploadStore {
+    root: PathBuf,
+    pub max_size: u64,
+    pub expiry: Duration,
+    locked: Mutex<HashSet<String>>,
+}
+
+/// Marks an upload as being written, released when dropped.
+pub struct UploadLock<'a> {
+    store: &'a UploadStore,
+    id: String,
+}
+
+impl Drop for UploadLock<'_> {
+    fn drop(&mut self) {
+        self.store.locked.lock().unwrap().remove(&self.id);
+    }
+}
+
+impl UploadStore {
+    pub fn new(root: PathBuf, max_size: u64, expiry: Duration) -> Self {
+        Self {
+            root,
+            max_size,
+            expiry,
+            locked: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let root = std::env::var("RESUMABLE_UPLOAD_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| std::env::temp_dir().join("terms-of-use-uploads"));
+        let max_size = std::env::var("RESUMABLE_UPLOAD_MAX_SIZE")
+            .unwrap_or_else(|_| "104857600".to_string()) // 100 MiB
+            .parse()
+            .expect("RESUMABLE_UPLOAD_MAX_SIZE must be a valid u64");
+        let expiry_hours: u64 = std::env::var("RESUMABLE_UPLOAD_EXPIRY_HOURS")
+            .unwrap_or_else(|_| "24".to_string())
+            .parse()
+            .expect("RESUMABLE_UPLOAD_EXPIRY_HOURS must be a valid u64");
+
+        Self::new(root, max_size, Duration::from_secs(expiry_hours * 3600))
+    }
+
+    /// Registers a new, empty upload and returns its id. Expired uploads are removed first.
+    pub async fn create(&self, info: &UploadInfo) -> io::Result<String> {
+        fs::create_dir_all(&self.root).await?;
+
+        self.remove_expired().await;
+
+        let id = uuid::Uuid::new_v4().simple().to_string();
+
+        fs::write(self.info_path(&id), serde_json::to_vec(info)?).await?;
+        fs::write(self.data_path(&id), b"").await?;
+
+        Ok(id)
+    }
+
+    /// Returns the upload info, `None` for unknown or malformed ids.
+    pub async fn info(&self, id: &str) -> io::Result<Option<UploadInfo>> {
+        if uuid::Uuid::try_parse(id).is_err() {
+            return Ok(None);
+        }
+
+        match fs::read(self.info_path(id)).await {
+            Ok(content) => Ok(Some(serde_json::from_slice(&content)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub async fn offset(&self, id: &str) -> io::Result<u64> {
+        Ok(fs::metadata(self.data_path(id)).await?.len())
+    }
+
+    /// Appends a chunk to the content of an upload.
+    pub async fn append(&self, id: &str, chunk: &[u8]) -> io::Result<()> {
+        let mut file = fs::OpenOptions::new()
+            .append(true)
+            .open(self.data_path(id))
+            .await?;
+
+        file.write_all(chunk).await?;
+        file.flush().await
+    }
+
+    /// Cuts the content of an upload back to `offset`, dropping a rejected chunk.
+    pub async fn truncate(&self, id: &str, offset: u64) -> io::Result<()> {
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .open(self.data_path(id))
+            .await?;
+
+        file.set_len(offset).await
+    }
+
+    pub async fn remove(&self, id: &str) {
+        let _ = fs::remove_file(self.data_path(id)).await;
+        let _ = fs::remove_file(self.info_path(id)).await;
+    }
+
+    /// Locks an upload for writing, `None` if another request is writing it.
+    pub fn lock(&self, id: &str) -> Option<UploadLock<'_>> {
+        if !self.locked.lock().unwrap().insert(id.to_string()) {
+            return None;
+        }
+
+        Some(UploadLock {
+            store: self,
+            id: id.to_string(),
+        })
+    }
+
+    pub fn expires_at(&self, info: &UploadInfo) -> SystemTime {
+        UNIX_EPOCH + Duration::from_secs(info.created_at) + self.expiry
+    }
+
+    pub fn data_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{id}.bin"))
+    }
+
+    fn info_path(&self, id: &str) -> PathBuf {
+        self.root.join(format!("{id}.json"))
+    }
+
+    async fn remove_expired(&self) {
+        let Ok(mut entries) = fs::read_dir(&self.root).await else {
+            return;
+        };
+
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+
+            let Some(id) = path.file_stem().and_then(|stem| stem.to_str()) else {
+                continue;
+            };
+
+            if let Ok(Some(info)) = self.info(id).await
+                && self.expires_at(&info) < SystemTime::now()
+                && self.lock(id).is_some()
+            {
+                self.remove(id).await;
+            }
+        }
+    }
+}
+
+/// Current time as a Unix timestamp in seconds.
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+pub fn temp_store(max_size: u64) -> UploadStore {
+    UploadStore::new(
+        std::env::temp_dir().join(format!("resumable-uploads-{}", uuid::Uuid::new_v4())),
+        max_size,
+        Duration::from_secs(3600),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{UploadInfo, temp_store, unix_now};
+
+    fn upload_info(created_at: u64) -> UploadInfo {
+        UploadInfo {
+            length: 8,
+            group: "privacy-policy".to_string(),
+            info: None,
+            content_type: "application/pdf".to_string(),
+            created_at,
+            change_summaries: BTreeMap::new(),
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    #[tokio::test]
+    async fn appends_chunks_and_tracks_offset() {
+        let store = temp_store(1024);
+
+        let id = store.create(&upload_info(unix_now())).await.unwrap();
+        store.append(&id, b"%PDF").await.unwrap();
+        store.append(&id, b"-1.4").await.unwrap();
+
+        assert_eq!(store.offset(&id).await.unwrap(), 8);
+        assert_eq!(
+            store.info(&id).await.unwrap().unwrap().group,
+            "privacy-policy"
+        );
+
+        store.truncate(&id, 4).await.unwrap();
+        assert_eq!(store.offset(&id).await.unwrap(), 4);
+
+        store.remove(&id).await;
+        assert!(store.info(&id).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_ids() {
+        let store = temp_store(1024);
+
+        assert!(store.info("../secrets").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn locks_upload_once() {
+        let store = temp_store(1024);
+
+        let lock = store.lock("upload");
+
+        assert!(lock.is_some());
+        assert!(store.lock("upload").is_none());
+
+        drop(lock);
+        assert!(store.lock("upload").is_some());
+    }
+
+    #[tokio::test]
+    async fn removes_expired_uploads_on_create() {
+        let store = temp_store(1024);
+
+        let expired = store.create(&upload_info(0)).await.unwrap();
+        let fresh = store.create(&upload_info(unix_now())).await.unwrap();
+
+        assert!(store.info(&expired).await.unwrap().is_none());
+        assert!(store.info(&fresh).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,194 @@
+use actix_web::{
+    HttpResponse, get,
+    web::{self, Path},
+};
+use domain::use_cases::get_consent_status_use_case;
+
+use crate::{
+    actix::{error::response::ProblemDetails, v2::response::HasConsentedResponse},
+    config::Config,
+};
+
+pub fn configure(cfg: &mut web::ServiceConfig) {
+    cfg.service(web::scope("/v2/terms-of-use").service(get_consent_status));
+}
+
+#[tracing::instrument(skip(config, path))]
+#[get("/has-consent/{group}/{user_id}")]
+async fn get_consent_status(
+    path: Path<(String, i32)>,
+    config: web::Data<Config>,
+) -> Result<HttpResponse, ProblemDetails> {
+    let (group, user_id) = path.into_inner();
+
+    let status = get_consent_status_use_case(
+        config.repository.as_ref(),
+        config.cache.as_ref(),
+        user_id,
+        &group,
+    )
+    .await?;
+
+    Ok(HttpResponse::Ok().json(HasConsentedResponse::from(status)))
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{App, http::StatusCode, test, web};
+    use chrono::{TimeDelta, Utc};
+    use domain::entities::{ConsentStatus, Group, TermOfUse};
+    use mockall::predicate::eq;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+
+    use crate::{Config, actix::v2::controller::configure, mocks::*};
+
+    fn build_config(repository: MockDatabaseRepository, cache: MockCacheService) -> Config {
+        Config {
+            repository: Arc::new(repository),
+            cache: Arc::new(cache),
+            storage: Arc::new(MockStorageService::new()),
+            publisher: Arc::new(MockPublisherService::new()),
+            scanner: Arc::new(clean_scanner()),
+            documents: Arc::new(sample_documents()),
+            schemas: Arc::new(valid_schemas()),
+        }
+    }
+
+    fn term(id: i32, version: u32) -> TermOfUse {
+        TermOfUse {
+            id,
+            group: "privacy-policy".to_string(),
+            url: format!("privacy-policy/v{version}.pdf"),
+            version,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        }
+    }
+
+    async fn consent_status(repository: MockDatabaseRepository, cache: MockCacheService) -> Value {
+        let app = test::init_service(
+            App::new()
+                .app_data(web::Data::new(build_config(repository, cache)))
+                .configure(configure),
+        )
+        .await;
+
+        let response = test::call_service(
+            &app,
+            test::TestRequest::get()
+                .uri("/v2/terms-of-use/has-consent/privacy-policy/42")
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(response.status(), StatusCode::OK);
+
+        test::read_body_json(response).await
+    }
+
+    #[actix_web::test]
+    async fn has_consent_reports_older_versions() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(3, 2))));
+        repository
+            .expect_get_agreed_at()
+            .with(eq(42), eq(3))
+            .returning(|_, _| Ok(None));
+        repository
+            .expect_get_terms_for_group()
+            .returning(|_, _| Ok(vec![term(3, 2), term(1, 1)]));
+        repository
+            .expect_get_agreed_term_ids()
+            .returning(|_, _| Ok(vec![1]));
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(42),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentedToOlder { version: 1 }),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let body = consent_status(repository, cache).await;
+
+        assert_eq!(
+            body,
+            json!({
+                "hasConsented": false,
+                "status": "consented_to_older",
+                "consentedVersion": 1
+            })
+        );
+    }
+
+    #[actix_web::test]
+    async fn has_consent_reports_expired_consents() {
+        let mut repository = MockDatabaseRepository::new();
+        repository
+            .expect_get_latest_term_for_group()
+            .returning(|_| Ok(Some(term(3, 2))));
+        repository
+            .expect_get_agreed_at()
+            .with(eq(42), eq(3))
+            .returning(|_, _| Ok(Some(Utc::now().naive_utc() - TimeDelta::days(400))));
+        repository.expect_get_group().returning(|name| {
+            Ok(Some(Group {
+                max_consent_age_days: Some(365),
+                ..registered_group(name)
+            }))
+        });
+
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(None));
+        cache
+            .expect_store_user_agreement()
+            .with(
+                eq(42),
+                eq("privacy-policy"),
+                eq(ConsentStatus::ConsentExpired),
+                eq(None),
+            )
+            .times(1)
+            .returning(|_, _, _, _| Ok(()));
+
+        let body = consent_status(repository, cache).await;
+
+        assert_eq!(
+            body,
+            json!({ "hasConsented": false, "status": "consent_expired" })
+        );
+    }
+
+    #[actix_web::test]
+    async fn has_consent_reports_groups_without_terms() {
+        let mut cache = MockCacheService::new();
+        cache
+            .expect_find_user_agreement()
+            .returning(|_, _| Ok(Some(ConsentStatus::NoTermsPublished)));
+
+        let body = consent_status(MockDatabaseRepository::new(), cache).await;
+
+        assert_eq!(
+            body,
+            json!({ "hasConsented": false, "status": "no_terms_published" })
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use domain::entities::ConsentStatus;
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HasConsentedResponse {
+    pub has_consented: bool,
+    pub status: &'static str,
+    /// Newest version the user agreed to, when it is not the latest.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub consented_version: Option<u32>,
+}
+
+impl From<ConsentStatus> for HasConsentedResponse {
+    fn from(status: ConsentStatus) -> Self {
+        HasConsentedResponse {
+            has_consented: status.has_consented(),
+            status: match status {
+                ConsentStatus::ConsentedToLatest => "consented_to_latest",
+                ConsentStatus::ConsentedToOlder { .. } => "consented_to_older",
+                ConsentStatus::ConsentExpired => "consent_expired",
+                ConsentStatus::NeverConsented => "never_consented",
+                ConsentStatus::NoTermsPublished => "no_terms_published",
+            },
+            consented_version: match status {
+                ConsentStatus::ConsentedToOlder { version } => Some(version),
+                _ => None,
+            },
+        }
+    }
+}
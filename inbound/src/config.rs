@@ -2,7 +2,8 @@ use std::{collections::HashMap, sync::Arc};
 
 use domain::data::{
     CacheServiceWithHealthCheck, DatabaseRepositoryWithHealthCheck,
-    PublisherServiceWithHealthCheck, StorageServiceWithHealthCheck,
+    PublisherServiceWithHealthCheck, ScannerServiceWithHealthCheck, StorageServiceWithHealthCheck,
+    service::{DocumentService, SchemaService},
 };
 use tokio::join;
 
@@ -12,6 +13,9 @@ pub struct Config {
     pub cache: Arc<dyn CacheServiceWithHealthCheck>,
     pub storage: Arc<dyn StorageServiceWithHealthCheck>,
     pub publisher: Arc<dyn PublisherServiceWithHealthCheck>,
+    pub scanner: Arc<dyn ScannerServiceWithHealthCheck>,
+    pub documents: Arc<dyn DocumentService>,
+    pub schemas: Arc<dyn SchemaService>,
 }
 
 impl Config {
@@ -20,29 +24,37 @@ impl Config {
         cache: Arc<dyn CacheServiceWithHealthCheck>,
         storage: Arc<dyn StorageServiceWithHealthCheck>,
         publisher: Arc<dyn PublisherServiceWithHealthCheck>,
+        scanner: Arc<dyn ScannerServiceWithHealthCheck>,
+        documents: Arc<dyn DocumentService>,
+        schemas: Arc<dyn SchemaService>,
     ) -> Self {
         Config {
             repository,
             cache,
             storage,
             publisher,
+            scanner,
+            documents,
+            schemas,
         }
     }
 
     pub async fn ping(&self) -> HashMap<&'static str, bool> {
         let mut results = HashMap::new();
 
-        let (repository, cache, storage, publisher) = join!(
+        let (repository, cache, storage, publisher, scanner) = join!(
             self.repository.ping(),
             self.cache.ping(),
             self.storage.ping(),
             self.publisher.ping(),
+            self.scanner.ping(),
         );
 
         results.insert("repository", repository.is_ok());
         results.insert("cache", cache.is_ok());
         results.insert("storage", storage.is_ok());
         results.insert("publisher", publisher.is_ok());
+        results.insert("scanner", scanner.is_ok());
 
         results
     }
@@ -68,6 +80,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
@@ -76,6 +91,9 @@ mod tests {
         assert!(Arc::strong_count(&config.cache) >= 1);
         assert!(Arc::strong_count(&config.storage) >= 1);
         assert!(Arc::strong_count(&config.publisher) >= 1);
+        assert!(Arc::strong_count(&config.scanner) >= 1);
+        assert!(Arc::strong_count(&config.documents) >= 1);
+        assert!(Arc::strong_count(&config.schemas) >= 1);
     }
 
     #[tokio::test]
@@ -97,6 +115,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
@@ -106,7 +127,8 @@ mod tests {
         assert_eq!(results.get("cache"), Some(&true));
         assert_eq!(results.get("storage"), Some(&true));
         assert_eq!(results.get("publisher"), Some(&true));
-        assert_eq!(results.len(), 4);
+        assert_eq!(results.get("scanner"), Some(&true));
+        assert_eq!(results.len(), 5);
     }
 
     #[tokio::test]
@@ -130,6 +152,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
@@ -162,6 +187,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
@@ -196,6 +224,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
@@ -207,6 +238,42 @@ mod tests {
         assert_eq!(results.get("publisher"), Some(&true));
     }
 
+    #[tokio::test]
+    async fn ping_returns_false_for_unhealthy_scanner() {
+        let mut repository = MockDatabaseRepository::new();
+        repository.expect_ping().returning(|| Ok(()));
+
+        let mut cache = MockCacheService::new();
+        cache.expect_ping().returning(|| Ok(()));
+
+        let mut storage = MockStorageService::new();
+        storage.expect_ping().returning(|| Ok(()));
+
+        let mut publisher = MockPublisherService::new();
+        publisher.expect_ping().returning(|| Ok(()));
+
+        let mut scanner = MockScannerService::new();
+        scanner
+            .expect_ping()
+            .returning(|| Err(TermsOfUseError::InternalServerError));
+
+        let config = Config::new(
+            Arc::new(repository),
+            Arc::new(cache),
+            Arc::new(storage),
+            Arc::new(publisher),
+            Arc::new(scanner),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
+        )
+        .await;
+
+        let results = config.ping().await;
+
+        assert_eq!(results.get("repository"), Some(&true));
+        assert_eq!(results.get("scanner"), Some(&false));
+    }
+
     #[tokio::test]
     async fn config_is_cloneable() {
         let repository = MockDatabaseRepository::new();
@@ -219,6 +286,9 @@ mod tests {
             Arc::new(cache),
             Arc::new(storage),
             Arc::new(publisher),
+            Arc::new(clean_scanner()),
+            Arc::new(sample_documents()),
+            Arc::new(valid_schemas()),
         )
         .await;
 
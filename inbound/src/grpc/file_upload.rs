@@ -1,4 +1,4 @@
-use std::path::PathBuf;
+use std::{io::ErrorKind, path::PathBuf};
 
 use tokio::fs::File;
 use tonic::Status;
@@ -18,9 +18,28 @@ pub async fn create_temp_file() -> Result<(File, PathBuf), Status> {
     Ok((file, file_path))
 }
 
+/// Removes the temporary upload once the request is done, whatever its outcome.
+pub struct TempFileGuard(PathBuf);
+
+impl TempFileGuard {
+    pub fn new(path: PathBuf) -> Self {
+        Self(path)
+    }
+}
+
+impl Drop for TempFileGuard {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.0)
+            && e.kind() != ErrorKind::NotFound
+        {
+            error!("Failed to remove temp file {}: {e}", self.0.display());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::create_temp_file;
+    use super::{TempFileGuard, create_temp_file};
 
     #[tokio::test]
     async fn test_create_temp_file_success() {
@@ -35,4 +54,14 @@ mod tests {
         drop(file);
         let _ = tokio::fs::remove_file(&path).await;
     }
+
+    #[tokio::test]
+    async fn test_temp_file_guard_removes_file() {
+        let (file, path) = create_temp_file().await.unwrap();
+        drop(file);
+
+        drop(TempFileGuard::new(path.clone()));
+
+        assert!(!path.exists());
+    }
 }
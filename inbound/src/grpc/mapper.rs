@@ -1,7 +1,23 @@
-use domain::{entities::TermOfUse, errors::TermsOfUseError};
+use domain::{
+    dto::{ConsentAtDTO, DiffHunk, DiffLine, DiffOperation, TermDiffDTO},
+    entities::{
+        Bundle, ChangeSummary as ChangeSummaryEntity, Clause as ClauseEntity, Group,
+        PdfMetadata as PdfMetadataEntity, TermMetadata, TermOfUse, UploadPolicy, UserAgreement,
+    },
+    errors::TermsOfUseError,
+};
 use tonic::Status;
 
-use crate::grpc::{CreateTermResponse, get_latest_terms_response::TermContent};
+use crate::grpc::{
+    BundleResponse, ChangeSummary, Clause, CreateGroupRequest, CreateTermResponse,
+    GetConsentAtResponse, GetTermDiffResponse, GroupResponse, PdfMetadata, SaveBundleRequest,
+    UpdateGroupRequest, UploadPolicyResponse,
+    get_consent_at_response::Agreement,
+    get_latest_terms_response::TermContent,
+    get_pending_terms_response::PendingTerm,
+    get_term_diff_response::{Hunk, Line, Operation},
+    get_term_history_response::TermVersion,
+};
 
 pub trait ToStatus {
     fn to_status(&self) -> Status;
@@ -12,6 +28,61 @@ impl ToStatus for TermsOfUseError {
         match self {
             TermsOfUseError::InternalServerError => Status::internal("Internal server error"),
             TermsOfUseError::NotFound => Status::not_found("Terms of use not found"),
+            TermsOfUseError::Validation(detail) => Status::invalid_argument(detail),
+        }
+    }
+}
+
+/// Metadata travels as JSON text, protobuf having no map of arbitrary values.
+fn metadata_to_json(metadata: TermMetadata) -> String {
+    serde_json::Value::Object(metadata).to_string()
+}
+
+/// Reads metadata sent as JSON text, which must be an object when present.
+pub fn parse_metadata(json: Option<&str>) -> Result<TermMetadata, Status> {
+    match json.filter(|json| !json.is_empty()) {
+        Some(json) => serde_json::from_str(json)
+            .map_err(|_| Status::invalid_argument("Metadata must be a JSON object")),
+        None => Ok(TermMetadata::new()),
+    }
+}
+
+impl From<ChangeSummaryEntity> for ChangeSummary {
+    fn from(summary: ChangeSummaryEntity) -> Self {
+        ChangeSummary {
+            locale: summary.locale,
+            markdown: summary.markdown,
+            html: summary.html,
+        }
+    }
+}
+
+impl From<PdfMetadataEntity> for PdfMetadata {
+    fn from(metadata: PdfMetadataEntity) -> Self {
+        PdfMetadata {
+            page_count: metadata.page_count,
+            title: metadata.title,
+            producer: metadata.producer,
+        }
+    }
+}
+
+impl From<ClauseEntity> for Clause {
+    fn from(clause: ClauseEntity) -> Self {
+        Clause {
+            key: clause.key,
+            title: clause.title,
+            mandatory: clause.mandatory,
+        }
+    }
+}
+
+impl From<Clause> for ClauseEntity {
+    fn from(clause: Clause) -> Self {
+        ClauseEntity {
+            key: clause.key,
+            title: clause.title,
+            mandatory: clause.mandatory,
         }
     }
 }
@@ -23,6 +94,60 @@ impl From<TermOfUse> for TermContent {
             group: term.group,
             url: term.url,
             info: term.info,
+            html: term.html,
+            change_summaries: term.change_summaries.into_iter().map(Into::into).collect(),
+            pdf_metadata: term.pdf_metadata.map(Into::into),
+            metadata: metadata_to_json(term.metadata),
+            clauses: term.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TermOfUse> for TermVersion {
+    fn from(term: TermOfUse) -> Self {
+        TermVersion {
+            id: term.id,
+            version: term.version,
+            url: term.url,
+            info: term.info,
+            created_at: term.created_at.and_utc().to_rfc3339(),
+            change_summaries: term.change_summaries.into_iter().map(Into::into).collect(),
+            pdf_metadata: term.pdf_metadata.map(Into::into),
+            metadata: metadata_to_json(term.metadata),
+            clauses: term.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<UserAgreement> for Agreement {
+    fn from(agreement: UserAgreement) -> Self {
+        Agreement {
+            user_id: agreement.user_id,
+            term_id: agreement.term_id,
+            agreed_at: agreement.agreed_at.and_utc().to_rfc3339(),
+            accepted_clauses: agreement.accepted_clauses,
+        }
+    }
+}
+
+impl From<ConsentAtDTO> for GetConsentAtResponse {
+    fn from(consent: ConsentAtDTO) -> Self {
+        GetConsentAtResponse {
+            group: consent.term.group.clone(),
+            has_consented: consent.has_consented,
+            term: Some(consent.term.into()),
+            agreement: consent.agreement.map(Into::into),
+        }
+    }
+}
+
+impl From<TermOfUse> for PendingTerm {
+    fn from(term: TermOfUse) -> Self {
+        PendingTerm {
+            group: term.group,
+            term_id: term.id,
+            version: term.version,
+            url: term.url,
         }
     }
 }
@@ -34,6 +159,120 @@ impl From<TermOfUse> for CreateTermResponse {
             group: term.group,
             url: term.url,
             info: term.info,
+            pdf_metadata: term.pdf_metadata.map(Into::into),
+            metadata: metadata_to_json(term.metadata),
+            clauses: term.clauses.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<DiffLine> for Line {
+    fn from(line: DiffLine) -> Self {
+        let operation = match line.operation {
+            DiffOperation::Equal => Operation::Equal,
+            DiffOperation::Insert => Operation::Insert,
+            DiffOperation::Delete => Operation::Delete,
+        };
+
+        Line {
+            operation: operation.into(),
+            old_line: line.old_line.map(|number| number as u32),
+            new_line: line.new_line.map(|number| number as u32),
+            text: line.text,
+        }
+    }
+}
+
+impl From<DiffHunk> for Hunk {
+    fn from(hunk: DiffHunk) -> Self {
+        Hunk {
+            old_start: hunk.old_start as u32,
+            old_lines: hunk.old_lines as u32,
+            new_start: hunk.new_start as u32,
+            new_lines: hunk.new_lines as u32,
+            lines: hunk.lines.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<TermDiffDTO> for GetTermDiffResponse {
+    fn from(diff: TermDiffDTO) -> Self {
+        GetTermDiffResponse {
+            group: diff.group,
+            from_version: diff.from_version,
+            to_version: diff.to_version,
+            hunks: diff.hunks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<UploadPolicy> for UploadPolicyResponse {
+    fn from(policy: UploadPolicy) -> Self {
+        UploadPolicyResponse {
+            group: policy.group,
+            content_types: policy.content_types,
+            max_size: policy.max_size,
+            required_fields: policy.required_fields,
+            metadata_schema: policy.metadata_schema.map(|schema| schema.to_string()),
+        }
+    }
+}
+
+impl From<Group> for GroupResponse {
+    fn from(group: Group) -> Self {
+        GroupResponse {
+            name: group.name,
+            description: group.description,
+            owner: group.owner,
+            mandatory: group.mandatory,
+            default_locale: group.default_locale,
+            max_consent_age_days: group.max_consent_age_days,
+        }
+    }
+}
+
+impl From<CreateGroupRequest> for Group {
+    fn from(request: CreateGroupRequest) -> Self {
+        Group {
+            name: request.name,
+            description: request.description,
+            owner: request.owner,
+            mandatory: request.mandatory,
+            default_locale: request.default_locale.unwrap_or_else(|| "en".to_string()),
+            max_consent_age_days: request.max_consent_age_days,
+        }
+    }
+}
+
+impl From<UpdateGroupRequest> for Group {
+    fn from(request: UpdateGroupRequest) -> Self {
+        Group {
+            name: request.name,
+            description: request.description,
+            owner: request.owner,
+            mandatory: request.mandatory,
+            default_locale: request.default_locale.unwrap_or_else(|| "en".to_string()),
+            max_consent_age_days: request.max_consent_age_days,
+        }
+    }
+}
+
+impl From<Bundle> for BundleResponse {
+    fn from(bundle: Bundle) -> Self {
+        BundleResponse {
+            name: bundle.name,
+            description: bundle.description,
+            groups: bundle.groups,
+        }
+    }
+}
+
+impl From<SaveBundleRequest> for Bundle {
+    fn from(request: SaveBundleRequest) -> Self {
+        Bundle {
+            name: request.name,
+            description: request.description,
+            groups: request.groups,
         }
     }
 }
@@ -41,11 +280,17 @@ impl From<TermOfUse> for CreateTermResponse {
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
-    use domain::{entities::TermOfUse, errors::TermsOfUseError};
+    use domain::{
+        entities::{ChangeSummary, Clause, PdfMetadata, TermOfUse},
+        errors::TermsOfUseError,
+    };
     use tonic::Code;
 
     use crate::grpc::{
-        CreateTermResponse, get_latest_terms_response::TermContent, mapper::ToStatus,
+        CreateTermResponse,
+        get_latest_terms_response::TermContent,
+        get_term_history_response::TermVersion,
+        mapper::{ToStatus, parse_metadata},
     };
 
     #[test]
@@ -68,6 +313,16 @@ mod tests {
         assert!(status.message().contains("Internal server error"));
     }
 
+    #[test]
+    fn test_to_status_validation() {
+        let error = TermsOfUseError::Validation("The document hash does not match".to_string());
+
+        let status = error.to_status();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "The document hash does not match");
+    }
+
     #[test]
     fn test_term_of_use_to_term_content() {
         let term = TermOfUse {
@@ -77,6 +332,12 @@ mod tests {
             url: "uploads/privacy-v3.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: Some("Latest privacy policy".to_string()),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let term_content: TermContent = term.clone().into();
@@ -96,6 +357,23 @@ mod tests {
             url: "uploads/cookie-v2.pdf".to_string(),
             created_at: Utc::now().naive_utc(),
             info: Some("Updated cookie policy".to_string()),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: Some(PdfMetadata {
+                page_count: 2,
+                title: Some("Cookie Policy".to_string()),
+                producer: None,
+            }),
+            metadata: serde_json::json!({ "region": "eu" })
+                .as_object()
+                .cloned()
+                .unwrap(),
+            clauses: vec![Clause {
+                key: "marketing-emails".to_string(),
+                title: "Marketing emails".to_string(),
+                mandatory: false,
+            }],
         };
 
         let response: CreateTermResponse = term.clone().into();
@@ -104,5 +382,59 @@ mod tests {
         assert_eq!(response.group, term.group);
         assert_eq!(response.url, term.url);
         assert_eq!(response.info, term.info);
+
+        let pdf_metadata = response.pdf_metadata.unwrap();
+        assert_eq!(pdf_metadata.page_count, 2);
+        assert_eq!(pdf_metadata.title.as_deref(), Some("Cookie Policy"));
+        assert_eq!(response.metadata, r#"{"region":"eu"}"#);
+        assert_eq!(response.clauses[0].key, "marketing-emails");
+        assert!(!response.clauses[0].mandatory);
+    }
+
+    #[test]
+    fn test_term_of_use_to_term_version() {
+        let term = TermOfUse {
+            id: 7,
+            group: "privacy-policy".to_string(),
+            version: 4,
+            url: "https://cdn.example.com/privacy-v4.pdf".to_string(),
+            created_at: Utc::now().naive_utc(),
+            info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![ChangeSummary {
+                locale: "en".to_string(),
+                markdown: "Shorter **retention**".to_string(),
+                html: "<p>Shorter <strong>retention</strong></p>".to_string(),
+            }],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
+        };
+
+        let version: TermVersion = term.clone().into();
+
+        assert_eq!(version.id, term.id);
+        assert_eq!(version.version, term.version);
+        assert_eq!(version.url, term.url);
+        assert_eq!(version.change_summaries.len(), 1);
+        assert_eq!(version.change_summaries[0].locale, "en");
+        assert_eq!(
+            version.change_summaries[0].html,
+            "<p>Shorter <strong>retention</strong></p>"
+        );
+    }
+
+    #[test]
+    fn test_parse_metadata_requires_a_json_object() {
+        assert!(parse_metadata(None).unwrap().is_empty());
+        assert_eq!(
+            parse_metadata(Some(r#"{"region":"eu"}"#)).unwrap()["region"],
+            "eu"
+        );
+
+        let status = parse_metadata(Some(r#"["eu"]"#)).unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
     }
 }
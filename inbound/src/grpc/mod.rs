@@ -16,6 +16,7 @@ mod file_upload;
 mod health_check;
 mod mapper;
 mod server;
+mod v2;
 
 #[cfg(test)]
 mod tests;
@@ -34,7 +35,8 @@ pub async fn start_grpc_server(config: Config) -> Result<(), impl Error> {
 
     server
         .add_service(HealthServer::from_arc(service.clone()))
-        .add_service(TermsOfUseServiceServer::from_arc(service))
+        .add_service(TermsOfUseServiceServer::from_arc(service.clone()))
+        .add_service(v2::terms_of_use_service_server::TermsOfUseServiceServer::from_arc(service))
         .serve(addr)
         .await
         .map_err(|e| {
@@ -2,9 +2,16 @@ use std::sync::Arc;
 
 use domain::{
     dto::CreateTermOfUseDTO,
+    entities::UploadPolicy,
     use_cases::{
-        create_term_of_use_use_case, create_user_agreement_use_case, get_latest_term_use_case,
-        has_user_agreed_to_term_use_case,
+        create_bundle_agreement_use_case, create_group_use_case, create_term_of_use_use_case,
+        create_user_agreement_use_case, delete_bundle_use_case, delete_group_use_case,
+        diff_terms_use_case, get_bundle_use_case, get_consent_at_use_case, get_group_use_case,
+        get_latest_term_use_case, get_pending_bundle_groups_use_case, get_pending_terms_use_case,
+        get_term_history_use_case, get_upload_policy_use_case, has_user_accepted_clause_use_case,
+        has_user_agreed_to_term_use_case, has_user_agreed_to_version_use_case,
+        list_bundles_use_case, list_groups_use_case, parse_metadata_filter, parse_point_in_time,
+        save_bundle_use_case, set_upload_policy_use_case, update_group_use_case,
     },
 };
 use tokio::io::AsyncWriteExt;
@@ -14,12 +21,19 @@ use tracing::{debug, error, info};
 use crate::{
     config::Config,
     grpc::{
-        CreateConsentRequest, CreateTermRequest, CreateTermResponse, GetLatestTermsRequest,
-        GetLatestTermsResponse, HasConsentResponse, HasConsentedRequest,
+        BundleResponse, CreateBundleConsentRequest, CreateConsentRequest, CreateGroupRequest,
+        CreateTermRequest, CreateTermResponse, DeleteBundleRequest, DeleteGroupRequest,
+        GetBundleRequest, GetConsentAtRequest, GetConsentAtResponse, GetGroupRequest,
+        GetLatestTermsRequest, GetLatestTermsResponse, GetPendingTermsRequest,
+        GetPendingTermsResponse, GetTermDiffRequest, GetTermDiffResponse, GetTermHistoryRequest,
+        GetTermHistoryResponse, GetUploadPolicyRequest, GroupResponse, HasBundleConsentRequest,
+        HasBundleConsentResponse, HasConsentResponse, HasConsentedRequest, ListBundlesResponse,
+        ListGroupsResponse, SaveBundleRequest, SetUploadPolicyRequest, UpdateGroupRequest,
+        UploadPolicyResponse,
         create_term_request::{CreateTermContent, CreateTermData},
         file_upload,
         get_latest_terms_response::TermOfUseContent,
-        mapper::ToStatus,
+        mapper::{ToStatus, parse_metadata},
         terms_of_use_service_server::TermsOfUseService,
     },
 };
@@ -43,13 +57,41 @@ impl TermsOfUseService for GrpcService {
     ) -> Result<Response<HasConsentResponse>, Status> {
         let request = request.into_inner();
 
-        let result = has_user_agreed_to_term_use_case(
-            self.config.repository.as_ref(),
-            self.config.cache.as_ref(),
-            request.user_id,
-            &request.group,
-        )
-        .await
+        let result = match (request.clause.as_deref(), request.min_version) {
+            (Some(_), Some(_)) => {
+                return Err(Status::invalid_argument(
+                    "A clause can only be checked on the latest version",
+                ));
+            }
+            (Some(clause), None) => {
+                has_user_accepted_clause_use_case(
+                    self.config.repository.as_ref(),
+                    request.user_id,
+                    &request.group,
+                    clause,
+                )
+                .await
+            }
+            (None, Some(min_version)) => {
+                has_user_agreed_to_version_use_case(
+                    self.config.repository.as_ref(),
+                    self.config.cache.as_ref(),
+                    request.user_id,
+                    &request.group,
+                    min_version,
+                )
+                .await
+            }
+            (None, None) => {
+                has_user_agreed_to_term_use_case(
+                    self.config.repository.as_ref(),
+                    self.config.cache.as_ref(),
+                    request.user_id,
+                    &request.group,
+                )
+                .await
+            }
+        }
         .map_err(|e| e.to_status())?;
 
         Ok(Response::new(HasConsentResponse {
@@ -64,7 +106,7 @@ impl TermsOfUseService for GrpcService {
     ) -> Result<Response<GetLatestTermsResponse>, Status> {
         let request = request.into_inner();
 
-        let terms = get_latest_term_use_case(
+        let mut terms = get_latest_term_use_case(
             self.config.repository.as_ref(),
             self.config.cache.as_ref(),
             self.config.storage.as_ref(),
@@ -73,10 +115,14 @@ impl TermsOfUseService for GrpcService {
         .await
         .map_err(|e| e.to_status())?;
 
+        if !request.include_html {
+            terms.html = None;
+        }
+
         Ok(Response::new(GetLatestTermsResponse {
             term_of_use_content: Some(match request.only_url {
                 true => TermOfUseContent::Url(terms.url),
-                false => TermOfUseContent::Term(terms.into()),
+                false => TermOfUseContent::Term(Box::new(terms.into())),
             }),
         }))
     }
@@ -94,6 +140,7 @@ impl TermsOfUseService for GrpcService {
             self.config.publisher.as_ref(),
             request.user_id,
             request.term_id,
+            &request.accepted_clauses,
         )
         .await
         .map_err(|e| e.to_status())?;
@@ -108,6 +155,7 @@ impl TermsOfUseService for GrpcService {
     ) -> Result<Response<CreateTermResponse>, Status> {
         let mut create_term_data: Option<CreateTermData> = None;
         let (mut file, file_path) = file_upload::create_temp_file().await?;
+        let _temp_file = file_upload::TempFileGuard::new(file_path.clone());
 
         let mut stream = request.into_inner();
 
@@ -134,6 +182,13 @@ impl TermsOfUseService for GrpcService {
                 }
             }
         }
+        // Writes of the async file are still pending until flushed
+        file.flush().await.map_err(|e| {
+            error!("Failed to flush temp file: {e}");
+
+            Status::internal(format!("Failed to flush temp file: {e}"))
+        })?;
+
         let data = match create_term_data {
             Some(data) => data,
             None => {
@@ -145,9 +200,15 @@ impl TermsOfUseService for GrpcService {
             self.config.repository.as_ref(),
             self.config.storage.as_ref(),
             self.config.cache.as_ref(),
+            self.config.scanner.as_ref(),
+            self.config.documents.as_ref(),
+            self.config.schemas.as_ref(),
             CreateTermOfUseDTO {
                 group: data.group,
                 info: data.info,
+                change_summaries: data.change_summaries.into_iter().collect(),
+                metadata: parse_metadata(data.metadata.as_deref())?,
+                clauses: data.clauses.into_iter().map(Into::into).collect(),
             },
             &file_path,
             &data.content_type,
@@ -157,4 +218,300 @@ impl TermsOfUseService for GrpcService {
 
         Ok(Response::new(CreateTermResponse::from(term)))
     }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_term_diff(
+        &self,
+        request: Request<GetTermDiffRequest>,
+    ) -> Result<Response<GetTermDiffResponse>, Status> {
+        let request = request.into_inner();
+
+        let diff = diff_terms_use_case(
+            self.config.repository.as_ref(),
+            self.config.documents.as_ref(),
+            &request.group,
+            request.from_version,
+            request.to_version,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GetTermDiffResponse::from(diff)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_term_history(
+        &self,
+        request: Request<GetTermHistoryRequest>,
+    ) -> Result<Response<GetTermHistoryResponse>, Status> {
+        let request = request.into_inner();
+
+        let terms = get_term_history_use_case(
+            self.config.repository.as_ref(),
+            self.config.storage.as_ref(),
+            &request.group,
+            &parse_metadata_filter(request.metadata),
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GetTermHistoryResponse {
+            group: request.group,
+            versions: terms.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_pending_terms(
+        &self,
+        request: Request<GetPendingTermsRequest>,
+    ) -> Result<Response<GetPendingTermsResponse>, Status> {
+        let request = request.into_inner();
+
+        let terms = get_pending_terms_use_case(
+            self.config.repository.as_ref(),
+            self.config.cache.as_ref(),
+            self.config.storage.as_ref(),
+            request.user_id,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GetPendingTermsResponse {
+            terms: terms.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_consent_at(
+        &self,
+        request: Request<GetConsentAtRequest>,
+    ) -> Result<Response<GetConsentAtResponse>, Status> {
+        let request = request.into_inner();
+
+        let at = parse_point_in_time(&request.at).map_err(|e| e.to_status())?;
+
+        let consent = get_consent_at_use_case(
+            self.config.repository.as_ref(),
+            self.config.storage.as_ref(),
+            request.user_id,
+            &request.group,
+            at,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(consent.into()))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_upload_policy(
+        &self,
+        request: Request<GetUploadPolicyRequest>,
+    ) -> Result<Response<UploadPolicyResponse>, Status> {
+        let request = request.into_inner();
+
+        let policy = get_upload_policy_use_case(self.config.repository.as_ref(), &request.group)
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(UploadPolicyResponse::from(policy)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn set_upload_policy(
+        &self,
+        request: Request<SetUploadPolicyRequest>,
+    ) -> Result<Response<UploadPolicyResponse>, Status> {
+        let request = request.into_inner();
+
+        let policy = set_upload_policy_use_case(
+            self.config.repository.as_ref(),
+            self.config.schemas.as_ref(),
+            UploadPolicy {
+                group: request.group,
+                content_types: request.content_types,
+                max_size: request.max_size,
+                required_fields: request.required_fields,
+                metadata_schema: request
+                    .metadata_schema
+                    .as_deref()
+                    .map(serde_json::from_str)
+                    .transpose()
+                    .map_err(|_| Status::invalid_argument("The metadata schema must be JSON"))?,
+            },
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(UploadPolicyResponse::from(policy)))
+    }
+
+    #[tracing::instrument(skip(self, _request))]
+    async fn list_groups(
+        &self,
+        _request: Request<()>,
+    ) -> Result<Response<ListGroupsResponse>, Status> {
+        let groups = list_groups_use_case(self.config.repository.as_ref())
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(ListGroupsResponse {
+            groups: groups.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_group(
+        &self,
+        request: Request<GetGroupRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let request = request.into_inner();
+
+        let group = get_group_use_case(self.config.repository.as_ref(), &request.name)
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GroupResponse::from(group)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn create_group(
+        &self,
+        request: Request<CreateGroupRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let group =
+            create_group_use_case(self.config.repository.as_ref(), request.into_inner().into())
+                .await
+                .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GroupResponse::from(group)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn update_group(
+        &self,
+        request: Request<UpdateGroupRequest>,
+    ) -> Result<Response<GroupResponse>, Status> {
+        let group = update_group_use_case(
+            self.config.repository.as_ref(),
+            self.config.cache.as_ref(),
+            request.into_inner().into(),
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(GroupResponse::from(group)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn delete_group(
+        &self,
+        request: Request<DeleteGroupRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+
+        delete_group_use_case(self.config.repository.as_ref(), &request.name)
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(()))
+    }
+
+    #[tracing::instrument(skip(self, _request))]
+    async fn list_bundles(
+        &self,
+        _request: Request<()>,
+    ) -> Result<Response<ListBundlesResponse>, Status> {
+        let bundles = list_bundles_use_case(self.config.repository.as_ref())
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(ListBundlesResponse {
+            bundles: bundles.into_iter().map(Into::into).collect(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn get_bundle(
+        &self,
+        request: Request<GetBundleRequest>,
+    ) -> Result<Response<BundleResponse>, Status> {
+        let request = request.into_inner();
+
+        let bundle = get_bundle_use_case(self.config.repository.as_ref(), &request.name)
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(BundleResponse::from(bundle)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn save_bundle(
+        &self,
+        request: Request<SaveBundleRequest>,
+    ) -> Result<Response<BundleResponse>, Status> {
+        let bundle =
+            save_bundle_use_case(self.config.repository.as_ref(), request.into_inner().into())
+                .await
+                .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(BundleResponse::from(bundle)))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn delete_bundle(
+        &self,
+        request: Request<DeleteBundleRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+
+        delete_bundle_use_case(self.config.repository.as_ref(), &request.name)
+            .await
+            .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(()))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn create_bundle_consent(
+        &self,
+        request: Request<CreateBundleConsentRequest>,
+    ) -> Result<Response<()>, Status> {
+        let request = request.into_inner();
+
+        create_bundle_agreement_use_case(
+            self.config.repository.as_ref(),
+            self.config.cache.as_ref(),
+            self.config.publisher.as_ref(),
+            request.user_id,
+            &request.bundle,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(()))
+    }
+
+    #[tracing::instrument(skip(self, request))]
+    async fn has_bundle_consent(
+        &self,
+        request: Request<HasBundleConsentRequest>,
+    ) -> Result<Response<HasBundleConsentResponse>, Status> {
+        let request = request.into_inner();
+
+        let pending_groups = get_pending_bundle_groups_use_case(
+            self.config.repository.as_ref(),
+            self.config.cache.as_ref(),
+            request.user_id,
+            &request.bundle,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(HasBundleConsentResponse {
+            has_consented: pending_groups.is_empty(),
+            pending_groups,
+        }))
+    }
 }
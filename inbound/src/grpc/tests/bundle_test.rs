@@ -0,0 +1,176 @@
+use chrono::Utc;
+use domain::{
+    entities::{Bundle, TermOfUse},
+    errors::TermsOfUseError,
+};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        CreateBundleConsentRequest, HasBundleConsentRequest, SaveBundleRequest,
+        server::GrpcService, terms_of_use_service_server::TermsOfUseService,
+        tests::create_test_config,
+    },
+    mocks::{MockCacheService, MockDatabaseRepository, MockPublisherService},
+};
+
+const BUNDLE: &str = "sign-up";
+
+fn sign_up() -> Bundle {
+    Bundle {
+        name: BUNDLE.to_string(),
+        description: None,
+        groups: vec!["terms-of-service".to_string(), "privacy-policy".to_string()],
+    }
+}
+
+fn repository_with_sign_up() -> MockDatabaseRepository {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_bundle()
+        .with(eq(BUNDLE))
+        .returning(|_| Ok(Some(sign_up())));
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .returning(|group| {
+            Ok(Some(TermOfUse {
+                id: if group == "terms-of-service" { 1 } else { 2 },
+                group: group.to_string(),
+                url: format!("{group}/v1.pdf"),
+                version: 1,
+                info: None,
+                created_at: Utc::now().naive_utc(),
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
+            }))
+        });
+    // Groups are only read for their consent expiry
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+    mock_repo
+}
+
+#[tokio::test]
+async fn test_save_bundle_rejects_unregistered_groups() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+    mock_repo.expect_save_bundle().times(0);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .save_bundle(Request::new(SaveBundleRequest {
+            name: BUNDLE.to_string(),
+            description: None,
+            groups: vec!["terms-of-service".to_string()],
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_create_bundle_consent_success() {
+    let mut mock_repo = repository_with_sign_up();
+    mock_repo
+        .expect_get_agreed_term_ids()
+        .returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_create_user_agreements()
+        .withf(|user_id, term_ids| *user_id == 42 && term_ids == [1, 2])
+        .times(1)
+        .returning(|_, _| Ok(()));
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_store_user_agreement()
+        .times(2)
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut mock_publisher = MockPublisherService::new();
+    mock_publisher
+        .expect_publish_agreement()
+        .times(2)
+        .returning(|_| Ok(()));
+
+    let config = create_test_config(
+        Some(mock_repo),
+        Some(mock_cache),
+        None,
+        Some(mock_publisher),
+    );
+    let service = GrpcService::new(config);
+
+    let response = service
+        .create_bundle_consent(Request::new(CreateBundleConsentRequest {
+            user_id: 42,
+            bundle: BUNDLE.to_string(),
+        }))
+        .await;
+
+    assert!(response.is_ok());
+}
+
+#[tokio::test]
+async fn test_create_bundle_consent_propagates_storage_failure() {
+    let mut mock_repo = repository_with_sign_up();
+    mock_repo
+        .expect_get_agreed_term_ids()
+        .returning(|_, _| Ok(vec![]));
+    mock_repo
+        .expect_create_user_agreements()
+        .returning(|_, _| Err(TermsOfUseError::InternalServerError));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .create_bundle_consent(Request::new(CreateBundleConsentRequest {
+            user_id: 42,
+            bundle: BUNDLE.to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
+
+#[tokio::test]
+async fn test_has_bundle_consent_lists_pending_groups() {
+    let mut mock_repo = repository_with_sign_up();
+    mock_repo
+        .expect_get_agreed_at()
+        .returning(|_, term_id| Ok((term_id == 2).then(|| Utc::now().naive_utc())));
+    mock_repo
+        .expect_get_terms_for_group()
+        .returning(|_, _| Ok(vec![]));
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(None));
+    mock_cache
+        .expect_store_user_agreement()
+        .returning(|_, _, _, _| Ok(()));
+
+    let config = create_test_config(Some(mock_repo), Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .has_bundle_consent(Request::new(HasBundleConsentRequest {
+            user_id: 42,
+            bundle: BUNDLE.to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert!(!response.has_consented);
+    assert_eq!(response.pending_groups, ["terms-of-service"]);
+}
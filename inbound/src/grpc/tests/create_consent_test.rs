@@ -1,4 +1,8 @@
-use domain::{entities::TermOfUse, errors::TermsOfUseError};
+use chrono::{TimeDelta, Utc};
+use domain::{
+    entities::{ConsentStatus, Group, TermOfUse},
+    errors::TermsOfUseError,
+};
 use mockall::predicate::*;
 use tonic::{Code, Request};
 
@@ -7,9 +11,26 @@ use crate::{
         CreateConsentRequest, server::GrpcService, terms_of_use_service_server::TermsOfUseService,
         tests::create_test_config,
     },
-    mocks::{MockCacheService, MockDatabaseRepository, MockPublisherService},
+    mocks::{MockCacheService, MockDatabaseRepository, MockPublisherService, registered_group},
 };
 
+fn term(id: i32, group: &str) -> TermOfUse {
+    TermOfUse {
+        id,
+        group: group.to_string(),
+        version: 1,
+        url: "uploads/privacy-v1.pdf".to_string(),
+        created_at: Utc::now().naive_utc(),
+        info: None,
+        html: None,
+        text: None,
+        change_summaries: vec![],
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
 #[tokio::test]
 async fn test_create_consent_success() {
     const USER_ID: i32 = 100;
@@ -17,32 +38,44 @@ async fn test_create_consent_success() {
     const GROUP: &str = "privacy-policy";
 
     let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .with(eq(GROUP))
+        .returning(|_| Ok(Some(term(TERM_ID, GROUP))));
     mock_repo
         .expect_get_term_by_id()
         .with(eq(TERM_ID))
         .times(1)
-        .returning(move |_| {
-            Ok(Some(TermOfUse {
-                id: TERM_ID,
-                group: GROUP.to_string(),
-                version: 1,
-                url: "uploads/privacy-v1.pdf".to_string(),
-                created_at: chrono::Utc::now().naive_utc(),
-                info: None,
-            }))
-        });
+        .returning(move |_| Ok(Some(term(TERM_ID, GROUP))));
     mock_repo
         .expect_create_user_agreement()
-        .with(eq(USER_ID), eq(TERM_ID))
+        .with(eq(USER_ID), eq(TERM_ID), always())
         .times(1)
-        .returning(|_, _| Ok(()));
+        .returning(|_, _, _| Ok(()));
+    mock_repo
+        .expect_get_agreed_at()
+        .with(eq(USER_ID), eq(TERM_ID))
+        .returning(|_, _| Ok(Some(Utc::now().naive_utc())));
+    mock_repo.expect_get_group().with(eq(GROUP)).returning(|_| {
+        Ok(Some(Group {
+            max_consent_age_days: Some(30),
+            ..registered_group(GROUP)
+        }))
+    });
 
     let mut mock_cache = MockCacheService::new();
     mock_cache
         .expect_store_user_agreement()
-        .with(eq(USER_ID), eq(GROUP), eq(true))
+        .withf(|user_id, group, status, expires_at| {
+            *user_id == USER_ID
+                && group == GROUP
+                && *status == ConsentStatus::ConsentedToLatest
+                && expires_at.is_some_and(|expires_at| {
+                    expires_at > Utc::now().naive_utc() + TimeDelta::days(29)
+                })
+        })
         .times(1)
-        .returning(|_, _, _| Ok(()));
+        .returning(|_, _, _, _| Ok(()));
 
     let mut mock_publisher = MockPublisherService::new();
     mock_publisher
@@ -61,6 +94,7 @@ async fn test_create_consent_success() {
     let request = Request::new(CreateConsentRequest {
         user_id: USER_ID,
         term_id: TERM_ID,
+        accepted_clauses: vec![],
     });
 
     let response = service.create_consent(request).await;
@@ -86,6 +120,7 @@ async fn test_create_consent_term_not_found() {
     let request = Request::new(CreateConsentRequest {
         user_id: USER_ID,
         term_id: TERM_ID,
+        accepted_clauses: vec![],
     });
 
     let response = service.create_consent(request).await;
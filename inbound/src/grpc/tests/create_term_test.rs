@@ -1,5 +1,10 @@
+use std::{collections::HashMap, sync::Arc};
+
 use chrono::Utc;
-use domain::{entities::TermOfUse, errors::TermsOfUseError};
+use domain::{
+    entities::{ScanVerdict, TermOfUse, UploadPolicy},
+    errors::TermsOfUseError,
+};
 use mockall::predicate::eq;
 use tokio::{net::TcpStream, sync::oneshot, time};
 use tonic::transport::Server;
@@ -13,9 +18,24 @@ use crate::{
         terms_of_use_service_server::TermsOfUseServiceServer,
         tests::create_test_config,
     },
-    mocks::{MockCacheService, MockDatabaseRepository, MockStorageService},
+    mocks::{
+        MockCacheService, MockDatabaseRepository, MockScannerService, MockStorageService,
+        registered_group,
+    },
 };
 
+const SAMPLE_PDF: &[u8] = include_bytes!("../../../../example/sample.pdf");
+
+/// Repository of registered groups that have no upload policy of their own.
+fn repository_without_policy() -> MockDatabaseRepository {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .returning(|name| Ok(Some(registered_group(name))));
+    mock_repo.expect_get_upload_policy().returning(|_| Ok(None));
+    mock_repo
+}
+
 async fn spawn_test_server(service: GrpcService) -> (String, oneshot::Sender<()>) {
     let (tx, rx) = oneshot::channel();
 
@@ -52,32 +72,50 @@ async fn test_create_term_client_success() {
     const GROUP: &str = "privacy-policy";
     const INFO: &str = "Privacy policy v1";
     const CONTENT_TYPE: &str = "application/pdf";
-    const CONTENT: &[u8] = b"PDF content here";
+    const CONTENT: &[u8] = SAMPLE_PDF;
     const CONTENT_SIZE: u64 = CONTENT.len() as u64;
     const TERM_ID: i32 = 100;
 
-    let mut mock_repo = MockDatabaseRepository::new();
+    let mut mock_repo = repository_without_policy();
     mock_repo
         .expect_get_latest_term_for_group()
         .with(eq(GROUP))
         .times(1)
         .returning(|_| Ok(None));
-    mock_repo.expect_create_term().times(1).returning(move |_| {
-        Ok(TermOfUse {
-            id: TERM_ID,
-            group: GROUP.to_string(),
-            version: 1,
-            url: "uploads/privacy-v1.pdf".to_string(),
-            created_at: Utc::now().naive_utc(),
-            info: Some(INFO.to_string()),
+    mock_repo
+        .expect_create_term()
+        .withf(|term| {
+            term.change_summaries.len() == 1
+                && term.change_summaries[0].locale == "en"
+                && term.change_summaries[0].markdown == "Initial **release**"
         })
-    });
+        .times(1)
+        .returning(move |_| {
+            Ok(TermOfUse {
+                id: TERM_ID,
+                group: GROUP.to_string(),
+                version: 1,
+                url: "uploads/privacy-v1.pdf".to_string(),
+                created_at: Utc::now().naive_utc(),
+                info: Some(INFO.to_string()),
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
+            })
+        });
 
     let mut mock_storage = MockStorageService::new();
     mock_storage
         .expect_upload_file()
         .times(1)
-        .returning(|_, _| Ok("uploads/privacy-v1.pdf".to_string()));
+        .returning(|_, _, _, _| Ok("uploads/privacy-v1.pdf".to_string()));
+    mock_storage
+        .expect_publish_file()
+        .times(1)
+        .returning(|_, _| Ok(()));
     mock_storage
         .expect_get_file_url()
         .times(1)
@@ -102,6 +140,12 @@ async fn test_create_term_client_success() {
                 info: Some(INFO.to_string()),
                 content_type: CONTENT_TYPE.to_string(),
                 content_size: CONTENT_SIZE,
+                change_summaries: HashMap::from([(
+                    "en".to_string(),
+                    "Initial **release**".to_string(),
+                )]),
+                metadata: None,
+                clauses: vec![],
             })),
         },
         CreateTermRequest {
@@ -126,14 +170,14 @@ async fn test_create_term_client_success() {
 #[tokio::test]
 async fn test_create_term_client_multiple_chunks() {
     const GROUP: &str = "terms-of-service";
-    const CONTENT_TYPE: &str = "text/plain";
+    const CONTENT_TYPE: &str = "text/markdown";
     const CHUNK1: &[u8] = b"First chunk";
     const CHUNK2: &[u8] = b"Second chunk";
     const CHUNK3: &[u8] = b"Third chunk";
     const CONTENT_SIZE: u64 = (CHUNK1.len() + CHUNK2.len() + CHUNK3.len()) as u64;
     const TERM_ID: i32 = 200;
 
-    let mut mock_repo = MockDatabaseRepository::new();
+    let mut mock_repo = repository_without_policy();
     mock_repo
         .expect_get_latest_term_for_group()
         .with(eq(GROUP))
@@ -145,9 +189,15 @@ async fn test_create_term_client_multiple_chunks() {
             id: TERM_ID,
             group: GROUP.to_string(),
             version: 1,
-            url: "uploads/tos-v1.txt".to_string(),
+            url: "uploads/tos-v1.md".to_string(),
             created_at: Utc::now().naive_utc(),
             info: None,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         })
     });
 
@@ -155,12 +205,16 @@ async fn test_create_term_client_multiple_chunks() {
     mock_storage
         .expect_upload_file()
         .times(1)
-        .returning(|_, _| Ok("uploads/tos-v1.txt".to_string()));
+        .returning(|_, _, _, _| Ok("uploads/tos-v1.md".to_string()));
 
+    mock_storage
+        .expect_publish_file()
+        .times(1)
+        .returning(|_, _| Ok(()));
     mock_storage
         .expect_get_file_url()
         .times(1)
-        .returning(|_| Ok("https://storage.example.com/uploads/tos-v1.txt".to_string()));
+        .returning(|_| Ok("https://storage.example.com/uploads/tos-v1.md".to_string()));
 
     let mut mock_cache = MockCacheService::new();
     mock_cache
@@ -181,6 +235,9 @@ async fn test_create_term_client_multiple_chunks() {
                 info: None,
                 content_type: CONTENT_TYPE.to_string(),
                 content_size: CONTENT_SIZE,
+                change_summaries: HashMap::new(),
+                metadata: None,
+                clauses: vec![],
             })),
         },
         CreateTermRequest {
@@ -232,10 +289,10 @@ async fn test_create_term_client_no_data_error() {
 async fn test_create_term_client_use_case_error() {
     const GROUP: &str = "error-group";
     const CONTENT_TYPE: &str = "application/pdf";
-    const CONTENT: &[u8] = b"PDF content";
+    const CONTENT: &[u8] = SAMPLE_PDF;
     const CONTENT_SIZE: u64 = CONTENT.len() as u64;
 
-    let mut mock_repo = MockDatabaseRepository::new();
+    let mut mock_repo = repository_without_policy();
     mock_repo
         .expect_get_latest_term_for_group()
         .with(eq(GROUP))
@@ -251,7 +308,7 @@ async fn test_create_term_client_use_case_error() {
     mock_storage
         .expect_upload_file()
         .times(1)
-        .returning(|_, _| Ok("uploads/error.pdf".to_string()));
+        .returning(|_, _, _, _| Ok("uploads/error.pdf".to_string()));
     mock_storage
         .expect_delete_file()
         .times(1)
@@ -270,6 +327,9 @@ async fn test_create_term_client_use_case_error() {
                 info: None,
                 content_type: CONTENT_TYPE.to_string(),
                 content_size: CONTENT_SIZE,
+                change_summaries: HashMap::new(),
+                metadata: None,
+                clauses: vec![],
             })),
         },
         CreateTermRequest {
@@ -284,3 +344,153 @@ async fn test_create_term_client_use_case_error() {
 
     shutdown.send(()).ok();
 }
+
+#[tokio::test]
+async fn test_create_term_client_rejects_corrupted_pdf() {
+    let mut mock_storage = MockStorageService::new();
+    mock_storage.expect_upload_file().times(0);
+
+    let config = create_test_config(
+        Some(repository_without_policy()),
+        None,
+        Some(mock_storage),
+        None,
+    );
+    let service = GrpcService::new(config);
+    let (url, shutdown) = spawn_test_server(service).await;
+
+    let mut client = TermsOfUseServiceClient::connect(url).await.unwrap();
+
+    let messages = vec![
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Data(CreateTermData {
+                group: "corrupted-group".to_string(),
+                info: None,
+                content_type: "application/pdf".to_string(),
+                content_size: 11,
+                change_summaries: HashMap::new(),
+                metadata: None,
+                clauses: vec![],
+            })),
+        },
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Chunk(b"PDF content".to_vec())),
+        },
+    ];
+
+    let response = client.create_term(tokio_stream::iter(messages)).await;
+
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+
+    shutdown.send(()).ok();
+}
+
+#[tokio::test]
+async fn test_create_term_client_rejects_infected_document() {
+    let mut mock_storage = MockStorageService::new();
+    mock_storage.expect_upload_file().times(0);
+
+    let mut mock_scanner = MockScannerService::new();
+    mock_scanner
+        .expect_scan_file()
+        .times(1)
+        .returning(|_| Ok(ScanVerdict::Infected("Eicar-Signature".to_string())));
+
+    let mut config = create_test_config(
+        Some(repository_without_policy()),
+        None,
+        Some(mock_storage),
+        None,
+    );
+    Arc::get_mut(&mut config).unwrap().scanner = Arc::new(mock_scanner);
+    let service = GrpcService::new(config);
+    let (url, shutdown) = spawn_test_server(service).await;
+
+    let mut client = TermsOfUseServiceClient::connect(url).await.unwrap();
+
+    let messages = vec![
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Data(CreateTermData {
+                group: "infected-group".to_string(),
+                info: None,
+                content_type: "application/pdf".to_string(),
+                content_size: SAMPLE_PDF.len() as u64,
+                change_summaries: HashMap::new(),
+                metadata: None,
+                clauses: vec![],
+            })),
+        },
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Chunk(SAMPLE_PDF.to_vec())),
+        },
+    ];
+
+    let response = client.create_term(tokio_stream::iter(messages)).await;
+
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert!(status.message().contains("Eicar-Signature"));
+
+    shutdown.send(()).ok();
+}
+
+#[tokio::test]
+async fn test_create_term_client_enforces_group_policy() {
+    const GROUP: &str = "pdf-only-group";
+
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .returning(|name| Ok(Some(registered_group(name))));
+    mock_repo
+        .expect_get_upload_policy()
+        .with(eq(GROUP))
+        .returning(|group| {
+            Ok(Some(UploadPolicy {
+                group: group.to_string(),
+                content_types: vec!["application/pdf".to_string()],
+                max_size: 2_000_000,
+                required_fields: vec![],
+                metadata_schema: None,
+            }))
+        });
+    mock_repo.expect_create_term().times(0);
+
+    let mut mock_storage = MockStorageService::new();
+    mock_storage.expect_upload_file().times(0);
+
+    let config = create_test_config(Some(mock_repo), None, Some(mock_storage), None);
+    let service = GrpcService::new(config);
+    let (url, shutdown) = spawn_test_server(service).await;
+
+    let mut client = TermsOfUseServiceClient::connect(url).await.unwrap();
+
+    let messages = vec![
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Data(CreateTermData {
+                group: GROUP.to_string(),
+                info: None,
+                content_type: "text/markdown".to_string(),
+                content_size: 8,
+                change_summaries: HashMap::new(),
+                metadata: None,
+                clauses: vec![],
+            })),
+        },
+        CreateTermRequest {
+            create_term_content: Some(CreateTermContent::Chunk(b"# Terms\n".to_vec())),
+        },
+    ];
+
+    let response = client.create_term(tokio_stream::iter(messages)).await;
+
+    let status = response.unwrap_err();
+    assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    assert_eq!(
+        status.message(),
+        "Documents of group 'pdf-only-group' must be one of: application/pdf"
+    );
+
+    shutdown.send(()).ok();
+}
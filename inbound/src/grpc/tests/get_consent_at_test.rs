@@ -0,0 +1,86 @@
+use domain::{entities::TermOfUse, use_cases::parse_point_in_time};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        GetConsentAtRequest, server::GrpcService, terms_of_use_service_server::TermsOfUseService,
+        tests::create_test_config,
+    },
+    mocks::{MockDatabaseRepository, MockStorageService},
+};
+
+const USER_ID: i32 = 123;
+const GROUP: &str = "privacy-policy";
+
+fn term(version: u32, created_at: &str) -> TermOfUse {
+    TermOfUse {
+        id: version as i32,
+        group: GROUP.to_string(),
+        url: format!("uploads/privacy-v{version}.pdf"),
+        version,
+        info: None,
+        created_at: parse_point_in_time(created_at).unwrap(),
+        html: None,
+        text: None,
+        change_summaries: vec![],
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
+fn request(at: &str) -> Request<GetConsentAtRequest> {
+    Request::new(GetConsentAtRequest {
+        user_id: USER_ID,
+        group: GROUP.to_string(),
+        at: at.to_string(),
+    })
+}
+
+#[tokio::test]
+async fn test_get_consent_at_without_agreement() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_get_terms_for_group().returning(|_, _| {
+        Ok(vec![
+            term(2, "2025-06-01T00:00:00Z"),
+            term(1, "2025-01-01T00:00:00Z"),
+        ])
+    });
+    mock_repo
+        .expect_get_agreement_at()
+        .with(eq(USER_ID), eq(1), always())
+        .returning(|_, _, _| Ok(None));
+
+    let mut mock_storage = MockStorageService::new();
+    mock_storage
+        .expect_get_file_url()
+        .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+    let config = create_test_config(Some(mock_repo), None, Some(mock_storage), None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .get_consent_at(request("2025-03-01T00:00:00Z"))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.group, GROUP);
+    assert!(!response.has_consented);
+    assert_eq!(response.term.unwrap().version, 1);
+    assert!(response.agreement.is_none());
+}
+
+#[tokio::test]
+async fn test_get_consent_at_invalid_timestamp() {
+    let config = create_test_config(None, None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .get_consent_at(request("1 March 2025"))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
@@ -31,6 +31,12 @@ async fn test_get_latest_terms_success_full_content() {
                 url: TERM_URL.to_string(),
                 created_at: Utc::now().naive_utc(),
                 info: Some(TERM_INFO.to_string()),
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
             }))
         });
 
@@ -40,6 +46,7 @@ async fn test_get_latest_terms_success_full_content() {
     let request = Request::new(GetLatestTermsRequest {
         group: GROUP.to_string(),
         only_url: false,
+        include_html: false,
     });
 
     let response = service.get_latest_terms(request).await;
@@ -58,6 +65,53 @@ async fn test_get_latest_terms_success_full_content() {
     }
 }
 
+#[tokio::test]
+async fn test_get_latest_terms_includes_html_on_request() {
+    const GROUP: &str = "privacy-policy";
+    const TERM_HTML: &str = "<h1>Privacy policy</h1>";
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_get_latest_term_for_group()
+        .with(eq(GROUP))
+        .returning(move |_| {
+            Ok(Some(domain::entities::TermOfUse {
+                id: 5,
+                group: GROUP.to_string(),
+                version: 1,
+                url: "uploads/privacy-v1.md".to_string(),
+                created_at: Utc::now().naive_utc(),
+                info: None,
+                html: Some(TERM_HTML.to_string()),
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
+            }))
+        });
+
+    let config = create_test_config(None, Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetLatestTermsRequest {
+        group: GROUP.to_string(),
+        only_url: false,
+        include_html: true,
+    });
+
+    let response = service
+        .get_latest_terms(request)
+        .await
+        .unwrap()
+        .into_inner();
+
+    match response.term_of_use_content.unwrap() {
+        TermOfUseContent::Term(term) => assert_eq!(term.html, Some(TERM_HTML.to_string())),
+        TermOfUseContent::Url(_) => panic!("Expected Term, got Url"),
+    }
+}
+
 #[tokio::test]
 async fn test_get_latest_terms_success_url_only() {
     const GROUP: &str = "terms-of-service";
@@ -77,6 +131,12 @@ async fn test_get_latest_terms_success_url_only() {
                 url: TERM_URL.to_string(),
                 created_at: Utc::now().naive_utc(),
                 info: None,
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![],
             }))
         });
 
@@ -86,6 +146,7 @@ async fn test_get_latest_terms_success_url_only() {
     let request = Request::new(GetLatestTermsRequest {
         group: GROUP.to_string(),
         only_url: true,
+        include_html: false,
     });
 
     let response = service.get_latest_terms(request).await;
@@ -124,6 +185,7 @@ async fn test_get_latest_terms_not_found_error() {
     let request = Request::new(GetLatestTermsRequest {
         group: GROUP.to_string(),
         only_url: false,
+        include_html: false,
     });
 
     let response = service.get_latest_terms(request).await;
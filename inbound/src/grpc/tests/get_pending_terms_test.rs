@@ -0,0 +1,128 @@
+use chrono::Utc;
+use domain::{
+    entities::{Group, TermOfUse},
+    errors::TermsOfUseError,
+};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        GetPendingTermsRequest, server::GrpcService,
+        terms_of_use_service_server::TermsOfUseService, tests::create_test_config,
+    },
+    mocks::{MockCacheService, MockDatabaseRepository, MockStorageService, registered_group},
+};
+
+fn mandatory_group(name: &str) -> Group {
+    Group {
+        mandatory: true,
+        ..registered_group(name)
+    }
+}
+
+fn latest_term(id: i32, group: &str) -> TermOfUse {
+    TermOfUse {
+        id,
+        group: group.to_string(),
+        url: format!("uploads/{group}-v3.pdf"),
+        version: 3,
+        info: None,
+        created_at: Utc::now().naive_utc(),
+        html: None,
+        text: None,
+        change_summaries: vec![],
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
+fn empty_cache() -> MockCacheService {
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_get_latest_term_for_group()
+        .returning(|_| Ok(None));
+    mock_cache
+        .expect_store_latest_term_for_group()
+        .returning(|_| Ok(()));
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(None));
+    mock_cache
+        .expect_store_user_agreement()
+        .returning(|_, _, _, _| Ok(()));
+    mock_cache
+}
+
+#[tokio::test]
+async fn test_get_pending_terms_success() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_get_groups().returning(|| {
+        Ok(vec![
+            mandatory_group("terms-of-service"),
+            mandatory_group("privacy-policy"),
+            registered_group("newsletter"),
+        ])
+    });
+    mock_repo
+        .expect_get_latest_terms_for_groups()
+        .times(1)
+        .returning(|_| {
+            Ok(vec![
+                latest_term(10, "terms-of-service"),
+                latest_term(20, "privacy-policy"),
+            ])
+        });
+    mock_repo
+        .expect_get_agreed_term_ids()
+        .with(eq(5), always())
+        .times(1)
+        .returning(|_, _| Ok(vec![10]));
+
+    let mut mock_storage = MockStorageService::new();
+    mock_storage
+        .expect_get_file_url()
+        .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+    let config = create_test_config(
+        Some(mock_repo),
+        Some(empty_cache()),
+        Some(mock_storage),
+        None,
+    );
+    let service = GrpcService::new(config);
+
+    let response = service
+        .get_pending_terms(Request::new(GetPendingTermsRequest { user_id: 5 }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.terms.len(), 1);
+    assert_eq!(response.terms[0].group, "privacy-policy");
+    assert_eq!(response.terms[0].term_id, 20);
+    assert_eq!(response.terms[0].version, 3);
+    assert_eq!(
+        response.terms[0].url,
+        "https://cdn.example.com/uploads/privacy-policy-v3.pdf"
+    );
+}
+
+#[tokio::test]
+async fn test_get_pending_terms_internal_error() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_groups()
+        .returning(|| Err(TermsOfUseError::InternalServerError));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .get_pending_terms(Request::new(GetPendingTermsRequest { user_id: 5 }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
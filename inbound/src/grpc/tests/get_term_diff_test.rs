@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use domain::entities::TermOfUse;
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        GetTermDiffRequest, get_term_diff_response::Operation, server::GrpcService,
+        terms_of_use_service_server::TermsOfUseService, tests::create_test_config,
+    },
+    mocks::{MockDatabaseRepository, replaced_clause_documents},
+};
+
+const GROUP: &str = "privacy-policy";
+
+fn term(version: u32, text: Option<&str>) -> TermOfUse {
+    TermOfUse {
+        id: version as i32,
+        group: GROUP.to_string(),
+        url: format!("uploads/privacy-v{version}.pdf"),
+        version,
+        info: None,
+        created_at: Utc::now().naive_utc(),
+        html: None,
+        text: text.map(str::to_string),
+        change_summaries: vec![],
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
+#[tokio::test]
+async fn test_get_term_diff_success() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_term_by_version()
+        .with(eq(GROUP), eq(1))
+        .returning(|_, _| Ok(Some(term(1, Some("Intro\nOld clause")))));
+    mock_repo
+        .expect_get_term_by_version()
+        .with(eq(GROUP), eq(2))
+        .returning(|_, _| Ok(Some(term(2, Some("Intro\nNew clause")))));
+
+    let mut config = create_test_config(Some(mock_repo), None, None, None);
+    Arc::get_mut(&mut config).unwrap().documents = Arc::new(replaced_clause_documents());
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetTermDiffRequest {
+        group: GROUP.to_string(),
+        from_version: 1,
+        to_version: 2,
+    });
+
+    let response = service.get_term_diff(request).await.unwrap().into_inner();
+
+    assert_eq!(response.group, GROUP);
+    assert_eq!(response.hunks.len(), 1);
+
+    let lines = &response.hunks[0].lines;
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.operation() == Operation::Delete
+                && line.old_line == Some(2)
+                && line.text == "Old clause")
+    );
+    assert!(
+        lines
+            .iter()
+            .any(|line| line.operation() == Operation::Insert
+                && line.new_line == Some(2)
+                && line.text == "New clause")
+    );
+}
+
+#[tokio::test]
+async fn test_get_term_diff_not_found_error() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_term_by_version()
+        .returning(|_, _| Ok(None));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetTermDiffRequest {
+        group: GROUP.to_string(),
+        from_version: 1,
+        to_version: 2,
+    });
+
+    let response = service.get_term_diff(request).await;
+
+    assert_eq!(response.unwrap_err().code(), Code::NotFound);
+}
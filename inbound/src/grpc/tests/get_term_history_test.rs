@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+
+use chrono::Utc;
+use domain::entities::{ChangeSummary, TermMetadata, TermOfUse};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        GetTermHistoryRequest, server::GrpcService, terms_of_use_service_server::TermsOfUseService,
+        tests::create_test_config,
+    },
+    mocks::{MockDatabaseRepository, MockStorageService},
+};
+
+const GROUP: &str = "privacy-policy";
+
+fn term(version: u32, change_summaries: Vec<ChangeSummary>) -> TermOfUse {
+    TermOfUse {
+        id: version as i32,
+        group: GROUP.to_string(),
+        url: format!("uploads/privacy-v{version}.pdf"),
+        version,
+        info: None,
+        created_at: Utc::now().naive_utc(),
+        html: None,
+        text: None,
+        change_summaries,
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
+#[tokio::test]
+async fn test_get_term_history_success() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_terms_for_group()
+        .with(eq(GROUP), eq(TermMetadata::new()))
+        .times(1)
+        .returning(|_, _| {
+            Ok(vec![
+                term(
+                    2,
+                    vec![ChangeSummary {
+                        locale: "en".to_string(),
+                        markdown: "Shorter **retention**".to_string(),
+                        html: "<p>Shorter <strong>retention</strong></p>".to_string(),
+                    }],
+                ),
+                term(1, vec![]),
+            ])
+        });
+
+    let mut mock_storage = MockStorageService::new();
+    mock_storage
+        .expect_get_file_url()
+        .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+    let config = create_test_config(Some(mock_repo), None, Some(mock_storage), None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetTermHistoryRequest {
+        group: GROUP.to_string(),
+        metadata: Default::default(),
+    });
+
+    let response = service
+        .get_term_history(request)
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.group, GROUP);
+    assert_eq!(response.versions.len(), 2);
+    assert_eq!(response.versions[0].version, 2);
+    assert_eq!(
+        response.versions[0].url,
+        "https://cdn.example.com/uploads/privacy-v2.pdf"
+    );
+    assert_eq!(response.versions[0].change_summaries[0].locale, "en");
+    assert!(response.versions[1].change_summaries.is_empty());
+}
+
+#[tokio::test]
+async fn test_get_term_history_not_found_error() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_terms_for_group()
+        .returning(|_, _| Ok(vec![]));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetTermHistoryRequest {
+        group: "unknown".to_string(),
+        metadata: Default::default(),
+    });
+
+    let response = service.get_term_history(request).await;
+
+    assert_eq!(response.unwrap_err().code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_get_term_history_filters_by_metadata() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_terms_for_group()
+        .withf(|group, metadata| {
+            group == GROUP
+                && metadata.len() == 1
+                && metadata.get("year") == Some(&serde_json::Value::from(2025))
+        })
+        .times(1)
+        .returning(|_, _| {
+            Ok(vec![TermOfUse {
+                metadata: serde_json::json!({ "year": 2025 })
+                    .as_object()
+                    .cloned()
+                    .unwrap(),
+                ..term(1, vec![])
+            }])
+        });
+
+    let mut mock_storage = MockStorageService::new();
+    mock_storage
+        .expect_get_file_url()
+        .returning(|key| Ok(format!("https://cdn.example.com/{key}")));
+
+    let config = create_test_config(Some(mock_repo), None, Some(mock_storage), None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(GetTermHistoryRequest {
+        group: GROUP.to_string(),
+        metadata: HashMap::from([("year".to_string(), "2025".to_string())]),
+    });
+
+    let response = service
+        .get_term_history(request)
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.versions.len(), 1);
+    assert_eq!(response.versions[0].metadata, r#"{"year":2025}"#);
+}
@@ -0,0 +1,148 @@
+use domain::entities::Group;
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        CreateGroupRequest, DeleteGroupRequest, GetGroupRequest, server::GrpcService,
+        terms_of_use_service_server::TermsOfUseService, tests::create_test_config,
+    },
+    mocks::{MockDatabaseRepository, registered_group},
+};
+
+const GROUP: &str = "privacy-policy";
+
+#[tokio::test]
+async fn test_create_group_defaults_the_locale() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .with(eq(GROUP))
+        .returning(|_| Ok(None));
+    mock_repo
+        .expect_create_group()
+        .with(eq(Group {
+            name: GROUP.to_string(),
+            description: None,
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: Some(365),
+        }))
+        .times(1)
+        .returning(Ok);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .create_group(Request::new(CreateGroupRequest {
+            name: GROUP.to_string(),
+            description: None,
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: None,
+            max_consent_age_days: Some(365),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.name, GROUP);
+    assert_eq!(response.default_locale, "en");
+    assert_eq!(response.max_consent_age_days, Some(365));
+    assert!(response.mandatory);
+}
+
+#[tokio::test]
+async fn test_create_group_rejects_invalid_locale() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_create_group().times(0);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .create_group(Request::new(CreateGroupRequest {
+            name: GROUP.to_string(),
+            description: None,
+            owner: None,
+            mandatory: false,
+            default_locale: Some("english".to_string()),
+            max_consent_age_days: None,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_list_groups_returns_every_group() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_groups()
+        .returning(|| Ok(vec![registered_group("cookies"), registered_group(GROUP)]));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .list_groups(Request::new(()))
+        .await
+        .unwrap()
+        .into_inner();
+
+    let names: Vec<_> = response
+        .groups
+        .iter()
+        .map(|group| group.name.as_str())
+        .collect();
+    assert_eq!(names, ["cookies", GROUP]);
+}
+
+#[tokio::test]
+async fn test_get_group_not_found() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .get_group(Request::new(GetGroupRequest {
+            name: GROUP.to_string(),
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::NotFound);
+}
+
+#[tokio::test]
+async fn test_delete_group_success() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .returning(|name| Ok(Some(registered_group(name))));
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .returning(|_| Ok(None));
+    mock_repo.expect_get_bundles().returning(|| Ok(vec![]));
+    mock_repo
+        .expect_delete_group()
+        .with(eq(GROUP))
+        .times(1)
+        .returning(|_| Ok(()));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .delete_group(Request::new(DeleteGroupRequest {
+            name: GROUP.to_string(),
+        }))
+        .await;
+
+    assert!(response.is_ok());
+}
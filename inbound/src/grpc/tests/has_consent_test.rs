@@ -1,4 +1,8 @@
-use domain::errors::TermsOfUseError;
+use chrono::Utc;
+use domain::{
+    entities::{Clause, ConsentStatus, TermOfUse},
+    errors::TermsOfUseError,
+};
 use mockall::predicate::*;
 use tonic::{Code, Request};
 
@@ -20,7 +24,7 @@ async fn test_has_consent_success_true() {
         .expect_find_user_agreement()
         .with(eq(USER_ID), eq(GROUP))
         .times(1)
-        .returning(|_, _| Ok(Some(true)));
+        .returning(|_, _| Ok(Some(ConsentStatus::ConsentedToLatest)));
 
     let config = create_test_config(None, Some(mock_cache), None, None);
     let service = GrpcService::new(config);
@@ -28,6 +32,8 @@ async fn test_has_consent_success_true() {
     let request = Request::new(HasConsentedRequest {
         user_id: USER_ID,
         group: GROUP.to_string(),
+        clause: None,
+        min_version: None,
     });
 
     let response = service.has_consent(request).await;
@@ -61,6 +67,8 @@ async fn test_has_consent_not_found_error() {
     let request = Request::new(HasConsentedRequest {
         user_id: USER_ID,
         group: GROUP.to_string(),
+        clause: None,
+        min_version: None,
     });
 
     let response = service.has_consent(request).await;
@@ -68,3 +76,111 @@ async fn test_has_consent_not_found_error() {
     let status = response.unwrap_err();
     assert_eq!(status.code(), Code::NotFound);
 }
+
+#[tokio::test]
+async fn test_has_consent_checks_a_clause_without_the_cache() {
+    const USER_ID: i32 = 123;
+    const GROUP: &str = "privacy-policy";
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache.expect_find_user_agreement().times(0);
+
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .with(eq(GROUP))
+        .returning(|group| {
+            Ok(Some(TermOfUse {
+                id: 4,
+                group: group.to_string(),
+                url: "privacy-policy/v2.pdf".to_string(),
+                version: 2,
+                info: None,
+                created_at: Utc::now().naive_utc(),
+                html: None,
+                text: None,
+                change_summaries: vec![],
+                pdf_metadata: None,
+                metadata: Default::default(),
+                clauses: vec![Clause {
+                    key: "marketing-emails".to_string(),
+                    title: "Marketing emails".to_string(),
+                    mandatory: false,
+                }],
+            }))
+        });
+    mock_repo
+        .expect_get_accepted_clauses()
+        .with(eq(USER_ID), eq(4))
+        .returning(|_, _| Ok(Some(vec![])));
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+
+    let config = create_test_config(Some(mock_repo), Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(HasConsentedRequest {
+        user_id: USER_ID,
+        group: GROUP.to_string(),
+        clause: Some("marketing-emails".to_string()),
+        min_version: None,
+    });
+
+    let response = service.has_consent(request).await;
+
+    assert!(!response.unwrap().into_inner().has_consented);
+}
+
+#[tokio::test]
+async fn test_has_consent_accepts_later_versions_than_the_minimum() {
+    const USER_ID: i32 = 123;
+    const GROUP: &str = "privacy-policy";
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_agreed_version()
+        .returning(|_, _| Ok(None));
+    mock_cache
+        .expect_store_agreed_version()
+        .with(eq(USER_ID), eq(GROUP), eq(3), eq(None))
+        .returning(|_, _, _, _| Ok(()));
+
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_newest_agreed_version()
+        .with(eq(USER_ID), eq(GROUP), eq(2))
+        .times(1)
+        .returning(|_, _, _| Ok(Some(3)));
+    // Groups are only read for their consent expiry
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+
+    let config = create_test_config(Some(mock_repo), Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(HasConsentedRequest {
+        user_id: USER_ID,
+        group: GROUP.to_string(),
+        clause: None,
+        min_version: Some(2),
+    });
+
+    let response = service.has_consent(request).await;
+
+    assert!(response.unwrap().into_inner().has_consented);
+}
+
+#[tokio::test]
+async fn test_has_consent_rejects_clauses_with_a_minimum_version() {
+    let config = create_test_config(None, None, None, None);
+    let service = GrpcService::new(config);
+
+    let request = Request::new(HasConsentedRequest {
+        user_id: 123,
+        group: "privacy-policy".to_string(),
+        clause: Some("marketing-emails".to_string()),
+        min_version: Some(2),
+    });
+
+    let status = service.has_consent(request).await.unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
@@ -0,0 +1,151 @@
+use chrono::Utc;
+use domain::{
+    entities::{ConsentStatus, TermOfUse},
+    errors::TermsOfUseError,
+};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        server::GrpcService,
+        tests::create_test_config,
+        v2::{
+            HasConsentRequest, has_consent_response, terms_of_use_service_server::TermsOfUseService,
+        },
+    },
+    mocks::{MockCacheService, MockDatabaseRepository},
+};
+
+const USER_ID: i32 = 123;
+const GROUP: &str = "privacy-policy";
+
+fn term(id: i32, version: u32) -> TermOfUse {
+    TermOfUse {
+        id,
+        group: GROUP.to_string(),
+        url: format!("privacy-policy/v{version}.pdf"),
+        version,
+        info: None,
+        created_at: Utc::now().naive_utc(),
+        html: None,
+        text: None,
+        change_summaries: vec![],
+        pdf_metadata: None,
+        metadata: Default::default(),
+        clauses: vec![],
+    }
+}
+
+fn request() -> Request<HasConsentRequest> {
+    Request::new(HasConsentRequest {
+        user_id: USER_ID,
+        group: GROUP.to_string(),
+    })
+}
+
+#[tokio::test]
+async fn test_has_consent_v2_reports_older_versions() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .with(eq(GROUP))
+        .returning(|_| Ok(Some(term(7, 3))));
+    mock_repo
+        .expect_get_agreed_at()
+        .with(eq(USER_ID), eq(7))
+        .returning(|_, _| Ok(None));
+    mock_repo
+        .expect_get_terms_for_group()
+        .returning(|_, _| Ok(vec![term(7, 3), term(5, 2), term(2, 1)]));
+    mock_repo
+        .expect_get_agreed_term_ids()
+        .returning(|_, _| Ok(vec![5]));
+
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(None));
+    mock_cache
+        .expect_store_user_agreement()
+        .with(
+            eq(USER_ID),
+            eq(GROUP),
+            eq(ConsentStatus::ConsentedToOlder { version: 2 }),
+            eq(None),
+        )
+        .times(1)
+        .returning(|_, _, _, _| Ok(()));
+
+    let config = create_test_config(Some(mock_repo), Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let response = service.has_consent(request()).await.unwrap().into_inner();
+
+    assert!(!response.has_consented);
+    assert_eq!(
+        response.status(),
+        has_consent_response::ConsentStatus::ConsentedToOlder
+    );
+    assert_eq!(response.consented_version, Some(2));
+}
+
+#[tokio::test]
+async fn test_has_consent_v2_reports_expired_consents() {
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(Some(ConsentStatus::ConsentExpired)));
+
+    let config = create_test_config(None, Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let response = service.has_consent(request()).await.unwrap().into_inner();
+
+    assert!(!response.has_consented);
+    assert_eq!(
+        response.status(),
+        has_consent_response::ConsentStatus::ConsentExpired
+    );
+    assert_eq!(response.consented_version, None);
+}
+
+#[tokio::test]
+async fn test_has_consent_v2_reports_groups_without_terms() {
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(Some(ConsentStatus::NoTermsPublished)));
+
+    let config = create_test_config(None, Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let response = service.has_consent(request()).await.unwrap().into_inner();
+
+    assert!(!response.has_consented);
+    assert_eq!(
+        response.status(),
+        has_consent_response::ConsentStatus::NoTermsPublished
+    );
+    assert_eq!(response.consented_version, None);
+}
+
+#[tokio::test]
+async fn test_has_consent_v2_internal_error() {
+    let mut mock_cache = MockCacheService::new();
+    mock_cache
+        .expect_find_user_agreement()
+        .returning(|_, _| Ok(None));
+
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_latest_term_for_group()
+        .returning(|_| Err(TermsOfUseError::InternalServerError));
+
+    let config = create_test_config(Some(mock_repo), Some(mock_cache), None, None);
+    let service = GrpcService::new(config);
+
+    let status = service.has_consent(request()).await.unwrap_err();
+
+    assert_eq!(status.code(), Code::Internal);
+}
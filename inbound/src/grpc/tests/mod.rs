@@ -2,14 +2,25 @@ use std::sync::Arc;
 
 use crate::{
     config::Config,
-    mocks::{MockCacheService, MockDatabaseRepository, MockPublisherService, MockStorageService},
+    mocks::{
+        MockCacheService, MockDatabaseRepository, MockPublisherService, MockStorageService,
+        clean_scanner, sample_documents, valid_schemas,
+    },
 };
 
+mod bundle_test;
 mod create_consent_test;
 mod create_term_test;
+mod get_consent_at_test;
 mod get_latest_terms_test;
+mod get_pending_terms_test;
+mod get_term_diff_test;
+mod get_term_history_test;
+mod group_test;
 mod has_consent_test;
+mod has_consent_v2_test;
 mod health_check_test;
+mod upload_policy_test;
 
 pub fn create_test_config(
     repository: Option<MockDatabaseRepository>,
@@ -22,5 +33,8 @@ pub fn create_test_config(
         cache: Arc::new(cache.unwrap_or(MockCacheService::new())),
         storage: Arc::new(storage.unwrap_or(MockStorageService::new())),
         publisher: Arc::new(publisher.unwrap_or(MockPublisherService::new())),
+        scanner: Arc::new(clean_scanner()),
+        documents: Arc::new(sample_documents()),
+        schemas: Arc::new(valid_schemas()),
     })
 }
@@ -0,0 +1,126 @@
+use domain::entities::{DEFAULT_MAX_DOCUMENT_SIZE, UploadPolicy};
+use mockall::predicate::*;
+use tonic::{Code, Request};
+
+use crate::{
+    grpc::{
+        GetUploadPolicyRequest, SetUploadPolicyRequest, server::GrpcService,
+        terms_of_use_service_server::TermsOfUseService, tests::create_test_config,
+    },
+    mocks::{MockDatabaseRepository, registered_group},
+};
+
+const GROUP: &str = "privacy-policy";
+
+#[tokio::test]
+async fn test_get_upload_policy_falls_back_to_default() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_upload_policy()
+        .with(eq(GROUP))
+        .times(1)
+        .returning(|_| Ok(None));
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .get_upload_policy(Request::new(GetUploadPolicyRequest {
+            group: GROUP.to_string(),
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.group, GROUP);
+    assert_eq!(response.max_size, DEFAULT_MAX_DOCUMENT_SIZE);
+    assert_eq!(response.content_types.len(), 3);
+    assert!(response.required_fields.is_empty());
+}
+
+#[tokio::test]
+async fn test_set_upload_policy_success() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .with(eq(GROUP))
+        .returning(|name| Ok(Some(registered_group(name))));
+    mock_repo
+        .expect_save_upload_policy()
+        .with(eq(UploadPolicy {
+            group: GROUP.to_string(),
+            content_types: vec!["application/pdf".to_string(), "text/html".to_string()],
+            max_size: 2_000_000,
+            required_fields: vec!["info".to_string()],
+            metadata_schema: None,
+        }))
+        .times(1)
+        .returning(Ok);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let response = service
+        .set_upload_policy(Request::new(SetUploadPolicyRequest {
+            group: GROUP.to_string(),
+            content_types: vec!["text/html".to_string(), "application/pdf".to_string()],
+            max_size: 2_000_000,
+            required_fields: vec!["info".to_string()],
+            metadata_schema: None,
+        }))
+        .await
+        .unwrap()
+        .into_inner();
+
+    assert_eq!(response.content_types, ["application/pdf", "text/html"]);
+    assert_eq!(response.max_size, 2_000_000);
+    assert_eq!(response.required_fields, ["info"]);
+}
+
+#[tokio::test]
+async fn test_set_upload_policy_rejects_oversized_limit() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo
+        .expect_get_group()
+        .returning(|name| Ok(Some(registered_group(name))));
+    mock_repo.expect_save_upload_policy().times(0);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .set_upload_policy(Request::new(SetUploadPolicyRequest {
+            group: GROUP.to_string(),
+            content_types: vec!["application/pdf".to_string()],
+            max_size: u64::MAX,
+            required_fields: vec![],
+            metadata_schema: None,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
+
+#[tokio::test]
+async fn test_set_upload_policy_rejects_unregistered_group() {
+    let mut mock_repo = MockDatabaseRepository::new();
+    mock_repo.expect_get_group().returning(|_| Ok(None));
+    mock_repo.expect_save_upload_policy().times(0);
+
+    let config = create_test_config(Some(mock_repo), None, None, None);
+    let service = GrpcService::new(config);
+
+    let status = service
+        .set_upload_policy(Request::new(SetUploadPolicyRequest {
+            group: "unknown".to_string(),
+            content_types: vec!["application/pdf".to_string()],
+            max_size: 2_000_000,
+            required_fields: vec![],
+            metadata_schema: None,
+        }))
+        .await
+        .unwrap_err();
+
+    assert_eq!(status.code(), Code::InvalidArgument);
+}
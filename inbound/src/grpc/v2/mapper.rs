@@ -0,0 +1,23 @@
+use domain::entities::ConsentStatus as ConsentStatusEntity;
+
+use crate::grpc::v2::{HasConsentResponse, has_consent_response::ConsentStatus};
+
+impl From<ConsentStatusEntity> for HasConsentResponse {
+    fn from(status: ConsentStatusEntity) -> Self {
+        let (consent_status, consented_version) = match status {
+            ConsentStatusEntity::ConsentedToLatest => (ConsentStatus::ConsentedToLatest, None),
+            ConsentStatusEntity::ConsentedToOlder { version } => {
+                (ConsentStatus::ConsentedToOlder, Some(version))
+            }
+            ConsentStatusEntity::ConsentExpired => (ConsentStatus::ConsentExpired, None),
+            ConsentStatusEntity::NeverConsented => (ConsentStatus::NeverConsented, None),
+            ConsentStatusEntity::NoTermsPublished => (ConsentStatus::NoTermsPublished, None),
+        };
+
+        HasConsentResponse {
+            has_consented: status.has_consented(),
+            status: consent_status.into(),
+            consented_version,
+        }
+    }
+}
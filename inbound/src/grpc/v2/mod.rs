@@ -0,0 +1,4 @@
+tonic::include_proto!("terms_of_use.v2");
+
+mod mapper;
+mod server;
@@ -0,0 +1,30 @@
+use domain::use_cases::get_consent_status_use_case;
+use tonic::{Request, Response, Status};
+
+use crate::grpc::{
+    mapper::ToStatus,
+    server::GrpcService,
+    v2::{HasConsentRequest, HasConsentResponse, terms_of_use_service_server::TermsOfUseService},
+};
+
+#[tonic::async_trait]
+impl TermsOfUseService for GrpcService {
+    #[tracing::instrument(skip(self, request))]
+    async fn has_consent(
+        &self,
+        request: Request<HasConsentRequest>,
+    ) -> Result<Response<HasConsentResponse>, Status> {
+        let request = request.into_inner();
+
+        let status = get_consent_status_use_case(
+            self.config.repository.as_ref(),
+            self.config.cache.as_ref(),
+            request.user_id,
+            &request.group,
+        )
+        .await
+        .map_err(|e| e.to_status())?;
+
+        Ok(Response::new(HasConsentResponse::from(status)))
+    }
+}
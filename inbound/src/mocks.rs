@@ -1,11 +1,15 @@
 use domain::data::{
     CacheServiceWithHealthCheck, DatabaseRepositoryWithHealthCheck,
-    PublisherServiceWithHealthCheck, StorageServiceWithHealthCheck,
+    PublisherServiceWithHealthCheck, ScannerServiceWithHealthCheck, StorageServiceWithHealthCheck,
     health_check::HealthCheck,
     repository::{
-        DatabaseRepository as DatabaseRepositoryTrait, TermRepository, UserAgreementRepository,
+        BundleRepository, DatabaseRepository as DatabaseRepositoryTrait, GroupRepository,
+        TermRepository, TermReservationRepository, UploadPolicyRepository, UserAgreementRepository,
+    },
+    service::{
+        CacheService, DocumentService, PublisherService, ScannerService, SchemaService,
+        StorageService,
     },
-    service::{CacheService, PublisherService, StorageService},
 };
 use domain::errors::Result;
 use mockall::mock;
@@ -17,14 +21,57 @@ mock! {
     #[async_trait::async_trait]
     impl TermRepository for DatabaseRepository {
         async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<domain::entities::TermOfUse>>;
+        async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<domain::entities::TermOfUse>>;
         async fn get_term_by_id(&self, term_id: i32) -> Result<Option<domain::entities::TermOfUse>>;
+        async fn get_term_by_version(&self, group: &str, version: u32) -> Result<Option<domain::entities::TermOfUse>>;
+        async fn get_terms_for_group(&self, group: &str, metadata: &domain::entities::TermMetadata) -> Result<Vec<domain::entities::TermOfUse>>;
         async fn create_term(&self, term: domain::entities::TermOfUse) -> Result<domain::entities::TermOfUse>;
+        async fn get_all_terms(&self) -> Result<Vec<domain::entities::TermOfUse>>;
     }
 
     #[async_trait::async_trait]
     impl UserAgreementRepository for DatabaseRepository {
-        async fn has_user_agreed_to_term(&self, user_id: i32, term_id: i32) -> Result<bool>;
-        async fn create_user_agreement(&self, user_id: i32, term_id: i32) -> Result<()>;
+        async fn get_agreed_at(&self, user_id: i32, term_id: i32) -> Result<Option<chrono::NaiveDateTime>>;
+        async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>>;
+        async fn get_newest_agreed_version(&self, user_id: i32, group: &str, min_version: u32) -> Result<Option<u32>>;
+        async fn get_accepted_clauses(&self, user_id: i32, term_id: i32) -> Result<Option<Vec<String>>>;
+        async fn get_agreement_at(&self, user_id: i32, term_id: i32, at: chrono::NaiveDateTime) -> Result<Option<domain::entities::UserAgreement>>;
+        async fn create_user_agreement(&self, user_id: i32, term_id: i32, accepted_clauses: &[String]) -> Result<()>;
+        async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()>;
+    }
+
+    #[async_trait::async_trait]
+    impl TermReservationRepository for DatabaseRepository {
+        async fn create_reservation(&self, reservation: domain::entities::TermReservation) -> Result<domain::entities::TermReservation>;
+        async fn get_reservation(&self, reservation_id: i32) -> Result<Option<domain::entities::TermReservation>>;
+        async fn get_reservation_for_version(&self, group: &str, version: u32) -> Result<Option<domain::entities::TermReservation>>;
+        async fn claim_reservation(&self, reservation_id: i32, until: chrono::NaiveDateTime) -> Result<bool>;
+        async fn release_reservation(&self, reservation_id: i32) -> Result<()>;
+        async fn delete_reservation(&self, reservation_id: i32) -> Result<()>;
+        async fn get_all_reservations(&self) -> Result<Vec<domain::entities::TermReservation>>;
+    }
+
+    #[async_trait::async_trait]
+    impl UploadPolicyRepository for DatabaseRepository {
+        async fn get_upload_policy(&self, group: &str) -> Result<Option<domain::entities::UploadPolicy>>;
+        async fn save_upload_policy(&self, policy: domain::entities::UploadPolicy) -> Result<domain::entities::UploadPolicy>;
+    }
+
+    #[async_trait::async_trait]
+    impl GroupRepository for DatabaseRepository {
+        async fn get_group(&self, name: &str) -> Result<Option<domain::entities::Group>>;
+        async fn get_groups(&self) -> Result<Vec<domain::entities::Group>>;
+        async fn create_group(&self, group: domain::entities::Group) -> Result<domain::entities::Group>;
+        async fn update_group(&self, group: domain::entities::Group) -> Result<domain::entities::Group>;
+        async fn delete_group(&self, name: &str) -> Result<()>;
+    }
+
+    #[async_trait::async_trait]
+    impl BundleRepository for DatabaseRepository {
+        async fn get_bundle(&self, name: &str) -> Result<Option<domain::entities::Bundle>>;
+        async fn get_bundles(&self) -> Result<Vec<domain::entities::Bundle>>;
+        async fn save_bundle(&self, bundle: domain::entities::Bundle) -> Result<domain::entities::Bundle>;
+        async fn delete_bundle(&self, name: &str) -> Result<()>;
     }
 
     #[async_trait::async_trait]
@@ -42,15 +89,21 @@ mock! {
 
     #[async_trait::async_trait]
     impl CacheService for CacheService {
-        async fn find_user_agreement(&self, user_id: i32, group: &str) -> Result<Option<bool>>;
+        async fn find_user_agreement(&self, user_id: i32, group: &str) -> Result<Option<domain::entities::ConsentStatus>>;
+
+        async fn store_user_agreement(&self, user_id: i32, group: &str, status: domain::entities::ConsentStatus, expires_at: Option<chrono::NaiveDateTime>) -> Result<()>;
+
+        async fn find_agreed_version(&self, user_id: i32, group: &str) -> Result<Option<u32>>;
 
-        async fn store_user_agreement(&self, user_id: i32, group: &str, agreed: bool) -> Result<()>;
+        async fn store_agreed_version(&self, user_id: i32, group: &str, version: u32, expires_at: Option<chrono::NaiveDateTime>) -> Result<()>;
 
         async fn get_latest_term_for_group(&self, group: &str) -> Result<Option<domain::entities::TermOfUse>>;
 
         async fn store_latest_term_for_group(&self, term: &domain::entities::TermOfUse) -> Result<()>;
 
         async fn invalidate_cache_for_group(&self, group: &str) -> Result<()>;
+
+        async fn invalidate_consents_for_group(&self, group: &str) -> Result<()>;
     }
 
     #[async_trait::async_trait]
@@ -66,11 +119,27 @@ mock! {
 
     #[async_trait::async_trait]
     impl StorageService for StorageService {
-        async fn upload_file(&self, file: &Path, content_type: &str) -> Result<String>;
+        async fn upload_file(&self, file: &Path, content_type: &str, group: &str, version: u32) -> Result<String>;
+
+        async fn put_file(&self, key: &str, file: &Path, content_type: &str, group: &str, version: u32) -> Result<()>;
+
+        async fn publish_file(&self, path: &str, term_id: i32) -> Result<()>;
 
         async fn delete_file(&self, path: &str) -> Result<()>;
 
         async fn get_file_url(&self, path: &str) -> Result<String>;
+
+        async fn download_file(&self, path: &str, destination: &Path) -> Result<()>;
+
+        async fn create_upload_url(&self, group: &str, version: u32, content_type: &str, size: u64, sha256: &str, expires_in: std::time::Duration) -> Result<domain::entities::PresignedUpload>;
+
+        async fn get_file_info(&self, path: &str) -> Result<Option<domain::entities::StoredFileInfo>>;
+
+        async fn list_files(&self) -> Result<Vec<domain::entities::StoredFile>>;
+
+        fn copies(&self) -> usize;
+
+        fn lists_whole_bucket(&self) -> bool;
     }
 
     #[async_trait::async_trait]
@@ -96,3 +165,131 @@ mock! {
 }
 
 impl PublisherServiceWithHealthCheck for MockPublisherService {}
+
+mock! {
+    pub ScannerService {}
+
+    #[async_trait::async_trait]
+    impl ScannerService for ScannerService {
+        async fn scan_file(&self, file: &Path) -> Result<domain::entities::ScanVerdict>;
+    }
+
+    #[async_trait::async_trait]
+    impl HealthCheck for ScannerService {
+        async fn ping(&self) -> Result<()>;
+    }
+}
+
+impl ScannerServiceWithHealthCheck for MockScannerService {}
+
+/// Scanner reporting every document as clean, for tests not concerned with scanning.
+pub fn clean_scanner() -> MockScannerService {
+    let mut scanner = MockScannerService::new();
+    scanner
+        .expect_scan_file()
+        .returning(|_| Ok(domain::entities::ScanVerdict::Clean));
+    scanner.expect_ping().returning(|| Ok(()));
+    scanner
+}
+
+mock! {
+    pub DocumentService {}
+
+    #[async_trait::async_trait]
+    impl DocumentService for DocumentService {
+        async fn read_document(&self, file: &Path, content_type: &str) -> Result<domain::entities::TermDocument>;
+        async fn sha256(&self, file: &Path) -> Result<String>;
+        fn render_markdown(&self, markdown: &str) -> String;
+        fn diff_text(&self, old: &str, new: &str, context_lines: usize) -> Vec<domain::dto::DiffHunk>;
+    }
+}
+
+/// Document service reading every document as a one-page PDF, for tests not concerned with
+/// document contents.
+pub fn sample_documents() -> MockDocumentService {
+    let mut documents = MockDocumentService::new();
+    documents.expect_read_document().returning(|_, _| {
+        Ok(domain::entities::TermDocument {
+            html: None,
+            text: Some("Sample terms".to_string()),
+            pdf_metadata: Some(domain::entities::PdfMetadata {
+                page_count: 1,
+                title: None,
+                producer: None,
+            }),
+        })
+    });
+    documents
+        .expect_render_markdown()
+        .returning(|markdown| format!("<p>{markdown}</p>"));
+    documents.expect_sha256().returning(|_| {
+        Ok("2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824".to_string())
+    });
+    documents
+}
+
+/// Document service diffing any two texts as "Old clause" replaced by "New clause" on the
+/// second line.
+pub fn replaced_clause_documents() -> MockDocumentService {
+    use domain::dto::{DiffHunk, DiffLine, DiffOperation};
+
+    let mut documents = MockDocumentService::new();
+    documents.expect_diff_text().returning(|_, _, _| {
+        vec![DiffHunk {
+            old_start: 1,
+            old_lines: 2,
+            new_start: 1,
+            new_lines: 2,
+            lines: vec![
+                DiffLine {
+                    operation: DiffOperation::Equal,
+                    old_line: Some(1),
+                    new_line: Some(1),
+                    text: "Intro".to_string(),
+                },
+                DiffLine {
+                    operation: DiffOperation::Delete,
+                    old_line: Some(2),
+                    new_line: None,
+                    text: "Old clause".to_string(),
+                },
+                DiffLine {
+                    operation: DiffOperation::Insert,
+                    old_line: None,
+                    new_line: Some(2),
+                    text: "New clause".to_string(),
+                },
+            ],
+        }]
+    });
+    documents
+}
+
+mock! {
+    pub SchemaService {}
+
+    impl SchemaService for SchemaService {
+        fn check_schema(&self, schema: &serde_json::Value) -> Result<()>;
+        fn violations(&self, schema: &serde_json::Value, instance: &serde_json::Value) -> Result<Vec<String>>;
+    }
+}
+
+/// Schema service accepting every schema and metadata, for tests not concerned with schemas.
+pub fn valid_schemas() -> MockSchemaService {
+    let mut schemas = MockSchemaService::new();
+    schemas.expect_check_schema().returning(|_| Ok(()));
+    schemas.expect_violations().returning(|_, _| Ok(vec![]));
+    schemas
+}
+
+/// Group registered with default settings, for tests not concerned with the registry.
+pub fn registered_group(name: &str) -> domain::entities::Group {
+    domain::entities::Group {
+        name: name.to_string(),
+        description: None,
+        owner: None,
+        mandatory: false,
+        default_locale: "en".to_string(),
+        max_consent_age_days: None,
+    }
+}
@@ -1,12 +1,42 @@
 pub use sea_orm_migration::prelude::*;
 
 mod m20220101_000001_create_table;
+mod m20261018_000001_create_term_reservations;
+mod m20261018_000002_add_term_html;
+mod m20261018_000003_add_term_text;
+mod m20261018_000004_add_term_change_summaries;
+mod m20261018_000005_add_term_pdf_metadata;
+mod m20261018_000006_create_upload_policies;
+mod m20261018_000007_add_term_metadata;
+mod m20261018_000008_create_groups;
+mod m20261018_000009_create_bundles;
+mod m20261018_000010_add_clauses;
+mod m20261018_000011_add_group_consent_age;
+mod m20261018_000012_keep_agreement_history;
+mod m20261018_000013_add_reservation_change_summaries;
+mod m20261018_000014_replace_require_info;
 
 pub struct Migrator;
 
 #[async_trait::async_trait]
 impl MigratorTrait for Migrator {
     fn migrations() -> Vec<Box<dyn MigrationTrait>> {
-        vec![Box::new(m20220101_000001_create_table::Migration)]
+        vec![
+            Box::new(m20220101_000001_create_table::Migration),
+            Box::new(m20261018_000001_create_term_reservations::Migration),
+            Box::new(m20261018_000002_add_term_html::Migration),
+            Box::new(m20261018_000003_add_term_text::Migration),
+            Box::new(m20261018_000004_add_term_change_summaries::Migration),
+            Box::new(m20261018_000005_add_term_pdf_metadata::Migration),
+            Box::new(m20261018_000006_create_upload_policies::Migration),
+            Box::new(m20261018_000007_add_term_metadata::Migration),
+            Box::new(m20261018_000008_create_groups::Migration),
+            Box::new(m20261018_000009_create_bundles::Migration),
+            Box::new(m20261018_000010_add_clauses::Migration),
+            Box::new(m20261018_000011_add_group_consent_age::Migration),
+            Box::new(m20261018_000012_keep_agreement_history::Migration),
+            Box::new(m20261018_000013_add_reservation_change_summaries::Migration),
+            Box::new(m20261018_000014_replace_require_info::Migration),
+        ]
     }
 }
@@ -0,0 +1,54 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_TERM_RESERVATIONS: &str = "term_reservations";
+
+const INDEX_TERM_RESERVATIONS_GROUP_VERSION: &str = "idx_term_reservations_group_version";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .if_not_exists()
+                    .col(pk_auto("id"))
+                    .col(string("group"))
+                    .col(unsigned("version"))
+                    .col(text("info").null())
+                    .col(string("key"))
+                    .col(string("content_type"))
+                    .col(big_unsigned("size"))
+                    .col(string("sha256"))
+                    .col(date_time("expires_at").not_null())
+                    .col(date_time("claimed_until").null())
+                    .index(
+                        Index::create()
+                            .unique()
+                            .name(INDEX_TERM_RESERVATIONS_GROUP_VERSION)
+                            .col("group")
+                            .col("version"),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_TERM_RESERVATIONS_GROUP_VERSION)
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(TABLE_TERM_RESERVATIONS).to_owned())
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_TERMS: &str = "terms";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .add_column_if_not_exists(integer("pdf_page_count").null())
+                    .add_column_if_not_exists(text("pdf_title").null())
+                    .add_column_if_not_exists(text("pdf_producer").null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .drop_column("pdf_page_count")
+                    .drop_column("pdf_title")
+                    .drop_column("pdf_producer")
+                    .to_owned(),
+            )
+            .await
+    }
+}
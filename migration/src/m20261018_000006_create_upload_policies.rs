@@ -0,0 +1,30 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_UPLOAD_POLICIES: &str = "upload_policies";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .if_not_exists()
+                    .col(string("group").primary_key())
+                    .col(json_binary("content_types"))
+                    .col(big_unsigned("max_size"))
+                    .col(boolean("require_info").default(false))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TABLE_UPLOAD_POLICIES).to_owned())
+            .await
+    }
+}
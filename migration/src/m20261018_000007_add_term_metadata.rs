@@ -0,0 +1,87 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_TERMS: &str = "terms";
+const TABLE_TERM_RESERVATIONS: &str = "term_reservations";
+const TABLE_UPLOAD_POLICIES: &str = "upload_policies";
+const INDEX_TERMS_METADATA: &str = "idx_terms_metadata";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .add_column_if_not_exists(json_binary("metadata").default(Expr::cust("'{}'")))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Serves the `@>` containment filter of the version history
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "CREATE INDEX IF NOT EXISTS {INDEX_TERMS_METADATA} ON {TABLE_TERMS} USING GIN (metadata)"
+            ))
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .add_column_if_not_exists(json_binary("metadata").default(Expr::cust("'{}'")))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .add_column_if_not_exists(json_binary("metadata_schema").null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .drop_column("metadata_schema")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .drop_column("metadata")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_TERMS_METADATA)
+                    .table(TABLE_TERMS)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .drop_column("metadata")
+                    .to_owned(),
+            )
+            .await
+    }
+}
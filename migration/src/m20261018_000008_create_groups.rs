@@ -0,0 +1,42 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_GROUPS: &str = "groups";
+const TABLE_TERMS: &str = "terms";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TABLE_GROUPS)
+                    .if_not_exists()
+                    .col(string("name").primary_key())
+                    .col(text("description").null())
+                    .col(string("owner").null())
+                    .col(boolean("mandatory").default(false))
+                    .col(string("default_locale").default("en"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Groups used by existing terms stay usable once unknown groups are rejected
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "INSERT INTO {TABLE_GROUPS} (name) SELECT DISTINCT \"group\" FROM {TABLE_TERMS} ON CONFLICT DO NOTHING"
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TABLE_GROUPS).to_owned())
+            .await
+    }
+}
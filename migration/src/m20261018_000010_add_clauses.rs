@@ -0,0 +1,72 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_TERMS: &str = "terms";
+const TABLE_TERM_RESERVATIONS: &str = "term_reservations";
+const TABLE_USER_AGREEMENTS: &str = "user_agreements";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .add_column_if_not_exists(json_binary("clauses").default(Expr::cust("'[]'")))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .add_column_if_not_exists(json_binary("clauses").default(Expr::cust("'[]'")))
+                    .to_owned(),
+            )
+            .await?;
+
+        // Existing agreements predate clauses, so no optional clause was accepted
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_USER_AGREEMENTS)
+                    .add_column_if_not_exists(
+                        json_binary("accepted_clauses").default(Expr::cust("'[]'")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_USER_AGREEMENTS)
+                    .drop_column("accepted_clauses")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .drop_column("clauses")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERMS)
+                    .drop_column("clauses")
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -0,0 +1,32 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_GROUPS: &str = "groups";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Existing groups keep agreements that never expire
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_GROUPS)
+                    .add_column_if_not_exists(integer("max_consent_age_days").null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_GROUPS)
+                    .drop_column("max_consent_age_days")
+                    .to_owned(),
+            )
+            .await
+    }
+}
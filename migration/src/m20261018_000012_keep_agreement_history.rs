@@ -0,0 +1,64 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_USER_AGREEMENTS: &str = "user_agreements";
+const INDEX_USER_AGREEMENTS_USER_TERM: &str = "idx_user_agreements_user_term";
+const INDEX_USER_AGREEMENTS_USER_TERM_AGREED_AT: &str = "idx_user_agreements_user_term_agreed_at";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // Agreeing again, e.g. once a consent expired, adds a row instead of failing
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "ALTER TABLE {TABLE_USER_AGREEMENTS} DROP CONSTRAINT IF EXISTS {INDEX_USER_AGREEMENTS_USER_TERM}"
+            ))
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .if_not_exists()
+                    .name(INDEX_USER_AGREEMENTS_USER_TERM_AGREED_AT)
+                    .table(TABLE_USER_AGREEMENTS)
+                    .col("user_id")
+                    .col("term_of_use_id")
+                    .col("agreed_at")
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name(INDEX_USER_AGREEMENTS_USER_TERM_AGREED_AT)
+                    .table(TABLE_USER_AGREEMENTS)
+                    .to_owned(),
+            )
+            .await?;
+
+        // Only the newest agreement to each term is kept
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "DELETE FROM {TABLE_USER_AGREEMENTS} older USING {TABLE_USER_AGREEMENTS} newer \
+                 WHERE older.user_id = newer.user_id \
+                 AND older.term_of_use_id = newer.term_of_use_id \
+                 AND (older.agreed_at, older.id) < (newer.agreed_at, newer.id)"
+            ))
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "ALTER TABLE {TABLE_USER_AGREEMENTS} ADD CONSTRAINT {INDEX_USER_AGREEMENTS_USER_TERM} UNIQUE (user_id, term_of_use_id)"
+            ))
+            .await
+            .map(|_| ())
+    }
+}
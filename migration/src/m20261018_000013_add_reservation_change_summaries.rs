@@ -0,0 +1,33 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_TERM_RESERVATIONS: &str = "term_reservations";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .add_column_if_not_exists(
+                        json_binary("change_summaries").default(Expr::cust("'[]'")),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_TERM_RESERVATIONS)
+                    .drop_column("change_summaries")
+                    .to_owned(),
+            )
+            .await
+    }
+}
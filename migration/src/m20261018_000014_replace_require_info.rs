@@ -0,0 +1,66 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLE_UPLOAD_POLICIES: &str = "upload_policies";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .add_column_if_not_exists(
+                        json_binary("required_fields").default(Expr::cust("'[]'")),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // Policies requiring info keep requiring it as a named field
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "UPDATE {TABLE_UPLOAD_POLICIES} SET required_fields = '[\"info\"]' WHERE require_info"
+            ))
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .drop_column("require_info")
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .add_column_if_not_exists(boolean("require_info").default(false))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "UPDATE {TABLE_UPLOAD_POLICIES} SET require_info = required_fields ? 'info'"
+            ))
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(TABLE_UPLOAD_POLICIES)
+                    .drop_column("required_fields")
+                    .to_owned(),
+            )
+            .await
+    }
+}
@@ -1,42 +1,131 @@
 use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
 use deadpool_redis::redis::{AsyncCommands, pipe};
 use domain::{
     data::service::CacheService,
-    entities::TermOfUse,
+    entities::{ConsentStatus, TermOfUse},
     errors::{Result, TermsOfUseError},
 };
 use tracing::error;
 
 use crate::cache::deadpool_redis::DeadpoolRedisCache;
 
-const USER_AGREEMENTS_PREFIX: &str = "USER_AGREEMENTS:";
+// Replaces the `USER_AGREEMENTS:` booleans, which are left to expire
+const CONSENT_STATUS_PREFIX: &str = "CONSENT_STATUS:";
+const AGREED_VERSION_PREFIX: &str = "AGREED_VERSION:";
 const LATEST_TERMS_PREFIX: &str = "LATEST_TERMS:";
 
+impl DeadpoolRedisCache {
+    /// Seconds an agreement entry may live, `None` once `expires_at` has passed.
+    fn agreement_ttl(&self, expires_at: Option<NaiveDateTime>) -> Option<u64> {
+        let Some(expires_at) = expires_at else {
+            return Some(self.agreement_ttl_seconds);
+        };
+
+        let remaining_seconds = (expires_at - Utc::now().naive_utc()).num_seconds();
+
+        u64::try_from(remaining_seconds)
+            .ok()
+            .filter(|seconds| *seconds > 0)
+            .map(|seconds| seconds.min(self.agreement_ttl_seconds))
+    }
+}
+
 #[async_trait]
 impl CacheService for DeadpoolRedisCache {
     #[tracing::instrument(skip(self))]
-    async fn find_user_agreement(&self, user_id: i32, group: &str) -> Result<Option<bool>> {
+    async fn find_user_agreement(
+        &self,
+        user_id: i32,
+        group: &str,
+    ) -> Result<Option<ConsentStatus>> {
+        let mut conn = self.get_connection().await?;
+
+        let key = format!("{CONSENT_STATUS_PREFIX}{group}:{user_id}");
+
+        let result = conn
+            .get::<String, Option<String>>(key)
+            .await
+            .map_err(|err| {
+                error!("Failed to get consent status from cache: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        result
+            .map(|serialized_status| {
+                serde_json::from_str(&serialized_status).map_err(|err| {
+                    error!("Failed to deserialize consent status from cache: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })
+            })
+            .transpose()
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn store_user_agreement(
+        &self,
+        user_id: i32,
+        group: &str,
+        status: ConsentStatus,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        let Some(ttl_seconds) = self.agreement_ttl(expires_at) else {
+            return Ok(());
+        };
+
+        let mut conn = self.get_connection().await?;
+
+        let key = format!("{CONSENT_STATUS_PREFIX}{group}:{user_id}");
+        let value = serde_json::to_string(&status).map_err(|err| {
+            error!("Failed to serialize consent status for caching: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        conn.set_ex::<String, String, ()>(key, value, ttl_seconds)
+            .await
+            .map_err(|err| {
+                error!("Failed to store consent status in cache: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn find_agreed_version(&self, user_id: i32, group: &str) -> Result<Option<u32>> {
         let mut conn = self.get_connection().await?;
 
-        let key = format!("{USER_AGREEMENTS_PREFIX}{group}:{user_id}");
+        let key = format!("{AGREED_VERSION_PREFIX}{group}:{user_id}");
 
-        conn.get::<String, Option<bool>>(key).await.map_err(|err| {
-            error!("Failed to get user agreement from cache: {err}");
+        conn.get::<String, Option<u32>>(key).await.map_err(|err| {
+            error!("Failed to get agreed version from cache: {err}");
 
             TermsOfUseError::InternalServerError
         })
     }
 
     #[tracing::instrument(skip(self))]
-    async fn store_user_agreement(&self, user_id: i32, group: &str, agreed: bool) -> Result<()> {
+    async fn store_agreed_version(
+        &self,
+        user_id: i32,
+        group: &str,
+        version: u32,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        let Some(ttl_seconds) = self.agreement_ttl(expires_at) else {
+            return Ok(());
+        };
+
         let mut conn = self.get_connection().await?;
 
-        let key = format!("{USER_AGREEMENTS_PREFIX}{group}:{user_id}");
+        let key = format!("{AGREED_VERSION_PREFIX}{group}:{user_id}");
 
-        conn.set_ex::<String, bool, ()>(key, agreed, self.agreement_ttl_seconds)
+        conn.set_ex::<String, u32, ()>(key, version, ttl_seconds)
             .await
             .map_err(|err| {
-                error!("Failed to store user agreement in cache: {err}");
+                error!("Failed to store agreed version in cache: {err}");
 
                 TermsOfUseError::InternalServerError
             })
@@ -99,7 +188,7 @@ impl CacheService for DeadpoolRedisCache {
         pipe.atomic();
 
         let mut keys = conn
-            .scan_match::<String, String>(format!("{USER_AGREEMENTS_PREFIX}{group}:*"))
+            .scan_match::<String, String>(format!("{CONSENT_STATUS_PREFIX}{group}:*"))
             .await
             .map_err(|err| {
                 error!("Failed to scan keys for cache invalidation: {err}");
@@ -121,21 +210,50 @@ impl CacheService for DeadpoolRedisCache {
             TermsOfUseError::InternalServerError
         })
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn invalidate_consents_for_group(&self, group: &str) -> Result<()> {
+        let mut conn = self.get_connection().await?;
+
+        let mut pipe = pipe();
+        pipe.atomic();
+
+        for prefix in [CONSENT_STATUS_PREFIX, AGREED_VERSION_PREFIX] {
+            let mut keys = conn
+                .scan_match::<String, String>(format!("{prefix}{group}:*"))
+                .await
+                .map_err(|err| {
+                    error!("Failed to scan keys for consent invalidation: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            while let Some(key) = keys.next_item().await {
+                pipe.unlink(key).ignore();
+            }
+        }
+
+        pipe.query_async::<()>(&mut conn).await.map_err(|err| {
+            error!("Failed to invalidate consents for group: {err}");
+
+            TermsOfUseError::InternalServerError
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{LATEST_TERMS_PREFIX, USER_AGREEMENTS_PREFIX};
+    use super::{AGREED_VERSION_PREFIX, CONSENT_STATUS_PREFIX, LATEST_TERMS_PREFIX};
     use crate::cache::deadpool_redis::{
         DeadpoolRedisCache,
         tests::{build_cache, flushdb, redis_server_available},
     };
 
-    use chrono::Utc;
+    use chrono::{TimeDelta, Utc};
     use deadpool_redis::redis::AsyncCommands;
     use domain::{
         data::service::CacheService,
-        entities::TermOfUse,
+        entities::{ConsentStatus, TermOfUse},
         errors::{Result, TermsOfUseError},
     };
     use redis_test::server::RedisServer;
@@ -159,6 +277,12 @@ mod tests {
             version,
             info: Some("Sample info".to_string()),
             created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         }
     }
 
@@ -191,17 +315,84 @@ mod tests {
         flushdb(&cache).await?;
 
         cache
-            .store_user_agreement(1, "legal", true)
+            .store_user_agreement(
+                1,
+                "legal",
+                ConsentStatus::ConsentedToOlder { version: 3 },
+                None,
+            )
             .await
             .expect("store should succeed");
 
         let found = cache.find_user_agreement(1, "legal").await?;
-        assert_eq!(found, Some(true));
+        assert_eq!(found, Some(ConsentStatus::ConsentedToOlder { version: 3 }));
 
         let missing = cache.find_user_agreement(2, "legal").await?;
         assert!(missing.is_none());
 
-        let ttl = ttl_for(&cache, &format!("{USER_AGREEMENTS_PREFIX}legal:1")).await?;
+        let ttl = ttl_for(&cache, &format!("{CONSENT_STATUS_PREFIX}legal:1")).await?;
+        assert!(ttl <= 5 && ttl > 0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn store_user_agreement_caps_the_ttl_at_the_expiry() -> Result<()> {
+        if !redis_server_available() {
+            eprintln!(
+                "redis-server not available; skipping test store_user_agreement_caps_the_ttl_at_the_expiry"
+            );
+            return Ok(());
+        }
+        let server = RedisServer::new();
+        let cache = build_cache(&server, 60, 10).await;
+        flushdb(&cache).await?;
+
+        let now = Utc::now().naive_utc();
+        cache
+            .store_user_agreement(
+                1,
+                "legal",
+                ConsentStatus::ConsentedToLatest,
+                Some(now + TimeDelta::seconds(5)),
+            )
+            .await?;
+        cache
+            .store_user_agreement(
+                2,
+                "legal",
+                ConsentStatus::ConsentedToLatest,
+                Some(now - TimeDelta::seconds(5)),
+            )
+            .await?;
+
+        let ttl = ttl_for(&cache, &format!("{CONSENT_STATUS_PREFIX}legal:1")).await?;
+        assert!(ttl <= 5 && ttl > 0);
+
+        // Already expired consents are not cached at all
+        assert!(cache.find_user_agreement(2, "legal").await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn store_and_find_agreed_version() -> Result<()> {
+        if !redis_server_available() {
+            eprintln!("redis-server not available; skipping test store_and_find_agreed_version");
+            return Ok(());
+        }
+        let server = RedisServer::new();
+        let cache = build_cache(&server, 5, 10).await;
+        flushdb(&cache).await?;
+
+        cache.store_agreed_version(1, "legal", 2, None).await?;
+
+        assert_eq!(cache.find_agreed_version(1, "legal").await?, Some(2));
+        assert_eq!(cache.find_agreed_version(2, "legal").await?, None);
+
+        let ttl = ttl_for(&cache, &format!("{AGREED_VERSION_PREFIX}legal:1")).await?;
         assert!(ttl <= 5 && ttl > 0);
 
         Ok(())
@@ -271,13 +462,19 @@ mod tests {
         let cache = build_cache(&server, 10, 10).await;
         flushdb(&cache).await?;
 
-        cache.store_user_agreement(1, "group-a", true).await?;
-        cache.store_user_agreement(2, "group-a", false).await?;
+        cache
+            .store_user_agreement(1, "group-a", ConsentStatus::ConsentedToLatest, None)
+            .await?;
+        cache
+            .store_user_agreement(2, "group-a", ConsentStatus::NeverConsented, None)
+            .await?;
         cache
             .store_latest_term_for_group(&sample_term("group-a", 1))
             .await?;
 
-        cache.store_user_agreement(1, "group-b", true).await?;
+        cache
+            .store_user_agreement(1, "group-b", ConsentStatus::ConsentedToLatest, None)
+            .await?;
         cache
             .store_latest_term_for_group(&sample_term("group-b", 1))
             .await?;
@@ -286,7 +483,7 @@ mod tests {
 
         let mut conn = cache.get_connection().await?;
         let group_a_one: i64 = conn
-            .exists(format!("{USER_AGREEMENTS_PREFIX}group-a:1"))
+            .exists(format!("{CONSENT_STATUS_PREFIX}group-a:1"))
             .await
             .map_err(|err| {
                 error!("Failed to check key existence: {err}");
@@ -294,7 +491,7 @@ mod tests {
                 TermsOfUseError::InternalServerError
             })?;
         let group_a_two: i64 = conn
-            .exists(format!("{USER_AGREEMENTS_PREFIX}group-a:2"))
+            .exists(format!("{CONSENT_STATUS_PREFIX}group-a:2"))
             .await
             .map_err(|err| {
                 error!("Failed to check key existence: {err}");
@@ -325,4 +522,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn invalidate_consents_for_group_removes_consents_only() -> Result<()> {
+        if !redis_server_available() {
+            eprintln!(
+                "redis-server not available; skipping test invalidate_consents_for_group_removes_consents_only"
+            );
+            return Ok(());
+        }
+        let server = RedisServer::new();
+        let cache = build_cache(&server, 10, 10).await;
+        flushdb(&cache).await?;
+
+        cache
+            .store_user_agreement(1, "group-a", ConsentStatus::ConsentedToLatest, None)
+            .await?;
+        cache.store_agreed_version(1, "group-a", 2, None).await?;
+        cache
+            .store_latest_term_for_group(&sample_term("group-a", 2))
+            .await?;
+        cache.store_agreed_version(1, "group-b", 1, None).await?;
+
+        cache.invalidate_consents_for_group("group-a").await?;
+
+        assert_eq!(cache.find_user_agreement(1, "group-a").await?, None);
+        assert_eq!(cache.find_agreed_version(1, "group-a").await?, None);
+        assert!(cache.get_latest_term_for_group("group-a").await?.is_some());
+        assert_eq!(cache.find_agreed_version(1, "group-b").await?, Some(1));
+
+        Ok(())
+    }
 }
@@ -1,7 +1,8 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use domain::{
     data::{CacheServiceWithHealthCheck, health_check::HealthCheck, service::CacheService},
-    entities::TermOfUse,
+    entities::{ConsentStatus, TermOfUse},
     errors::Result,
 };
 
@@ -16,11 +17,35 @@ impl NoopCache {
 
 #[async_trait]
 impl CacheService for NoopCache {
-    async fn find_user_agreement(&self, _user_id: i32, _group: &str) -> Result<Option<bool>> {
+    async fn find_user_agreement(
+        &self,
+        _user_id: i32,
+        _group: &str,
+    ) -> Result<Option<ConsentStatus>> {
         Ok(None)
     }
 
-    async fn store_user_agreement(&self, _user_id: i32, _group: &str, _agreed: bool) -> Result<()> {
+    async fn store_user_agreement(
+        &self,
+        _user_id: i32,
+        _group: &str,
+        _status: ConsentStatus,
+        _expires_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn find_agreed_version(&self, _user_id: i32, _group: &str) -> Result<Option<u32>> {
+        Ok(None)
+    }
+
+    async fn store_agreed_version(
+        &self,
+        _user_id: i32,
+        _group: &str,
+        _version: u32,
+        _expires_at: Option<NaiveDateTime>,
+    ) -> Result<()> {
         Ok(())
     }
 
@@ -35,6 +60,10 @@ impl CacheService for NoopCache {
     async fn invalidate_cache_for_group(&self, _group: &str) -> Result<()> {
         Ok(())
     }
+
+    async fn invalidate_consents_for_group(&self, _group: &str) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[async_trait]
@@ -76,7 +105,9 @@ mod tests {
     async fn store_user_agreement_should_always_succeed() {
         let cache = NoopCache::new().await;
 
-        let result = cache.store_user_agreement(1, "privacy-policy", true).await;
+        let result = cache
+            .store_user_agreement(1, "privacy-policy", ConsentStatus::ConsentedToLatest, None)
+            .await;
 
         assert!(
             result.is_ok(),
@@ -111,4 +142,16 @@ mod tests {
             "invalidate_cache_for_group should always return Ok(())"
         );
     }
+
+    #[tokio::test]
+    async fn invalidate_consents_for_group_should_always_succeed() {
+        let cache = NoopCache::new().await;
+
+        let result = cache.invalidate_consents_for_group("privacy-policy").await;
+
+        assert!(
+            result.is_ok(),
+            "invalidate_consents_for_group should always return Ok(())"
+        );
+    }
 }
@@ -1,11 +1,20 @@
-use aws_sdk_dynamodb::types::{
-    AttributeDefinition, BillingMode, GlobalSecondaryIndex, KeySchemaElement, KeyType, Projection,
-    ProjectionType, ScalarAttributeType,
+use std::{collections::BTreeSet, time::Duration};
+
+use aws_sdk_dynamodb::{
+    client::Waiters,
+    types::{
+        AttributeDefinition, AttributeValue, BillingMode, GlobalSecondaryIndex, KeySchemaElement,
+        KeyType, Projection, ProjectionType, ScalarAttributeType,
+    },
 };
 use domain::errors::{Result, TermsOfUseError};
 use tracing::{error, info};
 
-use crate::database::dynamodb::model::{TERMS_TABLE, USER_AGREEMENTS_TABLE};
+use crate::database::dynamodb::model::{
+    BUNDLES_TABLE, GROUPS_TABLE, TERM_BODIES_TABLE, TERM_RESERVATION_VERSIONS_TABLE,
+    TERM_RESERVATIONS_TABLE, TERM_VERSIONS_TABLE, TERMS_TABLE, UPLOAD_POLICIES_TABLE,
+    USER_AGREEMENTS_TABLE,
+};
 
 pub const GSI_TERMS_GROUP_VERSION: &str = "gsi_group_version";
 pub const COUNTERS_TABLE: &str = "counters";
@@ -25,7 +34,14 @@ pub async fn run_migrations(client: &aws_sdk_dynamodb::Client) -> Result<()> {
 
     create_counters_table(client).await?;
     create_terms_table(client).await?;
+    create_term_bodies_table(client).await?;
+    create_version_table(client, TERM_VERSIONS_TABLE).await?;
     create_user_agreements_table(client).await?;
+    create_term_reservations_table(client).await?;
+    create_version_table(client, TERM_RESERVATION_VERSIONS_TABLE).await?;
+    create_upload_policies_table(client).await?;
+    create_groups_table(client).await?;
+    create_bundles_table(client).await?;
 
     Ok(())
 }
@@ -93,6 +109,41 @@ async fn create_terms_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
     Ok(())
 }
 
+/// Creates the `term_bodies` table holding the rendered HTML and extracted text of terms,
+/// which would not fit the 400 KB of a term item, with:
+/// - Partition key: `term_id` (Number)
+/// - Sort key: `part` (String) - Format: "{html|text}#{chunk index}"
+async fn create_term_bodies_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    if table_exists(client, TERM_BODIES_TABLE).await {
+        info!("Table '{TERM_BODIES_TABLE}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let term_id_attr = build_attribute_definition("term_id", ScalarAttributeType::N)?;
+    let part_attr = build_attribute_definition("part", ScalarAttributeType::S)?;
+
+    client
+        .create_table()
+        .table_name(TERM_BODIES_TABLE)
+        .attribute_definitions(term_id_attr)
+        .attribute_definitions(part_attr)
+        .key_schema(build_key_schema_element("term_id", KeyType::Hash)?)
+        .key_schema(build_key_schema_element("part", KeyType::Range)?)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{TERM_BODIES_TABLE}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{TERM_BODIES_TABLE}'");
+
+    Ok(())
+}
+
 /// Creates the `user_agreements` table with:
 /// - Primary key: `agreement_key` (String) - Format: "{user_id}#{term_id}"
 async fn create_user_agreements_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
@@ -124,6 +175,231 @@ async fn create_user_agreements_table(client: &aws_sdk_dynamodb::Client) -> Resu
     Ok(())
 }
 
+/// Creates the `term_reservations` table with:
+/// - Primary key: `id` (Number)
+async fn create_term_reservations_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    if table_exists(client, TERM_RESERVATIONS_TABLE).await {
+        info!("Table '{TERM_RESERVATIONS_TABLE}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let id_attr = build_attribute_definition("id", ScalarAttributeType::N)?;
+    let pk_schema = build_key_schema_element("id", KeyType::Hash)?;
+
+    client
+        .create_table()
+        .table_name(TERM_RESERVATIONS_TABLE)
+        .attribute_definitions(id_attr)
+        .key_schema(pk_schema)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{TERM_RESERVATIONS_TABLE}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{TERM_RESERVATIONS_TABLE}'");
+
+    Ok(())
+}
+
+/// Creates `term_versions` or `term_reservation_versions`, whose items hold a version of a
+/// group for a term or a reservation, so two of them cannot take the same version, with:
+/// - Primary key: `version_key` (String) - Format: "{group}#{version}"
+///
+/// Terms created before `term_versions` existed are not held, new versions come after them.
+async fn create_version_table(client: &aws_sdk_dynamodb::Client, table_name: &str) -> Result<()> {
+    if table_exists(client, table_name).await {
+        info!("Table '{table_name}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let version_key_attr = build_attribute_definition("version_key", ScalarAttributeType::S)?;
+    let pk_schema = build_key_schema_element("version_key", KeyType::Hash)?;
+
+    client
+        .create_table()
+        .table_name(table_name)
+        .attribute_definitions(version_key_attr)
+        .key_schema(pk_schema)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{table_name}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{table_name}'");
+
+    Ok(())
+}
+
+/// Creates the `upload_policies` table with:
+/// - Primary key: `group` (String)
+async fn create_upload_policies_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    if table_exists(client, UPLOAD_POLICIES_TABLE).await {
+        info!("Table '{UPLOAD_POLICIES_TABLE}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let group_attr = build_attribute_definition("group", ScalarAttributeType::S)?;
+    let pk_schema = build_key_schema_element("group", KeyType::Hash)?;
+
+    client
+        .create_table()
+        .table_name(UPLOAD_POLICIES_TABLE)
+        .attribute_definitions(group_attr)
+        .key_schema(pk_schema)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{UPLOAD_POLICIES_TABLE}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{UPLOAD_POLICIES_TABLE}'");
+
+    Ok(())
+}
+
+/// Creates the `bundles` table with:
+/// - Primary key: `name` (String)
+async fn create_bundles_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    if table_exists(client, BUNDLES_TABLE).await {
+        info!("Table '{BUNDLES_TABLE}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let name_attr = build_attribute_definition("name", ScalarAttributeType::S)?;
+    let pk_schema = build_key_schema_element("name", KeyType::Hash)?;
+
+    client
+        .create_table()
+        .table_name(BUNDLES_TABLE)
+        .attribute_definitions(name_attr)
+        .key_schema(pk_schema)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{BUNDLES_TABLE}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{BUNDLES_TABLE}'");
+
+    Ok(())
+}
+
+/// Creates the `groups` table with:
+/// - Primary key: `name` (String)
+///
+/// A new table is filled with the groups of the existing terms.
+async fn create_groups_table(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    if table_exists(client, GROUPS_TABLE).await {
+        info!("Table '{GROUPS_TABLE}' already exists, skipping creation");
+
+        return Ok(());
+    }
+
+    let name_attr = build_attribute_definition("name", ScalarAttributeType::S)?;
+    let pk_schema = build_key_schema_element("name", KeyType::Hash)?;
+
+    client
+        .create_table()
+        .table_name(GROUPS_TABLE)
+        .attribute_definitions(name_attr)
+        .key_schema(pk_schema)
+        .billing_mode(BillingMode::PayPerRequest)
+        .send()
+        .await
+        .map_err(|err| {
+            error!("Failed to create DynamoDB table '{GROUPS_TABLE}': {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    info!("Created DynamoDB table '{GROUPS_TABLE}'");
+
+    backfill_groups(client).await
+}
+
+/// Registers the groups of existing terms, so they stay usable once unknown groups are rejected.
+async fn backfill_groups(client: &aws_sdk_dynamodb::Client) -> Result<()> {
+    client
+        .wait_until_table_exists()
+        .table_name(GROUPS_TABLE)
+        .wait(Duration::from_secs(60))
+        .await
+        .map_err(|err| {
+            error!("DynamoDB table '{GROUPS_TABLE}' did not become active: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+    let mut groups = BTreeSet::new();
+    let mut exclusive_start_key = None;
+
+    loop {
+        let output = client
+            .scan()
+            .table_name(TERMS_TABLE)
+            .projection_expression("#group")
+            .expression_attribute_names("#group", "group")
+            .set_exclusive_start_key(exclusive_start_key)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to scan the groups of existing terms: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        groups.extend(
+            output
+                .items()
+                .iter()
+                .filter_map(|item| item.get("group")?.as_s().ok().cloned()),
+        );
+
+        match output.last_evaluated_key {
+            Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+            _ => break,
+        }
+    }
+
+    for group in &groups {
+        client
+            .put_item()
+            .table_name(GROUPS_TABLE)
+            .item("name", AttributeValue::S(group.clone()))
+            .item("mandatory", AttributeValue::Bool(false))
+            .item("default_locale", AttributeValue::S("en".to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to register group '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+    }
+
+    info!("Registered {} groups of existing terms", groups.len());
+
+    Ok(())
+}
+
 fn build_attribute_definition(
     name: &str,
     attr_type: ScalarAttributeType,
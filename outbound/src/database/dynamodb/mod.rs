@@ -1,5 +1,9 @@
 use aws_config::BehaviorVersion;
-use aws_sdk_dynamodb::types::{AttributeValue, ReturnValue};
+use aws_sdk_dynamodb::{
+    error::SdkError,
+    operation::transact_write_items::TransactWriteItemsError,
+    types::{AttributeValue, ReturnValue},
+};
 use domain::{
     data::{DatabaseRepositoryWithHealthCheck, repository::DatabaseRepository},
     errors::{Result, TermsOfUseError},
@@ -82,6 +86,17 @@ impl DynamoRepository {
     }
 }
 
+/// Tells whether a transaction was canceled because the condition of one of its writes failed.
+fn is_transaction_condition_failed<R>(err: &SdkError<TransactWriteItemsError, R>) -> bool {
+    match err.as_service_error() {
+        Some(TransactWriteItemsError::TransactionCanceledException(canceled)) => canceled
+            .cancellation_reasons()
+            .iter()
+            .any(|reason| reason.code() == Some("ConditionalCheckFailed")),
+        _ => false,
+    }
+}
+
 impl DatabaseRepository for DynamoRepository {}
 
 impl DatabaseRepositoryWithHealthCheck for DynamoRepository {}
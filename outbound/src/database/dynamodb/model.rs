@@ -1,15 +1,26 @@
 use std::collections::HashMap;
 
 use aws_sdk_dynamodb::types::AttributeValue;
-use chrono::DateTime;
+use chrono::{DateTime, NaiveDateTime};
 use domain::{
-    entities::TermOfUse,
+    entities::{
+        Bundle, ChangeSummary, Clause, Group, PdfMetadata, TermMetadata, TermOfUse,
+        TermReservation, UploadPolicy, UserAgreement,
+    },
     errors::{Result, TermsOfUseError},
 };
+use serde_json::{Number, Value};
 use tracing::error;
 
 pub const TERMS_TABLE: &str = "terms";
+pub const TERM_BODIES_TABLE: &str = "term_bodies";
 pub const USER_AGREEMENTS_TABLE: &str = "user_agreements";
+pub const TERM_RESERVATIONS_TABLE: &str = "term_reservations";
+pub const TERM_VERSIONS_TABLE: &str = "term_versions";
+pub const TERM_RESERVATION_VERSIONS_TABLE: &str = "term_reservation_versions";
+pub const UPLOAD_POLICIES_TABLE: &str = "upload_policies";
+pub const GROUPS_TABLE: &str = "groups";
+pub const BUNDLES_TABLE: &str = "bundles";
 
 fn as_string(val: Option<&AttributeValue>) -> String {
     if let Some(v) = val
@@ -53,6 +64,17 @@ fn as_i64(val: Option<&AttributeValue>) -> i64 {
     0
 }
 
+fn as_u64(val: Option<&AttributeValue>) -> u64 {
+    if let Some(v) = val
+        && let Ok(s) = v.as_n()
+        && let Ok(n) = s.parse::<u64>()
+    {
+        return n;
+    }
+
+    0
+}
+
 fn as_u32(val: Option<&AttributeValue>) -> u32 {
     if let Some(v) = val
         && let Ok(s) = v.as_n()
@@ -64,6 +86,215 @@ fn as_u32(val: Option<&AttributeValue>) -> u32 {
     0
 }
 
+fn as_optional_u32(val: Option<&AttributeValue>) -> Option<u32> {
+    if let Some(v) = val
+        && let Ok(s) = v.as_n()
+        && let Ok(n) = s.parse::<u32>()
+    {
+        return Some(n);
+    }
+
+    None
+}
+
+fn as_change_summaries(val: Option<&AttributeValue>) -> Vec<ChangeSummary> {
+    let Some(Ok(entries)) = val.map(AttributeValue::as_l) else {
+        return vec![];
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| entry.as_m().ok())
+        .map(|entry| ChangeSummary {
+            locale: as_string(entry.get("locale")),
+            markdown: as_string(entry.get("markdown")),
+            html: as_string(entry.get("html")),
+        })
+        .collect()
+}
+
+pub fn change_summaries_to_attribute(summaries: &[ChangeSummary]) -> AttributeValue {
+    AttributeValue::L(
+        summaries
+            .iter()
+            .map(|summary| {
+                AttributeValue::M(HashMap::from([
+                    (
+                        "locale".to_string(),
+                        AttributeValue::S(summary.locale.clone()),
+                    ),
+                    (
+                        "markdown".to_string(),
+                        AttributeValue::S(summary.markdown.clone()),
+                    ),
+                    ("html".to_string(), AttributeValue::S(summary.html.clone())),
+                ]))
+            })
+            .collect(),
+    )
+}
+
+fn as_clauses(val: Option<&AttributeValue>) -> Vec<Clause> {
+    let Some(Ok(entries)) = val.map(AttributeValue::as_l) else {
+        return vec![];
+    };
+
+    entries
+        .iter()
+        .filter_map(|entry| entry.as_m().ok())
+        .map(|entry| Clause {
+            key: as_string(entry.get("key")),
+            title: as_string(entry.get("title")),
+            mandatory: matches!(entry.get("mandatory"), Some(AttributeValue::Bool(true))),
+        })
+        .collect()
+}
+
+pub fn clauses_to_attribute(clauses: &[Clause]) -> AttributeValue {
+    AttributeValue::L(
+        clauses
+            .iter()
+            .map(|clause| {
+                AttributeValue::M(HashMap::from([
+                    ("key".to_string(), AttributeValue::S(clause.key.clone())),
+                    ("title".to_string(), AttributeValue::S(clause.title.clone())),
+                    (
+                        "mandatory".to_string(),
+                        AttributeValue::Bool(clause.mandatory),
+                    ),
+                ]))
+            })
+            .collect(),
+    )
+}
+
+/// Optional clauses recorded with an agreement, agreements made before clauses existed have none.
+pub fn map_accepted_clauses_from_item(item: &HashMap<String, AttributeValue>) -> Vec<String> {
+    match item.get("accepted_clauses").map(AttributeValue::as_l) {
+        Some(Ok(entries)) => entries
+            .iter()
+            .filter_map(|entry| entry.as_s().ok().cloned())
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// When an agreement was given, stored as the text of a naive UTC date.
+pub fn map_agreed_at_from_item(item: &HashMap<String, AttributeValue>) -> Result<NaiveDateTime> {
+    let agreed_at = as_string(item.get("agreed_at"));
+
+    NaiveDateTime::parse_from_str(&agreed_at, "%Y-%m-%d %H:%M:%S%.f").map_err(|err| {
+        error!("Failed to parse agreed_at date '{agreed_at}': {err}");
+
+        TermsOfUseError::InternalServerError
+    })
+}
+
+/// Every agreement of an agreement item, oldest first. Items written before renewals were
+/// kept only hold their latest agreement.
+pub fn map_agreements_from_item(
+    item: &HashMap<String, AttributeValue>,
+) -> Result<Vec<UserAgreement>> {
+    let user_id = as_i32(item.get("user_id"));
+    let term_id = as_i32(item.get("term_id"));
+
+    let agreement = |entry: &HashMap<String, AttributeValue>| {
+        Ok(UserAgreement {
+            user_id,
+            term_id,
+            agreed_at: map_agreed_at_from_item(entry)?,
+            accepted_clauses: map_accepted_clauses_from_item(entry),
+        })
+    };
+
+    match item.get("agreements").map(AttributeValue::as_l) {
+        Some(Ok(entries)) => entries
+            .iter()
+            .filter_map(|entry| entry.as_m().ok())
+            .map(agreement)
+            .collect(),
+        _ => Ok(vec![agreement(item)?]),
+    }
+}
+
+pub fn accepted_clauses_to_attribute(accepted_clauses: &[String]) -> AttributeValue {
+    AttributeValue::L(
+        accepted_clauses
+            .iter()
+            .cloned()
+            .map(AttributeValue::S)
+            .collect(),
+    )
+}
+
+pub fn agreements_to_attribute(agreements: &[UserAgreement]) -> AttributeValue {
+    AttributeValue::L(
+        agreements
+            .iter()
+            .map(|agreement| {
+                AttributeValue::M(HashMap::from([
+                    (
+                        "agreed_at".to_string(),
+                        AttributeValue::S(agreement.agreed_at.to_string()),
+                    ),
+                    (
+                        "accepted_clauses".to_string(),
+                        accepted_clauses_to_attribute(&agreement.accepted_clauses),
+                    ),
+                ]))
+            })
+            .collect(),
+    )
+}
+
+fn as_json(val: &AttributeValue) -> Value {
+    match val {
+        AttributeValue::Bool(b) => Value::Bool(*b),
+        AttributeValue::N(n) => n
+            .parse::<i64>()
+            .map(Number::from)
+            .ok()
+            .or_else(|| n.parse::<f64>().ok().and_then(Number::from_f64))
+            .map_or(Value::Null, Value::Number),
+        AttributeValue::S(s) => Value::String(s.clone()),
+        AttributeValue::L(entries) => Value::Array(entries.iter().map(as_json).collect()),
+        AttributeValue::M(entries) => Value::Object(
+            entries
+                .iter()
+                .map(|(key, value)| (key.clone(), as_json(value)))
+                .collect(),
+        ),
+        _ => Value::Null,
+    }
+}
+
+pub fn json_to_attribute(value: &Value) -> AttributeValue {
+    match value {
+        Value::Null => AttributeValue::Null(true),
+        Value::Bool(b) => AttributeValue::Bool(*b),
+        Value::Number(n) => AttributeValue::N(n.to_string()),
+        Value::String(s) => AttributeValue::S(s.clone()),
+        Value::Array(entries) => AttributeValue::L(entries.iter().map(json_to_attribute).collect()),
+        Value::Object(entries) => AttributeValue::M(
+            entries
+                .iter()
+                .map(|(key, value)| (key.clone(), json_to_attribute(value)))
+                .collect(),
+        ),
+    }
+}
+
+fn as_metadata(val: Option<&AttributeValue>) -> TermMetadata {
+    match val.map(as_json) {
+        Some(Value::Object(metadata)) => metadata,
+        _ => TermMetadata::new(),
+    }
+}
+
+pub fn metadata_to_attribute(metadata: &TermMetadata) -> AttributeValue {
+    json_to_attribute(&Value::Object(metadata.clone()))
+}
+
 pub fn map_term_from_item(item: &HashMap<String, AttributeValue>) -> Result<TermOfUse> {
     let id = as_i32(item.get("id"));
     let group = as_string(item.get("group"));
@@ -86,5 +317,115 @@ pub fn map_term_from_item(item: &HashMap<String, AttributeValue>) -> Result<Term
         url,
         info,
         created_at,
+        html: None,
+        text: None,
+        change_summaries: as_change_summaries(item.get("change_summaries")),
+        pdf_metadata: item.get("pdf_page_count").map(|page_count| PdfMetadata {
+            page_count: as_u32(Some(page_count)),
+            title: as_optional_string(item.get("pdf_title")),
+            producer: as_optional_string(item.get("pdf_producer")),
+        }),
+        metadata: as_metadata(item.get("metadata")),
+        clauses: as_clauses(item.get("clauses")),
+    })
+}
+
+/// Number of `term_bodies` chunks of a body part of a term item, `None` when the term
+/// has no such part.
+pub fn term_body_chunks(item: &HashMap<String, AttributeValue>, part: &str) -> Option<u32> {
+    item.get(&format!("{part}_chunks"))
+        .map(|chunks| as_u32(Some(chunks)))
+}
+
+/// Key of the items of `term_versions` and `term_reservation_versions` that hold a version
+/// of a group.
+pub fn version_key(group: &str, version: u32) -> String {
+    format!("{group}#{version}")
+}
+
+pub fn map_reservation_from_item(
+    item: &HashMap<String, AttributeValue>,
+) -> Result<TermReservation> {
+    let timestamp = as_i64(item.get("expires_at"));
+    let expires_at = DateTime::from_timestamp(timestamp, 0)
+        .ok_or_else(|| {
+            error!("Failed to parse expires_at timestamp: {timestamp}");
+
+            TermsOfUseError::InternalServerError
+        })?
+        .naive_utc();
+
+    Ok(TermReservation {
+        id: as_i32(item.get("id")),
+        group: as_string(item.get("group")),
+        version: as_u32(item.get("version")),
+        info: as_optional_string(item.get("info")),
+        key: as_string(item.get("key")),
+        content_type: as_string(item.get("content_type")),
+        size: as_u64(item.get("size")),
+        sha256: as_string(item.get("sha256")),
+        metadata: as_metadata(item.get("metadata")),
+        clauses: as_clauses(item.get("clauses")),
+        change_summaries: as_change_summaries(item.get("change_summaries")),
+        expires_at,
     })
 }
+
+pub fn map_upload_policy_from_item(item: &HashMap<String, AttributeValue>) -> UploadPolicy {
+    let content_types = match item.get("content_types").map(AttributeValue::as_l) {
+        Some(Ok(entries)) => entries
+            .iter()
+            .filter_map(|entry| entry.as_s().ok().cloned())
+            .collect(),
+        _ => vec![],
+    };
+    let required_fields = match item.get("required_fields").map(AttributeValue::as_l) {
+        Some(Ok(entries)) => entries
+            .iter()
+            .filter_map(|entry| entry.as_s().ok().cloned())
+            .collect(),
+        // Policies saved before fields could be named only required info
+        _ if matches!(item.get("require_info"), Some(AttributeValue::Bool(true))) => {
+            vec!["info".to_string()]
+        }
+        _ => vec![],
+    };
+
+    UploadPolicy {
+        group: as_string(item.get("group")),
+        content_types,
+        max_size: as_u64(item.get("max_size")),
+        required_fields,
+        // Schemas are stored as JSON text so that keywords such as `$ref`
+        // survive the round trip untouched.
+        metadata_schema: as_optional_string(item.get("metadata_schema"))
+            .and_then(|schema| serde_json::from_str(&schema).ok()),
+    }
+}
+
+pub fn map_group_from_item(item: &HashMap<String, AttributeValue>) -> Group {
+    Group {
+        name: as_string(item.get("name")),
+        description: as_optional_string(item.get("description")),
+        owner: as_optional_string(item.get("owner")),
+        mandatory: matches!(item.get("mandatory"), Some(AttributeValue::Bool(true))),
+        default_locale: as_string(item.get("default_locale")),
+        max_consent_age_days: as_optional_u32(item.get("max_consent_age_days")),
+    }
+}
+
+pub fn map_bundle_from_item(item: &HashMap<String, AttributeValue>) -> Bundle {
+    let groups = match item.get("groups").map(AttributeValue::as_l) {
+        Some(Ok(entries)) => entries
+            .iter()
+            .filter_map(|entry| entry.as_s().ok().cloned())
+            .collect(),
+        _ => vec![],
+    };
+
+    Bundle {
+        name: as_string(item.get("name")),
+        description: as_optional_string(item.get("description")),
+        groups,
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use domain::{data::repository::BundleRepository, entities::Bundle, errors::TermsOfUseError};
+use tracing::error;
+
+use crate::database::dynamodb::{
+    DynamoRepository,
+    model::{BUNDLES_TABLE, map_bundle_from_item},
+};
+
+fn bundle_to_item(bundle: &Bundle) -> HashMap<String, AttributeValue> {
+    let mut item = HashMap::new();
+
+    item.insert("name".to_string(), AttributeValue::S(bundle.name.clone()));
+    if let Some(description) = &bundle.description {
+        item.insert(
+            "description".to_string(),
+            AttributeValue::S(description.clone()),
+        );
+    }
+    item.insert(
+        "groups".to_string(),
+        AttributeValue::L(
+            bundle
+                .groups
+                .iter()
+                .cloned()
+                .map(AttributeValue::S)
+                .collect(),
+        ),
+    );
+
+    item
+}
+
+#[async_trait]
+impl BundleRepository for DynamoRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_bundle(&self, name: &str) -> Result<Option<Bundle>, TermsOfUseError> {
+        let value = self
+            .client
+            .get_item()
+            .table_name(BUNDLES_TABLE)
+            .key("name", AttributeValue::S(name.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to get bundle '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(value.item.as_ref().map(map_bundle_from_item))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_bundles(&self) -> Result<Vec<Bundle>, TermsOfUseError> {
+        let mut bundles = Vec::new();
+        let mut exclusive_start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(BUNDLES_TABLE)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to scan bundles: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            bundles.extend(output.items().iter().map(map_bundle_from_item));
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        bundles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(bundles)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_bundle(&self, bundle: Bundle) -> Result<Bundle, TermsOfUseError> {
+        self.client
+            .put_item()
+            .table_name(BUNDLES_TABLE)
+            .set_item(Some(bundle_to_item(&bundle)))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to save bundle '{}': {err}", bundle.name);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(bundle)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_bundle(&self, name: &str) -> Result<(), TermsOfUseError> {
+        self.client
+            .delete_item()
+            .table_name(BUNDLES_TABLE)
+            .key("name", AttributeValue::S(name.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to delete bundle '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::repository::BundleRepository, entities::Bundle};
+
+    use crate::database::dynamodb::DynamoRepository;
+
+    async fn create_test_repository() -> DynamoRepository {
+        DynamoRepository::new().await
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_bundle_round_trip() {
+        let repo = create_test_repository().await;
+
+        let bundle = Bundle {
+            name: "bundlerepository-round-trip".to_string(),
+            description: Some("Accepted when creating an account".to_string()),
+            groups: vec!["terms-of-service".to_string(), "privacy-policy".to_string()],
+        };
+
+        repo.save_bundle(bundle.clone())
+            .await
+            .expect("Bundle should be saved");
+
+        let fetched = repo
+            .get_bundle(&bundle.name)
+            .await
+            .unwrap()
+            .expect("Bundle should exist");
+
+        assert_eq!(fetched, bundle);
+
+        repo.delete_bundle(&bundle.name).await.unwrap();
+
+        assert!(repo.get_bundle(&bundle.name).await.unwrap().is_none());
+    }
+}
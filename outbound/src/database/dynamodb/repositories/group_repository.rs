@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use domain::{data::repository::GroupRepository, entities::Group, errors::TermsOfUseError};
+use tracing::error;
+
+use crate::database::dynamodb::{
+    DynamoRepository,
+    model::{GROUPS_TABLE, map_group_from_item},
+};
+
+fn group_to_item(group: &Group) -> HashMap<String, AttributeValue> {
+    let mut item = HashMap::new();
+
+    item.insert("name".to_string(), AttributeValue::S(group.name.clone()));
+    if let Some(description) = &group.description {
+        item.insert(
+            "description".to_string(),
+            AttributeValue::S(description.clone()),
+        );
+    }
+    if let Some(owner) = &group.owner {
+        item.insert("owner".to_string(), AttributeValue::S(owner.clone()));
+    }
+    item.insert(
+        "mandatory".to_string(),
+        AttributeValue::Bool(group.mandatory),
+    );
+    item.insert(
+        "default_locale".to_string(),
+        AttributeValue::S(group.default_locale.clone()),
+    );
+    if let Some(days) = group.max_consent_age_days {
+        item.insert(
+            "max_consent_age_days".to_string(),
+            AttributeValue::N(days.to_string()),
+        );
+    }
+
+    item
+}
+
+#[async_trait]
+impl GroupRepository for DynamoRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_group(&self, name: &str) -> Result<Option<Group>, TermsOfUseError> {
+        let value = self
+            .client
+            .get_item()
+            .table_name(GROUPS_TABLE)
+            .key("name", AttributeValue::S(name.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to get group '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(value.item.as_ref().map(map_group_from_item))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_groups(&self) -> Result<Vec<Group>, TermsOfUseError> {
+        let mut groups = Vec::new();
+        let mut exclusive_start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(GROUPS_TABLE)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to scan groups: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            groups.extend(output.items().iter().map(map_group_from_item));
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(groups)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_group(&self, group: Group) -> Result<Group, TermsOfUseError> {
+        self.client
+            .put_item()
+            .table_name(GROUPS_TABLE)
+            .set_item(Some(group_to_item(&group)))
+            .condition_expression("attribute_not_exists(#name)")
+            .expression_attribute_names("#name", "name")
+            .send()
+            .await
+            .map_err(|err| {
+                if err
+                    .as_service_error()
+                    .is_some_and(|err| err.is_conditional_check_failed_exception())
+                {
+                    return TermsOfUseError::Validation(format!(
+                        "Group '{}' already exists",
+                        group.name
+                    ));
+                }
+
+                error!("Failed to create group '{}': {err}", group.name);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(group)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_group(&self, group: Group) -> Result<Group, TermsOfUseError> {
+        self.client
+            .put_item()
+            .table_name(GROUPS_TABLE)
+            .set_item(Some(group_to_item(&group)))
+            .condition_expression("attribute_exists(#name)")
+            .expression_attribute_names("#name", "name")
+            .send()
+            .await
+            .map_err(|err| {
+                if err
+                    .as_service_error()
+                    .is_some_and(|err| err.is_conditional_check_failed_exception())
+                {
+                    return TermsOfUseError::NotFound;
+                }
+
+                error!("Failed to update group '{}': {err}", group.name);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(group)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_group(&self, name: &str) -> Result<(), TermsOfUseError> {
+        self.client
+            .delete_item()
+            .table_name(GROUPS_TABLE)
+            .key("name", AttributeValue::S(name.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to delete group '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::repository::GroupRepository, entities::Group, errors::TermsOfUseError};
+
+    use crate::database::dynamodb::DynamoRepository;
+
+    async fn create_test_repository() -> DynamoRepository {
+        DynamoRepository::new().await
+    }
+
+    fn sample_group(name: &str) -> Group {
+        Group {
+            name: name.to_string(),
+            description: Some("How we process personal data".to_string()),
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: Some(365),
+        }
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_group_round_trip() {
+        let repo = create_test_repository().await;
+
+        let name = "grouprepository-round-trip";
+        let _ = repo.delete_group(name).await;
+
+        repo.create_group(sample_group(name))
+            .await
+            .expect("Group should be created");
+        assert!(matches!(
+            repo.create_group(sample_group(name)).await,
+            Err(TermsOfUseError::Validation(_))
+        ));
+
+        let updated = Group {
+            description: None,
+            mandatory: false,
+            ..sample_group(name)
+        };
+        repo.update_group(updated.clone())
+            .await
+            .expect("Group should be updated");
+
+        let fetched = repo
+            .get_group(name)
+            .await
+            .unwrap()
+            .expect("Group should exist");
+
+        assert_eq!(fetched, updated);
+        assert!(
+            repo.get_groups()
+                .await
+                .unwrap()
+                .iter()
+                .any(|group| group.name == name)
+        );
+
+        repo.delete_group(name).await.unwrap();
+
+        assert!(repo.get_group(name).await.unwrap().is_none());
+        assert!(matches!(
+            repo.update_group(sample_group(name)).await,
+            Err(TermsOfUseError::NotFound)
+        ));
+    }
+}
@@ -1,2 +1,6 @@
+mod bundle_repository;
+mod group_repository;
 mod term_repository;
+mod term_reservation_repository;
+mod upload_policy_repository;
 mod user_agreement_repository;
@@ -1,14 +1,138 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use aws_sdk_dynamodb::types::AttributeValue;
-use domain::{data::repository::TermRepository, entities::TermOfUse, errors::TermsOfUseError};
+use aws_sdk_dynamodb::types::{AttributeValue, Put, TransactWriteItem};
+use domain::{
+    data::repository::TermRepository,
+    entities::{TermMetadata, TermOfUse},
+    errors::TermsOfUseError,
+};
+use futures::future::try_join_all;
 use tracing::error;
 
 use crate::database::dynamodb::{
-    DynamoRepository,
+    DynamoRepository, is_transaction_condition_failed,
     migration::GSI_TERMS_GROUP_VERSION,
-    model::{TERMS_TABLE, map_term_from_item},
+    model::{
+        TERM_BODIES_TABLE, TERM_VERSIONS_TABLE, TERMS_TABLE, change_summaries_to_attribute,
+        clauses_to_attribute, json_to_attribute, map_term_from_item, metadata_to_attribute,
+        term_body_chunks, version_key,
+    },
 };
 
+/// Bodies are split into chunks well below the 400 KB limit of an item.
+const BODY_CHUNK_SIZE: usize = 350 * 1024;
+
+/// Splits a body into chunks of at most `BODY_CHUNK_SIZE` bytes, on character boundaries.
+fn body_chunks(body: &str) -> Vec<&str> {
+    let mut chunks = Vec::new();
+    let mut rest = body;
+
+    while !rest.is_empty() {
+        let mut end = rest.len().min(BODY_CHUNK_SIZE);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    chunks
+}
+
+impl DynamoRepository {
+    /// Stores the chunks of a body part and returns how many there are.
+    async fn put_term_body(
+        &self,
+        term_id: i32,
+        part: &str,
+        body: &str,
+    ) -> Result<u32, TermsOfUseError> {
+        let chunks = body_chunks(body);
+
+        for (index, chunk) in chunks.iter().enumerate() {
+            self.client
+                .put_item()
+                .table_name(TERM_BODIES_TABLE)
+                .item("term_id", AttributeValue::N(term_id.to_string()))
+                .item("part", AttributeValue::S(format!("{part}#{index:05}")))
+                .item("content", AttributeValue::S(chunk.to_string()))
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to store {part} of term '{term_id}': {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+        }
+
+        Ok(chunks.len() as u32)
+    }
+
+    /// Reads a term item along with the body parts kept in `term_bodies`.
+    async fn map_term_with_body(
+        &self,
+        item: &HashMap<String, AttributeValue>,
+    ) -> Result<TermOfUse, TermsOfUseError> {
+        let mut term = map_term_from_item(item)?;
+
+        let html_chunks = term_body_chunks(item, "html");
+        let text_chunks = term_body_chunks(item, "text");
+
+        let mut html = String::new();
+        let mut text = String::new();
+
+        if html_chunks.unwrap_or_default() + text_chunks.unwrap_or_default() > 0 {
+            let mut exclusive_start_key = None;
+
+            loop {
+                let output = self
+                    .client
+                    .query()
+                    .table_name(TERM_BODIES_TABLE)
+                    .key_condition_expression("term_id = :term_id")
+                    .expression_attribute_values(":term_id", AttributeValue::N(term.id.to_string()))
+                    .set_exclusive_start_key(exclusive_start_key)
+                    .send()
+                    .await
+                    .map_err(|err| {
+                        error!("Failed to query body of term '{}': {err}", term.id);
+
+                        TermsOfUseError::InternalServerError
+                    })?;
+
+                // Chunks come ordered by their `part` key
+                for chunk in output.items() {
+                    let part = chunk.get("part").and_then(|part| part.as_s().ok());
+                    let content = chunk.get("content").and_then(|content| content.as_s().ok());
+
+                    match (part, content) {
+                        (Some(part), Some(content)) if part.starts_with("html#") => {
+                            html.push_str(content)
+                        }
+                        (Some(part), Some(content)) if part.starts_with("text#") => {
+                            text.push_str(content)
+                        }
+                        _ => {}
+                    }
+                }
+
+                match output.last_evaluated_key {
+                    Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                    _ => break,
+                }
+            }
+        }
+
+        term.html = html_chunks.map(|_| html);
+        term.text = text_chunks.map(|_| text);
+
+        Ok(term)
+    }
+}
+
 #[async_trait]
 impl TermRepository for DynamoRepository {
     #[tracing::instrument(skip(self, group))]
@@ -37,7 +161,7 @@ impl TermRepository for DynamoRepository {
         if let Some(items) = value.items
             && let Some(item) = items.first()
         {
-            let term = map_term_from_item(item)?;
+            let term = self.map_term_with_body(item).await?;
 
             return Ok(Some(term));
         }
@@ -45,6 +169,22 @@ impl TermRepository for DynamoRepository {
         Ok(None)
     }
 
+    #[tracing::instrument(skip(self, groups))]
+    async fn get_latest_terms_for_groups(
+        &self,
+        groups: &[String],
+    ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+        // The group index only answers one group per query, so the queries run concurrently
+        let latest_terms = try_join_all(
+            groups
+                .iter()
+                .map(|group| self.get_latest_term_for_group(group)),
+        )
+        .await?;
+
+        Ok(latest_terms.into_iter().flatten().collect())
+    }
+
     #[tracing::instrument(skip(self, term_id))]
     async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>, TermsOfUseError> {
         let value = self
@@ -61,7 +201,7 @@ impl TermRepository for DynamoRepository {
             })?;
 
         if let Some(item) = value.item {
-            let term = map_term_from_item(&item)?;
+            let term = self.map_term_with_body(&item).await?;
 
             return Ok(Some(term));
         }
@@ -69,12 +209,102 @@ impl TermRepository for DynamoRepository {
         Ok(None)
     }
 
+    #[tracing::instrument(skip(self, group))]
+    async fn get_term_by_version(
+        &self,
+        group: &str,
+        version: u32,
+    ) -> Result<Option<TermOfUse>, TermsOfUseError> {
+        let value = self
+            .client
+            .query()
+            .table_name(TERMS_TABLE)
+            .index_name(GSI_TERMS_GROUP_VERSION)
+            .key_condition_expression("#group = :group AND #version = :version")
+            .expression_attribute_names("#group", "group")
+            .expression_attribute_names("#version", "version")
+            .expression_attribute_values(":group", AttributeValue::S(group.to_string()))
+            .expression_attribute_values(":version", AttributeValue::N(version.to_string()))
+            .limit(1)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to query version {version} of group '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        match value.items.and_then(|items| items.into_iter().next()) {
+            Some(item) => Ok(Some(self.map_term_with_body(&item).await?)),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(skip(self, group, metadata))]
+    async fn get_terms_for_group(
+        &self,
+        group: &str,
+        metadata: &TermMetadata,
+    ) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+        let mut terms = Vec::new();
+        let mut exclusive_start_key = None;
+
+        // Each filter entry becomes `#metadata.#kN = :vN`, placeholders keep
+        // arbitrary keys from clashing with reserved words.
+        let filter_expression = (!metadata.is_empty()).then(|| {
+            (0..metadata.len())
+                .map(|index| format!("#metadata.#k{index} = :v{index}"))
+                .collect::<Vec<_>>()
+                .join(" AND ")
+        });
+
+        loop {
+            let mut query = self
+                .client
+                .query()
+                .table_name(TERMS_TABLE)
+                .index_name(GSI_TERMS_GROUP_VERSION)
+                .key_condition_expression("#group = :group")
+                .expression_attribute_names("#group", "group")
+                .expression_attribute_values(":group", AttributeValue::S(group.to_string()))
+                .scan_index_forward(false)
+                .set_filter_expression(filter_expression.clone())
+                .set_exclusive_start_key(exclusive_start_key);
+
+            if !metadata.is_empty() {
+                query = query.expression_attribute_names("#metadata", "metadata");
+            }
+            for (index, (key, value)) in metadata.iter().enumerate() {
+                query = query
+                    .expression_attribute_names(format!("#k{index}"), key)
+                    .expression_attribute_values(format!(":v{index}"), json_to_attribute(value));
+            }
+
+            let output = query.send().await.map_err(|err| {
+                error!("Failed to query terms of group '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+            for item in output.items() {
+                terms.push(map_term_from_item(item)?);
+            }
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        Ok(terms)
+    }
+
     #[tracing::instrument(skip(self, term))]
     async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse, TermsOfUseError> {
         // Generate next ID atomically
         let id = self.get_next_id("terms_id_counter").await?;
 
-        let mut item = std::collections::HashMap::new();
+        let mut item = HashMap::new();
 
         item.insert("id".to_string(), AttributeValue::N(id.to_string()));
         item.insert("group".to_string(), AttributeValue::S(term.group.clone()));
@@ -86,18 +316,96 @@ impl TermRepository for DynamoRepository {
         if let Some(info) = &term.info {
             item.insert("info".to_string(), AttributeValue::S(info.clone()));
         }
+        // Bodies are stored first, so a term never points to a missing one
+        if let Some(html) = &term.html {
+            let chunks = self.put_term_body(id, "html", html).await?;
+            item.insert(
+                "html_chunks".to_string(),
+                AttributeValue::N(chunks.to_string()),
+            );
+        }
+        if let Some(text) = &term.text {
+            let chunks = self.put_term_body(id, "text", text).await?;
+            item.insert(
+                "text_chunks".to_string(),
+                AttributeValue::N(chunks.to_string()),
+            );
+        }
+        if !term.change_summaries.is_empty() {
+            item.insert(
+                "change_summaries".to_string(),
+                change_summaries_to_attribute(&term.change_summaries),
+            );
+        }
+        if let Some(metadata) = &term.pdf_metadata {
+            item.insert(
+                "pdf_page_count".to_string(),
+                AttributeValue::N(metadata.page_count.to_string()),
+            );
+            if let Some(title) = &metadata.title {
+                item.insert("pdf_title".to_string(), AttributeValue::S(title.clone()));
+            }
+            if let Some(producer) = &metadata.producer {
+                item.insert(
+                    "pdf_producer".to_string(),
+                    AttributeValue::S(producer.clone()),
+                );
+            }
+        }
+        if !term.metadata.is_empty() {
+            item.insert(
+                "metadata".to_string(),
+                metadata_to_attribute(&term.metadata),
+            );
+        }
+        if !term.clauses.is_empty() {
+            item.insert("clauses".to_string(), clauses_to_attribute(&term.clauses));
+        }
         item.insert(
             "created_at".to_string(),
             AttributeValue::N(term.created_at.and_utc().timestamp().to_string()),
         );
 
-        self.client
-            .put_item()
+        // The version is held along with the term, so no two terms of a group share it
+        let version = Put::builder()
+            .table_name(TERM_VERSIONS_TABLE)
+            .item(
+                "version_key",
+                AttributeValue::S(version_key(&term.group, term.version)),
+            )
+            .item("term_id", AttributeValue::N(id.to_string()))
+            .condition_expression("attribute_not_exists(version_key)")
+            .build()
+            .map_err(|err| {
+                error!("Failed to build the version of term '{id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+        let term_item = Put::builder()
             .table_name(TERMS_TABLE)
             .set_item(Some(item))
+            .build()
+            .map_err(|err| {
+                error!("Failed to build term '{id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        self.client
+            .transact_write_items()
+            .transact_items(TransactWriteItem::builder().put(version).build())
+            .transact_items(TransactWriteItem::builder().put(term_item).build())
             .send()
             .await
             .map_err(|err| {
+                // The bodies stored above are left behind, no term refers to them
+                if is_transaction_condition_failed(&err) {
+                    return TermsOfUseError::Validation(format!(
+                        "Version {} of group '{}' already exists",
+                        term.version, term.group
+                    ));
+                }
+
                 error!("Failed to create term '{:?}': {err}", term);
 
                 TermsOfUseError::InternalServerError
@@ -110,15 +418,58 @@ impl TermRepository for DynamoRepository {
             version: term.version,
             info: term.info,
             created_at: term.created_at,
+            html: term.html,
+            text: term.text,
+            change_summaries: term.change_summaries,
+            pdf_metadata: term.pdf_metadata,
+            metadata: term.metadata,
+            clauses: term.clauses,
         })
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_terms(&self) -> Result<Vec<TermOfUse>, TermsOfUseError> {
+        let mut terms = Vec::new();
+        let mut exclusive_start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(TERMS_TABLE)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to scan terms: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            for item in output.items() {
+                terms.push(map_term_from_item(item)?);
+            }
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        Ok(terms)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
-    use domain::{data::repository::TermRepository, entities::TermOfUse};
+    use domain::{
+        data::repository::TermRepository,
+        entities::{ChangeSummary, Clause, PdfMetadata, TermOfUse},
+        errors::TermsOfUseError,
+    };
 
+    use super::{BODY_CHUNK_SIZE, body_chunks};
     use crate::database::dynamodb::DynamoRepository;
 
     async fn create_test_repository() -> DynamoRepository {
@@ -133,6 +484,12 @@ mod tests {
             version,
             info: Some(format!("Test term for {group} v{version}")),
             created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         }
     }
 
@@ -174,6 +531,12 @@ mod tests {
             version: 1,
             info: Some("Test term".to_string()),
             created_at: created_at,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let result = repo.create_term(term).await.unwrap();
@@ -185,6 +548,304 @@ mod tests {
         assert!(result.id > 0);
     }
 
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_term_rejects_taken_version() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-taken-version";
+
+        repo.create_term(create_sample_term(0, GROUP, 1))
+            .await
+            .unwrap();
+
+        let result = repo.create_term(create_sample_term(0, GROUP, 1)).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_term_by_version_returns_matching_version() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-by-version";
+
+        let first = TermOfUse {
+            text: Some("First version".to_string()),
+            ..create_sample_term(0, GROUP, 1)
+        };
+        repo.create_term(first).await.unwrap();
+        repo.create_term(create_sample_term(0, GROUP, 2))
+            .await
+            .unwrap();
+
+        let retrieved_term = repo
+            .get_term_by_version(GROUP, 1)
+            .await
+            .unwrap()
+            .expect("Term should exist");
+
+        assert_eq!(retrieved_term.version, 1);
+        assert_eq!(retrieved_term.text.as_deref(), Some("First version"));
+        assert!(repo.get_term_by_version(GROUP, 3).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_terms_for_group_returns_history_with_change_summaries() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-history";
+
+        repo.create_term(create_sample_term(0, GROUP, 1))
+            .await
+            .unwrap();
+        let second = TermOfUse {
+            change_summaries: vec![ChangeSummary {
+                locale: "en".to_string(),
+                markdown: "Shorter **retention**".to_string(),
+                html: "<p>Shorter <strong>retention</strong></p>".to_string(),
+            }],
+            ..create_sample_term(0, GROUP, 2)
+        };
+        repo.create_term(second).await.unwrap();
+
+        let history = repo
+            .get_terms_for_group(GROUP, &Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].version, 2);
+        assert_eq!(history[0].change_summaries[0].locale, "en");
+        assert_eq!(
+            history[0].change_summaries[0].markdown,
+            "Shorter **retention**"
+        );
+        assert!(history[1].change_summaries.is_empty());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_terms_for_group_leaves_out_bodies() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-history-without-body";
+        let term = TermOfUse {
+            html: Some("<h1>Terms</h1>".to_string()),
+            text: Some("Terms".to_string()),
+            ..create_sample_term(0, GROUP, 1)
+        };
+        repo.create_term(term).await.unwrap();
+
+        let history = repo
+            .get_terms_for_group(GROUP, &Default::default())
+            .await
+            .unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].version, 1);
+        assert!(history[0].html.is_none());
+        assert!(history[0].text.is_none());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_terms_for_group_filters_by_metadata() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-metadata-filter";
+
+        let eu = TermOfUse {
+            metadata: serde_json::json!({ "region": "eu", "seats": 5 })
+                .as_object()
+                .cloned()
+                .unwrap(),
+            ..create_sample_term(0, GROUP, 1)
+        };
+        repo.create_term(eu.clone()).await.unwrap();
+        let us = TermOfUse {
+            metadata: serde_json::json!({ "region": "us", "seats": 5 })
+                .as_object()
+                .cloned()
+                .unwrap(),
+            ..create_sample_term(0, GROUP, 2)
+        };
+        repo.create_term(us).await.unwrap();
+
+        let filter = serde_json::json!({ "region": "eu", "seats": 5 })
+            .as_object()
+            .cloned()
+            .unwrap();
+        let history = repo.get_terms_for_group(GROUP, &filter).await.unwrap();
+
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].version, 1);
+        assert_eq!(history[0].metadata, eu.metadata);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_terms_for_group_matches_metadata_values_exactly() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-metadata-exact";
+
+        let term = TermOfUse {
+            metadata: serde_json::json!({ "regions": ["eu", "us"] })
+                .as_object()
+                .cloned()
+                .unwrap(),
+            ..create_sample_term(0, GROUP, 1)
+        };
+        repo.create_term(term.clone()).await.unwrap();
+
+        let subset = serde_json::json!({ "regions": ["eu"] })
+            .as_object()
+            .cloned()
+            .unwrap();
+        let history = repo.get_terms_for_group(GROUP, &subset).await.unwrap();
+
+        assert!(history.is_empty());
+
+        let history = repo
+            .get_terms_for_group(GROUP, &term.metadata)
+            .await
+            .unwrap();
+
+        assert_eq!(history.len(), 1);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_term_stores_pdf_metadata() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-pdf-metadata";
+        let metadata = PdfMetadata {
+            page_count: 12,
+            title: Some("Privacy Policy".to_string()),
+            producer: None,
+        };
+        let term = TermOfUse {
+            pdf_metadata: Some(metadata.clone()),
+            ..create_sample_term(0, GROUP, 1)
+        };
+
+        let created_term = repo.create_term(term).await.unwrap();
+
+        let retrieved_term = repo
+            .get_term_by_id(created_term.id)
+            .await
+            .unwrap()
+            .expect("Term should exist");
+
+        assert_eq!(retrieved_term.pdf_metadata, Some(metadata));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_term_stores_clauses() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-clauses";
+        let clauses = vec![
+            Clause {
+                key: "data-processing".to_string(),
+                title: "Processing of personal data".to_string(),
+                mandatory: true,
+            },
+            Clause {
+                key: "marketing-emails".to_string(),
+                title: "Marketing emails".to_string(),
+                mandatory: false,
+            },
+        ];
+        let term = TermOfUse {
+            clauses: clauses.clone(),
+            ..create_sample_term(0, GROUP, 1)
+        };
+
+        let created_term = repo.create_term(term).await.unwrap();
+
+        let retrieved_term = repo
+            .get_term_by_id(created_term.id)
+            .await
+            .unwrap()
+            .expect("Term should exist");
+
+        assert_eq!(retrieved_term.clauses, clauses);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_term_stores_rendered_html() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-rendered-html";
+        let term = TermOfUse {
+            html: Some("<h1>Terms</h1>".to_string()),
+            ..create_sample_term(0, GROUP, 1)
+        };
+
+        let created_term = repo.create_term(term).await.unwrap();
+
+        let retrieved_term = repo
+            .get_term_by_id(created_term.id)
+            .await
+            .unwrap()
+            .expect("Term should exist");
+
+        assert_eq!(retrieved_term.html.as_deref(), Some("<h1>Terms</h1>"));
+    }
+
+    #[test]
+    fn test_body_chunks_split_on_character_boundaries() {
+        let body = "é".repeat(BODY_CHUNK_SIZE);
+
+        let chunks = body_chunks(&body);
+
+        assert_eq!(chunks.len(), 2);
+        assert!(chunks.iter().all(|chunk| chunk.len() <= BODY_CHUNK_SIZE));
+        assert_eq!(chunks.concat(), body);
+        assert!(body_chunks("").is_empty());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_term_stores_body_larger_than_an_item() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-large-body";
+        let html = format!("<p>{}</p>", "a".repeat(900 * 1024));
+        let text = "b".repeat(500 * 1024);
+        let term = TermOfUse {
+            html: Some(html.clone()),
+            text: Some(text.clone()),
+            ..create_sample_term(0, GROUP, 1)
+        };
+
+        let created_term = repo.create_term(term).await.unwrap();
+
+        let retrieved_term = repo
+            .get_term_by_id(created_term.id)
+            .await
+            .unwrap()
+            .expect("Term should exist");
+
+        assert_eq!(retrieved_term.html, Some(html.clone()));
+        assert_eq!(retrieved_term.text, Some(text));
+
+        let latest_term = repo
+            .get_latest_term_for_group(GROUP)
+            .await
+            .unwrap()
+            .expect("Latest term should exist");
+
+        assert_eq!(latest_term.html, Some(html));
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn test_get_term_by_id_retrieves_existing_term() {
@@ -237,4 +898,53 @@ mod tests {
         assert_eq!(result.group, GROUP);
         assert_eq!(result.version, 3);
     }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_latest_terms_for_groups_skips_groups_without_terms() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-latest-terms-groups";
+
+        repo.create_term(create_sample_term(0, GROUP, 1))
+            .await
+            .unwrap();
+        repo.create_term(create_sample_term(0, GROUP, 2))
+            .await
+            .unwrap();
+
+        let result = repo
+            .get_latest_terms_for_groups(&[
+                GROUP.to_string(),
+                "termrepository-latest-terms-empty".to_string(),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].group, GROUP);
+        assert_eq!(result[0].version, 2);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_all_terms_includes_created_terms() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "termrepository-all-terms";
+
+        let first = repo
+            .create_term(create_sample_term(0, GROUP, 1))
+            .await
+            .unwrap();
+        let second = repo
+            .create_term(create_sample_term(0, GROUP, 2))
+            .await
+            .unwrap();
+
+        let terms = repo.get_all_terms().await.unwrap();
+
+        assert!(terms.iter().any(|term| term.id == first.id));
+        assert!(terms.iter().any(|term| term.id == second.id));
+    }
 }
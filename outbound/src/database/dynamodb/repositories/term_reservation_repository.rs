@@ -0,0 +1,478 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::{AttributeValue, Delete, Put, TransactWriteItem};
+use chrono::{NaiveDateTime, Utc};
+use domain::{
+    data::repository::TermReservationRepository, entities::TermReservation, errors::TermsOfUseError,
+};
+use tracing::error;
+
+use crate::database::dynamodb::{
+    DynamoRepository, is_transaction_condition_failed,
+    model::{
+        TERM_RESERVATION_VERSIONS_TABLE, TERM_RESERVATIONS_TABLE, change_summaries_to_attribute,
+        clauses_to_attribute, map_reservation_from_item, metadata_to_attribute, version_key,
+    },
+};
+
+#[async_trait]
+impl TermReservationRepository for DynamoRepository {
+    #[tracing::instrument(skip(self, reservation))]
+    async fn create_reservation(
+        &self,
+        reservation: TermReservation,
+    ) -> Result<TermReservation, TermsOfUseError> {
+        let id = self.get_next_id("term_reservations_id_counter").await?;
+
+        let mut item = std::collections::HashMap::new();
+
+        item.insert("id".to_string(), AttributeValue::N(id.to_string()));
+        item.insert(
+            "group".to_string(),
+            AttributeValue::S(reservation.group.clone()),
+        );
+        item.insert(
+            "version".to_string(),
+            AttributeValue::N(reservation.version.to_string()),
+        );
+        if let Some(info) = &reservation.info {
+            item.insert("info".to_string(), AttributeValue::S(info.clone()));
+        }
+        item.insert(
+            "key".to_string(),
+            AttributeValue::S(reservation.key.clone()),
+        );
+        item.insert(
+            "content_type".to_string(),
+            AttributeValue::S(reservation.content_type.clone()),
+        );
+        item.insert(
+            "size".to_string(),
+            AttributeValue::N(reservation.size.to_string()),
+        );
+        item.insert(
+            "sha256".to_string(),
+            AttributeValue::S(reservation.sha256.clone()),
+        );
+        if !reservation.change_summaries.is_empty() {
+            item.insert(
+                "change_summaries".to_string(),
+                change_summaries_to_attribute(&reservation.change_summaries),
+            );
+        }
+        if !reservation.metadata.is_empty() {
+            item.insert(
+                "metadata".to_string(),
+                metadata_to_attribute(&reservation.metadata),
+            );
+        }
+        if !reservation.clauses.is_empty() {
+            item.insert(
+                "clauses".to_string(),
+                clauses_to_attribute(&reservation.clauses),
+            );
+        }
+        item.insert(
+            "expires_at".to_string(),
+            AttributeValue::N(reservation.expires_at.and_utc().timestamp().to_string()),
+        );
+
+        // The version is held along with the reservation, so it cannot be reserved twice
+        let version = Put::builder()
+            .table_name(TERM_RESERVATION_VERSIONS_TABLE)
+            .item(
+                "version_key",
+                AttributeValue::S(version_key(&reservation.group, reservation.version)),
+            )
+            .item("reservation_id", AttributeValue::N(id.to_string()))
+            .condition_expression("attribute_not_exists(version_key)")
+            .build()
+            .map_err(|err| {
+                error!("Failed to build the version of term reservation '{id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+        let reservation_item = Put::builder()
+            .table_name(TERM_RESERVATIONS_TABLE)
+            .set_item(Some(item))
+            .build()
+            .map_err(|err| {
+                error!("Failed to build term reservation '{id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        self.client
+            .transact_write_items()
+            .transact_items(TransactWriteItem::builder().put(version).build())
+            .transact_items(TransactWriteItem::builder().put(reservation_item).build())
+            .send()
+            .await
+            .map_err(|err| {
+                if is_transaction_condition_failed(&err) {
+                    return TermsOfUseError::Validation(format!(
+                        "Version {} of group '{}' is already being uploaded",
+                        reservation.version, reservation.group
+                    ));
+                }
+
+                error!(
+                    "Failed to create term reservation '{:?}': {err}",
+                    reservation
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(TermReservation { id, ..reservation })
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn get_reservation(
+        &self,
+        reservation_id: i32,
+    ) -> Result<Option<TermReservation>, TermsOfUseError> {
+        let value = self
+            .client
+            .get_item()
+            .table_name(TERM_RESERVATIONS_TABLE)
+            .key("id", AttributeValue::N(reservation_id.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to get term reservation '{reservation_id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        if let Some(item) = value.item {
+            let reservation = map_reservation_from_item(&item)?;
+
+            return Ok(Some(reservation));
+        }
+
+        Ok(None)
+    }
+
+    #[tracing::instrument(skip(self, group))]
+    async fn get_reservation_for_version(
+        &self,
+        group: &str,
+        version: u32,
+    ) -> Result<Option<TermReservation>, TermsOfUseError> {
+        let value = self
+            .client
+            .get_item()
+            .table_name(TERM_RESERVATION_VERSIONS_TABLE)
+            .key(
+                "version_key",
+                AttributeValue::S(version_key(group, version)),
+            )
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to get the reservation of version {version} of group '{group}': {err}"
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        let reservation_id = value
+            .item
+            .as_ref()
+            .and_then(|item| item.get("reservation_id"))
+            .and_then(|id| id.as_n().ok())
+            .and_then(|id| id.parse::<i32>().ok());
+
+        match reservation_id {
+            Some(reservation_id) => self.get_reservation(reservation_id).await,
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn claim_reservation(
+        &self,
+        reservation_id: i32,
+        until: NaiveDateTime,
+    ) -> Result<bool, TermsOfUseError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(TERM_RESERVATIONS_TABLE)
+            .key("id", AttributeValue::N(reservation_id.to_string()))
+            .update_expression("SET claimed_until = :until")
+            .condition_expression(
+                "attribute_exists(id) AND (attribute_not_exists(claimed_until) OR claimed_until < :now)",
+            )
+            .expression_attribute_values(
+                ":until",
+                AttributeValue::N(until.and_utc().timestamp().to_string()),
+            )
+            .expression_attribute_values(
+                ":now",
+                AttributeValue::N(Utc::now().timestamp().to_string()),
+            )
+            .send()
+            .await;
+
+        match result {
+            Ok(_) => Ok(true),
+            Err(err)
+                if err
+                    .as_service_error()
+                    .is_some_and(|err| err.is_conditional_check_failed_exception()) =>
+            {
+                Ok(false)
+            }
+            Err(err) => {
+                error!("Failed to claim term reservation '{reservation_id}': {err}");
+
+                Err(TermsOfUseError::InternalServerError)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn release_reservation(&self, reservation_id: i32) -> Result<(), TermsOfUseError> {
+        let result = self
+            .client
+            .update_item()
+            .table_name(TERM_RESERVATIONS_TABLE)
+            .key("id", AttributeValue::N(reservation_id.to_string()))
+            .update_expression("REMOVE claimed_until")
+            .condition_expression("attribute_exists(id)")
+            .send()
+            .await;
+
+        // A reservation deleted meanwhile has nothing left to release
+        match result {
+            Ok(_) => Ok(()),
+            Err(err)
+                if err
+                    .as_service_error()
+                    .is_some_and(|err| err.is_conditional_check_failed_exception()) =>
+            {
+                Ok(())
+            }
+            Err(err) => {
+                error!("Failed to release term reservation '{reservation_id}': {err}");
+
+                Err(TermsOfUseError::InternalServerError)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn delete_reservation(&self, reservation_id: i32) -> Result<(), TermsOfUseError> {
+        let Some(reservation) = self.get_reservation(reservation_id).await? else {
+            return Ok(());
+        };
+
+        let version = Delete::builder()
+            .table_name(TERM_RESERVATION_VERSIONS_TABLE)
+            .key(
+                "version_key",
+                AttributeValue::S(version_key(&reservation.group, reservation.version)),
+            )
+            .condition_expression(
+                "attribute_not_exists(version_key) OR reservation_id = :reservation_id",
+            )
+            .expression_attribute_values(
+                ":reservation_id",
+                AttributeValue::N(reservation_id.to_string()),
+            )
+            .build()
+            .map_err(|err| {
+                error!("Failed to build the version of term reservation '{reservation_id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+        let reservation_item = Delete::builder()
+            .table_name(TERM_RESERVATIONS_TABLE)
+            .key("id", AttributeValue::N(reservation_id.to_string()))
+            .build()
+            .map_err(|err| {
+                error!("Failed to build term reservation '{reservation_id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        self.client
+            .transact_write_items()
+            .transact_items(TransactWriteItem::builder().delete(version).build())
+            .transact_items(
+                TransactWriteItem::builder()
+                    .delete(reservation_item)
+                    .build(),
+            )
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to delete term reservation '{reservation_id}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_reservations(&self) -> Result<Vec<TermReservation>, TermsOfUseError> {
+        let mut reservations = Vec::new();
+        let mut exclusive_start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .scan()
+                .table_name(TERM_RESERVATIONS_TABLE)
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to scan term reservations: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            for item in output.items() {
+                reservations.push(map_reservation_from_item(item)?);
+            }
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        Ok(reservations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeDelta, Utc};
+    use domain::{
+        data::repository::TermReservationRepository,
+        entities::{ChangeSummary, Clause, TermReservation},
+        errors::TermsOfUseError,
+    };
+
+    use crate::database::dynamodb::DynamoRepository;
+
+    async fn create_test_repository() -> DynamoRepository {
+        DynamoRepository::new().await
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_reservation_round_trip() {
+        let repo = create_test_repository().await;
+
+        let created = repo
+            .create_reservation(TermReservation {
+                id: 0,
+                group: "reservationrepository-round-trip".to_string(),
+                version: 1,
+                info: None,
+                key: "reservationrepository-round-trip/v1.pdf".to_string(),
+                content_type: "application/pdf".to_string(),
+                size: 1024,
+                sha256: "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+                    .to_string(),
+                metadata: serde_json::json!({ "region": "eu", "seats": 5 })
+                    .as_object()
+                    .cloned()
+                    .unwrap(),
+                clauses: vec![Clause {
+                    key: "marketing-emails".to_string(),
+                    title: "Marketing emails".to_string(),
+                    mandatory: false,
+                }],
+                expires_at: Utc::now().naive_utc() + TimeDelta::minutes(15),
+                change_summaries: vec![ChangeSummary {
+                    locale: "en".to_string(),
+                    markdown: "Clarified *retention*".to_string(),
+                    html: "<p>Clarified <em>retention</em></p>\n".to_string(),
+                }],
+            })
+            .await
+            .expect("Reservation should be created");
+
+        let fetched = repo
+            .get_reservation(created.id)
+            .await
+            .unwrap()
+            .expect("Reservation should exist");
+
+        assert_eq!(fetched.key, created.key);
+        assert_eq!(fetched.size, 1024);
+        assert_eq!(fetched.version, 1);
+        assert_eq!(fetched.metadata, created.metadata);
+        assert_eq!(fetched.clauses, created.clauses);
+        assert_eq!(fetched.change_summaries, created.change_summaries);
+
+        let for_version = repo
+            .get_reservation_for_version("reservationrepository-round-trip", 1)
+            .await
+            .unwrap();
+        assert_eq!(
+            for_version.map(|reservation| reservation.id),
+            Some(created.id)
+        );
+
+        let all = repo.get_all_reservations().await.unwrap();
+        assert!(all.iter().any(|reservation| reservation.id == created.id));
+
+        repo.delete_reservation(created.id).await.unwrap();
+
+        assert!(repo.get_reservation(created.id).await.unwrap().is_none());
+        assert!(
+            repo.get_reservation_for_version("reservationrepository-round-trip", 1)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_reservation_holds_its_version_until_deleted() {
+        let repo = create_test_repository().await;
+        let reservation = TermReservation {
+            id: 0,
+            group: "reservationrepository-held-version".to_string(),
+            version: 1,
+            info: None,
+            key: "reservationrepository-held-version/v1.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 1024,
+            sha256: "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824".to_string(),
+            metadata: Default::default(),
+            clauses: vec![],
+            expires_at: Utc::now().naive_utc() + TimeDelta::minutes(15),
+            change_summaries: vec![],
+        };
+
+        let created = repo.create_reservation(reservation.clone()).await.unwrap();
+
+        assert!(matches!(
+            repo.create_reservation(reservation.clone()).await,
+            Err(TermsOfUseError::Validation(_))
+        ));
+
+        let until = Utc::now().naive_utc() + TimeDelta::minutes(15);
+        assert!(repo.claim_reservation(created.id, until).await.unwrap());
+        assert!(!repo.claim_reservation(created.id, until).await.unwrap());
+
+        repo.release_reservation(created.id).await.unwrap();
+        assert!(repo.claim_reservation(created.id, until).await.unwrap());
+
+        repo.delete_reservation(created.id).await.unwrap();
+
+        let recreated = repo.create_reservation(reservation).await.unwrap();
+        repo.delete_reservation(recreated.id).await.unwrap();
+    }
+}
@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use aws_sdk_dynamodb::types::AttributeValue;
+use domain::{
+    data::repository::UploadPolicyRepository, entities::UploadPolicy, errors::TermsOfUseError,
+};
+use tracing::error;
+
+use crate::database::dynamodb::{
+    DynamoRepository,
+    model::{UPLOAD_POLICIES_TABLE, map_upload_policy_from_item},
+};
+
+#[async_trait]
+impl UploadPolicyRepository for DynamoRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_upload_policy(
+        &self,
+        group: &str,
+    ) -> Result<Option<UploadPolicy>, TermsOfUseError> {
+        let value = self
+            .client
+            .get_item()
+            .table_name(UPLOAD_POLICIES_TABLE)
+            .key("group", AttributeValue::S(group.to_string()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to get upload policy of '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(value.item.as_ref().map(map_upload_policy_from_item))
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_upload_policy(
+        &self,
+        policy: UploadPolicy,
+    ) -> Result<UploadPolicy, TermsOfUseError> {
+        let mut item = std::collections::HashMap::new();
+
+        item.insert("group".to_string(), AttributeValue::S(policy.group.clone()));
+        item.insert(
+            "content_types".to_string(),
+            AttributeValue::L(
+                policy
+                    .content_types
+                    .iter()
+                    .cloned()
+                    .map(AttributeValue::S)
+                    .collect(),
+            ),
+        );
+        item.insert(
+            "max_size".to_string(),
+            AttributeValue::N(policy.max_size.to_string()),
+        );
+        item.insert(
+            "required_fields".to_string(),
+            AttributeValue::L(
+                policy
+                    .required_fields
+                    .iter()
+                    .cloned()
+                    .map(AttributeValue::S)
+                    .collect(),
+            ),
+        );
+        if let Some(schema) = &policy.metadata_schema {
+            item.insert(
+                "metadata_schema".to_string(),
+                AttributeValue::S(schema.to_string()),
+            );
+        }
+
+        self.client
+            .put_item()
+            .table_name(UPLOAD_POLICIES_TABLE)
+            .set_item(Some(item))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to save upload policy of '{}': {err}", policy.group);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::repository::UploadPolicyRepository, entities::UploadPolicy};
+
+    use crate::database::dynamodb::DynamoRepository;
+
+    async fn create_test_repository() -> DynamoRepository {
+        DynamoRepository::new().await
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_upload_policy_round_trip() {
+        let repo = create_test_repository().await;
+
+        let group = "uploadpolicyrepository-round-trip";
+
+        repo.save_upload_policy(UploadPolicy {
+            group: group.to_string(),
+            content_types: vec!["application/pdf".to_string()],
+            max_size: 1024,
+            required_fields: vec![],
+            metadata_schema: None,
+        })
+        .await
+        .expect("Policy should be saved");
+
+        let replaced = UploadPolicy {
+            group: group.to_string(),
+            content_types: vec!["application/pdf".to_string(), "text/markdown".to_string()],
+            max_size: 2048,
+            required_fields: vec!["info".to_string(), "metadata".to_string()],
+            metadata_schema: Some(serde_json::json!({
+                "type": "object",
+                "required": ["region"]
+            })),
+        };
+
+        repo.save_upload_policy(replaced.clone())
+            .await
+            .expect("Policy should be replaced");
+
+        let fetched = repo
+            .get_upload_policy(group)
+            .await
+            .unwrap()
+            .expect("Policy should exist");
+
+        assert_eq!(fetched, replaced);
+        assert!(
+            repo.get_upload_policy("uploadpolicyrepository-missing")
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+}
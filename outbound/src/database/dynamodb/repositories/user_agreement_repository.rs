@@ -1,18 +1,122 @@
 use async_trait::async_trait;
-use aws_sdk_dynamodb::types::AttributeValue;
-use chrono::Utc;
+use std::collections::HashMap;
+
+use aws_sdk_dynamodb::types::{AttributeValue, KeysAndAttributes, Put, TransactWriteItem};
+use chrono::{NaiveDateTime, Utc};
 use domain::{
     data::repository::UserAgreementRepository,
+    entities::UserAgreement,
     errors::{Result, TermsOfUseError},
 };
 use tracing::error;
 
-use crate::database::dynamodb::{DynamoRepository, model::USER_AGREEMENTS_TABLE};
+use crate::database::dynamodb::{
+    DynamoRepository, is_transaction_condition_failed,
+    migration::GSI_TERMS_GROUP_VERSION,
+    model::{
+        TERMS_TABLE, USER_AGREEMENTS_TABLE, accepted_clauses_to_attribute, agreements_to_attribute,
+        map_accepted_clauses_from_item, map_agreed_at_from_item, map_agreements_from_item,
+    },
+};
+
+/// Most keys a single `BatchGetItem` request accepts.
+const BATCH_GET_LIMIT: usize = 100;
+
+/// How often an agreement is written when other agreements to the term keep being stored
+/// concurrently.
+const AGREEMENT_WRITE_ATTEMPTS: usize = 3;
+
+/// Write of an agreement item, only applied while no other agreement was stored since the
+/// item was read.
+struct AgreementWrite {
+    item: HashMap<String, AttributeValue>,
+    condition: &'static str,
+    previous_agreed_at: Option<AttributeValue>,
+}
+
+impl DynamoRepository {
+    /// Adds an agreement to the earlier agreements of the user to the term, the newest one is
+    /// also kept at the top of the item.
+    async fn agreement_write(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        accepted_clauses: &[String],
+    ) -> Result<AgreementWrite> {
+        let agreement_key = format!("{user_id}#{term_id}");
+
+        let existing = self
+            .client
+            .get_item()
+            .table_name(USER_AGREEMENTS_TABLE)
+            .key("agreement_key", AttributeValue::S(agreement_key.clone()))
+            .consistent_read(true)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to read user agreement for key '{agreement_key}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?
+            .item;
+
+        let mut agreements = existing
+            .as_ref()
+            .map(map_agreements_from_item)
+            .transpose()?
+            .unwrap_or_default();
+        let agreed_at = Utc::now().naive_utc();
+        agreements.push(UserAgreement {
+            user_id,
+            term_id,
+            agreed_at,
+            accepted_clauses: accepted_clauses.to_vec(),
+        });
+
+        let item = HashMap::from([
+            (
+                "agreement_key".to_string(),
+                AttributeValue::S(agreement_key),
+            ),
+            (
+                "user_id".to_string(),
+                AttributeValue::N(user_id.to_string()),
+            ),
+            (
+                "term_id".to_string(),
+                AttributeValue::N(term_id.to_string()),
+            ),
+            (
+                "agreed_at".to_string(),
+                AttributeValue::S(agreed_at.to_string()),
+            ),
+            (
+                "accepted_clauses".to_string(),
+                accepted_clauses_to_attribute(accepted_clauses),
+            ),
+            (
+                "agreements".to_string(),
+                agreements_to_attribute(&agreements),
+            ),
+        ]);
+
+        let previous_agreed_at = existing.and_then(|mut existing| existing.remove("agreed_at"));
+
+        Ok(AgreementWrite {
+            item,
+            condition: match previous_agreed_at {
+                Some(_) => "agreed_at = :previous_agreed_at",
+                None => "attribute_not_exists(agreement_key)",
+            },
+            previous_agreed_at,
+        })
+    }
+}
 
 #[async_trait]
 impl UserAgreementRepository for DynamoRepository {
     #[tracing::instrument(skip(self, user_id, term_id))]
-    async fn has_user_agreed_to_term(&self, user_id: i32, term_id: i32) -> Result<bool> {
+    async fn get_agreed_at(&self, user_id: i32, term_id: i32) -> Result<Option<NaiveDateTime>> {
         let agreement_key = format!("{user_id}#{term_id}");
 
         let result = self
@@ -28,53 +132,296 @@ impl UserAgreementRepository for DynamoRepository {
                 TermsOfUseError::InternalServerError
             })?;
 
-        Ok(result.item.is_some())
+        result
+            .item
+            .as_ref()
+            .map(map_agreed_at_from_item)
+            .transpose()
+    }
+
+    #[tracing::instrument(skip(self, user_id, term_ids))]
+    async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+        let mut agreed_term_ids = Vec::new();
+
+        for chunk in term_ids.chunks(BATCH_GET_LIMIT) {
+            let keys = chunk
+                .iter()
+                .map(|term_id| {
+                    HashMap::from([(
+                        "agreement_key".to_string(),
+                        AttributeValue::S(format!("{user_id}#{term_id}")),
+                    )])
+                })
+                .collect();
+
+            let mut request_items = Some(HashMap::from([(
+                USER_AGREEMENTS_TABLE.to_string(),
+                KeysAndAttributes::builder()
+                    .set_keys(Some(keys))
+                    .projection_expression("term_id")
+                    .build()
+                    .map_err(|err| {
+                        error!("Failed to build agreement lookup of user {user_id}: {err}");
+
+                        TermsOfUseError::InternalServerError
+                    })?,
+            )]));
+
+            // Throttled keys come back as unprocessed and are asked for again
+            while let Some(items) = request_items.take().filter(|items| !items.is_empty()) {
+                let result = self
+                    .client
+                    .batch_get_item()
+                    .set_request_items(Some(items))
+                    .send()
+                    .await
+                    .map_err(|err| {
+                        error!("Failed to read user agreements of user {user_id}: {err}");
+
+                        TermsOfUseError::InternalServerError
+                    })?;
+
+                let agreements = result
+                    .responses
+                    .and_then(|mut responses| responses.remove(USER_AGREEMENTS_TABLE))
+                    .unwrap_or_default();
+
+                agreed_term_ids.extend(agreements.iter().filter_map(|item| {
+                    item.get("term_id")
+                        .and_then(|value| value.as_n().ok())
+                        .and_then(|term_id| term_id.parse::<i32>().ok())
+                }));
+
+                request_items = result.unprocessed_keys;
+            }
+        }
+
+        Ok(agreed_term_ids)
+    }
+
+    #[tracing::instrument(skip(self, user_id, group))]
+    async fn get_newest_agreed_version(
+        &self,
+        user_id: i32,
+        group: &str,
+        min_version: u32,
+    ) -> Result<Option<u32>> {
+        // Agreements do not know their group, the versions in range are read first
+        let mut versions: Vec<(i32, u32)> = Vec::new();
+        let mut exclusive_start_key = None;
+
+        loop {
+            let output = self
+                .client
+                .query()
+                .table_name(TERMS_TABLE)
+                .index_name(GSI_TERMS_GROUP_VERSION)
+                .key_condition_expression("#group = :group AND #version >= :version")
+                .expression_attribute_names("#group", "group")
+                .expression_attribute_names("#version", "version")
+                .expression_attribute_values(":group", AttributeValue::S(group.to_string()))
+                .expression_attribute_values(":version", AttributeValue::N(min_version.to_string()))
+                .projection_expression("id, #version")
+                .set_exclusive_start_key(exclusive_start_key)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to query versions of group '{group}': {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            versions.extend(output.items().iter().filter_map(|item| {
+                let id = item.get("id")?.as_n().ok()?.parse::<i32>().ok()?;
+                let version = item.get("version")?.as_n().ok()?.parse::<u32>().ok()?;
+
+                Some((id, version))
+            }));
+
+            match output.last_evaluated_key {
+                Some(key) if !key.is_empty() => exclusive_start_key = Some(key),
+                _ => break,
+            }
+        }
+
+        if versions.is_empty() {
+            return Ok(None);
+        }
+
+        let term_ids: Vec<i32> = versions.iter().map(|(term_id, _)| *term_id).collect();
+        let agreed_term_ids = self.get_agreed_term_ids(user_id, &term_ids).await?;
+
+        Ok(versions
+            .into_iter()
+            .filter(|(term_id, _)| agreed_term_ids.contains(term_id))
+            .map(|(_, version)| version)
+            .max())
     }
 
     #[tracing::instrument(skip(self, user_id, term_id))]
-    async fn create_user_agreement(&self, user_id: i32, term_id: i32) -> Result<()> {
+    async fn get_accepted_clauses(
+        &self,
+        user_id: i32,
+        term_id: i32,
+    ) -> Result<Option<Vec<String>>> {
         let agreement_key = format!("{user_id}#{term_id}");
 
-        let mut item = std::collections::HashMap::new();
+        let result = self
+            .client
+            .get_item()
+            .table_name(USER_AGREEMENTS_TABLE)
+            .key("agreement_key", AttributeValue::S(agreement_key.clone()))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to read accepted clauses for key '{agreement_key}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
 
-        item.insert(
-            "agreement_key".to_string(),
-            AttributeValue::S(agreement_key.clone()),
-        );
-        item.insert(
-            "user_id".to_string(),
-            AttributeValue::N(user_id.to_string()),
-        );
-        item.insert(
-            "term_id".to_string(),
-            AttributeValue::N(term_id.to_string()),
-        );
-        item.insert(
-            "agreed_at".to_string(),
-            AttributeValue::S(Utc::now().naive_utc().to_string()),
-        );
+        Ok(result.item.as_ref().map(map_accepted_clauses_from_item))
+    }
 
-        self.client
-            .put_item()
+    #[tracing::instrument(skip(self, user_id, term_id))]
+    async fn get_agreement_at(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<Option<UserAgreement>> {
+        let agreement_key = format!("{user_id}#{term_id}");
+
+        let result = self
+            .client
+            .get_item()
             .table_name(USER_AGREEMENTS_TABLE)
-            .set_item(Some(item))
+            .key("agreement_key", AttributeValue::S(agreement_key.clone()))
             .send()
             .await
             .map_err(|err| {
-                error!("Failed to create user agreement for key '{agreement_key}': {err}");
+                error!("Failed to read user agreements for key '{agreement_key}': {err}");
 
                 TermsOfUseError::InternalServerError
             })?;
 
-        Ok(())
+        let Some(item) = result.item else {
+            return Ok(None);
+        };
+
+        Ok(map_agreements_from_item(&item)?
+            .into_iter()
+            .filter(|agreement| agreement.agreed_at <= at)
+            .max_by_key(|agreement| agreement.agreed_at))
+    }
+
+    #[tracing::instrument(skip(self, user_id, term_id, accepted_clauses))]
+    async fn create_user_agreement(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        accepted_clauses: &[String],
+    ) -> Result<()> {
+        let agreement_key = format!("{user_id}#{term_id}");
+        let mut attempt = 1;
+
+        loop {
+            let write = self
+                .agreement_write(user_id, term_id, accepted_clauses)
+                .await?;
+
+            let result = self
+                .client
+                .put_item()
+                .table_name(USER_AGREEMENTS_TABLE)
+                .set_item(Some(write.item))
+                .condition_expression(write.condition)
+                .set_expression_attribute_values(write.previous_agreed_at.map(|agreed_at| {
+                    HashMap::from([(":previous_agreed_at".to_string(), agreed_at)])
+                }))
+                .send()
+                .await;
+
+            match result {
+                Ok(_) => return Ok(()),
+                // Another agreement was stored since the item was read, it is read again
+                Err(err)
+                    if attempt < AGREEMENT_WRITE_ATTEMPTS
+                        && err
+                            .as_service_error()
+                            .is_some_and(|err| err.is_conditional_check_failed_exception()) =>
+                {
+                    attempt += 1;
+                }
+                Err(err) => {
+                    error!("Failed to create user agreement for key '{agreement_key}': {err}");
+
+                    return Err(TermsOfUseError::InternalServerError);
+                }
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, user_id))]
+    async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
+        let mut attempt = 1;
+
+        loop {
+            let mut items = Vec::with_capacity(term_ids.len());
+            for term_id in term_ids {
+                let write = self.agreement_write(user_id, *term_id, &[]).await?;
+
+                let put = Put::builder()
+                    .table_name(USER_AGREEMENTS_TABLE)
+                    .set_item(Some(write.item))
+                    .condition_expression(write.condition)
+                    .set_expression_attribute_values(write.previous_agreed_at.map(|agreed_at| {
+                        HashMap::from([(":previous_agreed_at".to_string(), agreed_at)])
+                    }))
+                    .build()
+                    .map_err(|err| {
+                        error!("Failed to build user agreement to term {term_id}: {err}");
+
+                        TermsOfUseError::InternalServerError
+                    })?;
+
+                items.push(TransactWriteItem::builder().put(put).build());
+            }
+
+            let result = self
+                .client
+                .transact_write_items()
+                .set_transact_items(Some(items))
+                .send()
+                .await;
+
+            match result {
+                Ok(_) => return Ok(()),
+                // Another agreement to one of the terms was stored meanwhile, all are read again
+                Err(err)
+                    if attempt < AGREEMENT_WRITE_ATTEMPTS
+                        && is_transaction_condition_failed(&err) =>
+                {
+                    attempt += 1;
+                }
+                Err(err) => {
+                    error!("Failed to create user agreements of user {user_id}: {err}");
+
+                    return Err(TermsOfUseError::InternalServerError);
+                }
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use domain::data::repository::UserAgreementRepository;
+    use chrono::Utc;
+    use domain::{
+        data::repository::{TermRepository, UserAgreementRepository},
+        entities::TermOfUse,
+    };
 
-    use crate::database::dynamodb::DynamoRepository;
+    use super::*;
 
     async fn create_test_repository() -> DynamoRepository {
         DynamoRepository::new().await
@@ -82,13 +429,13 @@ mod tests {
 
     #[tokio::test]
     #[test_log::test]
-    async fn test_has_user_agreed_to_term_returns_false_when_no_agreement() {
+    async fn test_get_agreed_at_returns_none_when_no_agreement() {
         let repo = create_test_repository().await;
 
-        let result = repo.has_user_agreed_to_term(1, 1).await;
+        let result = repo.get_agreed_at(1, 1).await;
 
         assert!(result.is_ok());
-        assert!(!result.unwrap());
+        assert!(result.unwrap().is_none());
     }
 
     #[tokio::test]
@@ -96,12 +443,195 @@ mod tests {
     async fn test_create_user_agreement_succeeds() {
         let repo = create_test_repository().await;
 
-        let result = repo.create_user_agreement(123, 456).await;
+        let result = repo.create_user_agreement(123, 456, &[]).await;
 
         assert!(result.is_ok());
 
-        let check_result = repo.has_user_agreed_to_term(123, 456).await;
+        let check_result = repo.get_agreed_at(123, 456).await;
         assert!(check_result.is_ok());
-        assert!(check_result.unwrap());
+        assert!(
+            check_result
+                .unwrap()
+                .is_some_and(|agreed_at| agreed_at <= Utc::now().naive_utc())
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_agreed_term_ids_returns_only_agreed_terms() {
+        let repo = create_test_repository().await;
+
+        repo.create_user_agreement(126, 460, &[]).await.unwrap();
+        repo.create_user_agreement(126, 462, &[]).await.unwrap();
+
+        let mut result = repo
+            .get_agreed_term_ids(126, &[460, 461, 462])
+            .await
+            .unwrap();
+        result.sort();
+
+        assert_eq!(result, vec![460, 462]);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_user_agreement_stores_accepted_clauses() {
+        let repo = create_test_repository().await;
+
+        assert_eq!(repo.get_accepted_clauses(125, 459).await.unwrap(), None);
+
+        repo.create_user_agreement(125, 459, &["marketing-emails".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repo.get_accepted_clauses(125, 459).await.unwrap(),
+            Some(vec!["marketing-emails".to_string()])
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_user_agreement_keeps_earlier_agreements() {
+        let repo = create_test_repository().await;
+
+        repo.create_user_agreement(129, 463, &[]).await.unwrap();
+        let first_agreed_at = repo.get_agreed_at(129, 463).await.unwrap().unwrap();
+
+        tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+
+        repo.create_user_agreement(129, 463, &["marketing-emails".to_string()])
+            .await
+            .unwrap();
+        let renewed_at = repo.get_agreed_at(129, 463).await.unwrap().unwrap();
+
+        assert!(renewed_at > first_agreed_at);
+        assert_eq!(
+            repo.get_accepted_clauses(129, 463).await.unwrap(),
+            Some(vec!["marketing-emails".to_string()])
+        );
+
+        let item = repo
+            .client
+            .get_item()
+            .table_name(USER_AGREEMENTS_TABLE)
+            .key("agreement_key", AttributeValue::S("129#463".to_string()))
+            .send()
+            .await
+            .unwrap()
+            .item
+            .expect("The agreement item should exist");
+        let agreements = map_agreements_from_item(&item).unwrap();
+
+        assert_eq!(agreements.len(), 2);
+        assert_eq!(agreements[0].agreed_at, first_agreed_at);
+        assert!(agreements[0].accepted_clauses.is_empty());
+        assert_eq!(agreements[1].agreed_at, renewed_at);
+
+        assert_eq!(
+            repo.get_agreement_at(129, 463, first_agreed_at)
+                .await
+                .unwrap()
+                .map(|agreement| agreement.agreed_at),
+            Some(first_agreed_at)
+        );
+        assert_eq!(
+            repo.get_agreement_at(129, 463, renewed_at)
+                .await
+                .unwrap()
+                .map(|agreement| agreement.agreed_at),
+            Some(renewed_at)
+        );
+        assert!(
+            repo.get_agreement_at(129, 463, first_agreed_at - chrono::TimeDelta::seconds(1))
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_user_agreement_keeps_concurrent_agreements() {
+        let repo = create_test_repository().await;
+
+        let (first, second) = tokio::join!(
+            repo.create_user_agreement(131, 467, &[]),
+            repo.create_user_agreement(131, 467, &[])
+        );
+        first.unwrap();
+        second.unwrap();
+
+        let item = repo
+            .client
+            .get_item()
+            .table_name(USER_AGREEMENTS_TABLE)
+            .key("agreement_key", AttributeValue::S("131#467".to_string()))
+            .send()
+            .await
+            .unwrap()
+            .item
+            .expect("The agreement item should exist");
+
+        assert_eq!(map_agreements_from_item(&item).unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_create_user_agreements_stores_every_term() {
+        let repo = create_test_repository().await;
+
+        let result = repo.create_user_agreements(124, &[457, 458]).await;
+
+        assert!(result.is_ok());
+        assert!(repo.get_agreed_at(124, 457).await.unwrap().is_some());
+        assert!(repo.get_agreed_at(124, 458).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn test_get_newest_agreed_version_only_counts_versions_in_range() {
+        let repo = create_test_repository().await;
+
+        const GROUP: &str = "useragreementrepository-newest-version";
+
+        let mut term_ids = Vec::new();
+        for version in 1..=3 {
+            let term = repo
+                .create_term(TermOfUse {
+                    id: 0,
+                    group: GROUP.to_string(),
+                    url: format!("https://example.com/terms/v{version}"),
+                    version,
+                    info: None,
+                    created_at: Utc::now().naive_utc(),
+                    html: None,
+                    text: None,
+                    change_summaries: vec![],
+                    pdf_metadata: None,
+                    metadata: Default::default(),
+                    clauses: vec![],
+                })
+                .await
+                .unwrap();
+            term_ids.push(term.id);
+        }
+
+        repo.create_user_agreement(127, term_ids[1], &[])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            repo.get_newest_agreed_version(127, GROUP, 1).await.unwrap(),
+            Some(2)
+        );
+        assert_eq!(
+            repo.get_newest_agreed_version(127, GROUP, 3).await.unwrap(),
+            None
+        );
+        assert_eq!(
+            repo.get_newest_agreed_version(128, GROUP, 1).await.unwrap(),
+            None
+        );
     }
 }
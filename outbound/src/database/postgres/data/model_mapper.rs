@@ -1,6 +1,12 @@
-use domain::entities::TermOfUse;
+use domain::entities::{
+    Bundle, ChangeSummary, Clause, Group, PdfMetadata, TermMetadata, TermOfUse, TermReservation,
+    UploadPolicy,
+};
+use tracing::error;
 
-use crate::database::postgres::data::models::terms;
+use crate::database::postgres::data::models::{
+    bundles, groups, term_reservations, terms, upload_policies,
+};
 
 impl From<terms::Model> for TermOfUse {
     fn from(value: terms::Model) -> Self {
@@ -11,6 +17,112 @@ impl From<terms::Model> for TermOfUse {
             version: value.version as u32,
             info: value.info,
             created_at: value.created_at,
+            html: value.html,
+            text: value.text,
+            change_summaries: as_change_summaries(value.change_summaries, "term", value.id),
+            pdf_metadata: value.pdf_page_count.map(|page_count| PdfMetadata {
+                page_count: page_count as u32,
+                title: value.pdf_title,
+                producer: value.pdf_producer,
+            }),
+            metadata: as_metadata(value.metadata),
+            clauses: as_clauses(value.clauses, "term", value.id),
+        }
+    }
+}
+
+impl From<term_reservations::Model> for TermReservation {
+    fn from(value: term_reservations::Model) -> Self {
+        TermReservation {
+            id: value.id,
+            group: value.group,
+            version: value.version as u32,
+            info: value.info,
+            key: value.key,
+            content_type: value.content_type,
+            size: value.size as u64,
+            sha256: value.sha256,
+            expires_at: value.expires_at,
+            change_summaries: as_change_summaries(value.change_summaries, "reservation", value.id),
+            metadata: as_metadata(value.metadata),
+            clauses: as_clauses(value.clauses, "reservation", value.id),
+        }
+    }
+}
+
+impl From<upload_policies::Model> for UploadPolicy {
+    fn from(value: upload_policies::Model) -> Self {
+        UploadPolicy {
+            content_types: serde_json::from_value(value.content_types).unwrap_or_else(|err| {
+                error!(
+                    "Failed to read content types of the upload policy of '{}': {err}",
+                    value.group
+                );
+
+                vec![]
+            }),
+            required_fields: serde_json::from_value(value.required_fields).unwrap_or_else(|err| {
+                error!(
+                    "Failed to read required fields of the upload policy of '{}': {err}",
+                    value.group
+                );
+
+                vec![]
+            }),
+            group: value.group,
+            max_size: value.max_size as u64,
+            metadata_schema: value.metadata_schema,
+        }
+    }
+}
+
+/// Metadata columns always hold an object, anything else is read as no metadata.
+fn as_metadata(value: serde_json::Value) -> TermMetadata {
+    match value {
+        serde_json::Value::Object(metadata) => metadata,
+        _ => TermMetadata::new(),
+    }
+}
+
+fn as_change_summaries(value: serde_json::Value, owner: &str, id: i32) -> Vec<ChangeSummary> {
+    serde_json::from_value(value).unwrap_or_else(|err| {
+        error!("Failed to read change summaries of {owner} {id}: {err}");
+
+        vec![]
+    })
+}
+
+fn as_clauses(value: serde_json::Value, owner: &str, id: i32) -> Vec<Clause> {
+    serde_json::from_value(value).unwrap_or_else(|err| {
+        error!("Failed to read clauses of {owner} {id}: {err}");
+
+        vec![]
+    })
+}
+
+impl From<groups::Model> for Group {
+    fn from(value: groups::Model) -> Self {
+        Group {
+            name: value.name,
+            description: value.description,
+            owner: value.owner,
+            mandatory: value.mandatory,
+            default_locale: value.default_locale,
+            max_consent_age_days: value.max_consent_age_days.map(|days| days as u32),
+        }
+    }
+}
+
+impl From<bundles::Model> for Bundle {
+    fn from(value: bundles::Model) -> Self {
+        Bundle {
+            groups: serde_json::from_value(value.groups).unwrap_or_else(|err| {
+                error!("Failed to read groups of bundle '{}': {err}", value.name);
+
+                vec![]
+            }),
+            name: value.name,
+            description: value.description,
         }
     }
 }
@@ -2,5 +2,9 @@
 
 pub mod prelude;
 
+pub mod bundles;
+pub mod groups;
+pub mod term_reservations;
 pub mod terms;
+pub mod upload_policies;
 pub mod user_agreements;
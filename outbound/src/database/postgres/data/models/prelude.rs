@@ -1,4 +1,8 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.20
 
+pub use super::bundles::Entity as Bundles;
+pub use super::groups::Entity as Groups;
+pub use super::term_reservations::Entity as TermReservations;
 pub use super::terms::Entity as Terms;
+pub use super::upload_policies::Entity as UploadPolicies;
 pub use super::user_agreements::Entity as UserAgreements;
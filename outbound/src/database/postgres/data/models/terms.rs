@@ -16,6 +16,21 @@ pub struct Model {
     #[sea_orm(column_type = "Text", nullable)]
     pub info: Option<String>,
     pub created_at: DateTime,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub html: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub text: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub change_summaries: Json,
+    pub pdf_page_count: Option<i32>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub pdf_title: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub pdf_producer: Option<String>,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub metadata: Json,
+    #[sea_orm(column_type = "JsonBinary")]
+    pub clauses: Json,
     #[sea_orm(has_many)]
     pub user_agreements: HasMany<super::user_agreements::Entity>,
 }
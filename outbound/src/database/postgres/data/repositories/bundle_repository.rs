@@ -0,0 +1,152 @@
+use async_trait::async_trait;
+use domain::{
+    data::repository::BundleRepository,
+    entities::Bundle,
+    errors::{Result, TermsOfUseError},
+};
+use sea_orm::{EntityTrait, QueryOrder, sea_query::OnConflict};
+use tracing::error;
+
+use crate::database::postgres::{
+    PostgresRepository,
+    data::models::{bundles, prelude::Bundles},
+};
+
+#[async_trait]
+impl BundleRepository for PostgresRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_bundle(&self, name: &str) -> Result<Option<Bundle>> {
+        Bundles::find_by_id(name.to_string())
+            .one(&self.db)
+            .await
+            .map(|bundle| bundle.map(Into::into))
+            .map_err(|err| {
+                error!("Failed to fetch bundle '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_bundles(&self) -> Result<Vec<Bundle>> {
+        Bundles::find()
+            .order_by_asc(bundles::Column::Name)
+            .all(&self.db)
+            .await
+            .map(|bundles| bundles.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!("Failed to fetch bundles: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_bundle(&self, bundle: Bundle) -> Result<Bundle> {
+        let model = bundles::ActiveModel {
+            name: sea_orm::Set(bundle.name.clone()),
+            description: sea_orm::Set(bundle.description.clone()),
+            groups: sea_orm::Set(serde_json::json!(bundle.groups)),
+        };
+
+        Bundles::insert(model)
+            .on_conflict(
+                OnConflict::column(bundles::Column::Name)
+                    .update_columns([bundles::Column::Description, bundles::Column::Groups])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to save bundle '{}': {err}", bundle.name);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(bundle)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_bundle(&self, name: &str) -> Result<()> {
+        Bundles::delete_by_id(name.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to delete bundle '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::errors::TermsOfUseError;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    fn bundle_model() -> bundles::Model {
+        bundles::Model {
+            name: "sign-up".to_string(),
+            description: None,
+            groups: serde_json::json!(["terms-of-service", "privacy-policy"]),
+        }
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_bundle_maps_the_stored_groups() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![bundle_model()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let bundle = repository
+            .get_bundle("sign-up")
+            .await
+            .unwrap()
+            .expect("Bundle should exist");
+
+        assert_eq!(bundle.groups, ["terms-of-service", "privacy-policy"]);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn save_bundle_upserts_the_bundle() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let bundle: Bundle = bundle_model().into();
+
+        let result = repository.save_bundle(bundle.clone()).await.unwrap();
+
+        assert_eq!(result, bundle);
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("ON CONFLICT"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_bundles_propagates_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(Vec::<Vec<bundles::Model>>::new())
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_bundles().await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
@@ -0,0 +1,209 @@
+use async_trait::async_trait;
+use domain::{
+    data::repository::GroupRepository,
+    entities::Group,
+    errors::{Result, TermsOfUseError},
+};
+use sea_orm::{ActiveModelTrait, EntityTrait, QueryOrder};
+use tracing::error;
+
+use crate::database::postgres::{
+    PostgresRepository,
+    data::models::{groups, prelude::Groups},
+};
+
+impl From<&Group> for groups::ActiveModel {
+    fn from(group: &Group) -> Self {
+        groups::ActiveModel {
+            name: sea_orm::Set(group.name.clone()),
+            description: sea_orm::Set(group.description.clone()),
+            owner: sea_orm::Set(group.owner.clone()),
+            mandatory: sea_orm::Set(group.mandatory),
+            default_locale: sea_orm::Set(group.default_locale.clone()),
+            max_consent_age_days: sea_orm::Set(group.max_consent_age_days.map(|days| days as i32)),
+        }
+    }
+}
+
+#[async_trait]
+impl GroupRepository for PostgresRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_group(&self, name: &str) -> Result<Option<Group>> {
+        Groups::find_by_id(name.to_string())
+            .one(&self.db)
+            .await
+            .map(|group| group.map(Into::into))
+            .map_err(|err| {
+                error!("Failed to fetch group '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_groups(&self) -> Result<Vec<Group>> {
+        Groups::find()
+            .order_by_asc(groups::Column::Name)
+            .all(&self.db)
+            .await
+            .map(|groups| groups.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!("Failed to fetch groups: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn create_group(&self, group: Group) -> Result<Group> {
+        Groups::insert(groups::ActiveModel::from(&group))
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to create group '{}': {err}", group.name);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(group)
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn update_group(&self, group: Group) -> Result<Group> {
+        groups::ActiveModel::from(&group)
+            .update(&self.db)
+            .await
+            .map(Into::into)
+            .map_err(|err| {
+                if matches!(err, sea_orm::DbErr::RecordNotUpdated) {
+                    return TermsOfUseError::NotFound;
+                }
+
+                error!("Failed to update group '{}': {err}", group.name);
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn delete_group(&self, name: &str) -> Result<()> {
+        Groups::delete_by_id(name.to_string())
+            .exec(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to delete group '{name}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::errors::TermsOfUseError;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    fn group_model(name: &str) -> groups::Model {
+        groups::Model {
+            name: name.to_string(),
+            description: Some("How we process personal data".to_string()),
+            owner: Some("legal".to_string()),
+            mandatory: true,
+            default_locale: "en".to_string(),
+            max_consent_age_days: None,
+        }
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_group_maps_the_stored_group() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![group_model("privacy-policy")]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let group = repository
+            .get_group("privacy-policy")
+            .await
+            .unwrap()
+            .expect("Group should exist");
+
+        assert_eq!(group.owner.as_deref(), Some("legal"));
+        assert!(group.mandatory);
+        assert_eq!(group.default_locale, "en");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_groups_maps_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                group_model("cookie-policy"),
+                group_model("privacy-policy"),
+            ]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let groups = repository.get_groups().await.unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].name, "cookie-policy");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn create_group_returns_the_created_group() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let group: Group = group_model("privacy-policy").into();
+
+        let result = repository.create_group(group.clone()).await.unwrap();
+
+        assert_eq!(result, group);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn update_group_returns_the_updated_row() {
+        let updated = groups::Model {
+            mandatory: false,
+            ..group_model("privacy-policy")
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![updated.clone()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.update_group(updated.into()).await.unwrap();
+
+        assert!(!result.mandatory);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn delete_group_propagates_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(Vec::<MockExecResult>::new())
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.delete_group("privacy-policy").await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
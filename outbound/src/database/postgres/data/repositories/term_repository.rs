@@ -1,10 +1,13 @@
 use async_trait::async_trait;
 use domain::{
     data::repository::TermRepository,
-    entities::TermOfUse,
+    entities::{TermMetadata, TermOfUse},
     errors::{Result, TermsOfUseError},
 };
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect, SqlErr,
+    sea_query::Expr,
+};
 use tracing::error;
 
 use crate::database::postgres::{
@@ -29,6 +32,27 @@ impl TermRepository for PostgresRepository {
             })
     }
 
+    #[tracing::instrument(skip(self, groups))]
+    async fn get_latest_terms_for_groups(&self, groups: &[String]) -> Result<Vec<TermOfUse>> {
+        // One row per group: DISTINCT ON keeps the first, highest version of each
+        Terms::find()
+            .filter(terms::Column::Group.is_in(groups))
+            .distinct_on([terms::Column::Group])
+            .order_by_asc(terms::Column::Group)
+            .order_by_desc(terms::Column::Version)
+            .all(&self.db)
+            .await
+            .map(|terms| terms.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!(
+                    "Failed to fetch latest terms of {} groups: {err}",
+                    groups.len()
+                );
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
     #[tracing::instrument(skip(self, term_id))]
     async fn get_term_by_id(&self, term_id: i32) -> Result<Option<TermOfUse>> {
         Terms::find_by_id(term_id)
@@ -42,18 +66,108 @@ impl TermRepository for PostgresRepository {
             })
     }
 
+    #[tracing::instrument(skip(self, group))]
+    async fn get_term_by_version(&self, group: &str, version: u32) -> Result<Option<TermOfUse>> {
+        Terms::find()
+            .filter(terms::Column::Group.eq(group))
+            .filter(terms::Column::Version.eq(version as i32))
+            .one(&self.db)
+            .await
+            .map(|term| term.map(Into::into))
+            .map_err(|err| {
+                error!("Failed to fetch version {version} of group {group}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self, group))]
+    async fn get_terms_for_group(
+        &self,
+        group: &str,
+        metadata: &TermMetadata,
+    ) -> Result<Vec<TermOfUse>> {
+        let mut query = Terms::find().filter(terms::Column::Group.eq(group));
+
+        if !metadata.is_empty() {
+            // jsonb containment, served by the GIN index on the column
+            query = query.filter(Expr::cust_with_values(
+                "\"metadata\" @> $1",
+                [serde_json::Value::Object(metadata.clone())],
+            ));
+            // Containment also matches subsets of arrays and objects, values
+            // must be equal as they are on DynamoDB
+            for (key, value) in metadata {
+                query = query.filter(Expr::cust_with_values(
+                    "(\"metadata\" -> $1) = $2",
+                    [
+                        sea_orm::Value::from(key.clone()),
+                        sea_orm::Value::from(value.clone()),
+                    ],
+                ));
+            }
+        }
+
+        query
+            .order_by_desc(terms::Column::Version)
+            .all(&self.db)
+            .await
+            .map(|terms| terms.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!("Failed to fetch terms of group {group}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
     #[tracing::instrument(skip(self, term))]
     async fn create_term(&self, term: TermOfUse) -> Result<TermOfUse> {
+        let change_summaries = serde_json::to_value(&term.change_summaries).map_err(|err| {
+            error!("Failed to serialize change summaries: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let clauses = serde_json::to_value(&term.clauses).map_err(|err| {
+            error!("Failed to serialize clauses: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let (pdf_page_count, pdf_title, pdf_producer) = match term.pdf_metadata {
+            Some(metadata) => (
+                Some(metadata.page_count as i32),
+                metadata.title,
+                metadata.producer,
+            ),
+            None => (None, None, None),
+        };
+
         let new_term = terms::ActiveModel {
             url: sea_orm::Set(term.url),
-            group: sea_orm::Set(term.group),
+            group: sea_orm::Set(term.group.clone()),
             info: sea_orm::Set(term.info),
+            html: sea_orm::Set(term.html),
+            text: sea_orm::Set(term.text),
+            change_summaries: sea_orm::Set(change_summaries),
+            pdf_page_count: sea_orm::Set(pdf_page_count),
+            pdf_title: sea_orm::Set(pdf_title),
+            pdf_producer: sea_orm::Set(pdf_producer),
+            metadata: sea_orm::Set(serde_json::Value::Object(term.metadata)),
+            clauses: sea_orm::Set(clauses),
             version: sea_orm::Set(term.version as i32),
             created_at: sea_orm::Set(term.created_at),
             ..Default::default()
         };
 
         let inserted_term = new_term.insert(&self.db).await.map_err(|err| {
+            if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+                return TermsOfUseError::Validation(format!(
+                    "Version {} of group '{}' already exists",
+                    term.version, term.group
+                ));
+            }
+
             error!("Failed to create new term: {err}");
 
             TermsOfUseError::InternalServerError
@@ -61,12 +175,29 @@ impl TermRepository for PostgresRepository {
 
         Ok(inserted_term.into())
     }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_terms(&self) -> Result<Vec<TermOfUse>> {
+        Terms::find()
+            .order_by_asc(terms::Column::Id)
+            .all(&self.db)
+            .await
+            .map(|terms| terms.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!("Failed to fetch all terms: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use chrono::Utc;
-    use domain::errors::TermsOfUseError;
+    use domain::{
+        entities::{Clause, PdfMetadata, TermMetadata},
+        errors::TermsOfUseError,
+    };
     use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
 
     use super::*;
@@ -83,6 +214,14 @@ mod tests {
             version: 2,
             info: Some("v2 info".to_string()),
             created_at,
+            html: None,
+            text: None,
+            change_summaries: serde_json::json!([]),
+            pdf_page_count: None,
+            pdf_title: None,
+            pdf_producer: None,
+            metadata: serde_json::json!({}),
+            clauses: serde_json::json!([]),
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -119,6 +258,47 @@ mod tests {
         assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
     }
 
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_latest_terms_for_groups_reads_every_group_at_once() {
+        let term_model = |id, group: &str| terms::Model {
+            id,
+            url: format!("{group}/v2.pdf"),
+            group: group.to_string(),
+            version: 2,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: serde_json::json!([]),
+            pdf_page_count: None,
+            pdf_title: None,
+            pdf_producer: None,
+            metadata: serde_json::json!({}),
+            clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                term_model(4, "consumer"),
+                term_model(7, "privacy"),
+            ]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository
+            .get_latest_terms_for_groups(&["consumer".to_string(), "privacy".to_string()])
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].group, "privacy");
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("DISTINCT ON"));
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn get_term_by_id_returns_none_for_missing() {
@@ -133,6 +313,134 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_term_by_version_maps_extracted_text() {
+        let term_model = terms::Model {
+            id: 3,
+            url: "consumer/v1.pdf".to_string(),
+            group: "consumer".to_string(),
+            version: 1,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: Some("Terms of use".to_string()),
+            change_summaries: serde_json::json!([]),
+            pdf_page_count: None,
+            pdf_title: None,
+            pdf_producer: None,
+            metadata: serde_json::json!({}),
+            clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![term_model.clone()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository
+            .get_term_by_version("consumer", 1)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(result.id, term_model.id);
+        assert_eq!(result.text, term_model.text);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_terms_for_group_maps_change_summaries() {
+        let term_model = terms::Model {
+            id: 4,
+            url: "consumer/v2.md".to_string(),
+            group: "consumer".to_string(),
+            version: 2,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: serde_json::json!([{
+                "locale": "en",
+                "markdown": "Shorter **retention**",
+                "html": "<p>Shorter <strong>retention</strong></p>",
+            }]),
+            pdf_page_count: None,
+            pdf_title: None,
+            pdf_producer: None,
+            metadata: serde_json::json!({}),
+            clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![term_model.clone()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository
+            .get_terms_for_group("consumer", &TermMetadata::new())
+            .await
+            .unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].change_summaries.len(), 1);
+        assert_eq!(result[0].change_summaries[0].locale, "en");
+        assert_eq!(
+            result[0].change_summaries[0].markdown,
+            "Shorter **retention**"
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_terms_for_group_filters_by_metadata_containment() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<terms::Model>::new()])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let filter = serde_json::json!({ "region": "eu" })
+            .as_object()
+            .unwrap()
+            .clone();
+        let result = repository
+            .get_terms_for_group("consumer", &filter)
+            .await
+            .unwrap();
+
+        assert!(result.is_empty());
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("@>"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_terms_for_group_matches_metadata_values_exactly() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<terms::Model>::new()])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let filter = serde_json::json!({ "regions": ["eu"] })
+            .as_object()
+            .unwrap()
+            .clone();
+        repository
+            .get_terms_for_group("consumer", &filter)
+            .await
+            .unwrap();
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("@> $2"));
+        assert!(log.contains("-> $3) = $4"));
+        assert!(log.contains(r#"Json(Some(Array [String("eu")]))"#));
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn create_term_returns_inserted_term() {
@@ -144,6 +452,23 @@ mod tests {
             version: 1,
             info: None,
             created_at,
+            html: Some("<p>Terms</p>".to_string()),
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: Some(PdfMetadata {
+                page_count: 3,
+                title: Some("Terms".to_string()),
+                producer: None,
+            }),
+            metadata: serde_json::json!({ "region": "eu" })
+                .as_object()
+                .unwrap()
+                .clone(),
+            clauses: vec![Clause {
+                key: "marketing-emails".to_string(),
+                title: "Marketing emails".to_string(),
+                mandatory: false,
+            }],
         };
 
         let inserted = terms::Model {
@@ -153,6 +478,16 @@ mod tests {
             version: input.version as i32,
             info: input.info.clone(),
             created_at: input.created_at,
+            html: input.html.clone(),
+            text: None,
+            change_summaries: serde_json::json!([]),
+            pdf_page_count: Some(3),
+            pdf_title: Some("Terms".to_string()),
+            pdf_producer: None,
+            metadata: serde_json::json!({ "region": "eu" }),
+            clauses: serde_json::json!([
+                { "key": "marketing-emails", "title": "Marketing emails", "mandatory": false }
+            ]),
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -165,6 +500,7 @@ mod tests {
 
         let repository = PostgresRepository::from_connection(db);
 
+        let pdf_metadata = input.pdf_metadata.clone();
         let result = repository.create_term(input).await.unwrap();
 
         assert_eq!(result.id, inserted.id);
@@ -173,6 +509,11 @@ mod tests {
         assert_eq!(result.version, inserted.version as u32);
         assert_eq!(result.info, inserted.info);
         assert_eq!(result.created_at, inserted.created_at);
+        assert_eq!(result.html, inserted.html);
+        assert_eq!(result.pdf_metadata, pdf_metadata);
+        assert_eq!(result.metadata["region"], "eu");
+        assert_eq!(result.clauses[0].key, "marketing-emails");
+        assert!(!result.clauses[0].mandatory);
     }
 
     #[tokio::test]
@@ -186,6 +527,12 @@ mod tests {
             version: 1,
             info: None,
             created_at,
+            html: None,
+            text: None,
+            change_summaries: vec![],
+            pdf_metadata: None,
+            metadata: Default::default(),
+            clauses: vec![],
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -198,4 +545,57 @@ mod tests {
 
         assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
     }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_all_terms_maps_rows() {
+        let created_at = Utc::now().naive_utc();
+
+        let models = vec![
+            terms::Model {
+                id: 1,
+                url: "privacy-policy/v1.pdf".to_string(),
+                group: "privacy-policy".to_string(),
+                version: 1,
+                info: None,
+                created_at,
+                html: None,
+                text: None,
+                change_summaries: serde_json::json!([]),
+                pdf_page_count: None,
+                pdf_title: None,
+                pdf_producer: None,
+                metadata: serde_json::json!({}),
+                clauses: serde_json::json!([]),
+            },
+            terms::Model {
+                id: 2,
+                url: "privacy-policy/v2.pdf".to_string(),
+                group: "privacy-policy".to_string(),
+                version: 2,
+                info: None,
+                created_at,
+                html: None,
+                text: None,
+                change_summaries: serde_json::json!([]),
+                pdf_page_count: None,
+                pdf_title: None,
+                pdf_producer: None,
+                metadata: serde_json::json!({}),
+                clauses: serde_json::json!([]),
+            },
+        ];
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![models])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_all_terms().await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0].url, "privacy-policy/v1.pdf");
+        assert_eq!(result[1].version, 2);
+    }
 }
@@ -0,0 +1,317 @@
+use async_trait::async_trait;
+use chrono::{NaiveDateTime, Utc};
+use domain::{
+    data::repository::TermReservationRepository,
+    entities::TermReservation,
+    errors::{Result, TermsOfUseError},
+};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, QueryOrder, SqlErr,
+    sea_query::Expr,
+};
+use tracing::error;
+
+use crate::database::postgres::{
+    PostgresRepository,
+    data::models::{prelude::TermReservations, term_reservations},
+};
+
+#[async_trait]
+impl TermReservationRepository for PostgresRepository {
+    #[tracing::instrument(skip(self, reservation))]
+    async fn create_reservation(&self, reservation: TermReservation) -> Result<TermReservation> {
+        let change_summaries =
+            serde_json::to_value(&reservation.change_summaries).map_err(|err| {
+                error!("Failed to serialize change summaries: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+        let clauses = serde_json::to_value(&reservation.clauses).map_err(|err| {
+            error!("Failed to serialize clauses: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let new_reservation = term_reservations::ActiveModel {
+            group: sea_orm::Set(reservation.group.clone()),
+            version: sea_orm::Set(reservation.version as i32),
+            info: sea_orm::Set(reservation.info),
+            key: sea_orm::Set(reservation.key),
+            content_type: sea_orm::Set(reservation.content_type),
+            size: sea_orm::Set(reservation.size as i64),
+            sha256: sea_orm::Set(reservation.sha256),
+            expires_at: sea_orm::Set(reservation.expires_at),
+            change_summaries: sea_orm::Set(change_summaries),
+            metadata: sea_orm::Set(serde_json::Value::Object(reservation.metadata)),
+            clauses: sea_orm::Set(clauses),
+            ..Default::default()
+        };
+
+        let inserted_reservation = new_reservation.insert(&self.db).await.map_err(|err| {
+            if matches!(err.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) {
+                return TermsOfUseError::Validation(format!(
+                    "Version {} of group '{}' is already being uploaded",
+                    reservation.version, reservation.group
+                ));
+            }
+
+            error!("Failed to create term reservation: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(inserted_reservation.into())
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn get_reservation(&self, reservation_id: i32) -> Result<Option<TermReservation>> {
+        TermReservations::find_by_id(reservation_id)
+            .one(&self.db)
+            .await
+            .map(|reservation| reservation.map(Into::into))
+            .map_err(|err| {
+                error!("Failed to fetch term reservation {reservation_id}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self, group))]
+    async fn get_reservation_for_version(
+        &self,
+        group: &str,
+        version: u32,
+    ) -> Result<Option<TermReservation>> {
+        TermReservations::find()
+            .filter(term_reservations::Column::Group.eq(group))
+            .filter(term_reservations::Column::Version.eq(version as i32))
+            .one(&self.db)
+            .await
+            .map(|reservation| reservation.map(Into::into))
+            .map_err(|err| {
+                error!(
+                    "Failed to fetch the reservation of version {version} of group {group}: {err}"
+                );
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn claim_reservation(&self, reservation_id: i32, until: NaiveDateTime) -> Result<bool> {
+        let result = TermReservations::update_many()
+            .col_expr(term_reservations::Column::ClaimedUntil, Expr::value(until))
+            .filter(term_reservations::Column::Id.eq(reservation_id))
+            .filter(
+                Condition::any()
+                    .add(term_reservations::Column::ClaimedUntil.is_null())
+                    .add(term_reservations::Column::ClaimedUntil.lt(Utc::now().naive_utc())),
+            )
+            .exec(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to claim term reservation {reservation_id}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn release_reservation(&self, reservation_id: i32) -> Result<()> {
+        TermReservations::update_many()
+            .col_expr(
+                term_reservations::Column::ClaimedUntil,
+                Expr::value(Option::<NaiveDateTime>::None),
+            )
+            .filter(term_reservations::Column::Id.eq(reservation_id))
+            .exec(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to release term reservation {reservation_id}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, reservation_id))]
+    async fn delete_reservation(&self, reservation_id: i32) -> Result<()> {
+        TermReservations::delete_by_id(reservation_id)
+            .exec(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to delete term reservation {reservation_id}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn get_all_reservations(&self) -> Result<Vec<TermReservation>> {
+        TermReservations::find()
+            .order_by_asc(term_reservations::Column::Id)
+            .all(&self.db)
+            .await
+            .map(|reservations| reservations.into_iter().map(Into::into).collect())
+            .map_err(|err| {
+                error!("Failed to fetch all term reservations: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use domain::errors::TermsOfUseError;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    fn reservation_model() -> term_reservations::Model {
+        term_reservations::Model {
+            id: 4,
+            group: "privacy-policy".to_string(),
+            version: 3,
+            info: None,
+            key: "privacy-policy/v3.pdf".to_string(),
+            content_type: "application/pdf".to_string(),
+            size: 1024,
+            sha256: "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824".to_string(),
+            expires_at: Utc::now().naive_utc(),
+            claimed_until: None,
+            change_summaries: serde_json::json!([{
+                "locale": "en",
+                "markdown": "Clarified *retention*",
+                "html": "<p>Clarified <em>retention</em></p>\n"
+            }]),
+            metadata: serde_json::json!({ "region": "eu" }),
+            clauses: serde_json::json!([]),
+        }
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn create_reservation_returns_inserted_reservation() {
+        let inserted = reservation_model();
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![inserted.clone()]])
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: inserted.id as u64,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let mut input: TermReservation = inserted.clone().into();
+        input.id = 0;
+
+        let result = repository.create_reservation(input).await.unwrap();
+
+        assert_eq!(result.id, inserted.id);
+        assert_eq!(result.version, inserted.version as u32);
+        assert_eq!(result.size, inserted.size as u64);
+        assert_eq!(result.key, inserted.key);
+        assert_eq!(result.metadata["region"], "eu");
+        assert_eq!(result.change_summaries[0].locale, "en");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_reservation_returns_none_for_missing() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<term_reservations::Model>::new()])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_reservation(42).await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_reservation_for_version_returns_the_reservation() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![reservation_model()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository
+            .get_reservation_for_version("privacy-policy", 3)
+            .await
+            .unwrap();
+
+        assert_eq!(result.map(|reservation| reservation.id), Some(4));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_all_reservations_returns_every_reservation() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                reservation_model(),
+                term_reservations::Model {
+                    id: 5,
+                    version: 4,
+                    key: "privacy-policy/v4.pdf".to_string(),
+                    ..reservation_model()
+                },
+            ]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_all_reservations().await.unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[1].key, "privacy-policy/v4.pdf");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn delete_reservation_propagates_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(Vec::<MockExecResult>::new())
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.delete_reservation(4).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn claim_reservation_tells_whether_it_got_the_claim() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+        let until = Utc::now().naive_utc();
+
+        assert!(repository.claim_reservation(4, until).await.unwrap());
+        assert!(!repository.claim_reservation(4, until).await.unwrap());
+    }
+}
@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use domain::{
+    data::repository::UploadPolicyRepository,
+    entities::UploadPolicy,
+    errors::{Result, TermsOfUseError},
+};
+use sea_orm::{EntityTrait, sea_query::OnConflict};
+use tracing::error;
+
+use crate::database::postgres::{
+    PostgresRepository,
+    data::models::{prelude::UploadPolicies, upload_policies},
+};
+
+#[async_trait]
+impl UploadPolicyRepository for PostgresRepository {
+    #[tracing::instrument(skip(self))]
+    async fn get_upload_policy(&self, group: &str) -> Result<Option<UploadPolicy>> {
+        UploadPolicies::find_by_id(group.to_string())
+            .one(&self.db)
+            .await
+            .map(|policy| policy.map(Into::into))
+            .map_err(|err| {
+                error!("Failed to fetch upload policy of '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self))]
+    async fn save_upload_policy(&self, policy: UploadPolicy) -> Result<UploadPolicy> {
+        let model = upload_policies::ActiveModel {
+            group: sea_orm::Set(policy.group.clone()),
+            content_types: sea_orm::Set(serde_json::json!(policy.content_types)),
+            max_size: sea_orm::Set(policy.max_size as i64),
+            metadata_schema: sea_orm::Set(policy.metadata_schema.clone()),
+            required_fields: sea_orm::Set(serde_json::json!(policy.required_fields)),
+        };
+
+        UploadPolicies::insert(model)
+            .on_conflict(
+                OnConflict::column(upload_policies::Column::Group)
+                    .update_columns([
+                        upload_policies::Column::ContentTypes,
+                        upload_policies::Column::MaxSize,
+                        upload_policies::Column::MetadataSchema,
+                        upload_policies::Column::RequiredFields,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to save upload policy of '{}': {err}", policy.group);
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(policy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::errors::TermsOfUseError;
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+
+    use super::*;
+
+    fn policy_model() -> upload_policies::Model {
+        upload_policies::Model {
+            group: "privacy-policy".to_string(),
+            content_types: serde_json::json!(["application/pdf"]),
+            max_size: 2 * 1024 * 1024,
+            metadata_schema: Some(serde_json::json!({ "required": ["region"] })),
+            required_fields: serde_json::json!(["info"]),
+        }
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_upload_policy_maps_the_stored_policy() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![policy_model()]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let policy = repository
+            .get_upload_policy("privacy-policy")
+            .await
+            .unwrap()
+            .expect("Policy should exist");
+
+        assert_eq!(policy.content_types, ["application/pdf"]);
+        assert_eq!(policy.max_size, 2 * 1024 * 1024);
+        assert_eq!(policy.required_fields, ["info"]);
+        assert_eq!(
+            policy.metadata_schema,
+            Some(serde_json::json!({ "required": ["region"] }))
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_upload_policy_returns_none_for_missing() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![Vec::<upload_policies::Model>::new()])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_upload_policy("cookie-policy").await.unwrap();
+
+        assert!(result.is_none());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn save_upload_policy_returns_the_saved_policy() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(vec![MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let policy: UploadPolicy = policy_model().into();
+
+        let result = repository.save_upload_policy(policy.clone()).await.unwrap();
+
+        assert_eq!(result, policy);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn save_upload_policy_propagates_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results(Vec::<MockExecResult>::new())
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.save_upload_policy(policy_model().into()).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
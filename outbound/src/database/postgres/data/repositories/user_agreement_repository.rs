@@ -1,27 +1,60 @@
 use async_trait::async_trait;
-use chrono::Utc;
+use chrono::{NaiveDateTime, Utc};
 use domain::{
     data::repository::UserAgreementRepository,
+    entities::UserAgreement,
     errors::{Result, TermsOfUseError},
 };
-use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, TransactionTrait,
+};
 use tracing::error;
 
 use crate::database::postgres::{
     PostgresRepository,
-    data::models::{prelude::UserAgreements, user_agreements},
+    data::models::{
+        prelude::{Terms, UserAgreements},
+        terms, user_agreements,
+    },
 };
 
+fn new_agreement(
+    user_id: i32,
+    term_id: i32,
+    accepted_clauses: &[String],
+) -> user_agreements::ActiveModel {
+    user_agreements::ActiveModel {
+        user_id: sea_orm::Set(user_id),
+        term_of_use_id: sea_orm::Set(term_id),
+        agreed_at: sea_orm::Set(Utc::now().naive_utc()),
+        accepted_clauses: sea_orm::Set(serde_json::json!(accepted_clauses)),
+        ..Default::default()
+    }
+}
+
+fn accepted_clauses(agreement: &user_agreements::Model) -> Vec<String> {
+    serde_json::from_value(agreement.accepted_clauses.clone()).unwrap_or_else(|err| {
+        error!(
+            "Failed to read accepted clauses of agreement {}: {err}",
+            agreement.id
+        );
+
+        vec![]
+    })
+}
+
+// Agreements are kept when the user agrees again, the newest one counts
 #[async_trait]
 impl UserAgreementRepository for PostgresRepository {
     #[tracing::instrument(skip(self, user_id, term_id))]
-    async fn has_user_agreed_to_term(&self, user_id: i32, term_id: i32) -> Result<bool> {
+    async fn get_agreed_at(&self, user_id: i32, term_id: i32) -> Result<Option<NaiveDateTime>> {
         UserAgreements::find()
             .filter(user_agreements::Column::UserId.eq(user_id))
             .filter(user_agreements::Column::TermOfUseId.eq(term_id))
+            .order_by_desc(user_agreements::Column::AgreedAt)
             .one(&self.db)
             .await
-            .map(|agreement| agreement.is_some())
+            .map(|agreement| agreement.map(|agreement| agreement.agreed_at))
             .map_err(|err| {
                 error!("Failed to check user agreement: {err}");
 
@@ -29,28 +62,153 @@ impl UserAgreementRepository for PostgresRepository {
             })
     }
 
+    #[tracing::instrument(skip(self, user_id, term_ids))]
+    async fn get_agreed_term_ids(&self, user_id: i32, term_ids: &[i32]) -> Result<Vec<i32>> {
+        UserAgreements::find()
+            .filter(user_agreements::Column::UserId.eq(user_id))
+            .filter(user_agreements::Column::TermOfUseId.is_in(term_ids.iter().copied()))
+            .all(&self.db)
+            .await
+            .map(|agreements| {
+                let mut agreed_term_ids: Vec<i32> = agreements
+                    .into_iter()
+                    .map(|agreement| agreement.term_of_use_id)
+                    .collect();
+                agreed_term_ids.sort_unstable();
+                agreed_term_ids.dedup();
+
+                agreed_term_ids
+            })
+            .map_err(|err| {
+                error!("Failed to check user agreements: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self, user_id, group))]
+    async fn get_newest_agreed_version(
+        &self,
+        user_id: i32,
+        group: &str,
+        min_version: u32,
+    ) -> Result<Option<u32>> {
+        Terms::find()
+            .inner_join(UserAgreements)
+            .filter(terms::Column::Group.eq(group))
+            .filter(terms::Column::Version.gte(min_version as i32))
+            .filter(user_agreements::Column::UserId.eq(user_id))
+            .order_by_desc(terms::Column::Version)
+            .one(&self.db)
+            .await
+            .map(|term| term.map(|term| term.version as u32))
+            .map_err(|err| {
+                error!("Failed to read the newest agreed version of group '{group}': {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
     #[tracing::instrument(skip(self, user_id, term_id))]
-    async fn create_user_agreement(&self, user_id: i32, term_id: i32) -> Result<()> {
-        let new_agreement = user_agreements::ActiveModel {
-            user_id: sea_orm::Set(user_id),
-            term_of_use_id: sea_orm::Set(term_id),
-            agreed_at: sea_orm::Set(Utc::now().naive_utc()),
-            ..Default::default()
-        };
+    async fn get_accepted_clauses(
+        &self,
+        user_id: i32,
+        term_id: i32,
+    ) -> Result<Option<Vec<String>>> {
+        let agreement = UserAgreements::find()
+            .filter(user_agreements::Column::UserId.eq(user_id))
+            .filter(user_agreements::Column::TermOfUseId.eq(term_id))
+            .order_by_desc(user_agreements::Column::AgreedAt)
+            .one(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to read accepted clauses: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(agreement.as_ref().map(accepted_clauses))
+    }
+
+    #[tracing::instrument(skip(self, user_id, term_id))]
+    async fn get_agreement_at(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        at: NaiveDateTime,
+    ) -> Result<Option<UserAgreement>> {
+        UserAgreements::find()
+            .filter(user_agreements::Column::UserId.eq(user_id))
+            .filter(user_agreements::Column::TermOfUseId.eq(term_id))
+            .filter(user_agreements::Column::AgreedAt.lte(at))
+            .order_by_desc(user_agreements::Column::AgreedAt)
+            .one(&self.db)
+            .await
+            .map(|agreement| {
+                agreement.map(|agreement| UserAgreement {
+                    user_id,
+                    term_id,
+                    agreed_at: agreement.agreed_at,
+                    accepted_clauses: accepted_clauses(&agreement),
+                })
+            })
+            .map_err(|err| {
+                error!("Failed to read the agreement in effect at {at}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    #[tracing::instrument(skip(self, user_id, term_id, accepted_clauses))]
+    async fn create_user_agreement(
+        &self,
+        user_id: i32,
+        term_id: i32,
+        accepted_clauses: &[String],
+    ) -> Result<()> {
+        new_agreement(user_id, term_id, accepted_clauses)
+            .insert(&self.db)
+            .await
+            .map_err(|err| {
+                error!("Failed to create user agreement: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
 
-        new_agreement.insert(&self.db).await.map_err(|err| {
-            error!("Failed to create user agreement: {err}");
+    #[tracing::instrument(skip(self, user_id))]
+    async fn create_user_agreements(&self, user_id: i32, term_ids: &[i32]) -> Result<()> {
+        let transaction = self.db.begin().await.map_err(|err| {
+            error!("Failed to start the transaction for user agreements: {err}");
 
             TermsOfUseError::InternalServerError
         })?;
 
-        Ok(())
+        // Dropping the transaction on error rolls back the agreements stored so far
+        for term_id in term_ids {
+            new_agreement(user_id, *term_id, &[])
+                .insert(&transaction)
+                .await
+                .map_err(|err| {
+                    error!("Failed to create user agreement to term {term_id}: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+        }
+
+        transaction.commit().await.map_err(|err| {
+            error!("Failed to commit user agreements: {err}");
+
+            TermsOfUseError::InternalServerError
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use chrono::Utc;
+    use chrono::{TimeDelta, Utc};
     use domain::errors::TermsOfUseError;
     use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
 
@@ -58,12 +216,14 @@ mod tests {
 
     #[tokio::test]
     #[test_log::test]
-    async fn has_user_agreed_to_term_returns_true_when_found() {
+    async fn get_agreed_at_returns_the_agreement_date() {
+        let agreed_at = Utc::now().naive_utc();
         let agreement = user_agreements::Model {
             id: 1,
             term_of_use_id: 2,
             user_id: 3,
-            agreed_at: Utc::now().naive_utc(),
+            agreed_at,
+            accepted_clauses: serde_json::json!([]),
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -72,39 +232,193 @@ mod tests {
 
         let repository = PostgresRepository::from_connection(db);
 
-        let result = repository.has_user_agreed_to_term(3, 2).await.unwrap();
+        let result = repository.get_agreed_at(3, 2).await.unwrap();
 
-        assert!(result);
+        assert_eq!(result, Some(agreed_at));
     }
 
     #[tokio::test]
     #[test_log::test]
-    async fn has_user_agreed_to_term_returns_false_when_missing() {
+    async fn get_agreed_at_returns_none_when_missing() {
         let db = MockDatabase::new(DatabaseBackend::Postgres)
             .append_query_results(vec![Vec::<user_agreements::Model>::new()])
             .into_connection();
 
         let repository = PostgresRepository::from_connection(db);
 
-        let result = repository.has_user_agreed_to_term(3, 2).await.unwrap();
+        let result = repository.get_agreed_at(3, 2).await.unwrap();
 
-        assert!(!result);
+        assert!(result.is_none());
     }
 
     #[tokio::test]
     #[test_log::test]
-    async fn has_user_agreed_to_term_propagates_error() {
+    async fn get_agreed_at_propagates_error() {
         let db = MockDatabase::new(DatabaseBackend::Postgres)
             .append_query_results(Vec::<Vec<user_agreements::Model>>::new())
             .into_connection();
 
         let repository = PostgresRepository::from_connection(db);
 
-        let result = repository.has_user_agreed_to_term(3, 2).await;
+        let result = repository.get_agreed_at(3, 2).await;
 
         assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
     }
 
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_agreed_term_ids_returns_the_agreed_terms() {
+        let agreement = user_agreements::Model {
+            id: 1,
+            term_of_use_id: 2,
+            user_id: 3,
+            agreed_at: Utc::now().naive_utc(),
+            accepted_clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![agreement]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_agreed_term_ids(3, &[2, 5]).await.unwrap();
+
+        assert_eq!(result, vec![2]);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_agreed_term_ids_lists_renewed_terms_once() {
+        let agreement = |id, agreed_at| user_agreements::Model {
+            id,
+            term_of_use_id: 2,
+            user_id: 3,
+            agreed_at,
+            accepted_clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![
+                agreement(1, Utc::now().naive_utc() - TimeDelta::days(400)),
+                agreement(2, Utc::now().naive_utc()),
+            ]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.get_agreed_term_ids(3, &[2]).await.unwrap();
+
+        assert_eq!(result, vec![2]);
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_agreement_at_reads_the_newest_agreement_given_by_then() {
+        let agreed_at = Utc::now().naive_utc() - TimeDelta::days(400);
+        let agreement = user_agreements::Model {
+            id: 1,
+            term_of_use_id: 2,
+            user_id: 3,
+            agreed_at,
+            accepted_clauses: serde_json::json!(["marketing-emails"]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![agreement]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository
+            .get_agreement_at(3, 2, agreed_at + TimeDelta::days(1))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            result,
+            Some(UserAgreement {
+                user_id: 3,
+                term_id: 2,
+                agreed_at,
+                accepted_clauses: vec!["marketing-emails".to_string()],
+            })
+        );
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains(r#"\"agreed_at\" <="#));
+        assert!(log.contains(r#"ORDER BY \"user_agreements\".\"agreed_at\" DESC"#));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_newest_agreed_version_joins_the_terms_of_the_group() {
+        let term = terms::Model {
+            id: 2,
+            group: "privacy-policy".to_string(),
+            url: "privacy-policy/v3.pdf".to_string(),
+            version: 3,
+            info: None,
+            created_at: Utc::now().naive_utc(),
+            html: None,
+            text: None,
+            change_summaries: serde_json::json!([]),
+            pdf_page_count: None,
+            pdf_title: None,
+            pdf_producer: None,
+            metadata: serde_json::json!({}),
+            clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![term], vec![]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        assert_eq!(
+            repository
+                .get_newest_agreed_version(9, "privacy-policy", 2)
+                .await
+                .unwrap(),
+            Some(3)
+        );
+        assert_eq!(
+            repository
+                .get_newest_agreed_version(9, "privacy-policy", 4)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("INNER JOIN"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn get_accepted_clauses_reads_the_agreement() {
+        let agreement = user_agreements::Model {
+            id: 1,
+            term_of_use_id: 2,
+            user_id: 3,
+            agreed_at: Utc::now().naive_utc(),
+            accepted_clauses: serde_json::json!(["marketing-emails"]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![agreement], vec![]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        assert_eq!(
+            repository.get_accepted_clauses(3, 2).await.unwrap(),
+            Some(vec!["marketing-emails".to_string()])
+        );
+        assert_eq!(repository.get_accepted_clauses(3, 4).await.unwrap(), None);
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn create_user_agreement_inserts_record() {
@@ -113,6 +427,7 @@ mod tests {
             term_of_use_id: 5,
             user_id: 9,
             agreed_at: Utc::now().naive_utc(),
+            accepted_clauses: serde_json::json!([]),
         };
 
         let db = MockDatabase::new(DatabaseBackend::Postgres)
@@ -125,7 +440,7 @@ mod tests {
 
         let repository = PostgresRepository::from_connection(db);
 
-        let result = repository.create_user_agreement(9, 5).await;
+        let result = repository.create_user_agreement(9, 5, &[]).await;
 
         assert!(result.is_ok());
     }
@@ -139,7 +454,53 @@ mod tests {
 
         let repository = PostgresRepository::from_connection(db);
 
-        let result = repository.create_user_agreement(9, 5).await;
+        let result = repository.create_user_agreement(9, 5, &[]).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn create_user_agreements_inserts_all_rows_in_one_transaction() {
+        let agreement = |id, term_of_use_id| user_agreements::Model {
+            id,
+            term_of_use_id,
+            user_id: 9,
+            agreed_at: Utc::now().naive_utc(),
+            accepted_clauses: serde_json::json!([]),
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![agreement(11, 5)], vec![agreement(12, 6)]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.create_user_agreements(9, &[5, 6]).await;
+
+        assert!(result.is_ok());
+
+        let log = format!("{:?}", repository.db.into_transaction_log());
+        assert!(log.contains("BEGIN"));
+        assert!(log.contains("COMMIT"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn create_user_agreements_propagates_error() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![user_agreements::Model {
+                id: 11,
+                term_of_use_id: 5,
+                user_id: 9,
+                agreed_at: Utc::now().naive_utc(),
+                accepted_clauses: serde_json::json!([]),
+            }]])
+            .into_connection();
+
+        let repository = PostgresRepository::from_connection(db);
+
+        let result = repository.create_user_agreements(9, &[5, 6]).await;
 
         assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
     }
@@ -0,0 +1,93 @@
+use domain::dto::{DiffHunk, DiffLine, DiffOperation};
+use similar::{ChangeTag, TextDiff};
+
+/// Groups the line changes between two texts into hunks with surrounding context.
+pub(super) fn diff_lines(old: &str, new: &str, context_lines: usize) -> Vec<DiffHunk> {
+    let diff = TextDiff::from_lines(old, new);
+
+    diff.grouped_ops(context_lines)
+        .iter()
+        .filter_map(|ops| {
+            let (first, last) = (ops.first()?, ops.last()?);
+            let old_range = first.old_range().start..last.old_range().end;
+            let new_range = first.new_range().start..last.new_range().end;
+
+            let lines = ops
+                .iter()
+                .flat_map(|op| diff.iter_changes(op))
+                .map(|change| DiffLine {
+                    operation: match change.tag() {
+                        ChangeTag::Equal => DiffOperation::Equal,
+                        ChangeTag::Insert => DiffOperation::Insert,
+                        ChangeTag::Delete => DiffOperation::Delete,
+                    },
+                    old_line: change.old_index().map(|index| index + 1),
+                    new_line: change.new_index().map(|index| index + 1),
+                    text: change.value().trim_end_matches('\n').to_string(),
+                })
+                .collect();
+
+            Some(DiffHunk {
+                old_start: old_range.start + 1,
+                old_lines: old_range.len(),
+                new_start: new_range.start + 1,
+                new_lines: new_range.len(),
+                lines,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::dto::{DiffLine, DiffOperation};
+
+    use super::diff_lines;
+
+    #[test]
+    fn diff_lines_returns_changed_lines_with_context() {
+        let hunks = diff_lines(
+            "Intro\nWe keep data for 30 days.\nContact\n",
+            "Intro\nWe keep data for 90 days.\nContact\nAppendix\n",
+            3,
+        );
+
+        assert_eq!(hunks.len(), 1);
+
+        let hunk = &hunks[0];
+        assert_eq!((hunk.old_start, hunk.old_lines), (1, 3));
+        assert_eq!((hunk.new_start, hunk.new_lines), (1, 4));
+        assert!(hunk.lines.contains(&DiffLine {
+            operation: DiffOperation::Delete,
+            old_line: Some(2),
+            new_line: None,
+            text: "We keep data for 30 days.".to_string(),
+        }));
+        assert!(hunk.lines.contains(&DiffLine {
+            operation: DiffOperation::Insert,
+            old_line: None,
+            new_line: Some(2),
+            text: "We keep data for 90 days.".to_string(),
+        }));
+        assert_eq!(
+            hunk.lines.last().map(|line| line.operation),
+            Some(DiffOperation::Insert)
+        );
+    }
+
+    #[test]
+    fn diff_lines_splits_distant_changes_into_hunks() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8\n9\n10\n";
+        let new = "one\n2\n3\n4\n5\n6\n7\n8\n9\nten\n";
+
+        let hunks = diff_lines(old, new, 1);
+
+        assert_eq!(hunks.len(), 2);
+        assert_eq!((hunks[1].old_start, hunks[1].old_lines), (9, 2));
+    }
+
+    #[test]
+    fn diff_lines_of_identical_texts_is_empty() {
+        assert!(diff_lines("Same\n", "Same\n", 3).is_empty());
+    }
+}
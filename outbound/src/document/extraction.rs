@@ -0,0 +1,119 @@
+use std::{
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::Path,
+};
+
+use lopdf::Document;
+use pdf_extract::{PlainTextOutput, output_doc};
+use tracing::warn;
+
+/// Width at which extracted HTML text is wrapped, wide enough to keep paragraphs on one line.
+const HTML_TEXT_WIDTH: usize = 10_000;
+
+/// Extracts the plain text of a document, `None` when it cannot be read.
+///
+/// Markdown and HTML documents are read from their rendered HTML, so the same
+/// content gives the same text in both formats, and PDF documents from their
+/// parsed `pdf`. Extraction is best effort and never fails an upload.
+pub(super) fn extract_text(
+    path: &Path,
+    pdf: Option<&Document>,
+    html: Option<&str>,
+) -> Option<String> {
+    let text = match (pdf, html) {
+        (_, Some(html)) => {
+            html2text::from_read(html.as_bytes(), HTML_TEXT_WIDTH).map_err(|err| err.to_string())
+        }
+        (Some(pdf), None) => extract_pdf_text(pdf),
+        _ => return None,
+    };
+
+    match text {
+        Ok(text) => Some(normalize(&text)),
+        Err(err) => {
+            warn!("Failed to extract text of {}: {err}", path.display());
+
+            None
+        }
+    }
+}
+
+fn extract_pdf_text(pdf: &Document) -> Result<String, String> {
+    let mut text = String::new();
+
+    // The PDF parser panics on some malformed documents instead of returning an error
+    catch_unwind(AssertUnwindSafe(|| {
+        output_doc(pdf, &mut PlainTextOutput::new(&mut text))
+    }))
+    .map_err(|_| "the PDF parser panicked".to_string())?
+    .map_err(|err| err.to_string())?;
+
+    Ok(text)
+}
+
+/// Drops trailing whitespace and blank lines, so layout changes do not show up in diffs.
+fn normalize(text: &str) -> String {
+    text.lines()
+        .map(str::trim_end)
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use lopdf::{Document, Object};
+
+    use super::extract_text;
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    #[test]
+    fn extract_text_reads_rendered_html() {
+        let text = extract_text(
+            Path::new("/tmp/terms.md"),
+            None,
+            Some("<h1>Terms</h1>\n<p>We never sell your data.</p>\n\n<p>Contact us.</p>"),
+        )
+        .unwrap();
+
+        assert!(text.contains("Terms"));
+        assert!(text.contains("We never sell your data."));
+        assert!(!text.contains("<p>"));
+        assert!(!text.lines().any(|line| line.trim().is_empty()));
+    }
+
+    #[test]
+    fn extract_text_reads_parsed_pdf() {
+        let document = Document::load(SAMPLE_PDF).unwrap();
+
+        let text = extract_text(Path::new(SAMPLE_PDF), Some(&document), None).unwrap();
+
+        assert!(!text.is_empty());
+        assert!(!text.lines().any(|line| line.trim().is_empty()));
+    }
+
+    #[test]
+    fn extract_text_skips_unreadable_pdf() {
+        // Parses, but no page has the media box its text is laid out in
+        let mut document = Document::load(SAMPLE_PDF).unwrap();
+        for object in document.objects.values_mut() {
+            if let Object::Dictionary(dictionary) = object {
+                dictionary.remove(b"MediaBox");
+            }
+        }
+
+        let text = extract_text(Path::new(SAMPLE_PDF), Some(&document), None);
+
+        assert!(text.is_none());
+    }
+
+    #[test]
+    fn extract_text_skips_unknown_formats() {
+        let text = extract_text(Path::new("/tmp/image.png"), None, None);
+
+        assert!(text.is_none());
+    }
+}
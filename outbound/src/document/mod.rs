@@ -0,0 +1,13 @@
+mod diff;
+mod extraction;
+mod pdf_metadata;
+mod rendering;
+mod service;
+
+/// Reads term documents in process.
+///
+/// Markdown and HTML documents are rendered to sanitized HTML, PDF documents are
+/// validated and parsed for their metadata, and the plain text of both is
+/// extracted for diffs between versions.
+#[derive(Clone, Debug)]
+pub struct DocumentProcessor;
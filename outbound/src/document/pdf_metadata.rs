@@ -0,0 +1,189 @@
+use std::path::Path;
+
+use domain::{
+    entities::PdfMetadata,
+    errors::{Result, TermsOfUseError},
+};
+use lopdf::{Document, Object};
+use tracing::{error, warn};
+
+/// Parses a PDF document, `None` for other documents.
+///
+/// Documents that cannot be parsed, have no pages or are encrypted are rejected,
+/// as clients could not open them.
+pub(super) fn load_pdf(path: &Path, content_type: &str) -> Result<Option<Document>> {
+    if content_type != "application/pdf" {
+        return Ok(None);
+    }
+
+    let content = std::fs::read(path).map_err(|err| {
+        error!("Failed to read {}: {err}", path.display());
+
+        TermsOfUseError::InternalServerError
+    })?;
+
+    // The PDF parser panics on some malformed documents instead of returning an error
+    let document = std::panic::catch_unwind(|| Document::load_mem(&content))
+        .map_err(|_| "the PDF parser panicked".to_string())
+        .and_then(|document| document.map_err(|err| err.to_string()))
+        .map_err(|err| {
+            warn!("Rejected unreadable PDF {}: {err}", path.display());
+
+            TermsOfUseError::Validation("The PDF document could not be read".to_string())
+        })?;
+
+    // The parser decrypts documents with an empty user password and removes their
+    // encryption dictionary from the trailer, keeping only the encryption state
+    if document.encryption_state.is_some() || document.trailer.get(b"Encrypt").is_ok() {
+        return Err(TermsOfUseError::Validation(
+            "Encrypted PDF documents are not accepted".to_string(),
+        ));
+    }
+
+    if document.get_pages().is_empty() {
+        return Err(TermsOfUseError::Validation(
+            "The PDF document has no pages".to_string(),
+        ));
+    }
+
+    Ok(Some(document))
+}
+
+/// Reads the page count and information dictionary of a parsed PDF document.
+pub(super) fn read_pdf_metadata(document: &Document) -> PdfMetadata {
+    PdfMetadata {
+        page_count: document.get_pages().len() as u32,
+        title: info_entry(document, b"Title"),
+        producer: info_entry(document, b"Producer"),
+    }
+}
+
+/// Reads a text entry of the document information dictionary, `None` when missing or blank.
+fn info_entry(document: &Document, key: &[u8]) -> Option<String> {
+    let info = document.trailer.get(b"Info").ok()?;
+    let (_, info) = document.dereference(info).ok()?;
+    let (_, value) = document
+        .dereference(info.as_dict().ok()?.get(key).ok()?)
+        .ok()?;
+
+    let text = match value {
+        Object::String(bytes, _) => decode_text_string(bytes),
+        _ => return None,
+    };
+
+    let text = text.trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+/// Decodes a PDF text string, either UTF-16BE with a byte order mark or PDFDocEncoding.
+fn decode_text_string(bytes: &[u8]) -> String {
+    match bytes {
+        [0xFE, 0xFF, rest @ ..] => {
+            let units: Vec<u16> = rest
+                .chunks_exact(2)
+                .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                .collect();
+
+            String::from_utf16_lossy(&units)
+        }
+        // PDFDocEncoding matches Latin-1 for all printable characters used in practice
+        _ => bytes.iter().map(|&byte| byte as char).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use domain::errors::TermsOfUseError;
+    use lopdf::{Dictionary, Document, Stream};
+
+    use super::{load_pdf, read_pdf_metadata};
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    fn write_document(name: &str, content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "pdf-metadata-{name}-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn read_pdf_metadata_reads_pages_title_and_producer() {
+        let document = load_pdf(Path::new(SAMPLE_PDF), "application/pdf")
+            .unwrap()
+            .unwrap();
+
+        let metadata = read_pdf_metadata(&document);
+
+        assert_eq!(metadata.page_count, 1);
+        assert_eq!(metadata.title.as_deref(), Some("sample"));
+        assert_eq!(
+            metadata.producer.as_deref(),
+            Some("Mac OS X 10.5.4 Quartz PDFContext")
+        );
+    }
+
+    #[test]
+    fn load_pdf_rejects_corrupted_pdf() {
+        let path = write_document("corrupted", b"%PDF-1.4\nnot really a PDF");
+
+        let result = load_pdf(&path, "application/pdf");
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[test]
+    fn load_pdf_rejects_encrypted_pdf() {
+        // Points the trailer to an encryption dictionary, like encrypting tools do
+        let content = std::fs::read(SAMPLE_PDF).unwrap();
+        let trailer = content
+            .windows(5)
+            .rposition(|window| window == b"/Root")
+            .unwrap();
+        let content = [
+            &content[..trailer],
+            b"/Encrypt 30 0 R ".as_slice(),
+            &content[trailer..],
+        ]
+        .concat();
+        let path = write_document("encrypted", &content);
+
+        let result = load_pdf(&path, "application/pdf");
+
+        assert!(
+            matches!(result, Err(TermsOfUseError::Validation(detail)) if detail.contains("Encrypted"))
+        );
+    }
+
+    #[test]
+    fn load_pdf_accepts_encrypt_name_in_content() {
+        // A content stream may show the name, only the trailer marks an encrypted document
+        let mut document = Document::load(SAMPLE_PDF).unwrap();
+        document.add_object(Stream::new(
+            Dictionary::new(),
+            b"BT /F1 12 Tf (/Encrypt) Tj /Encrypt Do ET".to_vec(),
+        ));
+        let mut content = Vec::new();
+        document.save_to(&mut content).unwrap();
+        let path = write_document("encrypt-name", &content);
+
+        let result = load_pdf(&path, "application/pdf").unwrap();
+
+        assert_eq!(read_pdf_metadata(&result.unwrap()).page_count, 1);
+    }
+
+    #[test]
+    fn load_pdf_skips_other_documents() {
+        let path = write_document("markdown", b"# Terms");
+
+        let result = load_pdf(&path, "text/markdown").unwrap();
+
+        assert!(result.is_none());
+    }
+}
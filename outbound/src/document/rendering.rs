@@ -0,0 +1,112 @@
+use std::path::Path;
+
+use domain::errors::{Result, TermsOfUseError};
+use pulldown_cmark::{Options, Parser, html::push_html};
+use tracing::error;
+
+/// Renders Markdown and HTML documents to sanitized HTML, `None` for other documents.
+///
+/// Scripts, event handlers and other unsafe markup are stripped, so the result
+/// can be embedded by clients as-is.
+pub(super) fn render_document(path: &Path, content_type: &str) -> Result<Option<String>> {
+    if content_type != "text/markdown" && content_type != "text/html" {
+        return Ok(None);
+    }
+
+    let content = std::fs::read(path).map_err(|err| {
+        error!("Failed to read {}: {err}", path.display());
+
+        TermsOfUseError::InternalServerError
+    })?;
+
+    let content = String::from_utf8(content).map_err(|_| {
+        TermsOfUseError::Validation("Term of use document must be UTF-8 encoded".to_string())
+    })?;
+
+    if content_type == "text/markdown" {
+        return Ok(Some(render_markdown(&content)));
+    }
+
+    Ok(Some(ammonia::clean(&content)))
+}
+
+/// Renders Markdown to sanitized HTML.
+pub(super) fn render_markdown(markdown: &str) -> String {
+    let mut html = String::with_capacity(markdown.len() * 3 / 2);
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    push_html(&mut html, Parser::new_ext(markdown, options));
+
+    ammonia::clean(&html)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use domain::errors::TermsOfUseError;
+
+    use super::render_document;
+
+    fn write_document(content: &[u8]) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "rendering-{}-{:?}",
+            std::process::id(),
+            std::thread::current().id()
+        ));
+        std::fs::write(&path, content).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn render_document_renders_markdown() {
+        let path = write_document(b"# Privacy\n\nWe **never** sell your data.");
+
+        let html = render_document(&path, "text/markdown").unwrap().unwrap();
+
+        assert!(html.contains("<h1>Privacy</h1>"));
+        assert!(html.contains("<strong>never</strong>"));
+    }
+
+    #[test]
+    fn render_document_sanitizes_html() {
+        let path = write_document(
+            b"<p onclick=\"steal()\">Terms</p><script>steal()</script><a href=\"javascript:steal()\">x</a>",
+        );
+
+        let html = render_document(&path, "text/html").unwrap().unwrap();
+
+        assert!(html.contains("Terms</p>"));
+        assert!(!html.contains("script"));
+        assert!(!html.contains("onclick"));
+        assert!(!html.contains("javascript"));
+    }
+
+    #[test]
+    fn render_document_sanitizes_markdown_with_inline_html() {
+        let path = write_document(b"Terms<img src=x onerror=steal()>");
+
+        let html = render_document(&path, "text/markdown").unwrap().unwrap();
+
+        assert!(!html.contains("onerror"));
+    }
+
+    #[test]
+    fn render_document_skips_pdf() {
+        let result = render_document(&PathBuf::from("/does/not/exist.pdf"), "application/pdf");
+
+        assert!(matches!(result, Ok(None)));
+    }
+
+    #[test]
+    fn render_document_rejects_invalid_utf8() {
+        let path = write_document(&[0xff, 0xfe, 0x00]);
+
+        let result = render_document(&path, "text/markdown");
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
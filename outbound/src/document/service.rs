@@ -0,0 +1,136 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use domain::{
+    data::service::DocumentService,
+    dto::DiffHunk,
+    entities::TermDocument,
+    errors::{Result, TermsOfUseError},
+};
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+use crate::document::{
+    DocumentProcessor,
+    diff::diff_lines,
+    extraction::extract_text,
+    pdf_metadata::{load_pdf, read_pdf_metadata},
+    rendering::{render_document, render_markdown},
+};
+
+#[async_trait]
+impl DocumentService for DocumentProcessor {
+    async fn read_document(&self, file: &Path, content_type: &str) -> Result<TermDocument> {
+        // Reading, parsing and rendering the document would block the runtime
+        let path = file.to_path_buf();
+        let content_type = content_type.to_string();
+        tokio::task::spawn_blocking(move || read_document_content(&path, &content_type))
+            .await
+            .map_err(|err| {
+                error!("Failed to read term document: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?
+    }
+
+    async fn sha256(&self, file: &Path) -> Result<String> {
+        let content = tokio::fs::read(file).await.map_err(|err| {
+            error!("Failed to read {}: {err}", file.display());
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(format!("{:x}", Sha256::digest(content)))
+    }
+
+    fn render_markdown(&self, markdown: &str) -> String {
+        render_markdown(markdown)
+    }
+
+    fn diff_text(&self, old: &str, new: &str, context_lines: usize) -> Vec<DiffHunk> {
+        diff_lines(old, new, context_lines)
+    }
+}
+
+/// Parses a PDF document once for both its metadata and its text.
+fn read_document_content(path: &Path, content_type: &str) -> Result<TermDocument> {
+    let pdf = load_pdf(path, content_type)?;
+    let html = render_document(path, content_type)?;
+    let text = extract_text(path, pdf.as_ref(), html.as_deref());
+
+    Ok(TermDocument {
+        html,
+        text,
+        pdf_metadata: pdf.as_ref().map(read_pdf_metadata),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use domain::{data::service::DocumentService, errors::TermsOfUseError};
+
+    use crate::document::DocumentProcessor;
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    #[tokio::test]
+    async fn read_document_reads_pdf_metadata_and_text() {
+        let document = DocumentProcessor
+            .read_document(Path::new(SAMPLE_PDF), "application/pdf")
+            .await
+            .unwrap();
+
+        assert!(document.html.is_none());
+        assert!(document.text.is_some_and(|text| !text.is_empty()));
+        assert_eq!(document.pdf_metadata.unwrap().page_count, 1);
+    }
+
+    #[tokio::test]
+    async fn read_document_renders_markdown_and_extracts_its_text() {
+        let path = std::env::temp_dir().join(format!("document-{}.md", std::process::id()));
+        std::fs::write(&path, "# Terms\n\nWe never sell your data.").unwrap();
+
+        let document = DocumentProcessor
+            .read_document(&path, "text/markdown")
+            .await
+            .unwrap();
+
+        assert!(document.html.unwrap().contains("<h1>Terms</h1>"));
+        assert!(document.text.unwrap().contains("We never sell your data."));
+        assert!(document.pdf_metadata.is_none());
+    }
+
+    #[tokio::test]
+    async fn read_document_rejects_corrupted_pdf() {
+        let path = std::env::temp_dir().join(format!("document-{}.pdf", std::process::id()));
+        std::fs::write(&path, b"%PDF-1.4\nnot really a PDF").unwrap();
+
+        let result = DocumentProcessor
+            .read_document(&path, "application/pdf")
+            .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn sha256_hashes_the_document_content() {
+        let path = std::env::temp_dir().join(format!("document-{}.txt", std::process::id()));
+        std::fs::write(&path, b"hello").unwrap();
+
+        let digest = DocumentProcessor.sha256(&path).await.unwrap();
+
+        assert_eq!(
+            digest,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn render_markdown_sanitizes_inline_html() {
+        let html = DocumentProcessor.render_markdown("Updated <script>steal()</script>");
+
+        assert!(!html.contains("script"));
+    }
+}
@@ -1,6 +1,9 @@
 mod cache;
 mod database;
+mod document;
 mod publisher;
+mod scanner;
+mod schema;
 mod storage;
 
 // Database adapters
@@ -27,6 +30,17 @@ pub use storage::s3::S3Storage;
 #[cfg(feature = "gcloud")]
 pub use storage::gcloud::GoogleCloudStorage;
 
+#[cfg(feature = "filesystem")]
+pub use storage::filesystem::FilesystemStorage;
+
+#[cfg(feature = "azure")]
+pub use storage::azure::AzureBlobStorage;
+
+pub use storage::mirror::MirrorStorage;
+
+// Document adapters
+pub use document::DocumentProcessor;
+
 // Publisher adapters
 #[cfg(feature = "sns")]
 pub use publisher::sns::SNSPublisher;
@@ -36,3 +50,13 @@ pub use publisher::kafka::KafkaPublisher;
 
 #[cfg(any(not(feature = "publisher"), test))]
 pub use publisher::noop::NoopPublisher;
+
+// Scanner adapters
+#[cfg(feature = "clamd")]
+pub use scanner::clamd::ClamdScanner;
+
+#[cfg(any(not(feature = "scanner"), test))]
+pub use scanner::noop::NoopScanner;
+
+// Schema adapters
+pub use schema::JsonSchemaValidator;
@@ -75,6 +75,7 @@ mod tests {
             term_id: 1,
             user_id: 2,
             group: "privacy-policy".to_string(),
+            accepted_clauses: vec![],
         };
 
         let res = publisher.publish_agreement(dto).await;
@@ -96,6 +97,7 @@ mod tests {
             term_id: 1,
             user_id: 2,
             group: "privacy-policy".to_string(),
+            accepted_clauses: vec![],
         };
 
         publisher.publish_agreement(dto).await.unwrap();
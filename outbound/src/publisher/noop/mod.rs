@@ -54,6 +54,7 @@ mod tests {
             term_id: 1,
             user_id: 2,
             group: "privacy-policy".to_string(),
+            accepted_clauses: vec![],
         };
 
         let result = publisher.publish_agreement(dto).await;
@@ -62,6 +62,7 @@ mod tests {
             term_id: 1,
             user_id: 2,
             group: "privacy-policy".to_string(),
+            accepted_clauses: vec![],
         }
     }
 
@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use domain::{
+    data::health_check::HealthCheck,
+    errors::{Result, TermsOfUseError},
+};
+use tracing::error;
+
+use super::ClamdScanner;
+
+#[async_trait]
+impl HealthCheck for ClamdScanner {
+    async fn ping(&self) -> Result<()> {
+        let reply = self.send("PING", None).await?;
+
+        if reply != "PONG" {
+            error!("Unexpected reply to clamd PING: {reply}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::health_check::HealthCheck, errors::TermsOfUseError};
+
+    use crate::scanner::clamd::fake_clamd;
+
+    #[tokio::test]
+    async fn health_check_ping_returns_ok() {
+        let (scanner, _server) = fake_clamd("PONG").await;
+
+        let result = scanner.ping().await;
+
+        assert!(
+            result.is_ok(),
+            "ping should succeed when clamd answers PONG"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_ping_fails_on_unexpected_reply() {
+        let (scanner, _server) = fake_clamd("UNKNOWN COMMAND").await;
+
+        let result = scanner.ping().await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
@@ -0,0 +1,165 @@
+use std::{path::Path, time::Duration};
+
+use domain::{
+    data::ScannerServiceWithHealthCheck,
+    errors::{Result, TermsOfUseError},
+};
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time,
+};
+use tracing::{error, info};
+
+mod health_check;
+mod service;
+
+/// Size of the chunks documents are streamed to clamd in, well below its default `StreamMaxLength`.
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Scans documents with a ClamAV daemon over the clamd protocol.
+#[derive(Clone, Debug)]
+pub struct ClamdScanner {
+    address: String,
+    timeout: Duration,
+}
+
+impl ClamdScanner {
+    pub async fn new() -> Self {
+        let address =
+            std::env::var("CLAMD_ADDRESS").unwrap_or_else(|_| "localhost:3310".to_string());
+
+        let timeout_seconds = std::env::var("CLAMD_TIMEOUT_SECONDS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .expect("CLAMD_TIMEOUT_SECONDS must be a valid u64");
+
+        info!("Initializing clamd scanner at {address}");
+
+        ClamdScanner {
+            address,
+            timeout: Duration::from_secs(timeout_seconds),
+        }
+    }
+
+    /// Sends a command, streaming `document` with it for `INSTREAM`, and returns clamd's reply.
+    async fn send(&self, command: &str, document: Option<&Path>) -> Result<String> {
+        let exchange = async {
+            let mut stream = TcpStream::connect(&self.address).await?;
+            stream.write_all(format!("z{command}\0").as_bytes()).await?;
+
+            if let Some(path) = document {
+                let mut file = File::open(path).await?;
+                let mut chunk = vec![0; CHUNK_SIZE];
+                loop {
+                    let read = file.read(&mut chunk).await?;
+                    if read == 0 {
+                        break;
+                    }
+                    stream.write_all(&(read as u32).to_be_bytes()).await?;
+                    stream.write_all(&chunk[..read]).await?;
+                }
+                stream.write_all(&0u32.to_be_bytes()).await?;
+            }
+
+            // clamd closes the connection after replying outside of sessions
+            let mut reply = Vec::new();
+            stream.read_to_end(&mut reply).await?;
+
+            Ok::<_, std::io::Error>(reply)
+        };
+
+        let reply = time::timeout(self.timeout, exchange)
+            .await
+            .map_err(|_| {
+                error!("clamd did not answer {command} within {:?}", self.timeout);
+
+                TermsOfUseError::InternalServerError
+            })?
+            .map_err(|err| {
+                error!(
+                    "Failed to send {command} to clamd at {}: {err}",
+                    self.address
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(String::from_utf8_lossy(&reply)
+            .trim_end_matches(['\0', '\n'])
+            .to_string())
+    }
+}
+
+impl ScannerServiceWithHealthCheck for ClamdScanner {}
+
+/// Fake clamd answering a single command with `reply`, returning the streamed document.
+#[cfg(test)]
+async fn fake_clamd(reply: &'static str) -> (ClamdScanner, tokio::task::JoinHandle<Vec<u8>>) {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let scanner = ClamdScanner {
+        address: listener.local_addr().unwrap().to_string(),
+        timeout: Duration::from_secs(5),
+    };
+
+    let server = tokio::spawn(async move {
+        let (mut stream, _) = listener.accept().await.unwrap();
+
+        let mut command = Vec::new();
+        loop {
+            let byte = stream.read_u8().await.unwrap();
+            if byte == 0 {
+                break;
+            }
+            command.push(byte);
+        }
+
+        let mut document = Vec::new();
+        if command == b"zINSTREAM" {
+            loop {
+                let length = stream.read_u32().await.unwrap() as usize;
+                if length == 0 {
+                    break;
+                }
+                let mut chunk = vec![0; length];
+                stream.read_exact(&mut chunk).await.unwrap();
+                document.extend(chunk);
+            }
+        }
+
+        stream
+            .write_all(format!("{reply}\0").as_bytes())
+            .await
+            .unwrap();
+
+        document
+    });
+
+    (scanner, server)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::ClamdScanner;
+
+    #[tokio::test]
+    async fn new_builds_scanner_with_env_vars() {
+        unsafe {
+            std::env::set_var("CLAMD_ADDRESS", "clamav:3310");
+            std::env::set_var("CLAMD_TIMEOUT_SECONDS", "5");
+        }
+
+        let scanner = ClamdScanner::new().await;
+
+        assert_eq!(scanner.address, "clamav:3310");
+        assert_eq!(scanner.timeout, Duration::from_secs(5));
+
+        unsafe {
+            std::env::remove_var("CLAMD_ADDRESS");
+            std::env::remove_var("CLAMD_TIMEOUT_SECONDS");
+        }
+    }
+}
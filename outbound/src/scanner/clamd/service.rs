@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use domain::{
+    data::service::ScannerService,
+    entities::ScanVerdict,
+    errors::{Result, TermsOfUseError},
+};
+use tracing::error;
+
+use super::ClamdScanner;
+
+#[async_trait]
+impl ScannerService for ClamdScanner {
+    #[tracing::instrument(skip(self))]
+    async fn scan_file(&self, file: &Path) -> Result<ScanVerdict> {
+        let reply = self.send("INSTREAM", Some(file)).await?;
+
+        parse_reply(&reply)
+    }
+}
+
+/// Replies look like `stream: OK`, `stream: <signature> FOUND` or `<reason> ERROR`.
+fn parse_reply(reply: &str) -> Result<ScanVerdict> {
+    let reply = reply.strip_prefix("stream: ").unwrap_or(reply);
+
+    if reply == "OK" {
+        return Ok(ScanVerdict::Clean);
+    }
+
+    if let Some(signature) = reply.strip_suffix(" FOUND") {
+        return Ok(ScanVerdict::Infected(signature.to_string()));
+    }
+
+    if reply.starts_with("INSTREAM size limit exceeded") {
+        return Err(TermsOfUseError::Validation(
+            "The document exceeds the size limit of the malware scanner".to_string(),
+        ));
+    }
+
+    error!("clamd failed to scan the document: {reply}");
+
+    Err(TermsOfUseError::InternalServerError)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::scanner::clamd::fake_clamd;
+
+    const SAMPLE_PDF: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../example/sample.pdf");
+
+    #[test]
+    fn parse_reply_reads_clean_documents() {
+        assert_eq!(parse_reply("stream: OK").unwrap(), ScanVerdict::Clean);
+    }
+
+    #[test]
+    fn parse_reply_reads_the_matched_signature() {
+        assert_eq!(
+            parse_reply("stream: Win.Test.EICAR_HDB-1 FOUND").unwrap(),
+            ScanVerdict::Infected("Win.Test.EICAR_HDB-1".to_string())
+        );
+    }
+
+    #[test]
+    fn parse_reply_rejects_documents_over_the_size_limit() {
+        let result = parse_reply("INSTREAM size limit exceeded. ERROR");
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+
+    #[test]
+    fn parse_reply_fails_on_scan_errors() {
+        let result = parse_reply("stream: Can't allocate memory ERROR");
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[tokio::test]
+    async fn scan_file_streams_the_document_to_clamd() {
+        let (scanner, server) = fake_clamd("stream: OK").await;
+
+        let verdict = scanner.scan_file(Path::new(SAMPLE_PDF)).await.unwrap();
+
+        assert_eq!(verdict, ScanVerdict::Clean);
+        assert_eq!(server.await.unwrap(), std::fs::read(SAMPLE_PDF).unwrap());
+    }
+
+    #[tokio::test]
+    async fn scan_file_reports_infected_documents() {
+        let (scanner, _server) = fake_clamd("stream: Eicar-Signature FOUND").await;
+
+        let verdict = scanner.scan_file(Path::new(SAMPLE_PDF)).await.unwrap();
+
+        assert_eq!(
+            verdict,
+            ScanVerdict::Infected("Eicar-Signature".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn scan_file_fails_when_clamd_is_unreachable() {
+        let scanner = ClamdScanner {
+            address: "127.0.0.1:1".to_string(),
+            timeout: Duration::from_secs(1),
+        };
+
+        let result = scanner.scan_file(Path::new(SAMPLE_PDF)).await;
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+}
@@ -0,0 +1,5 @@
+#[cfg(any(not(feature = "scanner"), test))]
+pub mod noop;
+
+#[cfg(feature = "clamd")]
+pub mod clamd;
@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use domain::{
+    data::{ScannerServiceWithHealthCheck, health_check::HealthCheck, service::ScannerService},
+    entities::ScanVerdict,
+    errors::Result,
+};
+
+#[derive(Clone, Debug)]
+pub struct NoopScanner;
+
+impl NoopScanner {
+    pub async fn new() -> Self {
+        Self
+    }
+}
+
+#[async_trait]
+impl ScannerService for NoopScanner {
+    async fn scan_file(&self, _file: &Path) -> Result<ScanVerdict> {
+        Ok(ScanVerdict::Clean)
+    }
+}
+
+#[async_trait]
+impl HealthCheck for NoopScanner {
+    async fn ping(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl ScannerServiceWithHealthCheck for NoopScanner {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn health_check_ping_should_always_succeed() {
+        let scanner = NoopScanner::new().await;
+
+        let result = scanner.ping().await;
+
+        assert!(
+            result.is_ok(),
+            "NoopScanner.ping() should always return Ok(())"
+        );
+    }
+
+    #[tokio::test]
+    async fn scan_file_should_always_report_clean() {
+        let scanner = NoopScanner::new().await;
+
+        let verdict = scanner.scan_file(Path::new("terms.pdf")).await.unwrap();
+
+        assert_eq!(verdict, ScanVerdict::Clean);
+    }
+}
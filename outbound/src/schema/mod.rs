@@ -0,0 +1,5 @@
+mod service;
+
+/// Validates term metadata against the JSON Schemas of upload policies in process.
+#[derive(Clone, Debug)]
+pub struct JsonSchemaValidator;
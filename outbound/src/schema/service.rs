@@ -0,0 +1,98 @@
+use domain::{
+    data::service::SchemaService,
+    errors::{Result, TermsOfUseError},
+};
+use serde_json::Value;
+use tracing::error;
+
+use crate::schema::JsonSchemaValidator;
+
+impl SchemaService for JsonSchemaValidator {
+    fn check_schema(&self, schema: &Value) -> Result<()> {
+        jsonschema::validator_for(schema)
+            .map(|_| ())
+            .map_err(|err| {
+                TermsOfUseError::Validation(format!("The metadata schema is invalid: {err}"))
+            })
+    }
+
+    fn violations(&self, schema: &Value, instance: &Value) -> Result<Vec<String>> {
+        let validator = jsonschema::validator_for(schema).map_err(|err| {
+            error!("Stored metadata schema is invalid: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(validator
+            .iter_errors(instance)
+            .map(|err| match err.instance_path().to_string() {
+                path if path.is_empty() => err.to_string(),
+                path => format!("{path}: {err}"),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::service::SchemaService, errors::TermsOfUseError};
+    use serde_json::{Value, json};
+
+    use crate::schema::JsonSchemaValidator;
+
+    fn schema() -> Value {
+        json!({
+            "type": "object",
+            "properties": {
+                "region": { "enum": ["eu", "us"] },
+                "year": { "type": "integer" }
+            },
+            "required": ["region"]
+        })
+    }
+
+    #[test]
+    fn violations_of_matching_instance_are_empty() {
+        let violations = JsonSchemaValidator
+            .violations(&schema(), &json!({ "region": "eu", "year": 2025 }))
+            .unwrap();
+
+        assert!(violations.is_empty());
+    }
+
+    #[test]
+    fn violations_lists_every_violation_with_its_path() {
+        let violations = JsonSchemaValidator
+            .violations(&schema(), &json!({ "region": "apac", "year": "2025" }))
+            .unwrap();
+
+        assert_eq!(violations.len(), 2);
+        assert!(violations.iter().any(|v| v.starts_with("/region: ")));
+        assert!(violations.iter().any(|v| v.starts_with("/year: ")));
+    }
+
+    #[test]
+    fn violations_reports_missing_keys() {
+        let violations = JsonSchemaValidator
+            .violations(&schema(), &json!({}))
+            .unwrap();
+
+        assert_eq!(violations.len(), 1);
+        assert!(violations[0].contains("region"));
+    }
+
+    #[test]
+    fn violations_of_invalid_schema_is_an_internal_error() {
+        let result = JsonSchemaValidator.violations(&json!({ "type": "no-such-type" }), &json!({}));
+
+        assert!(matches!(result, Err(TermsOfUseError::InternalServerError)));
+    }
+
+    #[test]
+    fn check_schema_rejects_invalid_schema() {
+        let result = JsonSchemaValidator.check_schema(&json!({ "type": "no-such-type" }));
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        assert!(JsonSchemaValidator.check_schema(&schema()).is_ok());
+    }
+}
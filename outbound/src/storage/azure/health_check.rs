@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use domain::{
+    data::health_check::HealthCheck,
+    errors::{Result, TermsOfUseError},
+};
+use tracing::error;
+
+use super::AzureBlobStorage;
+
+#[async_trait]
+impl HealthCheck for AzureBlobStorage {
+    async fn ping(&self) -> Result<()> {
+        self.container_client
+            .get_properties()
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to ping Azure Blob Storage container '{}': {err}",
+                    &self.container_name
+                );
+
+                TermsOfUseError::InternalServerError
+            })
+            .map(|_| ())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::health_check::HealthCheck, errors::TermsOfUseError};
+
+    use crate::storage::azure::tests::{azurite_endpoint, build_storage};
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn health_check_ping_should_succeed_with_existing_container() {
+        let storage = build_storage(&azurite_endpoint(), "health-check-container");
+
+        storage.container_client.create().await.ok();
+
+        let result = storage.ping().await;
+
+        assert!(result.is_ok(), "ping should succeed with Azurite container");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn health_check_ping_returns_internal_error_on_failure() {
+        let storage = build_storage("http://invalid:10000", "test-container");
+
+        let result = storage.ping().await;
+
+        assert!(matches!(
+            result.err().unwrap(),
+            TermsOfUseError::InternalServerError
+        ));
+    }
+}
@@ -0,0 +1,97 @@
+use azure_storage::{CloudLocation, StorageCredentials};
+use azure_storage_blobs::prelude::{ClientBuilder, ContainerClient};
+use domain::data::StorageServiceWithHealthCheck;
+use tracing::info;
+
+use crate::storage::key::ObjectKeyTemplate;
+
+mod health_check;
+mod service;
+
+#[derive(Clone, Debug)]
+pub struct AzureBlobStorage {
+    container_name: String,
+    container_client: ContainerClient,
+    sas_ttl_seconds: u64,
+    key_template: ObjectKeyTemplate,
+}
+
+impl AzureBlobStorage {
+    /// Creates a new AzureBlobStorage instance.
+    ///
+    /// Authenticates with the storage account shared key, which is also used to sign
+    /// the SAS URLs returned by `get_file_url`. Set `AZURE_STORAGE_ENDPOINT` to target
+    /// the Azurite emulator (e.g. `http://127.0.0.1:10000/devstoreaccount1`).
+    pub async fn new() -> Self {
+        let account = std::env::var("AZURE_STORAGE_ACCOUNT")
+            .expect("AZURE_STORAGE_ACCOUNT must be set in env vars");
+        let access_key = std::env::var("AZURE_STORAGE_ACCESS_KEY")
+            .expect("AZURE_STORAGE_ACCESS_KEY must be set in env vars");
+        let container_name = std::env::var("AZURE_STORAGE_CONTAINER")
+            .expect("AZURE_STORAGE_CONTAINER must be set in env vars");
+        let sas_ttl_seconds = std::env::var("AZURE_STORAGE_SAS_TTL_SECONDS")
+            .unwrap_or_else(|_| "86400".to_string()) // One day in seconds
+            .parse()
+            .expect("AZURE_STORAGE_SAS_TTL_SECONDS must be a valid u64");
+
+        let credentials = StorageCredentials::access_key(account.clone(), access_key);
+
+        let client_builder = match std::env::var("AZURE_STORAGE_ENDPOINT").ok() {
+            Some(uri) => {
+                info!("Using custom Azure Blob Storage endpoint URL: {uri}");
+
+                ClientBuilder::with_location(CloudLocation::Custom { account, uri }, credentials)
+            }
+            None => ClientBuilder::new(account, credentials),
+        };
+
+        info!("Initializing Azure Blob Storage with container: {container_name}");
+
+        AzureBlobStorage {
+            container_client: client_builder.container_client(&container_name),
+            container_name,
+            sas_ttl_seconds,
+            key_template: ObjectKeyTemplate::from_env(),
+        }
+    }
+}
+
+impl StorageServiceWithHealthCheck for AzureBlobStorage {}
+
+#[cfg(test)]
+mod tests {
+    use azure_storage::{CloudLocation, StorageCredentials};
+    use azure_storage_blobs::prelude::ClientBuilder;
+
+    use crate::storage::key::ObjectKeyTemplate;
+
+    use super::AzureBlobStorage;
+
+    /// Well-known Azurite development account.
+    pub const EMULATOR_ACCOUNT: &str = "devstoreaccount1";
+    pub const EMULATOR_KEY: &str =
+        "Eby8vdM02xNOcqFlqUwJPLlmEtlCDXJ1OUzFT50uSRZ6IFsuFq2UVErCz4I6tq/K1SZFPTOtr/KBHBeksoGMGw==";
+
+    pub fn build_storage(endpoint: &str, container_name: &str) -> AzureBlobStorage {
+        let credentials =
+            StorageCredentials::access_key(EMULATOR_ACCOUNT.to_string(), EMULATOR_KEY.to_string());
+
+        let location = CloudLocation::Custom {
+            account: EMULATOR_ACCOUNT.to_string(),
+            uri: format!("{endpoint}/{EMULATOR_ACCOUNT}"),
+        };
+
+        AzureBlobStorage {
+            container_name: container_name.to_string(),
+            container_client: ClientBuilder::with_location(location, credentials)
+                .container_client(container_name),
+            sas_ttl_seconds: 3600,
+            key_template: ObjectKeyTemplate::default(),
+        }
+    }
+
+    pub fn azurite_endpoint() -> String {
+        std::env::var("AZURE_STORAGE_ENDPOINT_BASE")
+            .unwrap_or_else(|_| "http://127.0.0.1:10000".to_string())
+    }
+}
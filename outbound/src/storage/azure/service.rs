@@ -0,0 +1,490 @@
+use std::{collections::HashMap, path::Path, time::Duration as StdDuration};
+
+use async_trait::async_trait;
+use azure_core::{
+    StatusCode,
+    request_options::{IfMatchCondition, Metadata},
+};
+use azure_storage::shared_access_signature::service_sas::BlobSasPermissions;
+use azure_storage_blobs::prelude::Tags;
+use domain::{
+    data::service::StorageService,
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
+    errors::{Result, TermsOfUseError},
+};
+use futures::StreamExt;
+use time::{Duration, OffsetDateTime};
+use tokio::fs;
+use tracing::{error, info};
+
+use crate::{
+    AzureBlobStorage,
+    storage::{
+        key::file_extension,
+        metadata::{CONTENT_HASH_KEY, GROUP_KEY, ObjectMetadata, TERM_ID_KEY, VERSION_KEY},
+    },
+};
+
+impl AzureBlobStorage {
+    async fn get_metadata(&self, path: &str) -> Result<HashMap<String, String>> {
+        let properties = self
+            .container_client
+            .blob_client(path)
+            .get_properties()
+            .await
+            .map_err(|err| {
+                error!("Failed to read blob properties from Azure Blob Storage: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(properties.blob.metadata.unwrap_or_default())
+    }
+
+    async fn signed_url(
+        &self,
+        path: &str,
+        permissions: BlobSasPermissions,
+        expiry: OffsetDateTime,
+    ) -> Result<String> {
+        let blob_client = self.container_client.blob_client(path);
+
+        let signature = blob_client
+            .shared_access_signature(permissions, expiry)
+            .await
+            .map_err(|err| {
+                error!("Failed to sign URL for blob {path}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        blob_client
+            .generate_signed_blob_url(&signature)
+            .map(|url| url.to_string())
+            .map_err(|err| {
+                error!("Failed to build signed URL for blob {path}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+}
+
+#[async_trait]
+impl StorageService for AzureBlobStorage {
+    async fn upload_file(
+        &self,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String> {
+        let key = self
+            .key_template
+            .render(group, version, file_extension(path, content_type));
+
+        self.put_file(&key, path, content_type, group, version)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn put_file(
+        &self,
+        blob_name: &str,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()> {
+        let content = fs::read(path).await.map_err(|err| {
+            error!("Failed to read file for upload: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let object_metadata = ObjectMetadata::from_file(path, group, version).await?;
+
+        let mut metadata = Metadata::new();
+        let mut tags = Tags::new();
+        for (name, value) in object_metadata.entries() {
+            metadata.insert(name, value.clone());
+            tags.insert(name, value);
+        }
+
+        self.container_client
+            .blob_client(blob_name)
+            .put_block_blob(content)
+            .content_type(content_type.to_string())
+            .metadata(metadata)
+            .tags(tags)
+            // Deterministic keys must never overwrite a previously published document
+            .if_match(IfMatchCondition::NotMatch("*".to_string()))
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to upload file to Azure Blob Storage: {}/{blob_name} ({err})",
+                    &self.container_name
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        info!(
+            "Successfully uploaded file to Azure Blob Storage: {blob_name} in container {}",
+            &self.container_name
+        );
+
+        Ok(())
+    }
+
+    async fn publish_file(&self, path: &str, term_id: i32) -> Result<()> {
+        let blob_client = self.container_client.blob_client(path);
+
+        // Setting tags replaces the whole tag set, so keep the upload tags
+        let mut tags = blob_client
+            .get_tags()
+            .await
+            .map_err(|err| {
+                error!("Failed to read tags from Azure Blob Storage: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?
+            .tags;
+
+        tags.insert(TERM_ID_KEY, term_id.to_string());
+
+        blob_client.set_tags(tags).await.map_err(|err| {
+            error!("Failed to tag file in Azure Blob Storage: {path} ({err})");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        // Index tags can be edited with a narrower permission, so the metadata marks publication
+        let mut metadata = Metadata::new();
+        for (name, value) in self.get_metadata(path).await? {
+            metadata.insert(name, value);
+        }
+        metadata.insert(TERM_ID_KEY, term_id.to_string());
+
+        blob_client
+            .set_metadata()
+            .metadata(metadata)
+            .await
+            .map_err(|err| {
+                error!("Failed to mark file as published in Azure Blob Storage: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<()> {
+        if self.get_metadata(path).await?.contains_key(TERM_ID_KEY) {
+            error!("Refusing to delete published document from Azure Blob Storage: {path}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
+        self.container_client
+            .blob_client(path)
+            .delete()
+            .await
+            .map_err(|err| {
+                error!("Failed to delete file from Azure Blob Storage: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        info!("Successfully deleted file from Azure Blob Storage: {path}");
+
+        Ok(())
+    }
+
+    async fn get_file_url(&self, path: &str) -> Result<String> {
+        let permissions = BlobSasPermissions {
+            read: true,
+            ..Default::default()
+        };
+        let expiry = OffsetDateTime::now_utc() + Duration::seconds(self.sas_ttl_seconds as i64);
+
+        self.signed_url(path, permissions, expiry).await
+    }
+
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+        let content = self
+            .container_client
+            .blob_client(path)
+            .get_content()
+            .await
+            .map_err(|err| {
+                error!("Failed to download blob from Azure Blob Storage: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        fs::write(destination, content).await.map_err(|err| {
+            error!("Failed to write {}: {err}", destination.display());
+
+            TermsOfUseError::InternalServerError
+        })
+    }
+
+    async fn create_upload_url(
+        &self,
+        group: &str,
+        version: u32,
+        content_type: &str,
+        _size: u64,
+        sha256: &str,
+        expires_in: StdDuration,
+    ) -> Result<PresignedUpload> {
+        let key =
+            self.key_template
+                .render(group, version, file_extension(Path::new(""), content_type));
+
+        // Create without write permission refuses to overwrite an existing blob
+        let permissions = BlobSasPermissions {
+            create: true,
+            ..Default::default()
+        };
+        let expiry = OffsetDateTime::now_utc() + Duration::seconds(expires_in.as_secs() as i64);
+
+        let url = self.signed_url(&key, permissions, expiry).await?;
+
+        Ok(PresignedUpload {
+            key,
+            method: "PUT".to_string(),
+            url,
+            headers: vec![
+                ("x-ms-blob-type".to_string(), "BlockBlob".to_string()),
+                ("content-type".to_string(), content_type.to_string()),
+                (format!("x-ms-meta-{GROUP_KEY}"), group.to_string()),
+                (format!("x-ms-meta-{VERSION_KEY}"), version.to_string()),
+                (format!("x-ms-meta-{CONTENT_HASH_KEY}"), sha256.to_string()),
+            ],
+        })
+    }
+
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+        let properties = match self
+            .container_client
+            .blob_client(path)
+            .get_properties()
+            .await
+        {
+            Ok(properties) => properties,
+            Err(err)
+                if err
+                    .as_http_error()
+                    .is_some_and(|err| err.status() == StatusCode::NotFound) =>
+            {
+                return Ok(None);
+            }
+            Err(err) => {
+                error!("Failed to read file info from Azure Blob Storage: {path} ({err})");
+
+                return Err(TermsOfUseError::InternalServerError);
+            }
+        };
+
+        Ok(Some(StoredFileInfo {
+            size: properties.blob.properties.content_length,
+            content_type: Some(properties.blob.properties.content_type)
+                .filter(|value| !value.is_empty()),
+            // Azure Blob Storage only computes MD5 digests
+            sha256: None,
+        }))
+    }
+
+    fn copies(&self) -> usize {
+        1
+    }
+
+    fn lists_whole_bucket(&self) -> bool {
+        self.key_template.list_prefix().is_empty()
+    }
+
+    async fn list_files(&self) -> Result<Vec<StoredFile>> {
+        let mut files = Vec::new();
+
+        let mut pages = self
+            .container_client
+            .list_blobs()
+            .prefix(self.key_template.list_prefix())
+            .into_stream();
+
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|err| {
+                error!(
+                    "Failed to list blobs in Azure Blob Storage container {}: {err}",
+                    &self.container_name
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+            for blob in page.blobs.blobs() {
+                files.push(StoredFile {
+                    key: blob.name.clone(),
+                    last_modified: chrono::DateTime::from_timestamp(
+                        blob.properties.creation_time.unix_timestamp(),
+                        0,
+                    )
+                    .unwrap_or_default()
+                    .naive_utc(),
+                });
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::data::service::StorageService;
+    use tokio::fs;
+
+    use crate::storage::{
+        azure::tests::{azurite_endpoint, build_storage},
+        key::ObjectKeyTemplate,
+    };
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn builds_signed_url_for_file() {
+        let storage = build_storage("http://127.0.0.1:10000", "terms");
+
+        let url = storage
+            .get_file_url("privacy-policy/v1.pdf")
+            .await
+            .expect("url should be built");
+
+        assert!(
+            url.starts_with("http://127.0.0.1:10000/devstoreaccount1/terms/privacy-policy/v1.pdf?")
+        );
+        assert!(url.contains("sp=r"), "SAS must only grant read access");
+        assert!(url.contains("sig="), "URL must be signed");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn presigns_upload_without_overwrite() {
+        let storage = build_storage("http://127.0.0.1:10000", "terms");
+
+        let upload = storage
+            .create_upload_url(
+                "privacy-policy",
+                1,
+                "application/pdf",
+                1024,
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+                std::time::Duration::from_secs(900),
+            )
+            .await
+            .expect("upload url should be built");
+
+        assert_eq!(upload.method, "PUT");
+        assert!(
+            upload.url.contains("sp=c"),
+            "SAS must only allow creating the blob"
+        );
+        assert!(upload.url.contains(&upload.key));
+        assert!(
+            upload
+                .headers
+                .contains(&("x-ms-blob-type".to_string(), "BlockBlob".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_upload_and_delete_file() {
+        let mut storage = build_storage(&azurite_endpoint(), "upload-container");
+        storage.key_template = ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap();
+
+        storage.container_client.create().await.ok();
+
+        let temp_file = std::env::temp_dir().join("azure-upload.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let group = format!("azure-{}", uuid::Uuid::new_v4());
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", &group, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(key, format!("{group}/v1.pdf"));
+
+        let second_upload = storage
+            .upload_file(&temp_file, "application/pdf", &group, 1)
+            .await;
+
+        assert!(
+            second_upload.is_err(),
+            "Existing blobs must not be overwritten"
+        );
+
+        storage.delete_file(&key).await.unwrap();
+
+        let deleted = storage
+            .container_client
+            .blob_client(&key)
+            .get_properties()
+            .await;
+
+        assert!(deleted.is_err(), "Blob should not exist after deletion");
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_refuse_to_delete_published_file() {
+        let storage = build_storage(&azurite_endpoint(), "upload-container");
+
+        storage.container_client.create().await.ok();
+
+        let temp_file = std::env::temp_dir().join("azure-tag.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        storage.publish_file(&key, 42).await.unwrap();
+
+        let result = storage.delete_file(&key).await;
+
+        assert!(result.is_err(), "Published documents must not be deleted");
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        storage
+            .container_client
+            .blob_client(&key)
+            .delete()
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn delete_missing_file_returns_error() {
+        let storage = build_storage(&azurite_endpoint(), "upload-container");
+
+        storage.container_client.create().await.ok();
+
+        let result = storage.delete_file("missing.pdf").await;
+
+        assert!(result.is_err());
+    }
+}
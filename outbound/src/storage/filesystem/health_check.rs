@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use domain::{
+    data::health_check::HealthCheck,
+    errors::{Result, TermsOfUseError},
+};
+use tracing::error;
+
+use super::FilesystemStorage;
+
+#[async_trait]
+impl HealthCheck for FilesystemStorage {
+    async fn ping(&self) -> Result<()> {
+        let metadata = tokio::fs::metadata(&self.root).await.map_err(|err| {
+            error!(
+                "Failed to access storage directory '{}': {err}",
+                self.root.display()
+            );
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        if !metadata.is_dir() {
+            error!("Storage path '{}' is not a directory", self.root.display());
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{data::health_check::HealthCheck, errors::TermsOfUseError};
+
+    use crate::storage::{
+        filesystem::tests::{build_storage, temp_root},
+        key::ObjectKeyTemplate,
+    };
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn health_check_ping_should_succeed_for_existing_directory() {
+        let root = temp_root();
+        tokio::fs::create_dir_all(&root).await.unwrap();
+
+        let storage = build_storage(root.clone(), ObjectKeyTemplate::default());
+
+        let result = storage.ping().await;
+
+        tokio::fs::remove_dir_all(&root).await.ok();
+        assert!(result.is_ok(), "ping should succeed for existing directory");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn health_check_ping_returns_internal_error_for_missing_directory() {
+        let storage = build_storage(temp_root(), ObjectKeyTemplate::default());
+
+        let result = storage.ping().await;
+
+        assert!(matches!(
+            result.err().unwrap(),
+            TermsOfUseError::InternalServerError
+        ));
+    }
+}
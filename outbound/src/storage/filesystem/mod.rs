@@ -0,0 +1,107 @@
+use std::path::{Component, Path, PathBuf};
+
+use domain::{
+    data::StorageServiceWithHealthCheck,
+    errors::{Result, TermsOfUseError},
+};
+use tracing::{error, info};
+
+use crate::storage::key::ObjectKeyTemplate;
+
+mod health_check;
+mod service;
+
+#[derive(Clone, Debug)]
+pub struct FilesystemStorage {
+    root: PathBuf,
+    base_url: String,
+    key_template: ObjectKeyTemplate,
+}
+
+impl FilesystemStorage {
+    /// Creates a new FilesystemStorage instance.
+    ///
+    /// Documents are written below `FILESYSTEM_STORAGE_PATH` and exposed under
+    /// `FILESYSTEM_STORAGE_BASE_URL`, which must point to the route serving that directory.
+    pub async fn new() -> Self {
+        let root = std::env::var("FILESYSTEM_STORAGE_PATH")
+            .map(PathBuf::from)
+            .expect("FILESYSTEM_STORAGE_PATH must be set in env vars");
+
+        let base_url = std::env::var("FILESYSTEM_STORAGE_BASE_URL")
+            .unwrap_or_else(|_| "http://127.0.0.1:8080/files".to_string());
+
+        if let Err(err) = tokio::fs::create_dir_all(&root).await {
+            panic!(
+                "Failed to create storage directory '{}': {err}",
+                root.display()
+            );
+        }
+
+        info!("Using filesystem storage at: {}", root.display());
+
+        FilesystemStorage {
+            root,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            key_template: ObjectKeyTemplate::from_env(),
+        }
+    }
+
+    /// Resolves a stored key below the storage root, rejecting keys that would escape it.
+    fn resolve(&self, key: &str) -> Result<PathBuf> {
+        let relative = Path::new(key);
+
+        let is_safe = relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)));
+
+        if key.is_empty() || !is_safe {
+            error!("Refusing to resolve storage key outside of the storage root: {key}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
+        Ok(self.root.join(relative))
+    }
+}
+
+impl StorageServiceWithHealthCheck for FilesystemStorage {}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::storage::key::ObjectKeyTemplate;
+
+    use super::FilesystemStorage;
+
+    pub fn build_storage(root: PathBuf, key_template: ObjectKeyTemplate) -> FilesystemStorage {
+        FilesystemStorage {
+            root,
+            base_url: "http://localhost:8080/files".to_string(),
+            key_template,
+        }
+    }
+
+    pub fn temp_root() -> PathBuf {
+        std::env::temp_dir().join(format!("filesystem-storage-{}", uuid::Uuid::new_v4()))
+    }
+
+    #[test]
+    fn resolve_joins_key_to_root() {
+        let storage = build_storage(PathBuf::from("/data"), ObjectKeyTemplate::default());
+
+        let path = storage.resolve("privacy-policy/v1.pdf").unwrap();
+
+        assert_eq!(path, PathBuf::from("/data/privacy-policy/v1.pdf"));
+    }
+
+    #[test]
+    fn resolve_rejects_traversal() {
+        let storage = build_storage(PathBuf::from("/data"), ObjectKeyTemplate::default());
+
+        assert!(storage.resolve("../etc/passwd").is_err());
+        assert!(storage.resolve("/etc/passwd").is_err());
+        assert!(storage.resolve("").is_err());
+    }
+}
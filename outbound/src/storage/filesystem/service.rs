@@ -0,0 +1,503 @@
+use std::{fs::Permissions, io::ErrorKind, path::Path, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    data::service::StorageService,
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
+    errors::{Result, TermsOfUseError},
+};
+use tokio::{fs, io::AsyncWriteExt};
+use tracing::{error, info};
+
+use crate::{FilesystemStorage, storage::key::file_extension};
+
+impl FilesystemStorage {
+    async fn permissions(&self, file_path: &Path) -> Result<Permissions> {
+        fs::metadata(file_path)
+            .await
+            .map(|metadata| metadata.permissions())
+            .map_err(|err| {
+                error!("Failed to read metadata of {}: {err}", file_path.display());
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+}
+
+#[async_trait]
+impl StorageService for FilesystemStorage {
+    async fn upload_file(
+        &self,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String> {
+        let key = self
+            .key_template
+            .render(group, version, file_extension(path, content_type));
+
+        self.put_file(&key, path, content_type, group, version)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn put_file(
+        &self,
+        key: &str,
+        path: &Path,
+        _content_type: &str,
+        _group: &str,
+        _version: u32,
+    ) -> Result<()> {
+        let destination = self.resolve(key)?;
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent).await.map_err(|err| {
+                error!("Failed to create directory for {key}: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+        }
+
+        let content = fs::read(path).await.map_err(|err| {
+            error!("Failed to read file for upload: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        // Deterministic keys must never overwrite a previously published document
+        let mut file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&destination)
+            .await
+            .map_err(|err| {
+                error!("Failed to create file {}: {err}", destination.display());
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        // Flushed so the document is complete on disk once the upload returns
+        let written = async {
+            file.write_all(&content).await?;
+            file.flush().await
+        };
+
+        written.await.map_err(|err| {
+            error!("Failed to write file {}: {err}", destination.display());
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        info!("Successfully stored file: {key}");
+
+        Ok(())
+    }
+
+    async fn publish_file(&self, path: &str, _term_id: i32) -> Result<()> {
+        let file_path = self.resolve(path)?;
+
+        // Plain files carry no tags, the read-only flag marks published documents
+        let mut permissions = self.permissions(&file_path).await?;
+        permissions.set_readonly(true);
+
+        fs::set_permissions(&file_path, permissions)
+            .await
+            .map_err(|err| {
+                error!(
+                    "Failed to mark file {} as read-only: {err}",
+                    file_path.display()
+                );
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<()> {
+        let file_path = self.resolve(path)?;
+
+        if self.permissions(&file_path).await?.readonly() {
+            error!("Refusing to delete published document: {path}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
+        fs::remove_file(&file_path).await.map_err(|err| {
+            error!("Failed to delete file {}: {err}", file_path.display());
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        info!("Successfully deleted file: {path}");
+
+        Ok(())
+    }
+
+    async fn get_file_url(&self, path: &str) -> Result<String> {
+        Ok(format!("{}/{path}", self.base_url))
+    }
+
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+        let file_path = self.resolve(path)?;
+
+        // Copied by content, `fs::copy` would carry the read-only flag of published files
+        let content = fs::read(&file_path).await.map_err(|err| {
+            error!("Failed to read file {}: {err}", file_path.display());
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        fs::write(destination, content).await.map_err(|err| {
+            error!("Failed to write {}: {err}", destination.display());
+
+            TermsOfUseError::InternalServerError
+        })
+    }
+
+    async fn create_upload_url(
+        &self,
+        _group: &str,
+        _version: u32,
+        _content_type: &str,
+        _size: u64,
+        _sha256: &str,
+        _expires_in: Duration,
+    ) -> Result<PresignedUpload> {
+        Err(TermsOfUseError::Validation(
+            "Direct uploads are not supported by this storage backend".to_string(),
+        ))
+    }
+
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+        let file_path = self.resolve(path)?;
+
+        match fs::metadata(&file_path).await {
+            Ok(metadata) => Ok(Some(StoredFileInfo {
+                size: metadata.len(),
+                content_type: None,
+                sha256: None,
+            })),
+            Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+            Err(err) => {
+                error!("Failed to read metadata of {}: {err}", file_path.display());
+
+                Err(TermsOfUseError::InternalServerError)
+            }
+        }
+    }
+
+    fn copies(&self) -> usize {
+        1
+    }
+
+    fn lists_whole_bucket(&self) -> bool {
+        self.key_template.list_prefix().is_empty()
+    }
+
+    async fn list_files(&self) -> Result<Vec<StoredFile>> {
+        let prefix = self.key_template.list_prefix();
+        let mut files = Vec::new();
+        let mut directories = vec![self.root.clone()];
+
+        while let Some(directory) = directories.pop() {
+            let mut entries = fs::read_dir(&directory).await.map_err(|err| {
+                error!("Failed to list directory {}: {err}", directory.display());
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+            while let Some(entry) = entries.next_entry().await.map_err(|err| {
+                error!("Failed to list directory {}: {err}", directory.display());
+
+                TermsOfUseError::InternalServerError
+            })? {
+                let path = entry.path();
+
+                let metadata = entry.metadata().await.map_err(|err| {
+                    error!("Failed to read metadata of {}: {err}", path.display());
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+                if metadata.is_dir() {
+                    directories.push(path);
+                    continue;
+                }
+
+                // Keys always use `/`, whatever the platform separator
+                let key = path
+                    .strip_prefix(&self.root)
+                    .unwrap_or(&path)
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+
+                if !key.starts_with(&prefix) {
+                    continue;
+                }
+
+                let last_modified = metadata
+                    .modified()
+                    .map(|modified| DateTime::<Utc>::from(modified).naive_utc())
+                    .unwrap_or_default();
+
+                files.push(StoredFile { key, last_modified });
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use domain::{data::service::StorageService, errors::TermsOfUseError};
+    use tokio::fs;
+
+    use crate::storage::{
+        filesystem::tests::{build_storage, temp_root},
+        key::ObjectKeyTemplate,
+    };
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn builds_url_for_file() {
+        let storage = build_storage(temp_root(), ObjectKeyTemplate::default());
+
+        let url = storage
+            .get_file_url("privacy-policy/v1.pdf")
+            .await
+            .expect("url should be built");
+
+        assert_eq!(url, "http://localhost:8080/files/privacy-policy/v1.pdf");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_upload_and_delete_file() {
+        let root = temp_root();
+        let storage = build_storage(
+            root.clone(),
+            ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap(),
+        );
+
+        let temp_file = std::env::temp_dir().join("filesystem-upload.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        assert_eq!(key, "privacy-policy/v1.pdf");
+        assert_eq!(
+            fs::read(root.join(&key)).await.unwrap(),
+            b"%PDF-1.4 test content"
+        );
+
+        storage.delete_file(&key).await.unwrap();
+        assert!(!root.join(&key).exists());
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_not_overwrite_existing_file() {
+        let root = temp_root();
+        let storage = build_storage(
+            root.clone(),
+            ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap(),
+        );
+
+        let temp_file = std::env::temp_dir().join("filesystem-overwrite.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        let result = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await;
+
+        assert!(result.is_err(), "Existing files must not be overwritten");
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_refuse_to_delete_published_file() {
+        let root = temp_root();
+        let storage = build_storage(root.clone(), ObjectKeyTemplate::default());
+
+        let temp_file = std::env::temp_dir().join("filesystem-published.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        storage.publish_file(&key, 7).await.unwrap();
+
+        let result = storage.delete_file(&key).await;
+
+        assert!(result.is_err(), "Published documents must not be deleted");
+        assert!(root.join(&key).exists());
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_download_published_file() {
+        let root = temp_root();
+        let storage = build_storage(root.clone(), ObjectKeyTemplate::default());
+
+        let temp_file = std::env::temp_dir().join("filesystem-download.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+        storage.publish_file(&key, 7).await.unwrap();
+
+        let destination = root.join("downloaded.pdf");
+        storage.download_file(&key, &destination).await.unwrap();
+
+        assert_eq!(
+            fs::read(&destination).await.unwrap(),
+            b"%PDF-1.4 test content"
+        );
+        assert!(
+            !fs::metadata(&destination)
+                .await
+                .unwrap()
+                .permissions()
+                .readonly()
+        );
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_read_file_info() {
+        let root = temp_root();
+        let storage = build_storage(root.clone(), ObjectKeyTemplate::default());
+
+        let temp_file = std::env::temp_dir().join("filesystem-info.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        let info = storage.get_file_info(&key).await.unwrap().unwrap();
+        let missing = storage.get_file_info("missing.pdf").await.unwrap();
+
+        assert_eq!(info.size, 21);
+        assert!(missing.is_none());
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_list_nested_files() {
+        let root = temp_root();
+        let storage = build_storage(
+            root.clone(),
+            ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap(),
+        );
+
+        let temp_file = std::env::temp_dir().join("filesystem-list.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        for version in [1, 2] {
+            storage
+                .upload_file(&temp_file, "application/pdf", "privacy-policy", version)
+                .await
+                .unwrap();
+        }
+
+        let mut keys: Vec<String> = storage
+            .list_files()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|file| file.key)
+            .collect();
+        keys.sort();
+
+        assert_eq!(keys, vec!["privacy-policy/v1.pdf", "privacy-policy/v2.pdf"]);
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        fs::remove_dir_all(&root).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn delete_missing_file_returns_error() {
+        let storage = build_storage(temp_root(), ObjectKeyTemplate::default());
+
+        let result = storage.delete_file("missing.pdf").await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn refuses_direct_uploads() {
+        let storage = build_storage(temp_root(), ObjectKeyTemplate::default());
+
+        let result = storage
+            .create_upload_url(
+                "privacy-policy",
+                1,
+                "application/pdf",
+                1024,
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+                Duration::from_secs(900),
+            )
+            .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+    }
+}
@@ -30,6 +30,7 @@ impl HealthCheck for GoogleCloudStorage {
 #[cfg(test)]
 mod tests {
     use super::GoogleCloudStorage;
+    use crate::storage::{key::ObjectKeyTemplate, retention::RetentionPolicy};
     use domain::{data::health_check::HealthCheck, errors::TermsOfUseError};
     use google_cloud_storage::client::{Storage, StorageControl};
 
@@ -48,6 +49,9 @@ mod tests {
             bucket_name: bucket_name.to_string(),
             client,
             control_client,
+            key_template: ObjectKeyTemplate::default(),
+            kms_key: None,
+            retention: RetentionPolicy::default(),
         }
     }
 
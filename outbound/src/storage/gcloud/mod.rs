@@ -2,6 +2,8 @@ use domain::data::StorageServiceWithHealthCheck;
 use google_cloud_storage::client::{Storage, StorageControl};
 use tracing::info;
 
+use crate::storage::{key::ObjectKeyTemplate, retention::RetentionPolicy};
+
 mod health_check;
 mod service;
 
@@ -11,6 +13,9 @@ pub struct GoogleCloudStorage {
     bucket_name: String,
     client: Storage,
     control_client: StorageControl,
+    key_template: ObjectKeyTemplate,
+    kms_key: Option<String>,
+    retention: RetentionPolicy,
 }
 
 #[inline]
@@ -49,11 +54,17 @@ impl GoogleCloudStorage {
 
         let control_client = initialize_control_client().await;
 
+        // Customer-managed encryption key, otherwise the bucket default encryption applies
+        let kms_key = std::env::var("GOOGLE_CLOUD_KMS_KEY").ok();
+
         GoogleCloudStorage {
             bucket: format!("projects/_/buckets/{bucket_name}"),
             bucket_name,
             client,
             control_client,
+            key_template: ObjectKeyTemplate::from_env(),
+            kms_key,
+            retention: RetentionPolicy::from_env(),
         }
     }
 }
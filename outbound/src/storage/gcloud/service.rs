@@ -1,34 +1,80 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use async_trait::async_trait;
+use chrono::Utc;
 use domain::{
     data::service::StorageService,
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
     errors::{Result, TermsOfUseError},
 };
+use google_cloud_storage::{
+    builder::storage::SignedUrlBuilder,
+    model::{
+        Object,
+        object::{Retention, retention::Mode},
+    },
+};
+use google_cloud_wkt::{FieldMask, Timestamp};
 use tokio::fs;
 use tracing::{error, info};
 
-use crate::GoogleCloudStorage;
+use crate::{
+    GoogleCloudStorage,
+    storage::{
+        key::file_extension,
+        metadata::{CONTENT_HASH_KEY, GROUP_KEY, ObjectMetadata, TERM_ID_KEY, VERSION_KEY},
+    },
+};
+
+impl GoogleCloudStorage {
+    async fn get_object(&self, path: &str) -> Result<Object> {
+        self.control_client
+            .get_object()
+            .set_bucket(&self.bucket)
+            .set_object(path)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to read file metadata from GCS: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+}
 
 #[async_trait]
 impl StorageService for GoogleCloudStorage {
-    async fn upload_file(&self, path: &Path, content_type: &str) -> Result<String> {
+    async fn upload_file(
+        &self,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String> {
+        let key = self
+            .key_template
+            .render(group, version, file_extension(path, content_type));
+
+        self.put_file(&key, path, content_type, group, version)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn put_file(
+        &self,
+        object_name: &str,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()> {
         let file_name = path
             .file_name()
             .and_then(|name| name.to_str())
             .unwrap_or("unknown");
 
-        let file_extension =
-            path.extension()
-                .and_then(|ext| ext.to_str())
-                .unwrap_or(match content_type {
-                    "application/pdf" => "pdf",
-                    "image/png" => "png",
-                    "image/jpeg" => "jpg",
-                    _ => "",
-                });
-
-        let object_name = format!("{}.{file_extension}", uuid::Uuid::new_v4());
+        let metadata = ObjectMetadata::from_file(path, group, version).await?;
 
         let file = fs::File::open(path).await.map_err(|err| {
             error!("Failed to open file for upload: {file_name} ({err})");
@@ -36,29 +82,85 @@ impl StorageService for GoogleCloudStorage {
             TermsOfUseError::InternalServerError
         })?;
 
-        self.client
-            .write_object(&self.bucket, &object_name, file)
-            .send_buffered()
-            .await
-            .map_err(|err| {
-                error!("Failed to upload file to GCS: {file_name} -> {object_name} ({err})");
+        let mut request = self
+            .client
+            .write_object(&self.bucket, object_name, file)
+            // Deterministic keys must never overwrite a previously published document
+            .set_if_generation_match(0)
+            .set_content_type(content_type)
+            .set_metadata(metadata.entries());
 
-                TermsOfUseError::InternalServerError
-            })?;
+        if let Some(ref kms_key) = self.kms_key {
+            request = request.set_kms_key(kms_key);
+        }
+
+        request.send_buffered().await.map_err(|err| {
+            error!("Failed to upload file to GCS: {file_name} -> {object_name} ({err})");
+
+            TermsOfUseError::InternalServerError
+        })?;
 
         info!(
             "Successfully uploaded file {file_name} to GCS: {object_name} in bucket {}",
             self.bucket_name
         );
 
-        Ok(object_name)
+        Ok(())
+    }
+
+    async fn publish_file(&self, path: &str, term_id: i32) -> Result<()> {
+        let object = self.get_object(path).await?;
+
+        // The update mask replaces the whole metadata map, so keep the upload entries
+        let mut metadata = object.metadata;
+        metadata.insert(TERM_ID_KEY.to_string(), term_id.to_string());
+
+        let mut update = Object::new()
+            .set_bucket(&self.bucket)
+            .set_name(path)
+            .set_metadata(metadata);
+        let mut paths = vec!["metadata"];
+
+        // Requires a bucket with object retention enabled
+        if let Some(retain_until) = self.retention.retain_until(Utc::now()) {
+            update = update.set_retention(
+                Retention::new()
+                    .set_mode(Mode::Locked)
+                    .set_retain_until_time(Timestamp::clamp(retain_until.timestamp(), 0)),
+            );
+            paths.push("retention");
+        }
+
+        self.control_client
+            .update_object()
+            .set_object(update)
+            .set_update_mask(FieldMask::default().set_paths(paths))
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to tag file in GCS: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(())
     }
 
     async fn delete_file(&self, path: &str) -> Result<()> {
+        let object = self.get_object(path).await?;
+
+        if object.metadata.contains_key(TERM_ID_KEY) {
+            error!("Refusing to delete published document from GCS: {path}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
         self.control_client
             .delete_object()
             .set_bucket(&self.bucket)
             .set_object(path)
+            // Fails if the document was published since it was read
+            .set_if_metageneration_match(object.metageneration)
             .send()
             .await
             .map_err(|err| {
@@ -78,6 +180,176 @@ impl StorageService for GoogleCloudStorage {
             self.bucket_name
         ))
     }
+
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+        let mut response = self
+            .client
+            .read_object(&self.bucket, path)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to download file from GCS: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        let mut content = Vec::new();
+        while let Some(chunk) = response.next().await {
+            let chunk = chunk.map_err(|err| {
+                error!("Failed to read file from GCS: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+            content.extend_from_slice(&chunk);
+        }
+
+        fs::write(destination, content).await.map_err(|err| {
+            error!("Failed to write {}: {err}", destination.display());
+
+            TermsOfUseError::InternalServerError
+        })
+    }
+
+    async fn create_upload_url(
+        &self,
+        group: &str,
+        version: u32,
+        content_type: &str,
+        size: u64,
+        sha256: &str,
+        expires_in: Duration,
+    ) -> Result<PresignedUpload> {
+        let key =
+            self.key_template
+                .render(group, version, file_extension(Path::new(""), content_type));
+
+        // Signed headers must be sent as-is, GCS does not verify the SHA-256 digest itself
+        let mut headers = vec![
+            ("content-type".to_string(), content_type.to_string()),
+            ("x-goog-if-generation-match".to_string(), "0".to_string()),
+            (
+                "x-goog-content-length-range".to_string(),
+                format!("{size},{size}"),
+            ),
+            (format!("x-goog-meta-{GROUP_KEY}"), group.to_string()),
+            (format!("x-goog-meta-{VERSION_KEY}"), version.to_string()),
+            (
+                format!("x-goog-meta-{CONTENT_HASH_KEY}"),
+                sha256.to_string(),
+            ),
+        ];
+
+        if let Some(ref kms_key) = self.kms_key {
+            headers.push((
+                "x-goog-encryption-kms-key-name".to_string(),
+                kms_key.clone(),
+            ));
+        }
+
+        let signer = google_cloud_auth::credentials::Builder::default()
+            .build_signer()
+            .map_err(|err| {
+                error!("Failed to load GCS signing credentials: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        let mut builder = SignedUrlBuilder::for_object(&self.bucket, &key)
+            .with_method(http::Method::PUT)
+            .with_expiration(expires_in);
+
+        for (name, value) in &headers {
+            builder = builder.with_header(name, value);
+        }
+
+        let url = builder.sign_with(&signer).await.map_err(|err| {
+            error!("Failed to sign upload URL for GCS: {key} ({err})");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(PresignedUpload {
+            key,
+            method: "PUT".to_string(),
+            url,
+            headers,
+        })
+    }
+
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+        let object = match self
+            .control_client
+            .get_object()
+            .set_bucket(&self.bucket)
+            .set_object(path)
+            .send()
+            .await
+        {
+            Ok(object) => object,
+            Err(err) if err.http_status_code() == Some(404) => return Ok(None),
+            Err(err) => {
+                error!("Failed to read file info from GCS: {path} ({err})");
+
+                return Err(TermsOfUseError::InternalServerError);
+            }
+        };
+
+        Ok(Some(StoredFileInfo {
+            size: object.size as u64,
+            content_type: Some(object.content_type).filter(|value| !value.is_empty()),
+            // Only CRC32C and MD5 are computed by GCS, the metadata digest is client supplied
+            sha256: None,
+        }))
+    }
+
+    fn copies(&self) -> usize {
+        1
+    }
+
+    fn lists_whole_bucket(&self) -> bool {
+        self.key_template.list_prefix().is_empty()
+    }
+
+    async fn list_files(&self) -> Result<Vec<StoredFile>> {
+        let mut files = Vec::new();
+        let mut page_token = String::new();
+
+        loop {
+            let response = self
+                .control_client
+                .list_objects()
+                .set_parent(&self.bucket)
+                .set_prefix(self.key_template.list_prefix())
+                .set_page_token(page_token)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to list files in GCS: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+
+            for object in response.objects {
+                let created_at = object.create_time.map(|time| time.seconds());
+
+                files.push(StoredFile {
+                    key: object.name,
+                    last_modified: chrono::DateTime::from_timestamp(created_at.unwrap_or(0), 0)
+                        .unwrap_or_default()
+                        .naive_utc(),
+                });
+            }
+
+            if response.next_page_token.is_empty() {
+                break;
+            }
+
+            page_token = response.next_page_token;
+        }
+
+        Ok(files)
+    }
 }
 
 #[cfg(test)]
@@ -86,6 +358,7 @@ mod tests {
     use google_cloud_storage::client::{Storage, StorageControl};
 
     use super::GoogleCloudStorage;
+    use crate::storage::{key::ObjectKeyTemplate, retention::RetentionPolicy};
 
     async fn build_test_storage(bucket_name: &str) -> GoogleCloudStorage {
         let client = Storage::builder()
@@ -102,6 +375,9 @@ mod tests {
             bucket_name: bucket_name.to_string(),
             client,
             control_client,
+            key_template: ObjectKeyTemplate::default(),
+            kms_key: None,
+            retention: RetentionPolicy::default(),
         }
     }
 
@@ -0,0 +1,282 @@
+use std::path::Path;
+
+const DEFAULT_KEY_TEMPLATE: &str = "{uuid}.{ext}";
+const PLACEHOLDERS: [&str; 5] = ["prefix", "group", "version", "uuid", "ext"];
+
+/// Layout used to name uploaded term documents.
+///
+/// The template is read from `STORAGE_KEY_TEMPLATE` and supports the
+/// `{prefix}`, `{group}`, `{version}`, `{uuid}` and `{ext}` placeholders,
+/// e.g. `{prefix}/{group}/v{version}.{ext}`. Keys already stored in the
+/// repository are used as-is, so changing the template never affects
+/// previously uploaded objects.
+///
+/// A term has exactly one document per group and version, so a template must
+/// contain `{uuid}` or both `{group}` and `{version}` to keep keys unique.
+#[derive(Clone, Debug)]
+pub struct ObjectKeyTemplate {
+    template: String,
+    prefix: String,
+}
+
+impl Default for ObjectKeyTemplate {
+    fn default() -> Self {
+        Self::new(DEFAULT_KEY_TEMPLATE, "").expect("the default key template is valid")
+    }
+}
+
+impl ObjectKeyTemplate {
+    /// Fails on unknown placeholders and on templates that would give two documents the same key.
+    pub fn new(template: impl Into<String>, prefix: impl Into<String>) -> Result<Self, String> {
+        let key_template = Self {
+            template: template.into(),
+            prefix: prefix.into(),
+        };
+
+        key_template.validate()?;
+
+        Ok(key_template)
+    }
+
+    pub fn from_env() -> Self {
+        let template = std::env::var("STORAGE_KEY_TEMPLATE")
+            .unwrap_or_else(|_| DEFAULT_KEY_TEMPLATE.to_string());
+        let prefix = std::env::var("STORAGE_KEY_PREFIX").unwrap_or_default();
+
+        Self::new(template, prefix)
+            .unwrap_or_else(|err| panic!("STORAGE_KEY_TEMPLATE is invalid: {err}"))
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        let mut rest = self.template.as_str();
+
+        while let Some(start) = rest.find('{') {
+            let Some(end) = rest[start..].find('}') else {
+                return Err(format!("unclosed placeholder in '{}'", self.template));
+            };
+
+            let placeholder = &rest[start + 1..start + end];
+
+            if !PLACEHOLDERS.contains(&placeholder) {
+                return Err(format!(
+                    "unknown placeholder '{{{placeholder}}}', expected one of: {}",
+                    PLACEHOLDERS.map(|name| format!("{{{name}}}")).join(", ")
+                ));
+            }
+
+            rest = &rest[start + end + 1..];
+        }
+
+        let has = |placeholder: &str| self.template.contains(&format!("{{{placeholder}}}"));
+
+        if !(has("uuid") || (has("group") && has("version"))) {
+            return Err(format!(
+                "'{}' must contain {{uuid}} or both {{group}} and {{version}} to tell documents apart",
+                self.template
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn render(&self, group: &str, version: u32, extension: &str) -> String {
+        let key = self
+            .template
+            .replace("{prefix}", &sanitize_path(&self.prefix))
+            .replace("{group}", &sanitize_segment(group))
+            .replace("{version}", &version.to_string())
+            .replace("{uuid}", &uuid::Uuid::new_v4().to_string());
+
+        let key = if extension.is_empty() {
+            key.replace(".{ext}", "").replace("{ext}", "")
+        } else {
+            key.replace("{ext}", extension)
+        };
+
+        // Empty placeholders (e.g. no prefix) must not leave stray separators behind
+        key.split('/')
+            .filter(|segment| !segment.is_empty())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    /// Common start of every rendered key, used to scope listings to the stored documents.
+    pub fn list_prefix(&self) -> String {
+        let prefix = sanitize_path(&self.prefix);
+
+        if prefix.is_empty() || !self.template.starts_with("{prefix}") {
+            String::new()
+        } else if self.template.starts_with("{prefix}/") {
+            format!("{prefix}/")
+        } else {
+            prefix
+        }
+    }
+}
+
+/// Returns the extension of the uploaded file, falling back to the content type.
+pub fn file_extension<'a>(path: &'a Path, content_type: &str) -> &'a str {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or(match content_type {
+            "application/pdf" => "pdf",
+            "text/markdown" => "md",
+            "text/html" => "html",
+            "image/png" => "png",
+            "image/jpeg" => "jpg",
+            _ => "",
+        })
+}
+
+/// Keeps object keys browsable by replacing anything outside `[A-Za-z0-9._-]`.
+fn sanitize_segment(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '.' | '_' | '-' => c,
+            _ => '-',
+        })
+        .collect::<String>()
+        .trim_start_matches('.')
+        .to_string()
+}
+
+fn sanitize_path(value: &str) -> String {
+    value
+        .split('/')
+        .map(sanitize_segment)
+        .filter(|segment| !segment.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::{ObjectKeyTemplate, file_extension};
+
+    #[test]
+    fn default_template_keeps_uuid_keys() {
+        let key = ObjectKeyTemplate::default().render("privacy-policy", 3, "pdf");
+
+        assert!(key.ends_with(".pdf"));
+        assert!(uuid::Uuid::parse_str(key.trim_end_matches(".pdf")).is_ok());
+    }
+
+    #[test]
+    fn renders_group_and_version() {
+        let template =
+            ObjectKeyTemplate::new("{prefix}/{group}/v{version}.{ext}", "terms").unwrap();
+
+        let key = template.render("privacy-policy", 3, "pdf");
+
+        assert_eq!(key, "terms/privacy-policy/v3.pdf");
+    }
+
+    #[test]
+    fn skips_empty_prefix() {
+        let template = ObjectKeyTemplate::new("{prefix}/{group}/v{version}.{ext}", "").unwrap();
+
+        let key = template.render("privacy-policy", 1, "pdf");
+
+        assert_eq!(key, "privacy-policy/v1.pdf");
+    }
+
+    #[test]
+    fn allows_nested_prefix() {
+        let template =
+            ObjectKeyTemplate::new("{prefix}/{group}/v{version}.{ext}", "/legal/terms/").unwrap();
+
+        let key = template.render("cookies", 2, "pdf");
+
+        assert_eq!(key, "legal/terms/cookies/v2.pdf");
+    }
+
+    #[test]
+    fn sanitizes_group() {
+        let template = ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap();
+
+        let key = template.render("../privacy policy?", 1, "pdf");
+
+        assert_eq!(key, "-privacy-policy-/v1.pdf");
+    }
+
+    #[test]
+    fn drops_missing_extension() {
+        let template = ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap();
+
+        let key = template.render("privacy-policy", 1, "");
+
+        assert_eq!(key, "privacy-policy/v1");
+    }
+
+    #[test]
+    fn list_prefix_follows_template() {
+        let nested =
+            ObjectKeyTemplate::new("{prefix}/{group}/v{version}.{ext}", "/legal/terms/").unwrap();
+        let inline = ObjectKeyTemplate::new("{prefix}{uuid}.{ext}", "terms-").unwrap();
+        let unscoped =
+            ObjectKeyTemplate::new("{group}/{prefix}/v{version}.{ext}", "terms").unwrap();
+
+        assert_eq!(nested.list_prefix(), "legal/terms/");
+        assert_eq!(inline.list_prefix(), "terms-");
+        assert_eq!(unscoped.list_prefix(), "");
+        assert_eq!(ObjectKeyTemplate::default().list_prefix(), "");
+    }
+
+    #[test]
+    fn accepts_unique_layouts() {
+        for template in [
+            "{uuid}.{ext}",
+            "{prefix}/{group}/v{version}.{ext}",
+            "{prefix}/{group}/{uuid}.{ext}",
+        ] {
+            assert!(
+                ObjectKeyTemplate::new(template, "").is_ok(),
+                "{template} should be accepted"
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_colliding_layouts() {
+        for template in [
+            "{prefix}/{group}.{ext}",
+            "{prefix}/v{version}.{ext}",
+            "terms.pdf",
+        ] {
+            let result = ObjectKeyTemplate::new(template, "");
+
+            assert!(result.is_err(), "{template} should be rejected");
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_placeholders() {
+        let result = ObjectKeyTemplate::new("{prefix}/{group}/v{version}/{locale}.{ext}", "");
+
+        assert!(result.unwrap_err().contains("'{locale}'"));
+
+        let result = ObjectKeyTemplate::new("{group}/v{version", "");
+
+        assert!(result.unwrap_err().contains("unclosed placeholder"));
+    }
+
+    #[test]
+    fn extension_falls_back_to_content_type() {
+        assert_eq!(
+            file_extension(Path::new("/tmp/file.txt"), "text/plain"),
+            "txt"
+        );
+        assert_eq!(
+            file_extension(Path::new("/tmp/file"), "application/pdf"),
+            "pdf"
+        );
+        assert_eq!(
+            file_extension(Path::new("/tmp/file"), "text/markdown"),
+            "md"
+        );
+        assert_eq!(file_extension(Path::new("/tmp/file"), "text/plain"), "");
+    }
+}
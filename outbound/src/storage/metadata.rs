@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use domain::errors::{Result, TermsOfUseError};
+use sha2::{Digest, Sha256};
+use tracing::error;
+
+pub const GROUP_KEY: &str = "group";
+pub const VERSION_KEY: &str = "version";
+pub const CONTENT_HASH_KEY: &str = "content_sha256";
+pub const TERM_ID_KEY: &str = "term_id";
+
+/// Labels attached to every uploaded document so bucket inventory and
+/// lifecycle rules can select objects by group, version or content.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ObjectMetadata {
+    pub group: String,
+    pub version: u32,
+    pub content_sha256: String,
+}
+
+impl ObjectMetadata {
+    pub async fn from_file(path: &Path, group: &str, version: u32) -> Result<Self> {
+        let content = tokio::fs::read(path).await.map_err(|err| {
+            error!("Failed to read file for hashing: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(Self {
+            group: group.to_string(),
+            version,
+            content_sha256: content_sha256(&content),
+        })
+    }
+
+    pub fn entries(&self) -> Vec<(&'static str, String)> {
+        vec![
+            (GROUP_KEY, self.group.clone()),
+            (VERSION_KEY, self.version.to_string()),
+            (CONTENT_HASH_KEY, self.content_sha256.clone()),
+        ]
+    }
+}
+
+/// Hex-encoded SHA-256 digest of the document.
+pub fn content_sha256(content: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(content))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CONTENT_HASH_KEY, GROUP_KEY, ObjectMetadata, VERSION_KEY, content_sha256};
+
+    #[test]
+    fn hashes_content_with_sha256() {
+        assert_eq!(
+            content_sha256(b"hello"),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[tokio::test]
+    async fn builds_metadata_from_file() {
+        let temp_file = std::env::temp_dir().join("metadata-from-file.pdf");
+        tokio::fs::write(&temp_file, b"hello").await.unwrap();
+
+        let metadata = ObjectMetadata::from_file(&temp_file, "privacy-policy", 2)
+            .await
+            .unwrap();
+
+        tokio::fs::remove_file(&temp_file).await.ok();
+
+        assert_eq!(
+            metadata.entries(),
+            vec![
+                (GROUP_KEY, "privacy-policy".to_string()),
+                (VERSION_KEY, "2".to_string()),
+                (CONTENT_HASH_KEY, content_sha256(b"hello")),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_for_missing_file() {
+        let result =
+            ObjectMetadata::from_file(std::path::Path::new("/nonexistent/file.pdf"), "g", 1).await;
+
+        assert!(result.is_err());
+    }
+}
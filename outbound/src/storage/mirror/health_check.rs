@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use domain::{data::health_check::HealthCheck, errors::Result};
+use tracing::error;
+
+use super::MirrorStorage;
+
+#[async_trait]
+impl HealthCheck for MirrorStorage {
+    async fn ping(&self) -> Result<()> {
+        let primary = self.primary.ping().await;
+        if primary.is_err() {
+            error!("Primary storage of the mirror is unhealthy");
+        }
+
+        let secondary = self.secondary.ping().await;
+        if secondary.is_err() {
+            error!("Secondary storage of the mirror is unhealthy");
+        }
+
+        // Uploads need both backends, so the mirror is only healthy if both are
+        primary.and(secondary)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use domain::data::health_check::HealthCheck;
+
+    use crate::storage::mirror::{MirrorStorage, tests::FakeStorage};
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn ping_succeeds_when_both_backends_are_healthy() {
+        let storage = MirrorStorage::new(
+            Arc::new(FakeStorage::default()),
+            Arc::new(FakeStorage::default()),
+        );
+
+        assert!(storage.ping().await.is_ok());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn ping_fails_when_secondary_is_unhealthy() {
+        let storage = MirrorStorage::new(
+            Arc::new(FakeStorage::default()),
+            Arc::new(FakeStorage::failing()),
+        );
+
+        assert!(storage.ping().await.is_err());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn ping_fails_when_primary_is_unhealthy() {
+        let storage = MirrorStorage::new(
+            Arc::new(FakeStorage::failing()),
+            Arc::new(FakeStorage::default()),
+        );
+
+        assert!(storage.ping().await.is_err());
+    }
+}
@@ -0,0 +1,208 @@
+use std::sync::Arc;
+
+use domain::data::StorageServiceWithHealthCheck;
+
+mod health_check;
+mod service;
+
+/// Writes every document to a primary and a secondary storage backend.
+///
+/// An upload only succeeds once both backends stored the document; otherwise the
+/// primary copy is rolled back. URLs, listings and file info always come from the
+/// primary, downloads fall back to the secondary copy. Direct uploads are not
+/// supported, as they would only reach one of the backends.
+#[derive(Clone)]
+pub struct MirrorStorage {
+    primary: Arc<dyn StorageServiceWithHealthCheck>,
+    secondary: Arc<dyn StorageServiceWithHealthCheck>,
+}
+
+impl MirrorStorage {
+    pub fn new(
+        primary: Arc<dyn StorageServiceWithHealthCheck>,
+        secondary: Arc<dyn StorageServiceWithHealthCheck>,
+    ) -> Self {
+        MirrorStorage { primary, secondary }
+    }
+}
+
+impl StorageServiceWithHealthCheck for MirrorStorage {}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        path::Path,
+        sync::{
+            Mutex,
+            atomic::{AtomicBool, Ordering},
+        },
+        time::Duration,
+    };
+
+    use async_trait::async_trait;
+    use domain::{
+        data::{StorageServiceWithHealthCheck, health_check::HealthCheck, service::StorageService},
+        entities::{PresignedUpload, StoredFile, StoredFileInfo},
+        errors::{Result, TermsOfUseError},
+    };
+
+    /// In-memory backend recording stored keys and whether they were published.
+    #[derive(Default)]
+    pub struct FakeStorage {
+        pub files: Mutex<HashMap<String, bool>>,
+        pub fail: AtomicBool,
+    }
+
+    impl FakeStorage {
+        pub fn failing() -> Self {
+            FakeStorage {
+                fail: AtomicBool::new(true),
+                ..Default::default()
+            }
+        }
+
+        pub fn contains(&self, key: &str) -> bool {
+            self.files.lock().unwrap().contains_key(key)
+        }
+
+        pub fn is_published(&self, key: &str) -> bool {
+            self.files.lock().unwrap().get(key) == Some(&true)
+        }
+
+        fn check(&self) -> Result<()> {
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(TermsOfUseError::InternalServerError);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[async_trait]
+    impl StorageService for FakeStorage {
+        async fn upload_file(
+            &self,
+            path: &Path,
+            content_type: &str,
+            group: &str,
+            version: u32,
+        ) -> Result<String> {
+            let key = format!("{group}/v{version}.pdf");
+
+            self.put_file(&key, path, content_type, group, version)
+                .await?;
+
+            Ok(key)
+        }
+
+        async fn put_file(
+            &self,
+            key: &str,
+            _path: &Path,
+            _content_type: &str,
+            _group: &str,
+            _version: u32,
+        ) -> Result<()> {
+            self.check()?;
+
+            self.files.lock().unwrap().insert(key.to_string(), false);
+
+            Ok(())
+        }
+
+        async fn publish_file(&self, path: &str, _term_id: i32) -> Result<()> {
+            self.check()?;
+
+            self.files.lock().unwrap().insert(path.to_string(), true);
+
+            Ok(())
+        }
+
+        async fn delete_file(&self, path: &str) -> Result<()> {
+            self.check()?;
+
+            match self.files.lock().unwrap().remove(path) {
+                Some(_) => Ok(()),
+                None => Err(TermsOfUseError::InternalServerError),
+            }
+        }
+
+        async fn get_file_url(&self, path: &str) -> Result<String> {
+            Ok(format!("fake://{path}"))
+        }
+
+        async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+            self.check()?;
+
+            if !self.contains(path) {
+                return Err(TermsOfUseError::NotFound);
+            }
+
+            std::fs::write(destination, path).map_err(|_| TermsOfUseError::InternalServerError)
+        }
+
+        async fn create_upload_url(
+            &self,
+            group: &str,
+            version: u32,
+            _content_type: &str,
+            _size: u64,
+            _sha256: &str,
+            _expires_in: Duration,
+        ) -> Result<PresignedUpload> {
+            self.check()?;
+
+            let key = format!("{group}/v{version}.pdf");
+
+            Ok(PresignedUpload {
+                url: format!("fake://{key}"),
+                key,
+                method: "PUT".to_string(),
+                headers: Vec::new(),
+            })
+        }
+
+        async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+            self.check()?;
+
+            Ok(self.contains(path).then(|| StoredFileInfo {
+                size: path.len() as u64,
+                content_type: None,
+                sha256: None,
+            }))
+        }
+
+        fn copies(&self) -> usize {
+            1
+        }
+
+        fn lists_whole_bucket(&self) -> bool {
+            false
+        }
+
+        async fn list_files(&self) -> Result<Vec<StoredFile>> {
+            self.check()?;
+
+            Ok(self
+                .files
+                .lock()
+                .unwrap()
+                .keys()
+                .map(|key| StoredFile {
+                    key: key.clone(),
+                    last_modified: chrono::Utc::now().naive_utc(),
+                })
+                .collect())
+        }
+    }
+
+    #[async_trait]
+    impl HealthCheck for FakeStorage {
+        async fn ping(&self) -> Result<()> {
+            self.check()
+        }
+    }
+
+    impl StorageServiceWithHealthCheck for FakeStorage {}
+}
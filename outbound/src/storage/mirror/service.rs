@@ -0,0 +1,336 @@
+use std::{path::Path, time::Duration};
+
+use async_trait::async_trait;
+use domain::{
+    data::service::StorageService,
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
+    errors::{Result, TermsOfUseError},
+};
+use tracing::error;
+
+use crate::storage::mirror::MirrorStorage;
+
+impl MirrorStorage {
+    /// Copies a document already stored in the primary, removing it again on failure.
+    async fn mirror_to_secondary(
+        &self,
+        key: &str,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()> {
+        if let Err(err) = self
+            .secondary
+            .put_file(key, path, content_type, group, version)
+            .await
+        {
+            error!("Failed to mirror {key} to the secondary storage, rolling back");
+
+            // Not referenced by any term yet; if this fails too, the reconcile job collects it
+            let _ = self.primary.delete_file(key).await;
+
+            return Err(err);
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl StorageService for MirrorStorage {
+    async fn upload_file(
+        &self,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String> {
+        let key = self
+            .primary
+            .upload_file(path, content_type, group, version)
+            .await?;
+
+        self.mirror_to_secondary(&key, path, content_type, group, version)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn put_file(
+        &self,
+        key: &str,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()> {
+        self.primary
+            .put_file(key, path, content_type, group, version)
+            .await?;
+
+        self.mirror_to_secondary(key, path, content_type, group, version)
+            .await
+    }
+
+    async fn publish_file(&self, path: &str, term_id: i32) -> Result<()> {
+        // Both copies must be protected, so the secondary is attempted even if the primary failed
+        let primary = self.primary.publish_file(path, term_id).await;
+        let secondary = self.secondary.publish_file(path, term_id).await;
+
+        primary.and(secondary)
+    }
+
+    async fn delete_file(&self, path: &str) -> Result<()> {
+        let primary = self.primary.delete_file(path).await;
+        let secondary = self.secondary.delete_file(path).await;
+
+        primary.and(secondary)
+    }
+
+    async fn get_file_url(&self, path: &str) -> Result<String> {
+        self.primary.get_file_url(path).await
+    }
+
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+        if self.primary.download_file(path, destination).await.is_ok() {
+            return Ok(());
+        }
+
+        error!("Failed to download {path} from the primary storage, reading the secondary copy");
+
+        self.secondary.download_file(path, destination).await
+    }
+
+    async fn create_upload_url(
+        &self,
+        _group: &str,
+        _version: u32,
+        _content_type: &str,
+        _size: u64,
+        _sha256: &str,
+        _expires_in: Duration,
+    ) -> Result<PresignedUpload> {
+        // A presigned upload only reaches one backend, leaving the secondary without a copy
+        Err(TermsOfUseError::Validation(
+            "Direct uploads are not supported by this storage backend".to_string(),
+        ))
+    }
+
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+        self.primary.get_file_info(path).await
+    }
+
+    fn lists_whole_bucket(&self) -> bool {
+        self.primary.lists_whole_bucket() || self.secondary.lists_whole_bucket()
+    }
+
+    fn copies(&self) -> usize {
+        self.primary.copies() + self.secondary.copies()
+    }
+
+    async fn list_files(&self) -> Result<Vec<StoredFile>> {
+        let mut files = self.primary.list_files().await?;
+
+        // Copies of both backends are listed, so a document missing from either shows up
+        files.extend(self.secondary.list_files().await?);
+
+        Ok(files)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, sync::Arc};
+
+    use domain::{data::service::StorageService, errors::TermsOfUseError};
+
+    use crate::storage::mirror::{MirrorStorage, tests::FakeStorage};
+
+    fn build_storage(
+        primary: FakeStorage,
+        secondary: FakeStorage,
+    ) -> (MirrorStorage, Arc<FakeStorage>, Arc<FakeStorage>) {
+        let primary = Arc::new(primary);
+        let secondary = Arc::new(secondary);
+
+        (
+            MirrorStorage::new(primary.clone(), secondary.clone()),
+            primary,
+            secondary,
+        )
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn upload_writes_both_backends_with_same_key() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::default());
+
+        let key = storage
+            .upload_file(Path::new("/tmp/file.pdf"), "application/pdf", "privacy", 1)
+            .await
+            .unwrap();
+
+        assert_eq!(key, "privacy/v1.pdf");
+        assert!(primary.contains(&key));
+        assert!(secondary.contains(&key));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn upload_rolls_back_primary_when_secondary_fails() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::failing());
+
+        let result = storage
+            .upload_file(Path::new("/tmp/file.pdf"), "application/pdf", "privacy", 1)
+            .await;
+
+        assert!(result.is_err());
+        assert!(!primary.contains("privacy/v1.pdf"));
+        assert!(!secondary.contains("privacy/v1.pdf"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn upload_skips_secondary_when_primary_fails() {
+        let (storage, _, secondary) = build_storage(FakeStorage::failing(), FakeStorage::default());
+
+        let result = storage
+            .upload_file(Path::new("/tmp/file.pdf"), "application/pdf", "privacy", 1)
+            .await;
+
+        assert!(result.is_err());
+        assert!(!secondary.contains("privacy/v1.pdf"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn publish_marks_both_copies() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::default());
+
+        let key = storage
+            .upload_file(Path::new("/tmp/file.pdf"), "application/pdf", "privacy", 1)
+            .await
+            .unwrap();
+
+        storage.publish_file(&key, 1).await.unwrap();
+
+        assert!(primary.is_published(&key));
+        assert!(secondary.is_published(&key));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn delete_reports_partial_failure_after_trying_both() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::default());
+
+        secondary
+            .files
+            .lock()
+            .unwrap()
+            .insert("orphan.pdf".to_string(), false);
+
+        let result = storage.delete_file("orphan.pdf").await;
+
+        assert!(result.is_err(), "Missing primary copy must be reported");
+        assert!(!primary.contains("orphan.pdf"));
+        assert!(!secondary.contains("orphan.pdf"));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn urls_come_from_primary() {
+        let (storage, _, _) = build_storage(FakeStorage::default(), FakeStorage::failing());
+
+        let url = storage.get_file_url("privacy/v1.pdf").await.unwrap();
+
+        assert_eq!(url, "fake://privacy/v1.pdf");
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn download_falls_back_to_secondary() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::default());
+
+        let key = storage
+            .upload_file(Path::new("/tmp/file.pdf"), "application/pdf", "privacy", 1)
+            .await
+            .unwrap();
+        primary.files.lock().unwrap().clear();
+
+        let destination = std::env::temp_dir().join("mirror-download-fallback.pdf");
+        let result = storage.download_file(&key, &destination).await;
+
+        tokio::fs::remove_file(&destination).await.ok();
+        assert!(result.is_ok());
+        assert!(secondary.contains(&key));
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn refuses_direct_uploads() {
+        let (storage, primary, _) = build_storage(FakeStorage::default(), FakeStorage::default());
+
+        let result = storage
+            .create_upload_url(
+                "privacy",
+                1,
+                "application/pdf",
+                1024,
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824",
+                std::time::Duration::from_secs(900),
+            )
+            .await;
+
+        assert!(matches!(result, Err(TermsOfUseError::Validation(_))));
+        assert!(primary.files.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn list_files_lists_the_copies_of_both_backends() {
+        let (storage, primary, secondary) =
+            build_storage(FakeStorage::default(), FakeStorage::default());
+        primary.files.lock().unwrap().extend([
+            ("privacy/v1.pdf".to_string(), true),
+            ("privacy/v2.pdf".to_string(), false),
+        ]);
+        secondary.files.lock().unwrap().extend([
+            ("privacy/v1.pdf".to_string(), true),
+            ("privacy/v3.pdf".to_string(), false),
+        ]);
+
+        let mut keys: Vec<String> = storage
+            .list_files()
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|file| file.key)
+            .collect();
+        keys.sort();
+
+        assert_eq!(storage.copies(), 2);
+        assert_eq!(
+            keys,
+            [
+                "privacy/v1.pdf",
+                "privacy/v1.pdf",
+                "privacy/v2.pdf",
+                "privacy/v3.pdf"
+            ]
+        );
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn list_files_fails_when_secondary_cannot_be_listed() {
+        let (storage, _, _) = build_storage(FakeStorage::default(), FakeStorage::failing());
+
+        assert!(storage.list_files().await.is_err());
+    }
+}
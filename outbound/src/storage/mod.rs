@@ -1,5 +1,20 @@
+#[cfg(feature = "storage")]
+mod key;
+#[cfg(any(feature = "s3", feature = "gcloud", feature = "azure"))]
+mod metadata;
+#[cfg(any(feature = "s3", feature = "gcloud"))]
+mod retention;
+
 #[cfg(feature = "s3")]
 pub mod s3;
 
 #[cfg(feature = "gcloud")]
 pub mod gcloud;
+
+#[cfg(feature = "filesystem")]
+pub mod filesystem;
+
+#[cfg(feature = "azure")]
+pub mod azure;
+
+pub mod mirror;
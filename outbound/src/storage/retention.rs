@@ -0,0 +1,57 @@
+use chrono::{DateTime, Duration, Utc};
+
+/// How long published documents stay immutable in the bucket.
+///
+/// Read from `STORAGE_RETENTION_DAYS`. Without it no retention is applied by the
+/// provider, but published documents are still refused by `delete_file`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RetentionPolicy {
+    days: Option<u32>,
+}
+
+impl RetentionPolicy {
+    pub fn new(days: Option<u32>) -> Self {
+        Self { days }
+    }
+
+    pub fn from_env() -> Self {
+        let days = std::env::var("STORAGE_RETENTION_DAYS").ok().map(|days| {
+            days.parse()
+                .expect("STORAGE_RETENTION_DAYS must be a valid u32")
+        });
+
+        Self::new(days)
+    }
+
+    /// Date until which a document published at `published_at` must be kept.
+    pub fn retain_until(&self, published_at: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        self.days
+            .map(|days| published_at + Duration::days(i64::from(days)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::RetentionPolicy;
+
+    #[test]
+    fn computes_retain_until_date() {
+        let published_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        let retain_until = RetentionPolicy::new(Some(30)).retain_until(published_at);
+
+        assert_eq!(
+            retain_until,
+            Some(Utc.with_ymd_and_hms(2025, 1, 31, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn skips_retention_when_not_configured() {
+        let published_at = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+
+        assert_eq!(RetentionPolicy::default().retain_until(published_at), None);
+    }
+}
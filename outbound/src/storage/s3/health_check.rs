@@ -28,10 +28,13 @@ impl HealthCheck for S3Storage {
 mod tests {
     use aws_config::{BehaviorVersion, Region};
     use aws_credential_types::Credentials;
-    use aws_sdk_s3::config::SharedCredentialsProvider;
+    use aws_sdk_s3::{config::SharedCredentialsProvider, types::ObjectLockRetentionMode};
     use domain::{data::health_check::HealthCheck, errors::TermsOfUseError};
 
-    use crate::S3Storage;
+    use crate::{
+        S3Storage,
+        storage::{key::ObjectKeyTemplate, retention::RetentionPolicy},
+    };
 
     #[tokio::test]
     #[test_log::test]
@@ -59,6 +62,10 @@ mod tests {
             bucket_name: "test-bucket".to_string(),
             client,
             endpoint_url: None,
+            key_template: ObjectKeyTemplate::default(),
+            kms_key_id: None,
+            retention: RetentionPolicy::default(),
+            object_lock_mode: ObjectLockRetentionMode::Compliance,
         };
 
         let result = storage.ping().await;
@@ -1,8 +1,10 @@
-use aws_config::BehaviorVersion;
-use aws_sdk_s3::config::Builder as S3ConfigBuilder;
+use aws_config::{BehaviorVersion, Region};
+use aws_sdk_s3::{config::Builder as S3ConfigBuilder, types::ObjectLockRetentionMode};
 use domain::data::StorageServiceWithHealthCheck;
 use tracing::info;
 
+use crate::storage::{key::ObjectKeyTemplate, retention::RetentionPolicy};
+
 mod health_check;
 mod service;
 
@@ -11,13 +13,30 @@ pub struct S3Storage {
     bucket_name: String,
     client: aws_sdk_s3::Client,
     endpoint_url: Option<String>,
+    key_template: ObjectKeyTemplate,
+    kms_key_id: Option<String>,
+    retention: RetentionPolicy,
+    object_lock_mode: ObjectLockRetentionMode,
 }
 
 impl S3Storage {
     pub async fn new() -> Self {
-        let config_builder = aws_config::defaults(BehaviorVersion::latest());
+        Self::with_env_prefix("").await
+    }
+
+    /// Creates an S3Storage reading its bucket settings from prefixed env vars,
+    /// e.g. `SECONDARY_S3_BUCKET_NAME` and `SECONDARY_AWS_REGION`, so a mirror can
+    /// target another bucket or region. Credentials are shared with the primary.
+    pub async fn with_env_prefix(prefix: &str) -> Self {
+        let env_var = |name: &str| std::env::var(format!("{prefix}{name}"));
+
+        let mut config_builder = aws_config::defaults(BehaviorVersion::latest());
 
-        let endpoint_url = std::env::var("AWS_ENDPOINT_URL").ok();
+        if let Ok(region) = env_var("AWS_REGION") {
+            config_builder = config_builder.region(Region::new(region));
+        }
+
+        let endpoint_url = env_var("AWS_ENDPOINT_URL").ok();
 
         let config = if let Some(ref url) = endpoint_url {
             info!("Using custom S3 endpoint URL: {url}");
@@ -27,8 +46,8 @@ impl S3Storage {
             config_builder.load().await
         };
 
-        let bucket_name =
-            std::env::var("S3_BUCKET_NAME").expect("S3_BUCKET_NAME must be set in env vars");
+        let bucket_name = env_var("S3_BUCKET_NAME")
+            .unwrap_or_else(|_| panic!("{prefix}S3_BUCKET_NAME must be set in env vars"));
 
         // Build S3 client with path-style addressing for LocalStack/MinIO compatibility
         let s3_config_builder = S3ConfigBuilder::from(&config);
@@ -42,10 +61,22 @@ impl S3Storage {
 
         let client = aws_sdk_s3::Client::from_conf(s3_config);
 
+        // Customer-managed key for SSE-KMS, otherwise the bucket default encryption applies
+        let kms_key_id = env_var("S3_SSE_KMS_KEY_ID").ok();
+
+        // COMPLIANCE locks cannot be shortened or removed, even by the root account
+        let object_lock_mode = env_var("S3_OBJECT_LOCK_MODE")
+            .map(|mode| ObjectLockRetentionMode::from(mode.to_uppercase().as_str()))
+            .unwrap_or(ObjectLockRetentionMode::Compliance);
+
         S3Storage {
             bucket_name,
             client,
             endpoint_url,
+            key_template: ObjectKeyTemplate::from_env(),
+            kms_key_id,
+            retention: RetentionPolicy::from_env(),
+            object_lock_mode,
         }
     }
 }
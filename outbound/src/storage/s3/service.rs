@@ -1,54 +1,195 @@
-use std::path::Path;
+use std::{path::Path, time::Duration};
 
 use async_trait::async_trait;
-use aws_sdk_s3::primitives::ByteStream;
+use aws_sdk_s3::{
+    presigning::PresigningConfig,
+    primitives::{ByteStream, DateTime},
+    types::{ChecksumMode, ObjectLockRetention, ServerSideEncryption, Tag, Tagging},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use chrono::Utc;
 use domain::{
     data::service::StorageService,
+    entities::{PresignedUpload, StoredFile, StoredFileInfo},
     errors::{Result, TermsOfUseError},
 };
+use tokio::fs;
 use tracing::error;
 
-use crate::S3Storage;
+use crate::{
+    S3Storage,
+    storage::{
+        key::file_extension,
+        metadata::{CONTENT_HASH_KEY, GROUP_KEY, ObjectMetadata, TERM_ID_KEY, VERSION_KEY},
+    },
+};
+
+impl S3Storage {
+    async fn get_tags(&self, path: &str) -> Result<Vec<Tag>> {
+        let output = self
+            .client
+            .get_object_tagging()
+            .bucket(&self.bucket_name)
+            .key(path)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to read tags from S3: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        Ok(output.tag_set().to_vec())
+    }
+}
 
 #[async_trait]
 impl StorageService for S3Storage {
-    async fn upload_file(&self, path: &Path, content_type: &str) -> Result<String> {
+    async fn upload_file(
+        &self,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<String> {
+        let key = self
+            .key_template
+            .render(group, version, file_extension(path, content_type));
+
+        self.put_file(&key, path, content_type, group, version)
+            .await?;
+
+        Ok(key)
+    }
+
+    async fn put_file(
+        &self,
+        key: &str,
+        path: &Path,
+        content_type: &str,
+        group: &str,
+        version: u32,
+    ) -> Result<()> {
         let body = ByteStream::from_path(path).await.map_err(|err| {
             error!("Failed to read file for upload: {err}");
 
             TermsOfUseError::InternalServerError
         })?;
 
-        let file_extension =
-            path.extension()
-                .and_then(|ext| ext.to_str())
-                .unwrap_or(match content_type {
-                    "application/pdf" => "pdf",
-                    _ => "",
-                });
-
-        let key = format!("{}.{file_extension}", uuid::Uuid::new_v4());
+        let metadata = ObjectMetadata::from_file(path, group, version).await?;
 
-        self.client
+        let mut request = self
+            .client
             .put_object()
             .bucket(&self.bucket_name)
-            .key(&key)
+            .key(key)
+            // Deterministic keys must never overwrite a previously published document
+            .if_none_match("*")
             .body(body)
             .content_type(content_type)
+            .tagging(encode_tags(&metadata.entries()));
+
+        for (name, value) in metadata.entries() {
+            request = request.metadata(name, value);
+        }
+
+        if let Some(ref kms_key_id) = self.kms_key_id {
+            request = request
+                .server_side_encryption(ServerSideEncryption::AwsKms)
+                .ssekms_key_id(kms_key_id);
+        }
+
+        request.send().await.map_err(|err| {
+            error!("Failed to upload file to S3: {err}");
+            error!("Bucket: {}, Key: {}", &self.bucket_name, &key);
+            error!("Content-Type: {:?}", err.into_source());
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(())
+    }
+
+    async fn publish_file(&self, path: &str, term_id: i32) -> Result<()> {
+        // PutObjectTagging replaces the whole tag set, so keep the upload tags
+        let mut tags: Vec<Tag> = self
+            .get_tags(path)
+            .await?
+            .into_iter()
+            .filter(|tag| tag.key() != TERM_ID_KEY)
+            .collect();
+
+        tags.push(
+            Tag::builder()
+                .key(TERM_ID_KEY)
+                .value(term_id.to_string())
+                .build()
+                .map_err(|err| {
+                    error!("Failed to build S3 tag: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?,
+        );
+
+        let tagging = Tagging::builder()
+            .set_tag_set(Some(tags))
+            .build()
+            .map_err(|err| {
+                error!("Failed to build S3 tagging: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        self.client
+            .put_object_tagging()
+            .bucket(&self.bucket_name)
+            .key(path)
+            .tagging(tagging)
             .send()
             .await
             .map_err(|err| {
-                error!("Failed to upload file to S3: {err}");
-                error!("Bucket: {}, Key: {}", &self.bucket_name, &key);
-                error!("Content-Type: {:?}", err.into_source());
+                error!("Failed to tag file in S3: {err}");
 
                 TermsOfUseError::InternalServerError
             })?;
 
-        Ok(key)
+        // Requires a bucket created with Object Lock enabled
+        if let Some(retain_until) = self.retention.retain_until(Utc::now()) {
+            let retention = ObjectLockRetention::builder()
+                .mode(self.object_lock_mode.clone())
+                .retain_until_date(DateTime::from_secs(retain_until.timestamp()))
+                .build();
+
+            self.client
+                .put_object_retention()
+                .bucket(&self.bucket_name)
+                .key(path)
+                .retention(retention)
+                .send()
+                .await
+                .map_err(|err| {
+                    error!("Failed to apply object lock retention in S3: {err}");
+
+                    TermsOfUseError::InternalServerError
+                })?;
+        }
+
+        Ok(())
     }
 
     async fn delete_file(&self, path: &str) -> Result<()> {
+        let is_published = self
+            .get_tags(path)
+            .await?
+            .iter()
+            .any(|tag| tag.key() == TERM_ID_KEY);
+
+        if is_published {
+            error!("Refusing to delete published document from S3: {path}");
+
+            return Err(TermsOfUseError::InternalServerError);
+        }
+
         self.client
             .delete_object()
             .bucket(&self.bucket_name)
@@ -74,13 +215,224 @@ impl StorageService for S3Storage {
             self.bucket_name,
         ))
     }
+
+    async fn download_file(&self, path: &str, destination: &Path) -> Result<()> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket_name)
+            .key(path)
+            .send()
+            .await
+            .map_err(|err| {
+                error!("Failed to download file from S3: {path} ({err})");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+        let content = output.body.collect().await.map_err(|err| {
+            error!("Failed to read file from S3: {path} ({err})");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        fs::write(destination, content.into_bytes())
+            .await
+            .map_err(|err| {
+                error!("Failed to write {}: {err}", destination.display());
+
+                TermsOfUseError::InternalServerError
+            })
+    }
+
+    async fn create_upload_url(
+        &self,
+        group: &str,
+        version: u32,
+        content_type: &str,
+        size: u64,
+        sha256: &str,
+        expires_in: Duration,
+    ) -> Result<PresignedUpload> {
+        let key =
+            self.key_template
+                .render(group, version, file_extension(Path::new(""), content_type));
+
+        let checksum = sha256_to_base64(sha256).ok_or_else(|| {
+            error!("Invalid SHA-256 digest for direct upload: {sha256}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let presigning_config = PresigningConfig::expires_in(expires_in).map_err(|err| {
+            error!("Invalid presigning config: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        let tags = [
+            (GROUP_KEY, group.to_string()),
+            (VERSION_KEY, version.to_string()),
+            (CONTENT_HASH_KEY, sha256.to_string()),
+        ];
+
+        // Every header below is signed, so S3 rejects uploads of another size or content
+        let mut request = self
+            .client
+            .put_object()
+            .bucket(&self.bucket_name)
+            .key(&key)
+            .if_none_match("*")
+            .content_type(content_type)
+            .content_length(size as i64)
+            .checksum_sha256(checksum)
+            .tagging(encode_tags(&tags));
+
+        for (name, value) in &tags {
+            request = request.metadata(*name, value);
+        }
+
+        if let Some(ref kms_key_id) = self.kms_key_id {
+            request = request
+                .server_side_encryption(ServerSideEncryption::AwsKms)
+                .ssekms_key_id(kms_key_id);
+        }
+
+        let presigned = request.presigned(presigning_config).await.map_err(|err| {
+            error!("Failed to presign upload to S3: {err}");
+
+            TermsOfUseError::InternalServerError
+        })?;
+
+        Ok(PresignedUpload {
+            key,
+            method: presigned.method().to_string(),
+            url: presigned.uri().to_string(),
+            headers: presigned
+                .headers()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+        })
+    }
+
+    async fn get_file_info(&self, path: &str) -> Result<Option<StoredFileInfo>> {
+        let output = match self
+            .client
+            .head_object()
+            .bucket(&self.bucket_name)
+            .key(path)
+            .checksum_mode(ChecksumMode::Enabled)
+            .send()
+            .await
+        {
+            Ok(output) => output,
+            Err(err) if err.as_service_error().is_some_and(|err| err.is_not_found()) => {
+                return Ok(None);
+            }
+            Err(err) => {
+                error!("Failed to read file info from S3: {path} ({err})");
+
+                return Err(TermsOfUseError::InternalServerError);
+            }
+        };
+
+        Ok(Some(StoredFileInfo {
+            size: output.content_length().unwrap_or_default() as u64,
+            content_type: output.content_type().map(String::from),
+            // Multipart uploads carry a checksum of checksums, which is not the document digest
+            sha256: output.checksum_sha256().and_then(sha256_from_base64),
+        }))
+    }
+
+    fn copies(&self) -> usize {
+        1
+    }
+
+    fn lists_whole_bucket(&self) -> bool {
+        self.key_template.list_prefix().is_empty()
+    }
+
+    async fn list_files(&self) -> Result<Vec<StoredFile>> {
+        let mut files = Vec::new();
+
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket_name)
+            .prefix(self.key_template.list_prefix())
+            .into_paginator()
+            .send();
+
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(|err| {
+                error!("Failed to list files in S3: {err}");
+
+                TermsOfUseError::InternalServerError
+            })?;
+
+            for object in page.contents() {
+                let (Some(key), Some(last_modified)) = (object.key(), object.last_modified())
+                else {
+                    continue;
+                };
+
+                files.push(StoredFile {
+                    key: key.to_string(),
+                    last_modified: chrono::DateTime::from_timestamp(last_modified.secs(), 0)
+                        .unwrap_or_default()
+                        .naive_utc(),
+                });
+            }
+        }
+
+        Ok(files)
+    }
+}
+
+/// Encodes tags as the URL query string expected by the `x-amz-tagging` header.
+fn encode_tags(entries: &[(&str, String)]) -> String {
+    entries
+        .iter()
+        .map(|(name, value)| format!("{}={}", encode_tag(name), encode_tag(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Converts a hex SHA-256 digest to the base64 form of the `x-amz-checksum-sha256` header.
+fn sha256_to_base64(sha256: &str) -> Option<String> {
+    let bytes = (0..sha256.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(sha256.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+
+    (bytes.len() == 32).then(|| STANDARD.encode(bytes))
+}
+
+fn sha256_from_base64(checksum: &str) -> Option<String> {
+    let bytes = STANDARD.decode(checksum).ok()?;
+
+    (bytes.len() == 32).then(|| bytes.iter().map(|byte| format!("{byte:02x}")).collect())
+}
+
+fn encode_tag(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            }
+            _ => format!("%{byte:02X}"),
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::storage::{key::ObjectKeyTemplate, retention::RetentionPolicy};
     use aws_config::BehaviorVersion;
     use aws_credential_types::{Credentials, provider::SharedCredentialsProvider};
+    use aws_sdk_s3::types::ObjectLockRetentionMode;
     use aws_types::region::Region;
     use tokio::fs;
 
@@ -97,9 +449,23 @@ mod tests {
             bucket_name: "test-bucket".to_string(),
             client,
             endpoint_url: endpoint_url.map(String::from),
+            key_template: ObjectKeyTemplate::default(),
+            kms_key_id: None,
+            retention: RetentionPolicy::default(),
+            object_lock_mode: ObjectLockRetentionMode::Compliance,
         }
     }
 
+    #[test]
+    fn encodes_tags_as_query_string() {
+        let encoded = encode_tags(&[
+            ("group", "privacy policy".to_string()),
+            ("version", "2".to_string()),
+        ]);
+
+        assert_eq!(encoded, "group=privacy%20policy&version=2");
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn builds_url_with_custom_endpoint() {
@@ -134,7 +500,9 @@ mod tests {
         let temp_file = std::env::temp_dir().join("test-upload-fail.txt");
         fs::write(&temp_file, "test content").await.unwrap();
 
-        let result = storage.upload_file(&temp_file, "text/plain").await;
+        let result = storage
+            .upload_file(&temp_file, "text/plain", "privacy-policy", 1)
+            .await;
 
         fs::remove_file(&temp_file).await.ok();
         assert!(result.is_err());
@@ -159,7 +527,10 @@ mod tests {
         fs::write(&temp_file, b"Hello S3!").await.unwrap();
 
         // Upload file
-        let result = storage.upload_file(&temp_file, "text/plain").await.unwrap();
+        let result = storage
+            .upload_file(&temp_file, "text/plain", "privacy-policy", 1)
+            .await
+            .unwrap();
 
         assert!(
             result.ends_with(".txt"),
@@ -204,7 +575,7 @@ mod tests {
 
         // Upload file
         let result = storage
-            .upload_file(&temp_file, "application/pdf")
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
             .await
             .unwrap();
 
@@ -218,6 +589,212 @@ mod tests {
         storage.delete_file(&result).await.ok();
     }
 
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_not_overwrite_existing_deterministic_key() {
+        let mut storage = S3Storage::new().await;
+        storage.key_template = ObjectKeyTemplate::new("{group}/v{version}.{ext}", "").unwrap();
+
+        // Create bucket first
+        storage
+            .client
+            .create_bucket()
+            .bucket(&storage.bucket_name)
+            .send()
+            .await
+            .ok();
+
+        let temp_file = std::env::temp_dir().join("test-deterministic.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let group = format!("deterministic-{}", uuid::Uuid::new_v4());
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", &group, 1)
+            .await
+            .unwrap();
+
+        assert_eq!(key, format!("{group}/v1.pdf"));
+
+        let second_upload = storage
+            .upload_file(&temp_file, "application/pdf", &group, 1)
+            .await;
+
+        assert!(
+            second_upload.is_err(),
+            "Existing objects must not be overwritten"
+        );
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        storage.delete_file(&key).await.ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_store_metadata_and_tags() {
+        let storage = S3Storage::new().await;
+
+        // Create bucket first
+        storage
+            .client
+            .create_bucket()
+            .bucket(&storage.bucket_name)
+            .send()
+            .await
+            .ok();
+
+        let temp_file = std::env::temp_dir().join("test-metadata.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 3)
+            .await
+            .unwrap();
+
+        storage.publish_file(&key, 42).await.unwrap();
+
+        let head = storage
+            .client
+            .head_object()
+            .bucket(&storage.bucket_name)
+            .key(&key)
+            .send()
+            .await
+            .unwrap();
+
+        let metadata = head.metadata().unwrap();
+        assert_eq!(metadata.get("group").unwrap(), "privacy-policy");
+        assert_eq!(metadata.get("version").unwrap(), "3");
+        assert_eq!(
+            metadata.get("content_sha256").unwrap(),
+            &crate::storage::metadata::content_sha256(b"%PDF-1.4 test content")
+        );
+
+        let tagging = storage
+            .client
+            .get_object_tagging()
+            .bucket(&storage.bucket_name)
+            .key(&key)
+            .send()
+            .await
+            .unwrap();
+
+        let tags: Vec<(&str, &str)> = tagging
+            .tag_set()
+            .iter()
+            .map(|tag| (tag.key(), tag.value()))
+            .collect();
+
+        assert!(tags.contains(&("group", "privacy-policy")));
+        assert!(tags.contains(&("version", "3")));
+        assert!(tags.contains(&("term_id", "42")));
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        storage
+            .client
+            .delete_object()
+            .bucket(&storage.bucket_name)
+            .key(&key)
+            .send()
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_refuse_to_delete_published_file() {
+        let storage = S3Storage::new().await;
+
+        // Create bucket first
+        storage
+            .client
+            .create_bucket()
+            .bucket(&storage.bucket_name)
+            .send()
+            .await
+            .ok();
+
+        let temp_file = std::env::temp_dir().join("test-published.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        storage.publish_file(&key, 7).await.unwrap();
+
+        let result = storage.delete_file(&key).await;
+
+        assert!(result.is_err(), "Published documents must not be deleted");
+
+        let head_result = storage
+            .client
+            .head_object()
+            .bucket(&storage.bucket_name)
+            .key(&key)
+            .send()
+            .await;
+
+        assert!(head_result.is_ok(), "Published document should still exist");
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        storage
+            .client
+            .delete_object()
+            .bucket(&storage.bucket_name)
+            .key(&key)
+            .send()
+            .await
+            .ok();
+    }
+
+    #[tokio::test]
+    #[test_log::test]
+    async fn should_list_uploaded_files() {
+        let mut storage = S3Storage::new().await;
+        let prefix = format!("list-{}", uuid::Uuid::new_v4());
+        storage.key_template =
+            ObjectKeyTemplate::new("{prefix}/{uuid}.{ext}", prefix.as_str()).unwrap();
+
+        // Create bucket first
+        storage
+            .client
+            .create_bucket()
+            .bucket(&storage.bucket_name)
+            .send()
+            .await
+            .ok();
+
+        let temp_file = std::env::temp_dir().join("test-list.pdf");
+        fs::write(&temp_file, "%PDF-1.4 test content")
+            .await
+            .unwrap();
+
+        let key = storage
+            .upload_file(&temp_file, "application/pdf", "privacy-policy", 1)
+            .await
+            .unwrap();
+
+        let files = storage.list_files().await.unwrap();
+
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].key, key);
+
+        // Clean up
+        fs::remove_file(&temp_file).await.ok();
+        storage.delete_file(&key).await.ok();
+    }
+
     #[tokio::test]
     #[test_log::test]
     async fn should_delete_file_successfully() {
@@ -238,7 +815,10 @@ mod tests {
             .await
             .unwrap();
 
-        let key = storage.upload_file(&temp_file, "text/plain").await.unwrap();
+        let key = storage
+            .upload_file(&temp_file, "text/plain", "privacy-policy", 1)
+            .await
+            .unwrap();
 
         storage.delete_file(&key).await.unwrap();
 
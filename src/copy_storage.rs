@@ -0,0 +1,86 @@
+use domain::{
+    data::{
+        DatabaseRepositoryWithHealthCheck, StorageServiceWithHealthCheck, service::DocumentService,
+    },
+    use_cases::copy_storage_use_case,
+};
+
+const USAGE: &str = "Usage: terms-of-use copy-storage <source> <destination> [--dry-run]";
+
+/// Reads the source and destination backend names, e.g. `gcloud` and `s3`.
+pub fn backends() -> (String, String) {
+    let mut names = std::env::args()
+        .skip(2)
+        .filter(|arg| !arg.starts_with("--"));
+
+    match (names.next(), names.next()) {
+        (Some(source), Some(destination)) => (source, destination),
+        _ => panic!("{USAGE}"),
+    }
+}
+
+/// Copies every term document from `source` to `destination`.
+///
+/// Invoked as `terms-of-use copy-storage <source> <destination> [--dry-run]`. With
+/// `--dry-run` the documents to copy are only listed. Running the command again
+/// resumes an interrupted copy. Returns the process exit code, non-zero when a
+/// document could not be copied.
+pub async fn run(
+    repository: &dyn DatabaseRepositoryWithHealthCheck,
+    source: &dyn StorageServiceWithHealthCheck,
+    destination: &dyn StorageServiceWithHealthCheck,
+    documents: &dyn DocumentService,
+) -> i32 {
+    let dry_run = std::env::args().any(|arg| arg == "--dry-run");
+
+    let work_dir = std::env::temp_dir().join("terms-of-use-copy");
+    if let Err(err) = std::fs::create_dir_all(&work_dir) {
+        eprintln!(
+            "Failed to create working directory {}: {err}",
+            work_dir.display()
+        );
+
+        return 1;
+    }
+
+    let report = match copy_storage_use_case(
+        repository,
+        source,
+        destination,
+        documents,
+        &work_dir,
+        dry_run,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Copy failed: {err:?}");
+
+            return 1;
+        }
+    };
+
+    for document in &report.copied_documents {
+        println!(
+            "{} document of term {}: {}",
+            if dry_run { "Would copy" } else { "Copied" },
+            document.term_id,
+            document.key
+        );
+    }
+
+    for term in &report.failed_terms {
+        println!("Failed to copy document of term {}: {}", term.id, term.url);
+    }
+
+    println!(
+        "{} document(s) {}, {} already in the destination, {} failed",
+        report.copied_documents.len(),
+        if dry_run { "to copy" } else { "copied" },
+        report.skipped_terms.len(),
+        report.failed_terms.len()
+    );
+
+    if report.failed_terms.is_empty() { 0 } else { 1 }
+}
@@ -2,7 +2,7 @@ use std::{error::Error, sync::Arc};
 
 use domain::data::{
     CacheServiceWithHealthCheck, DatabaseRepositoryWithHealthCheck,
-    PublisherServiceWithHealthCheck, StorageServiceWithHealthCheck,
+    PublisherServiceWithHealthCheck, ScannerServiceWithHealthCheck, StorageServiceWithHealthCheck,
 };
 use dotenvy::dotenv;
 use inbound::Config;
@@ -10,6 +10,9 @@ use inbound::Config;
 #[global_allocator]
 static GLOBAL: jemallocator::Jemalloc = jemallocator::Jemalloc;
 
+mod copy_storage;
+mod publish_documents;
+mod reconcile;
 mod telemetry;
 
 #[cfg(all(
@@ -19,9 +22,6 @@ mod telemetry;
 ))]
 compile_error!("Features 'dynamodb' and 'postgres' cannot be enabled at the same time.");
 
-#[cfg(all(feature = "s3", feature = "gcloud", not(any(test, clippy, rustfmt))))]
-compile_error!("Multiple storage features enabled. Please enable only one: 's3' or 'gcloud'.");
-
 #[cfg(all(feature = "redis", feature = "valkey", not(any(test, clippy, rustfmt))))]
 compile_error!("Features 'redis' and 'valkey' cannot be enabled at the same time.");
 
@@ -54,14 +54,88 @@ async fn get_publisher() -> impl PublisherServiceWithHealthCheck {
     return outbound::NoopPublisher::new().await;
 }
 
-async fn get_storage() -> Arc<dyn StorageServiceWithHealthCheck> {
+async fn get_scanner() -> impl ScannerServiceWithHealthCheck {
+    #[cfg(feature = "clamd")]
+    return outbound::ClamdScanner::new().await;
+    #[cfg(not(feature = "clamd"))]
+    return outbound::NoopScanner::new().await;
+}
+
+/// Storage backends compiled into this binary.
+const STORAGE_BACKENDS: &[&str] = &[
     #[cfg(feature = "s3")]
-    return Arc::new(outbound::S3Storage::new().await);
+    "s3",
     #[cfg(feature = "gcloud")]
-    return Arc::new(outbound::GoogleCloudStorage::new().await);
+    "gcloud",
+    #[cfg(feature = "azure")]
+    "azure",
+    #[cfg(feature = "filesystem")]
+    "filesystem",
+];
+
+#[cfg_attr(not(feature = "s3"), allow(unused_variables))]
+async fn build_storage(backend: &str, env_prefix: &str) -> Arc<dyn StorageServiceWithHealthCheck> {
+    #[cfg(feature = "s3")]
+    if backend == "s3" {
+        return Arc::new(outbound::S3Storage::with_env_prefix(env_prefix).await);
+    }
+    #[cfg(feature = "gcloud")]
+    if backend == "gcloud" {
+        return Arc::new(outbound::GoogleCloudStorage::new().await);
+    }
+    #[cfg(feature = "azure")]
+    if backend == "azure" {
+        return Arc::new(outbound::AzureBlobStorage::new().await);
+    }
+    #[cfg(feature = "filesystem")]
+    if backend == "filesystem" {
+        return Arc::new(outbound::FilesystemStorage::new().await);
+    }
+
+    panic!("Storage backend '{backend}' is not enabled. Available: {STORAGE_BACKENDS:?}");
+}
 
-    #[cfg(not(any(feature = "s3", feature = "gcloud", test, clippy, rustfmt)))]
-    compile_error!("No storage feature enabled. Please enable at least one: 's3' or 'gcloud'.");
+async fn get_storage() -> Arc<dyn StorageServiceWithHealthCheck> {
+    #[cfg(not(any(
+        feature = "s3",
+        feature = "gcloud",
+        feature = "azure",
+        feature = "filesystem",
+        test,
+        clippy,
+        rustfmt
+    )))]
+    compile_error!(
+        "No storage feature enabled. Please enable at least one: 's3', 'gcloud', 'azure' or 'filesystem'."
+    );
+
+    // With several backends compiled in, the primary has to be chosen explicitly
+    let primary = std::env::var("STORAGE_PRIMARY").unwrap_or_else(|_| match STORAGE_BACKENDS {
+        [backend] => backend.to_string(),
+        _ => panic!("STORAGE_PRIMARY must be set to one of {STORAGE_BACKENDS:?}"),
+    });
+
+    let storage = build_storage(&primary, "").await;
+
+    let Ok(secondary) = std::env::var("STORAGE_SECONDARY") else {
+        return storage;
+    };
+
+    Arc::new(outbound::MirrorStorage::new(
+        storage,
+        build_storage(&secondary, secondary_env_prefix(&primary, &secondary)).await,
+    ))
+}
+
+/// Only S3 can be paired with itself, reading the second bucket from `SECONDARY_` variables.
+fn secondary_env_prefix(primary: &str, secondary: &str) -> &'static str {
+    if secondary != primary {
+        ""
+    } else if secondary == "s3" {
+        "SECONDARY_"
+    } else {
+        panic!("The second storage backend must differ from the first for '{primary}'");
+    }
 }
 
 #[tokio::main]
@@ -72,11 +146,61 @@ async fn main() -> Result<(), impl Error> {
     let _provider = telemetry::init_telemetry();
 
     let repository = get_repository().await;
-    let cache = get_cache().await;
+
+    if std::env::args().nth(1).as_deref() == Some("copy-storage") {
+        let (from, to) = copy_storage::backends();
+
+        let source = build_storage(&from, "").await;
+        let destination = build_storage(&to, secondary_env_prefix(&from, &to)).await;
+
+        let exit_code = copy_storage::run(
+            repository.as_ref(),
+            source.as_ref(),
+            destination.as_ref(),
+            &outbound::DocumentProcessor,
+        )
+        .await;
+
+        #[cfg(feature = "otel")]
+        drop(_provider);
+
+        std::process::exit(exit_code);
+    }
+
     let storage = get_storage().await;
-    let publisher = get_publisher().await;
 
-    let config = Config::new(repository, Arc::new(cache), storage, Arc::new(publisher)).await;
+    if std::env::args().nth(1).as_deref() == Some("reconcile") {
+        let exit_code = reconcile::run(repository.as_ref(), storage.as_ref()).await;
+
+        #[cfg(feature = "otel")]
+        drop(_provider);
+
+        std::process::exit(exit_code);
+    }
+
+    if std::env::args().nth(1).as_deref() == Some("publish-documents") {
+        let exit_code = publish_documents::run(repository.as_ref(), storage.as_ref()).await;
+
+        #[cfg(feature = "otel")]
+        drop(_provider);
+
+        std::process::exit(exit_code);
+    }
+
+    let cache = get_cache().await;
+    let publisher = get_publisher().await;
+    let scanner = get_scanner().await;
+
+    let config = Config::new(
+        repository,
+        Arc::new(cache),
+        storage,
+        Arc::new(publisher),
+        Arc::new(scanner),
+        Arc::new(outbound::DocumentProcessor),
+        Arc::new(outbound::JsonSchemaValidator),
+    )
+    .await;
 
     #[cfg(feature = "actix-web")]
     return inbound::start_actix_server(config).await;
@@ -0,0 +1,38 @@
+use domain::{
+    data::{DatabaseRepositoryWithHealthCheck, StorageServiceWithHealthCheck},
+    use_cases::publish_documents_use_case,
+};
+
+/// Publishes the document of every term, so documents stored before publishing
+/// existed are protected too.
+///
+/// Invoked as `terms-of-use publish-documents`. Returns the process exit code,
+/// non-zero when a document could not be published.
+pub async fn run(
+    repository: &dyn DatabaseRepositoryWithHealthCheck,
+    storage: &dyn StorageServiceWithHealthCheck,
+) -> i32 {
+    let report = match publish_documents_use_case(repository, storage).await {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Publishing failed: {err:?}");
+
+            return 1;
+        }
+    };
+
+    for term in &report.failed_terms {
+        println!(
+            "Failed to publish document of term {} ({} v{}): {}",
+            term.id, term.group, term.version, term.url
+        );
+    }
+
+    println!(
+        "{} document(s) published, {} failed",
+        report.published_terms.len(),
+        report.failed_terms.len()
+    );
+
+    if report.failed_terms.is_empty() { 0 } else { 1 }
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use domain::{
+    data::{DatabaseRepositoryWithHealthCheck, StorageServiceWithHealthCheck},
+    use_cases::reconcile_storage_use_case,
+};
+
+const DEFAULT_GRACE_PERIOD_HOURS: &str = "24";
+
+/// Compares the stored documents with the terms in the repository.
+///
+/// Invoked as `terms-of-use reconcile [--delete]`. Orphans are only reported unless
+/// `--delete` is passed, which is refused when the whole bucket is listed. Files
+/// younger than `RECONCILE_GRACE_PERIOD_HOURS` are skipped. Returns the process exit
+/// code, non-zero when a term lost its document.
+pub async fn run(
+    repository: &dyn DatabaseRepositoryWithHealthCheck,
+    storage: &dyn StorageServiceWithHealthCheck,
+) -> i32 {
+    let delete_orphans = std::env::args().any(|arg| arg == "--delete");
+
+    let grace_period_hours: u64 = std::env::var("RECONCILE_GRACE_PERIOD_HOURS")
+        .unwrap_or_else(|_| DEFAULT_GRACE_PERIOD_HOURS.to_string())
+        .parse()
+        .expect("RECONCILE_GRACE_PERIOD_HOURS must be a valid u64");
+
+    let report = match reconcile_storage_use_case(
+        repository,
+        storage,
+        Duration::from_secs(grace_period_hours * 60 * 60),
+        delete_orphans,
+    )
+    .await
+    {
+        Ok(report) => report,
+        Err(err) => {
+            eprintln!("Reconciliation failed: {err:?}");
+
+            return 1;
+        }
+    };
+
+    for key in &report.orphaned_files {
+        if report.deleted_files.contains(key) {
+            println!("Deleted orphaned file: {key}");
+        } else {
+            println!("Orphaned file: {key}");
+        }
+    }
+
+    for term in &report.missing_documents {
+        println!(
+            "Missing document for term {} ({} v{}): {}",
+            term.id, term.group, term.version, term.url
+        );
+    }
+
+    println!(
+        "{} orphaned file(s), {} deleted, {} term(s) with a missing document",
+        report.orphaned_files.len(),
+        report.deleted_files.len(),
+        report.missing_documents.len()
+    );
+
+    if report.missing_documents.is_empty() {
+        0
+    } else {
+        1
+    }
+}